@@ -0,0 +1,83 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Resolution of ambiguous timezone abbreviations.
+//!
+//! Some abbreviations name different timezones in different regions, e.g.,
+//! "CST" is both US Central Standard Time and China Standard Time. GNU `date`
+//! resolves each of them to a single timezone; an [`AbbreviationPolicy`]
+//! configured on a [`Parser`](crate::Parser) can change that.
+//!
+//! An abbreviation also stands for a fixed offset, even if its timezone
+//! observes daylight saving time. A [`ZoneResolution`] can check or compute the
+//! offset from the rules of the timezone instead.
+
+/// A region of the world, used to pick one of the timezones an ambiguous
+/// abbreviation may name.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Region {
+    /// Africa, e.g., "WAT" is West Africa Time.
+    Africa,
+    /// North and South America, e.g., "CST" is Central Standard Time.
+    America,
+    /// Asia, e.g., "CST" is China Standard Time.
+    Asia,
+    /// Australia, e.g., "EST" is Eastern Standard Time.
+    Australia,
+    /// Europe, e.g., "IST" is Irish Standard Time.
+    Europe,
+    /// The Pacific islands, e.g., "SST" is Samoa Standard Time.
+    Pacific,
+}
+
+/// How to resolve a timezone abbreviation that names different timezones in
+/// different regions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum AbbreviationPolicy {
+    /// Use the built-in (GNU compatible) interpretation, e.g., "CST" is US
+    /// Central Standard Time.
+    #[default]
+    FirstMatch,
+    /// Reject ambiguous abbreviations.
+    ErrorOnAmbiguous,
+    /// Use the interpretation of the given region, if it has one, and the
+    /// built-in interpretation otherwise, e.g., "CST" is China Standard Time
+    /// with [`Region::Asia`].
+    PreferRegion(Region),
+}
+
+/// The timezone abbreviations that name different timezones in different
+/// regions, with the offset of each interpretation.
+pub(crate) const AMBIGUOUS: &[(&str, &[(Region, &str)])] = &[
+    ("bst", &[(Region::Asia, "+6"), (Region::Europe, "+1")]),
+    ("cst", &[(Region::America, "-6"), (Region::Asia, "+8")]),
+    (
+        "est",
+        &[(Region::America, "-5"), (Region::Australia, "+10")],
+    ),
+    ("ist", &[(Region::Asia, "+5:30"), (Region::Europe, "+1")]),
+    ("mst", &[(Region::America, "-7"), (Region::Asia, "+8")]),
+    ("pst", &[(Region::America, "-8"), (Region::Asia, "+8")]),
+    ("sst", &[(Region::Pacific, "-11"), (Region::Asia, "+8")]),
+];
+
+/// How to interpret a timezone abbreviation that stands for a timezone
+/// observing daylight saving time, e.g., "EST" and "EDT" for
+/// America/New_York.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ZoneResolution {
+    /// Use the fixed offset of the abbreviation, e.g., "EST" is always -05:00.
+    #[default]
+    FixedOffset,
+    /// Use the fixed offset of the abbreviation, but reject it if the zone
+    /// does not observe that offset at the given date and time, e.g.,
+    /// "2025-07-01 10:00 EST".
+    Verify,
+    /// Use the offset the zone observes at the given date and time, whether
+    /// the abbreviation names standard or daylight saving time, e.g.,
+    /// "2025-07-01 10:00 EST" is 10:00 -04:00 in America/New_York.
+    Compute,
+}
@@ -0,0 +1,384 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore nisan iyar iyyar sivan tammuz tamuz elul tishrei tishri cheshvan heshvan marheshvan
+// spell-checker:ignore marcheshvan kislev tevet shevat shvat muharram safar rabi awwal thani jumada ula
+// spell-checker:ignore akhirah rajab shaban sha'ban shawwal qadah qa'dah hijjah farvardin ordibehesht
+// spell-checker:ignore khordad mordad shahrivar mehr aban azar bahman esfand
+
+//! Non-Gregorian calendars.
+//!
+//! When a [`Calendar`] is selected on a [`Parser`](crate::Parser), dates
+//! written as a day, a month name and a year of that calendar (e.g., "10
+//! Tishrei 5785" or "15 Farvardin 1403") are accepted and converted to the
+//! Gregorian calendar.
+//!
+//! The conversions follow the arithmetic rules of each calendar: the Hebrew
+//! calendar is fully arithmetic, the Islamic calendar is the tabular (civil)
+//! variant, which may differ by a day or two from calendars based on the
+//! observation of the moon, and the Persian calendar uses the 33-year cycle
+//! breaks of the Solar Hijri calendar, valid for the years 1 to 3177.
+
+use jiff::{civil::Date, Span};
+
+/// A calendar in which dates may be written.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Calendar {
+    /// The Hebrew calendar, e.g., "10 Tishrei 5785".
+    Hebrew,
+    /// The tabular Islamic calendar, e.g., "1 Ramadan 1446".
+    Islamic,
+    /// The Persian (Solar Hijri) calendar, e.g., "15 Farvardin 1403".
+    Persian,
+}
+
+impl Calendar {
+    /// The month names of the calendar with their month numbers. Names
+    /// sharing a prefix are listed longest first.
+    pub(crate) fn month_names(self) -> &'static [(&'static str, u8)] {
+        match self {
+            Calendar::Hebrew => &[
+                ("nisan", 1),
+                ("iyyar", 2),
+                ("iyar", 2),
+                ("sivan", 3),
+                ("tammuz", 4),
+                ("tamuz", 4),
+                ("av", 5),
+                ("elul", 6),
+                ("tishrei", 7),
+                ("tishri", 7),
+                ("marcheshvan", 8),
+                ("marheshvan", 8),
+                ("cheshvan", 8),
+                ("heshvan", 8),
+                ("kislev", 9),
+                ("tevet", 10),
+                ("shevat", 11),
+                ("shvat", 11),
+                ("adar ii", 13),
+                ("adar i", 12),
+                ("adar", 12),
+            ],
+            Calendar::Islamic => &[
+                ("muharram", 1),
+                ("safar", 2),
+                ("rabi al-awwal", 3),
+                ("rabi al-thani", 4),
+                ("rabi ii", 4),
+                ("rabi i", 3),
+                ("jumada al-awwal", 5),
+                ("jumada al-ula", 5),
+                ("jumada al-thani", 6),
+                ("jumada al-akhirah", 6),
+                ("jumada ii", 6),
+                ("jumada i", 5),
+                ("rajab", 7),
+                ("sha'ban", 8),
+                ("shaban", 8),
+                ("ramadan", 9),
+                ("shawwal", 10),
+                ("dhu al-qa'dah", 11),
+                ("dhu al-qadah", 11),
+                ("dhu al-hijjah", 12),
+            ],
+            Calendar::Persian => &[
+                ("farvardin", 1),
+                ("ordibehesht", 2),
+                ("khordad", 3),
+                ("tir", 4),
+                ("mordad", 5),
+                ("shahrivar", 6),
+                ("mehr", 7),
+                ("aban", 8),
+                ("azar", 9),
+                ("dey", 10),
+                ("bahman", 11),
+                ("esfand", 12),
+            ],
+        }
+    }
+
+    /// Convert a date of the calendar to a Gregorian date, or return `None`
+    /// if the date does not exist.
+    pub(crate) fn to_gregorian(self, year: u16, month: u8, day: u8) -> Option<Date> {
+        let fixed = match self {
+            Calendar::Hebrew => hebrew::fixed(year, month, day)?,
+            Calendar::Islamic => islamic::fixed(year, month, day)?,
+            Calendar::Persian => persian::fixed(year, month, day)?,
+        };
+        from_fixed(fixed)
+    }
+}
+
+/// Convert a fixed day number (day 1 is 0001-01-01 in the proleptic
+/// Gregorian calendar) to a date.
+fn from_fixed(fixed: i64) -> Option<Date> {
+    let days = Span::new().try_days(fixed - 1).ok()?;
+    jiff::civil::date(1, 1, 1).checked_add(days).ok()
+}
+
+/// Convert a date to a fixed day number.
+fn to_fixed(date: Date) -> i64 {
+    i64::from(
+        date.since(jiff::civil::date(1, 1, 1))
+            .unwrap_or_default()
+            .get_days(),
+    ) + 1
+}
+
+mod hebrew {
+    /// The fixed day number of 1 Tishrei of year 1.
+    const EPOCH: i64 = -1_373_427;
+
+    fn is_leap_year(year: i64) -> bool {
+        (7 * year + 1).rem_euclid(19) < 7
+    }
+
+    fn last_month(year: i64) -> u8 {
+        if is_leap_year(year) {
+            13
+        } else {
+            12
+        }
+    }
+
+    /// The number of days from the epoch to the molad of Tishrei, delayed
+    /// when it falls on a Sunday, a Wednesday or a Friday.
+    fn elapsed_days(year: i64) -> i64 {
+        let months = (235 * year - 234).div_euclid(19);
+        let parts = 12084 + 13753 * months;
+        let days = 29 * months + parts.div_euclid(25920);
+        if (3 * (days + 1)).rem_euclid(7) < 3 {
+            days + 1
+        } else {
+            days
+        }
+    }
+
+    /// The additional delay of the new year keeping year lengths valid.
+    fn new_year_delay(year: i64) -> i64 {
+        let (ny0, ny1, ny2) = (
+            elapsed_days(year - 1),
+            elapsed_days(year),
+            elapsed_days(year + 1),
+        );
+        if ny2 - ny1 == 356 {
+            2
+        } else if ny1 - ny0 == 382 {
+            1
+        } else {
+            0
+        }
+    }
+
+    fn new_year(year: i64) -> i64 {
+        EPOCH + elapsed_days(year) + new_year_delay(year)
+    }
+
+    fn days_in_month(year: i64, month: u8) -> i64 {
+        let days_in_year = new_year(year + 1) - new_year(year);
+        let short = match month {
+            2 | 4 | 6 | 10 | 13 => true,
+            8 => !matches!(days_in_year, 355 | 385),
+            9 => matches!(days_in_year, 353 | 383),
+            12 => !is_leap_year(year),
+            _ => false,
+        };
+        if short {
+            29
+        } else {
+            30
+        }
+    }
+
+    pub(super) fn fixed(year: u16, month: u8, day: u8) -> Option<i64> {
+        let year = i64::from(year);
+        if year < 1 || month < 1 || month > last_month(year) {
+            return None;
+        }
+        if day < 1 || i64::from(day) > days_in_month(year, month) {
+            return None;
+        }
+
+        // The year starts in Tishrei, the seventh month.
+        let months: Vec<u8> = if month < 7 {
+            (7..=last_month(year)).chain(1..month).collect()
+        } else {
+            (7..month).collect()
+        };
+        let elapsed: i64 = months.into_iter().map(|m| days_in_month(year, m)).sum();
+
+        Some(new_year(year) + elapsed + i64::from(day) - 1)
+    }
+}
+
+mod islamic {
+    /// The fixed day number of 1 Muharram of year 1.
+    const EPOCH: i64 = 227_015;
+
+    fn is_leap_year(year: i64) -> bool {
+        (14 + 11 * year).rem_euclid(30) < 11
+    }
+
+    pub(super) fn fixed(year: u16, month: u8, day: u8) -> Option<i64> {
+        let year = i64::from(year);
+        let days_in_month = match month {
+            12 if is_leap_year(year) => 30,
+            1..=12 if month % 2 == 1 => 30,
+            1..=12 => 29,
+            _ => return None,
+        };
+        if year < 1 || day < 1 || day > days_in_month {
+            return None;
+        }
+
+        let month = i64::from(month);
+        Some(
+            EPOCH - 1
+                + (year - 1) * 354
+                + (3 + 11 * year).div_euclid(30)
+                + 29 * (month - 1)
+                + (6 * month - 1).div_euclid(11)
+                + i64::from(day),
+        )
+    }
+}
+
+mod persian {
+    use super::to_fixed;
+
+    /// The years at which the 33-year leap cycle of the calendar is broken.
+    const BREAKS: [i64; 20] = [
+        -61, 9, 38, 199, 426, 686, 756, 818, 1111, 1181, 1210, 1635, 2060, 2097, 2192, 2262, 2324,
+        2394, 2456, 3178,
+    ];
+
+    /// Return whether `year` is a leap year, and the day of March on which it
+    /// starts.
+    fn new_year(year: i64) -> Option<(bool, i8)> {
+        if year < 1 || year >= BREAKS[BREAKS.len() - 1] {
+            return None;
+        }
+
+        let gregorian_year = year + 621;
+        let mut leap_years = -14;
+        let mut previous = BREAKS[0];
+        let mut jump = 0;
+        for &brk in &BREAKS[1..] {
+            jump = brk - previous;
+            if year < brk {
+                break;
+            }
+            leap_years += jump / 33 * 8 + jump % 33 / 4;
+            previous = brk;
+        }
+
+        let mut n = year - previous;
+        leap_years += n / 33 * 8 + (n % 33 + 3) / 4;
+        if jump % 33 == 4 && jump - n == 4 {
+            leap_years += 1;
+        }
+
+        let gregorian_leap_years = gregorian_year / 4 - (gregorian_year / 100 + 1) * 3 / 4 - 150;
+        let march = 20 + leap_years - gregorian_leap_years;
+
+        if jump - n < 6 {
+            n = n - jump + (jump + 4) / 33 * 33;
+        }
+        let leap = ((n + 1) % 33 - 1) % 4 == 0;
+
+        Some((leap, i8::try_from(march).ok()?))
+    }
+
+    pub(super) fn fixed(year: u16, month: u8, day: u8) -> Option<i64> {
+        let year = i64::from(year);
+        let (leap, march) = new_year(year)?;
+        let days_in_month = match month {
+            1..=6 => 31,
+            7..=11 => 30,
+            12 if leap => 30,
+            12 => 29,
+            _ => return None,
+        };
+        if day < 1 || day > days_in_month {
+            return None;
+        }
+
+        let start = jiff::civil::Date::new(i16::try_from(year + 621).ok()?, 3, march).ok()?;
+        let month = i64::from(month);
+        let elapsed = (month - 1) * 31 - (month / 7) * (month - 7);
+        Some(to_fixed(start) + elapsed + i64::from(day) - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::Calendar;
+
+    #[test]
+    fn hebrew() {
+        for (year, month, day, expected) in [
+            (5785, 7, 1, date(2024, 10, 3)),
+            (5785, 7, 10, date(2024, 10, 12)),
+            (5785, 1, 15, date(2025, 4, 13)),
+            (5784, 13, 14, date(2024, 3, 24)),
+            (5784, 9, 25, date(2023, 12, 8)),
+            (5784, 7, 1, date(2023, 9, 16)),
+        ] {
+            assert_eq!(
+                Calendar::Hebrew.to_gregorian(year, month, day),
+                Some(expected),
+                "{year}-{month}-{day}"
+            );
+        }
+
+        // 5785 is not a leap year.
+        assert_eq!(Calendar::Hebrew.to_gregorian(5785, 13, 1), None);
+        // Iyyar has 29 days.
+        assert_eq!(Calendar::Hebrew.to_gregorian(5785, 2, 30), None);
+    }
+
+    #[test]
+    fn islamic() {
+        for (year, month, day, expected) in [
+            (1, 1, 1, date(622, 7, 19)),
+            (1446, 9, 1, date(2025, 3, 1)),
+            (1445, 10, 1, date(2024, 4, 10)),
+        ] {
+            assert_eq!(
+                Calendar::Islamic.to_gregorian(year, month, day),
+                Some(expected),
+                "{year}-{month}-{day}"
+            );
+        }
+
+        assert_eq!(Calendar::Islamic.to_gregorian(1446, 2, 30), None);
+        assert_eq!(Calendar::Islamic.to_gregorian(1446, 13, 1), None);
+    }
+
+    #[test]
+    fn persian() {
+        for (year, month, day, expected) in [
+            (1403, 1, 1, date(2024, 3, 20)),
+            (1403, 1, 15, date(2024, 4, 3)),
+            (1402, 12, 29, date(2024, 3, 19)),
+            (1403, 7, 1, date(2024, 9, 22)),
+            (1403, 12, 30, date(2025, 3, 20)),
+            (1404, 1, 1, date(2025, 3, 21)),
+        ] {
+            assert_eq!(
+                Calendar::Persian.to_gregorian(year, month, day),
+                Some(expected),
+                "{year}-{month}-{day}"
+            );
+        }
+
+        // 1402 is not a leap year.
+        assert_eq!(Calendar::Persian.to_gregorian(1402, 12, 30), None);
+        assert_eq!(Calendar::Persian.to_gregorian(1403, 7, 31), None);
+    }
+}
@@ -0,0 +1,73 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Compatibility cases with GNU `date`.
+//!
+//! The cases are the examples of the GNU manual and other common inputs, with
+//! the output of GNU `date` for a fixed base date and time. They are run by the
+//! `compat` integration test when the `compat-tests` feature is enabled, and
+//! are available to applications wrapping the parser (e.g., to check that
+//! their options do not break compatibility).
+
+use jiff::{tz::TimeZone, Zoned};
+
+/// The format of [`Case::expected`], that of `date --rfc-3339=seconds`.
+pub const FORMAT: &str = "%Y-%m-%d %H:%M:%S%:z";
+
+const GNU: &str = include_str!("gnu.txt");
+
+/// A compatibility case with GNU `date`.
+#[derive(Clone, Debug)]
+pub struct Case {
+    /// The line of the case in the corpus.
+    pub line: usize,
+    /// The input string.
+    pub input: &'static str,
+    /// The base date and time the input is parsed against.
+    pub base: Zoned,
+    /// The output of GNU `date` in UTC, formatted with [`FORMAT`], or `None`
+    /// if GNU `date` rejects the input.
+    pub expected: Option<&'static str>,
+    /// Whether the case is a known difference from GNU `date`.
+    pub known_difference: bool,
+}
+
+impl Case {
+    /// Whether `result`, the result of parsing [`input`](Self::input), is the
+    /// output of GNU `date`.
+    pub fn matches<E>(&self, result: &Result<Zoned, E>) -> bool {
+        let actual = result.as_ref().ok().map(|dt| {
+            dt.with_time_zone(TimeZone::UTC)
+                .strftime(FORMAT)
+                .to_string()
+        });
+        actual.as_deref() == self.expected
+    }
+}
+
+/// Returns the compatibility cases with GNU `date`.
+pub fn cases() -> Vec<Case> {
+    let mut base = None;
+    let mut cases = Vec::new();
+    for (i, line) in GNU.lines().enumerate() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(date) = line.strip_prefix("base = ") {
+            base = Some(date.parse::<Zoned>().expect("invalid base"));
+            continue;
+        }
+        let mut columns = line.split('\t');
+        let (Some(input), Some(expected)) = (columns.next(), columns.next()) else {
+            panic!("invalid case on line {}", i + 1);
+        };
+        cases.push(Case {
+            line: i + 1,
+            input,
+            base: base.clone().expect("case before any base"),
+            expected: (expected != "-").then_some(expected),
+            known_difference: columns.next() == Some("!"),
+        });
+    }
+    cases
+}
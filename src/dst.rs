@@ -0,0 +1,137 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Resolution of local times around daylight saving time transitions.
+//!
+//! A local time may not exist in a timezone (e.g., 02:30 on the day clocks
+//! move forward from 02:00 to 03:00) or may exist twice (e.g., 01:30 on the
+//! day clocks move back from 02:00 to 01:00). A [`DstResolution`] configured
+//! on a [`Parser`](crate::Parser) selects how such a time is resolved.
+
+use jiff::{
+    civil,
+    tz::{AmbiguousOffset, Offset, TimeZone},
+    Span, Zoned,
+};
+
+/// How to resolve a local time that falls into a gap or a fold of its
+/// timezone.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DstResolution {
+    /// Use the earlier of the two candidate instants: in a gap, the time is
+    /// read with the offset in effect after the transition (e.g., 02:30 is
+    /// 01:30 -05:00 in America/New_York); in a fold, the first occurrence.
+    Earliest,
+    /// Use the later of the two candidate instants: in a gap, the time is
+    /// read with the offset in effect before the transition (e.g., 02:30 is
+    /// 03:30 -04:00 in America/New_York); in a fold, the second occurrence.
+    Latest,
+    /// Move a time in a gap forward by the length of the gap, and use the
+    /// first occurrence of a time in a fold, unless the date or time of day
+    /// replaces that of a base date with the offset of the second occurrence.
+    #[default]
+    Shift,
+    /// Reject a time in a gap or in a fold.
+    Error,
+}
+
+/// Whether the local time of a parsed date was skipped or repeated by a
+/// daylight saving time transition, as returned by
+/// [`Parser::parse_datetime_with_dst`](crate::Parser::parse_datetime_with_dst).
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use parse_datetime::{DstAmbiguity, Parser};
+///
+/// let base = "2025-06-15T10:00:00-04:00[America/New_York]".parse::<Zoned>().unwrap();
+/// let (dt, dst) = Parser::new()
+///     .parse_datetime_at_date_with_dst(base, "2025-11-02 01:30")
+///     .unwrap();
+/// assert_eq!(dt.offset().seconds(), -4 * 3600);
+/// let DstAmbiguity::Repeated { alternative } = dst else {
+///     panic!("01:30 is repeated on 2025-11-02");
+/// };
+/// assert_eq!(alternative.offset().seconds(), -5 * 3600);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DstAmbiguity {
+    /// The local time exists exactly once, or the date has a fixed offset.
+    #[default]
+    None,
+    /// The local time does not exist, because it falls into the gap of a
+    /// transition to daylight saving time.
+    Skipped {
+        /// The other candidate for the local time, read with the offset the
+        /// result was not read with.
+        alternative: Zoned,
+    },
+    /// The local time exists twice, because it falls into the fold of a
+    /// transition back to standard time.
+    Repeated {
+        /// The occurrence of the local time that was not chosen.
+        alternative: Zoned,
+    },
+}
+
+impl DstAmbiguity {
+    /// Move the alternative resolution by an absolute `span`, as the resolved
+    /// date was.
+    pub(crate) fn checked_add(self, span: Span) -> Result<Self, jiff::Error> {
+        Ok(match self {
+            Self::None => Self::None,
+            Self::Skipped { alternative } => Self::Skipped {
+                alternative: alternative.checked_add(span)?,
+            },
+            Self::Repeated { alternative } => Self::Repeated {
+                alternative: alternative.checked_add(span)?,
+            },
+        })
+    }
+}
+
+impl DstResolution {
+    /// Resolve the local time `dt` in the timezone `tz`, and return whether it
+    /// was ambiguous. With [`DstResolution::Shift`], a time in a fold keeps the
+    /// offset `keep` if it is one of the two.
+    pub(crate) fn resolve(
+        self,
+        dt: civil::DateTime,
+        tz: TimeZone,
+        keep: Option<Offset>,
+    ) -> Result<(Zoned, DstAmbiguity), jiff::Error> {
+        let ambiguous = tz.to_ambiguous_zoned(dt);
+        let zoned = match self {
+            Self::Earliest => ambiguous.clone().earlier(),
+            Self::Latest => ambiguous.clone().later(),
+            Self::Shift => match ambiguous.offset() {
+                AmbiguousOffset::Fold { after, .. } if Some(after) == keep => {
+                    ambiguous.clone().later()
+                }
+                _ => ambiguous.clone().compatible(),
+            },
+            Self::Error => ambiguous.clone().unambiguous(),
+        }?;
+
+        let skipped = match ambiguous.offset() {
+            AmbiguousOffset::Unambiguous { .. } => return Ok((zoned, DstAmbiguity::None)),
+            AmbiguousOffset::Gap { .. } => true,
+            AmbiguousOffset::Fold { .. } => false,
+        };
+        let earlier = ambiguous.clone().earlier()?;
+        let alternative = if earlier == zoned {
+            ambiguous.later()?
+        } else {
+            earlier
+        };
+        let ambiguity = if skipped {
+            DstAmbiguity::Skipped { alternative }
+        } else {
+            DstAmbiguity::Repeated { alternative }
+        };
+        Ok((zoned, ambiguity))
+    }
+}
@@ -0,0 +1,235 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Named holidays and holiday calendars.
+//!
+//! A [`HolidayProvider`] set with [`Parser::holidays`](crate::Parser::holidays)
+//! resolves holiday names (e.g., "christmas 2025") to dates, and a
+//! [`HolidayCalendar`] set with
+//! [`Parser::business_calendar`](crate::Parser::business_calendar) lists the
+//! dates that business-day arithmetic (e.g., "+3 business days") skips in
+//! addition to weekends. [`Holidays`] is both.
+//!
+//! With the `holiday-data` feature, the holiday tables of some regions are
+//! built in (see [`Holidays::region`]).
+
+use jiff::civil::{Date, Weekday};
+
+#[cfg(feature = "holiday-data")]
+mod regions;
+
+/// A source of named holidays.
+///
+/// Names are passed in lowercase, with words separated by a single space
+/// (e.g., "new year's eve").
+pub trait HolidayProvider: Send + Sync {
+    /// Returns whether `name` is a holiday known to this provider.
+    fn contains(&self, name: &str) -> bool;
+
+    /// Returns the date of the holiday `name` in `year`, or `None` if the
+    /// holiday does not occur in that year.
+    fn date(&self, name: &str, year: i16) -> Option<Date>;
+}
+
+/// A calendar of non-working days, used by business-day arithmetic.
+pub trait HolidayCalendar: Send + Sync {
+    /// Returns whether `date` is a holiday. Weekends are always skipped by
+    /// business-day arithmetic and do not need to be reported here.
+    fn is_holiday(&self, date: Date) -> bool;
+}
+
+enum Rule {
+    Fixed { month: i8, day: i8 },
+    Computed(Box<dyn Fn(i16) -> Option<Date> + Send + Sync>),
+}
+
+impl Rule {
+    fn date(&self, year: i16) -> Option<Date> {
+        match self {
+            Rule::Fixed { month, day } => Date::new(year, *month, *day).ok(),
+            Rule::Computed(f) => f(year),
+        }
+    }
+}
+
+/// A table of named holidays.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::civil::{date, Date, Weekday};
+/// use parse_datetime::{HolidayProvider, Holidays};
+///
+/// let holidays = Holidays::builtin()
+///     .register("towel day", 5, 25)
+///     .register_with("thanksgiving", |year| {
+///         date(year, 11, 1).nth_weekday_of_month(4, Weekday::Thursday).ok()
+///     });
+///
+/// assert_eq!(holidays.date("towel day", 2024), Some(date(2024, 5, 25)));
+/// assert_eq!(holidays.date("thanksgiving", 2024), Some(date(2024, 11, 28)));
+/// assert_eq!(holidays.date("christmas", 2024), Some(date(2024, 12, 25)));
+/// ```
+#[derive(Default)]
+pub struct Holidays {
+    rules: Vec<(String, Rule)>,
+    /// Whether, as a business calendar, a holiday falling on a Saturday is
+    /// observed on the Friday before and one falling on a Sunday on the
+    /// Monday after.
+    observed: bool,
+}
+
+impl Holidays {
+    /// Create an empty holiday table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a holiday table with a small set of fixed-date holidays:
+    /// "new year" ("new year's day"), "valentine's day", "halloween",
+    /// "christmas eve", "christmas" ("christmas day") and "new year's eve".
+    pub fn builtin() -> Self {
+        Self::new()
+            .register("new year", 1, 1)
+            .register("new year's day", 1, 1)
+            .register("valentine's day", 2, 14)
+            .register("halloween", 10, 31)
+            .register("christmas eve", 12, 24)
+            .register("christmas", 12, 25)
+            .register("christmas day", 12, 25)
+            .register("new year's eve", 12, 31)
+    }
+
+    /// Register a holiday falling on the same month and day every year.
+    ///
+    /// A previously registered holiday with the same name is replaced.
+    pub fn register(self, name: &str, month: i8, day: i8) -> Self {
+        self.insert(name, Rule::Fixed { month, day })
+    }
+
+    /// Register a holiday whose date is computed from the year.
+    ///
+    /// A previously registered holiday with the same name is replaced.
+    pub fn register_with(
+        self,
+        name: &str,
+        f: impl Fn(i16) -> Option<Date> + Send + Sync + 'static,
+    ) -> Self {
+        self.insert(name, Rule::Computed(Box::new(f)))
+    }
+
+    fn insert(mut self, name: &str, rule: Rule) -> Self {
+        let name = normalize(name);
+        self.rules.retain(|(n, _)| *n != name);
+        self.rules.push((name, rule));
+        self
+    }
+
+    fn rule(&self, name: &str) -> Option<&Rule> {
+        self.rules
+            .iter()
+            .find_map(|(n, rule)| (n == name).then_some(rule))
+    }
+}
+
+impl HolidayProvider for Holidays {
+    fn contains(&self, name: &str) -> bool {
+        self.rule(name).is_some()
+    }
+
+    fn date(&self, name: &str, year: i16) -> Option<Date> {
+        self.rule(name)?.date(year)
+    }
+}
+
+impl HolidayCalendar for Holidays {
+    fn is_holiday(&self, date: Date) -> bool {
+        if !self.observed {
+            return self
+                .rules
+                .iter()
+                .any(|(_, rule)| rule.date(date.year()) == Some(date));
+        }
+
+        // An observed holiday may fall in the year before or after, e.g.,
+        // New Year's Day 2022 was observed on December 31, 2021.
+        let years = [
+            date.year().checked_sub(1),
+            Some(date.year()),
+            date.year().checked_add(1),
+        ];
+        self.rules.iter().any(|(_, rule)| {
+            years
+                .iter()
+                .flatten()
+                .filter_map(|year| rule.date(*year))
+                .any(|holiday| observed(holiday) == Some(date))
+        })
+    }
+}
+
+/// Return the weekday on which a holiday is observed, if it falls on a
+/// weekend.
+fn observed(date: Date) -> Option<Date> {
+    match date.weekday() {
+        Weekday::Saturday => date.yesterday().ok(),
+        Weekday::Sunday => date.tomorrow().ok(),
+        _ => Some(date),
+    }
+}
+
+/// Lowercase a name and collapse the whitespace between its words.
+fn normalize(name: &str) -> String {
+    name.split_whitespace()
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use super::*;
+
+    #[test]
+    fn builtin() {
+        let holidays = Holidays::builtin();
+        for (name, expected) in [
+            ("new year", date(2025, 1, 1)),
+            ("new year's day", date(2025, 1, 1)),
+            ("halloween", date(2025, 10, 31)),
+            ("christmas", date(2025, 12, 25)),
+            ("new year's eve", date(2025, 12, 31)),
+        ] {
+            assert!(holidays.contains(name), "{name}");
+            assert_eq!(holidays.date(name, 2025), Some(expected), "{name}");
+        }
+
+        assert!(!holidays.contains("easter"));
+        assert_eq!(holidays.date("easter", 2025), None);
+    }
+
+    #[test]
+    fn register() {
+        let holidays = Holidays::new()
+            .register("Leap  Day", 2, 29)
+            .register("christmas", 12, 26);
+
+        assert!(holidays.contains("leap day"));
+        assert_eq!(holidays.date("leap day", 2024), Some(date(2024, 2, 29)));
+        assert_eq!(holidays.date("leap day", 2025), None);
+
+        // Re-registering replaces the previous rule.
+        let holidays = holidays.register("christmas", 12, 25);
+        assert_eq!(holidays.date("christmas", 2025), Some(date(2025, 12, 25)));
+    }
+
+    #[test]
+    fn calendar() {
+        let holidays = Holidays::builtin();
+        assert!(holidays.is_holiday(date(2025, 12, 25)));
+        assert!(holidays.is_holiday(date(2026, 1, 1)));
+        assert!(!holidays.is_holiday(date(2025, 12, 26)));
+    }
+}
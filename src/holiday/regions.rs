@@ -0,0 +1,195 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore juneteenth
+
+//! Built-in holiday tables of regions.
+//!
+//! The tables follow the current rules of each region and do not go back to
+//! the years before a holiday was established, except for the holidays
+//! established since 1986.
+
+use jiff::civil::{Date, Weekday};
+
+use super::Holidays;
+
+impl Holidays {
+    /// Create the holiday table of a region, or return `None` if the region
+    /// code is unknown. Codes are case-insensitive:
+    ///
+    /// - "US": the federal holidays of the United States. As a business
+    ///   calendar, a holiday falling on a Saturday is observed on the Friday
+    ///   before, and one falling on a Sunday on the Monday after.
+    /// - "TARGET": the closing days of the TARGET2 payment system of the
+    ///   euro area, i.e., New Year's Day, Good Friday, Easter Monday, Labour
+    ///   Day (May 1), Christmas Day and December 26.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::civil::date;
+    /// use parse_datetime::{HolidayCalendar, HolidayProvider, Holidays};
+    ///
+    /// let us = Holidays::region("us").unwrap();
+    /// assert_eq!(us.date("thanksgiving", 2025), Some(date(2025, 11, 27)));
+    /// // Independence Day 2026 is a Saturday, observed on Friday.
+    /// assert!(us.is_holiday(date(2026, 7, 3)));
+    ///
+    /// let target = Holidays::region("TARGET").unwrap();
+    /// assert!(target.is_holiday(date(2025, 4, 18)));
+    /// ```
+    pub fn region(code: &str) -> Option<Self> {
+        match code.to_ascii_uppercase().as_str() {
+            "US" => Some(us()),
+            "TARGET" => Some(target()),
+            _ => None,
+        }
+    }
+}
+
+fn us() -> Holidays {
+    let mut holidays = Holidays::new()
+        .register("new year", 1, 1)
+        .register("new year's day", 1, 1)
+        .register_with("martin luther king day", |year| {
+            (year >= 1986).then(|| nth_weekday(year, 1, 3, Weekday::Monday))?
+        })
+        .register_with("mlk day", |year| {
+            (year >= 1986).then(|| nth_weekday(year, 1, 3, Weekday::Monday))?
+        })
+        .register_with("washington's birthday", |year| {
+            nth_weekday(year, 2, 3, Weekday::Monday)
+        })
+        .register_with("presidents day", |year| {
+            nth_weekday(year, 2, 3, Weekday::Monday)
+        })
+        .register_with("memorial day", |year| {
+            nth_weekday(year, 5, -1, Weekday::Monday)
+        })
+        .register_with("juneteenth", |year| {
+            (year >= 2021).then(|| Date::new(year, 6, 19).ok())?
+        })
+        .register("independence day", 7, 4)
+        .register_with("labor day", |year| nth_weekday(year, 9, 1, Weekday::Monday))
+        .register_with("columbus day", |year| {
+            nth_weekday(year, 10, 2, Weekday::Monday)
+        })
+        .register("veterans day", 11, 11)
+        .register_with("thanksgiving", |year| {
+            nth_weekday(year, 11, 4, Weekday::Thursday)
+        })
+        .register_with("thanksgiving day", |year| {
+            nth_weekday(year, 11, 4, Weekday::Thursday)
+        })
+        .register("christmas", 12, 25)
+        .register("christmas day", 12, 25);
+    holidays.observed = true;
+    holidays
+}
+
+fn target() -> Holidays {
+    Holidays::new()
+        .register("new year", 1, 1)
+        .register("new year's day", 1, 1)
+        .register_with("good friday", |year| {
+            easter(year)?.yesterday().ok()?.yesterday().ok()
+        })
+        .register_with("easter monday", |year| easter(year)?.tomorrow().ok())
+        .register("labour day", 5, 1)
+        .register("christmas", 12, 25)
+        .register("christmas day", 12, 25)
+        .register("boxing day", 12, 26)
+}
+
+/// Return the `nth` `weekday` of a month, counting from the end if `nth` is
+/// negative.
+fn nth_weekday(year: i16, month: i8, nth: i8, weekday: Weekday) -> Option<Date> {
+    Date::new(year, month, 1)
+        .ok()?
+        .nth_weekday_of_month(nth, weekday)
+        .ok()
+}
+
+/// Return the date of Easter Sunday in the Gregorian calendar, computed with
+/// the anonymous Gregorian algorithm (Meeus/Jones/Butcher).
+fn easter(year: i16) -> Option<Date> {
+    let y = i32::from(year);
+    let (a, b, c) = (y.rem_euclid(19), y.div_euclid(100), y.rem_euclid(100));
+    let (d, e) = (b.div_euclid(4), b.rem_euclid(4));
+    let f = (b + 8).div_euclid(25);
+    let g = (b - f + 1).div_euclid(3);
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let (i, k) = (c.div_euclid(4), c.rem_euclid(4));
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l).div_euclid(451);
+    let n = h + l - 7 * m + 114;
+    Date::new(year, (n / 31) as i8, (n % 31 + 1) as i8).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use crate::{HolidayCalendar, HolidayProvider};
+
+    use super::*;
+
+    #[test]
+    fn easter_sunday() {
+        for (year, expected) in [
+            (2000, date(2000, 4, 23)),
+            (2024, date(2024, 3, 31)),
+            (2025, date(2025, 4, 20)),
+            (2038, date(2038, 4, 25)),
+        ] {
+            assert_eq!(easter(year), Some(expected), "{year}");
+        }
+    }
+
+    #[test]
+    fn us() {
+        let us = Holidays::region("US").unwrap();
+        for (name, expected) in [
+            ("martin luther king day", date(2025, 1, 20)),
+            ("presidents day", date(2025, 2, 17)),
+            ("memorial day", date(2025, 5, 26)),
+            ("juneteenth", date(2025, 6, 19)),
+            ("labor day", date(2025, 9, 1)),
+            ("columbus day", date(2025, 10, 13)),
+            ("thanksgiving", date(2025, 11, 27)),
+        ] {
+            assert_eq!(us.date(name, 2025), Some(expected), "{name}");
+        }
+        assert_eq!(us.date("juneteenth", 2020), None);
+
+        // Holidays on a weekend are observed on the nearest weekday, which
+        // may be in the previous year.
+        assert!(us.is_holiday(date(2026, 7, 3)));
+        assert!(us.is_holiday(date(2023, 1, 2)));
+        assert!(us.is_holiday(date(2021, 12, 31)));
+        assert!(!us.is_holiday(date(2025, 12, 26)));
+    }
+
+    #[test]
+    fn target() {
+        let target = Holidays::region("target").unwrap();
+        for day in [
+            date(2025, 1, 1),
+            date(2025, 4, 18),
+            date(2025, 4, 21),
+            date(2025, 5, 1),
+            date(2025, 12, 25),
+            date(2025, 12, 26),
+        ] {
+            assert!(target.is_holiday(day), "{day}");
+        }
+        // Weekend holidays are not moved.
+        assert!(!target.is_holiday(date(2027, 12, 27)));
+        assert!(!target.is_holiday(date(2025, 7, 4)));
+    }
+
+    #[test]
+    fn unknown() {
+        assert!(Holidays::region("XX").is_none());
+    }
+}
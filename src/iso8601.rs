@@ -0,0 +1,90 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Values of the ISO 8601 grammar.
+//!
+//! An [`Iso8601Value`] is returned by [`parse_iso8601`](crate::parse_iso8601),
+//! which accepts ISO 8601 representations only, rather than the lenient
+//! grammar of GNU `date`.
+
+use jiff::{
+    civil::{Date, DateTime, Time},
+    tz::Offset,
+    Span,
+};
+
+/// A date, time of day, date and time, duration or time interval of ISO 8601.
+///
+/// Values do not depend on the current date and time or on a timezone: a date
+/// and time or a time of day without an offset is a local time, which the
+/// application resolves in the timezone of its choice.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::{civil::date, ToSpan};
+/// use parse_datetime::{parse_iso8601, Iso8601Value};
+///
+/// let Iso8601Value::Date(d) = parse_iso8601("2022-W46-1").unwrap() else {
+///     unreachable!()
+/// };
+/// assert_eq!(d, date(2022, 11, 14));
+///
+/// let Iso8601Value::Duration(span) = parse_iso8601("P1DT2H30M").unwrap() else {
+///     unreachable!()
+/// };
+/// assert_eq!(span.fieldwise(), 1.day().hours(2).minutes(30));
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Iso8601Value {
+    /// A date and time of day (e.g., "2022-11-14T10:20:30" or
+    /// "20221114T102030,5+0100"), with its offset from UTC if one is given.
+    DateTime {
+        /// The date and time of day.
+        datetime: DateTime,
+        /// The offset from UTC ("Z" is zero).
+        offset: Option<Offset>,
+    },
+    /// A calendar date (e.g., "2022-11-14"), an ordinal date (e.g.,
+    /// "2022-318") or a week date (e.g., "2022-W46-1"). A date of reduced
+    /// precision (e.g., "2022-11", "2022" or "2022-W46") is its first day.
+    Date(Date),
+    /// A time of day (e.g., "10:20:30", "T1020" or "T10:20:30.5Z"), with its
+    /// offset from UTC if one is given. The "T" is required in the basic
+    /// format, where "1020" is a year.
+    Time {
+        /// The time of day.
+        time: Time,
+        /// The offset from UTC ("Z" is zero).
+        offset: Option<Offset>,
+    },
+    /// A duration (e.g., "P1Y2M3DT4H5M6.5S", "P2W" or "P0001-02-03T04:05:06"),
+    /// negative if it starts with a minus sign (e.g., "-P1D").
+    Duration(Span),
+    /// A time interval (e.g., "2022-11-14/2022-11-21" or
+    /// "2022-11-14T10:00Z/PT1H"), as its start and end, each a date and time,
+    /// a date or a duration, but not both durations.
+    ///
+    /// The end may omit the components it shares with the start: a time of day
+    /// (e.g., "2022-11-14T10:00/12:00") or a month and day (e.g.,
+    /// "2022-11-14/12-01"); it is then given in full.
+    Interval {
+        /// The start of the interval, or its duration.
+        start: Box<Iso8601Value>,
+        /// The end of the interval, or its duration.
+        end: Box<Iso8601Value>,
+    },
+    /// A recurring time interval (e.g., "R5/2022-11-14T10:00Z/P2W" or
+    /// "R/2022-11-14/P1D"), repeated a number of times, or without bound if
+    /// the number is omitted.
+    ///
+    /// See [`Parser::parse_repeating_interval`](crate::Parser::parse_repeating_interval)
+    /// for its occurrences.
+    Repeating {
+        /// The number of repetitions, or `None` if unbounded.
+        repetitions: Option<u32>,
+        /// The repeated [`Iso8601Value::Interval`].
+        interval: Box<Iso8601Value>,
+    },
+}
@@ -0,0 +1,288 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse the annotations of an Internet Extended Date/Time Format (IXDTF)
+//! string, as defined by RFC 9557 (e.g., "[Europe/Paris][u-ca=gregory]").
+//!
+//! Annotations are always recognized, and GNU `date` rejects them. They
+//! directly follow the preceding item, without whitespace, and are written in
+//! square brackets:
+//!
+//! - A time zone annotation (e.g., "[Europe/Paris]" or "[+05:30]") must come
+//!   first. Like a `TZ="..."` rule, it sets the timezone in which the date and
+//!   time are interpreted. When the date and time has an offset, the result
+//!   is in the timezone of the annotation if the offset agrees with it, and
+//!   at the offset otherwise, unless the annotation is marked critical with a
+//!   "!" (e.g., "[!Europe/Paris]"), in which case a disagreement is an error.
+//!   After "Z", the offset to local time is unknown, so the result is in the
+//!   timezone of the annotation.
+//! - Other annotations are tags of the form "key=value" (e.g.,
+//!   "[u-ca=gregory]"), which are skipped. A tag marked critical with a "!"
+//!   (e.g., "[!u-ca=hebrew]") must not be skipped, so a critical tag that is
+//!   not understood is an error. The only tag that is understood is the
+//!   calendar, with the values "iso8601" and "gregory".
+
+use jiff::tz::TimeZone;
+use winnow::{
+    combinator::{alt, delimited, opt, repeat},
+    error::ErrMode,
+    stream::AsChar,
+    token::{one_of, take_while},
+    ModalResult, Parser,
+};
+
+use super::{offset, primitive::ctx_err};
+
+/// The timezone of a time zone annotation.
+#[derive(PartialEq, Debug, Clone)]
+pub(super) struct ZoneAnnotation {
+    pub(super) tz: TimeZone,
+    /// The annotation is marked critical, so the offset of the date and time
+    /// must agree with the timezone.
+    pub(super) critical: bool,
+}
+
+#[derive(PartialEq, Debug)]
+enum Annotation<'a> {
+    TimeZone(&'a str),
+    Tag { key: &'a str, value: &'a str },
+}
+
+/// Parse a sequence of annotations, and return the time zone annotation, if
+/// there is one.
+pub(super) fn parse(input: &mut &str) -> ModalResult<Option<ZoneAnnotation>> {
+    let annotations: Vec<(bool, Annotation)> = repeat(1.., annotation).parse_next(input)?;
+
+    let mut tz = None;
+    for (i, (critical, annotation)) in annotations.into_iter().enumerate() {
+        match annotation {
+            Annotation::TimeZone(name) if i == 0 => {
+                tz = Some(ZoneAnnotation {
+                    tz: time_zone(name).map_err(|e| ErrMode::Cut(ctx_err(e)))?,
+                    critical,
+                });
+            }
+            Annotation::TimeZone(_) => {
+                return Err(ErrMode::Cut(ctx_err(
+                    "time zone annotation must be the first annotation",
+                )));
+            }
+            Annotation::Tag {
+                key: "u-ca",
+                value: "iso8601" | "gregory",
+            } => {}
+            Annotation::Tag { .. } if critical => {
+                return Err(ErrMode::Cut(ctx_err(
+                    "critical annotation is not supported",
+                )));
+            }
+            Annotation::Tag { .. } => {}
+        }
+    }
+
+    Ok(tz)
+}
+
+/// Parse an annotation, and return whether it is marked critical.
+fn annotation<'a>(input: &mut &'a str) -> ModalResult<(bool, Annotation<'a>)> {
+    delimited(
+        '[',
+        (
+            opt('!').map(|critical| critical.is_some()),
+            alt((tag, time_zone_name.map(Annotation::TimeZone))),
+        ),
+        ']',
+    )
+    .parse_next(input)
+}
+
+fn tag<'a>(input: &mut &'a str) -> ModalResult<Annotation<'a>> {
+    (
+        (
+            one_of(|c: char| c.is_ascii_lowercase() || c == '_'),
+            take_while(0.., |c: char| {
+                c.is_ascii_lowercase() || c.is_dec_digit() || c == '_' || c == '-'
+            }),
+        )
+            .take(),
+        '=',
+        take_while(1.., |c: char| c.is_ascii_alphanumeric() || c == '-'),
+    )
+        .map(|(key, _, value)| Annotation::Tag { key, value })
+        .parse_next(input)
+}
+
+fn time_zone_name<'a>(input: &mut &'a str) -> ModalResult<&'a str> {
+    take_while(1.., |c: char| {
+        c.is_ascii_alphanumeric() || matches!(c, '/' | '_' | '-' | '+' | '.' | ':')
+    })
+    .parse_next(input)
+}
+
+/// Return the timezone of a time zone annotation, which is an IANA timezone
+/// name or a numeric offset.
+fn time_zone(name: &str) -> Result<TimeZone, &'static str> {
+    if name.starts_with(['+', '-']) {
+        let mut rest = name;
+        return match offset::timezone_offset(&mut rest) {
+            Ok(offset) if rest.is_empty() => TimeZone::try_from(&offset),
+            _ => Err("time zone annotation is invalid"),
+        };
+    }
+
+    TimeZone::get(name).map_err(|_| "time zone annotation is not a known timezone")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        items::{at, error::Error, parse_at_date},
+        parser::Options,
+    };
+
+    use super::parse;
+
+    #[test]
+    fn annotations() {
+        for (input, expected) in [
+            ("[europe/paris]", Some(("Europe/Paris", false))),
+            ("[!asia/tokyo]", Some(("Asia/Tokyo", true))),
+            ("[utc][u-ca=gregory]", Some(("UTC", false))),
+            ("[u-ca=hebrew]", None),
+            ("[!u-ca=iso8601]", None),
+            ("[_foo=bar][x-y=1-2]", None),
+        ] {
+            let mut s = input;
+            let zone = parse(&mut s).unwrap();
+            assert_eq!(
+                zone.as_ref()
+                    .map(|zone| (zone.tz.iana_name().unwrap(), zone.critical)),
+                expected,
+                "{input}"
+            );
+            assert_eq!(s, "", "{input}");
+        }
+
+        let mut s = "[+05:30]";
+        let tz = parse(&mut s).unwrap().unwrap().tz;
+        assert_eq!(
+            tz.to_offset(jiff::Timestamp::UNIX_EPOCH).seconds(),
+            5 * 3600 + 30 * 60
+        );
+
+        for input in [
+            "[!u-ca=hebrew]",
+            "[!_foo=bar]",
+            "[u-ca=gregory][europe/paris]",
+            "[unknown/timezone]",
+            "[+25:00]",
+            "[]",
+            "[europe/paris",
+            " [europe/paris]",
+        ] {
+            let mut s = input;
+            assert!(parse(&mut s).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn annotations_resolved() {
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            (
+                "2025-01-15T10:00:00[America/New_York]",
+                "2025-01-15 10:00:00 -05:00",
+            ),
+            (
+                "2025-06-15T10:00:00+02:00[Europe/Paris][u-ca=gregory]",
+                "2025-06-15 10:00:00 +02:00",
+            ),
+            (
+                "2025-06-15T10:00:00Z[u-ca=hebrew]",
+                "2025-06-15 10:00:00 +00:00",
+            ),
+            (
+                "2025-06-15T10:00:00Z[!u-ca=iso8601]",
+                "2025-06-15 10:00:00 +00:00",
+            ),
+            ("2025-06-15[Asia/Tokyo]", "2025-06-15 00:00:00 +09:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        for input in [
+            "2025-06-15T10:00:00Z[!u-ca=hebrew]",
+            "2025-06-15T10:00:00Z[!x-foo=bar]",
+            "2025-06-15T10:00:00Z [UTC]",
+            r#"TZ="UTC" 2025-06-15T10:00:00[Asia/Tokyo]"#,
+        ] {
+            assert!(
+                parse_at_date(now.clone(), input, &Options::default()).is_err(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn zone_annotations() {
+        let now = at("2025-06-15 10:00:00");
+
+        // The timezone is kept when the offset agrees with it, or after "Z".
+        for (input, expected, zone) in [
+            (
+                "2024-01-01T10:00:00+05:30[Asia/Kolkata]",
+                "2024-01-01 10:00:00 +05:30",
+                Some("Asia/Kolkata"),
+            ),
+            (
+                "2024-01-01T10:00:00+05:30[!Asia/Kolkata]",
+                "2024-01-01 10:00:00 +05:30",
+                Some("Asia/Kolkata"),
+            ),
+            (
+                "2024-01-01T10:00:00Z[!Asia/Kolkata]",
+                "2024-01-01 15:30:00 +05:30",
+                Some("Asia/Kolkata"),
+            ),
+            (
+                "2024-07-01T10:00:00+02:00[Europe/Paris] +1 day",
+                "2024-07-02 10:00:00 +02:00",
+                Some("Europe/Paris"),
+            ),
+            // The offset wins over an annotation that is not critical.
+            (
+                "2024-01-01T10:00:00+01:00[Asia/Kolkata]",
+                "2024-01-01 10:00:00 +01:00",
+                None,
+            ),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+                expected,
+                "{input}"
+            );
+            assert_eq!(result.time_zone().iana_name(), zone, "{input}");
+        }
+
+        // A critical annotation must agree with the offset.
+        for input in [
+            "2024-01-01T10:00:00+00:00[!Asia/Kolkata]",
+            "2024-07-01T10:00:00+01:00[!Europe/Paris]",
+        ] {
+            assert!(
+                matches!(
+                    parse_at_date(now.clone(), input, &Options::default()),
+                    Err(Error::Resolve(_))
+                ),
+                "{input}"
+            );
+        }
+    }
+}
@@ -1,25 +1,47 @@
 // For the full copyright and license information, please view the LICENSE
 // file that was distributed with this source code.
 
+use std::cell::Cell;
+
 use jiff::{civil, Span, Zoned};
 
-use super::{date, epoch, error, offset, relative, time, weekday, year, Item};
+use crate::{
+    parser::Options, DstAmbiguity, HolidayCalendar, Keyword, MonthArithmetic, ParsedDateTime,
+    Precision, Quirk, ZoneResolution,
+};
+
+use super::{
+    annotation, date, epoch, error, fiscal, holiday, keyword, offset, period, relative,
+    specification::Specification, time, timezone, weekday, year, Item,
+};
 
 /// The builder is used to construct a DateTime object from various components.
 /// The parser creates a `DateTimeBuilder` object with the parsed components,
 /// but without the baseline date and time. So you normally need to set the base
 /// date and time using the `set_base()` method before calling `build()`, or
 /// leave it unset to use the current date and time as the base.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub(crate) struct DateTimeBuilder {
     base: Option<Zoned>,
     timestamp: Option<epoch::Timestamp>,
     date: Option<date::Date>,
+    /// The date was given to the month only (e.g., "2024-07").
+    month_only: bool,
+    nth_weekday: Option<weekday::NthWeekday>,
+    holiday: Option<holiday::Holiday>,
+    keyword: Option<String>,
+    fiscal: Option<fiscal::Fiscal>,
+    period: Option<period::Period>,
     time: Option<time::Time>,
     weekday: Option<weekday::Weekday>,
     offset: Option<offset::Offset>,
     timezone: Option<jiff::tz::TimeZone>,
+    /// The timezone was given by a time zone annotation rather than a rule,
+    /// which is marked critical if `true`.
+    zone_annotation: Option<bool>,
+    rule_abbreviation: Option<String>,
     relative: Vec<relative::Relative>,
+    keep_time: bool,
 }
 
 impl DateTimeBuilder {
@@ -34,6 +56,14 @@ impl DateTimeBuilder {
         self
     }
 
+    /// Keeps the time of day of the base date and time where the items do not
+    /// give one, instead of truncating it to midnight, so that the base acts
+    /// as a template for the fields the input does not specify.
+    pub(super) fn keep_time(mut self) -> Self {
+        self.keep_time = true;
+        self
+    }
+
     /// Sets the timezone rule for the builder.
     ///
     /// By default, the builder uses the time zone rules indicated by the `TZ`
@@ -48,12 +78,54 @@ impl DateTimeBuilder {
         Ok(self)
     }
 
+    /// Sets the timezone of a time zone annotation, which is interpreted as a
+    /// timezone rule, and checked against the offset of the date and time.
+    fn set_zone_annotation(self, zone: annotation::ZoneAnnotation) -> Result<Self, &'static str> {
+        let mut builder = self.set_timezone(zone.tz)?;
+        builder.zone_annotation = Some(zone.critical);
+        Ok(builder)
+    }
+
+    /// Sets the abbreviation of a timezone rule whose timezone does not carry
+    /// one, such as the fixed offset of "UTC+5".
+    pub(super) fn set_rule_abbreviation(mut self, abbreviation: Option<String>) -> Self {
+        self.rule_abbreviation = abbreviation;
+        self
+    }
+
+    /// Returns the current date and time, in the timezone of the timezone rule
+    /// if there is one, or in the default timezone otherwise: that of the `TZ`
+    /// environment variable if it is honored, or the system timezone. This is
+    /// the base used when none is set.
+    pub(super) fn now(&self, options: &Options) -> Zoned {
+        match &self.timezone {
+            Some(tz) => jiff::Timestamp::now().to_zoned(tz.clone()),
+            None if options.tz_env => jiff::Timestamp::now().to_zoned(timezone::from_env()),
+            None => Zoned::now(),
+        }
+    }
+
+    /// Takes the base date and time, converted to the timezone of the
+    /// timezone rule if there is one, or returns the current date and time if
+    /// no base is set.
+    fn choose_base(&mut self, options: &Options) -> Zoned {
+        match (self.base.take(), &self.timezone) {
+            (Some(b), Some(tz)) => b.with_time_zone(tz.clone()),
+            (Some(b), None) => b,
+            (None, _) => self.now(options),
+        }
+    }
+
+    pub(super) fn base(&self) -> Option<&Zoned> {
+        self.base.as_ref()
+    }
+
     /// Sets a timestamp value. Timestamp values are exclusive to other date/time
     /// items (date, time, weekday, timezone, relative adjustments).
     pub(super) fn set_timestamp(mut self, ts: epoch::Timestamp) -> Result<Self, &'static str> {
         if self.timestamp.is_some() {
             return Err("timestamp cannot appear more than once");
-        } else if self.date.is_some()
+        } else if self.has_date()
             || self.time.is_some()
             || self.weekday.is_some()
             || self.offset.is_some()
@@ -69,7 +141,7 @@ impl DateTimeBuilder {
     fn set_date(mut self, date: date::Date) -> Result<Self, &'static str> {
         if self.timestamp.is_some() {
             return Err("timestamp cannot be combined with other date/time items");
-        } else if self.date.is_some() {
+        } else if self.has_date() {
             return Err("date cannot appear more than once");
         }
 
@@ -77,6 +149,96 @@ impl DateTimeBuilder {
         Ok(self)
     }
 
+    /// Sets a date given to the month only, as its first day.
+    fn set_month(self, date: date::Date) -> Result<Self, &'static str> {
+        let mut builder = self.set_date(date)?;
+        builder.month_only = true;
+        Ok(builder)
+    }
+
+    fn set_nth_weekday(mut self, nth_weekday: weekday::NthWeekday) -> Result<Self, &'static str> {
+        if self.timestamp.is_some() {
+            return Err("timestamp cannot be combined with other date/time items");
+        } else if self.has_date() {
+            return Err("date cannot appear more than once");
+        }
+
+        self.nth_weekday = Some(nth_weekday);
+        Ok(self)
+    }
+
+    fn set_holiday(mut self, holiday: holiday::Holiday) -> Result<Self, &'static str> {
+        if self.timestamp.is_some() {
+            return Err("timestamp cannot be combined with other date/time items");
+        } else if self.has_date() {
+            return Err("date cannot appear more than once");
+        }
+
+        self.holiday = Some(holiday);
+        Ok(self)
+    }
+
+    fn set_keyword(mut self, name: String) -> Result<Self, &'static str> {
+        if self.timestamp.is_some() {
+            return Err("timestamp cannot be combined with other date/time items");
+        } else if self.has_date() {
+            return Err("date cannot appear more than once");
+        }
+
+        self.keyword = Some(name);
+        Ok(self)
+    }
+
+    fn set_fiscal(mut self, fiscal: fiscal::Fiscal) -> Result<Self, &'static str> {
+        if self.timestamp.is_some() {
+            return Err("timestamp cannot be combined with other date/time items");
+        } else if self.has_date() {
+            return Err("date cannot appear more than once");
+        }
+
+        self.fiscal = Some(fiscal);
+        Ok(self)
+    }
+
+    fn set_period(mut self, period: period::Period) -> Result<Self, &'static str> {
+        if self.timestamp.is_some() {
+            return Err("timestamp cannot be combined with other date/time items");
+        } else if self.has_date() {
+            return Err("date cannot appear more than once");
+        }
+
+        self.period = Some(period);
+        Ok(self)
+    }
+
+    /// Returns whether an item specifying a calendar date has been set.
+    fn has_date(&self) -> bool {
+        self.date.is_some()
+            || self.nth_weekday.is_some()
+            || self.holiday.is_some()
+            || self.keyword.is_some()
+            || self.fiscal.is_some()
+            || self.period.is_some()
+    }
+
+    /// Returns whether the year of the calendar date was given with two
+    /// digits, and taken as a year of the 20th or 21st century.
+    pub(super) fn two_digit_year(&self) -> bool {
+        self.date.as_ref().is_some_and(|date| date.two_digit_year)
+            || self
+                .nth_weekday
+                .as_ref()
+                .is_some_and(|nth| nth.two_digit_year)
+            || self.holiday.as_ref().is_some_and(|h| h.two_digit_year)
+            || matches!(
+                self.fiscal,
+                Some(fiscal::Fiscal::Period {
+                    two_digit_year: true,
+                    ..
+                })
+            )
+    }
+
     fn set_time(mut self, time: time::Time) -> Result<Self, &'static str> {
         if self.timestamp.is_some() {
             return Err("timestamp cannot be combined with other date/time items");
@@ -136,7 +298,9 @@ impl DateTimeBuilder {
 
         if let Some(date) = self.date.as_mut() {
             if date.year.is_none() {
-                date.year = Some(year::year_from_str(&pure)?);
+                let year = year::year_from_str(&pure)?;
+                date.year = Some(year.value);
+                date.two_digit_year = year.expanded;
                 return Ok(self);
             }
         }
@@ -160,12 +324,114 @@ impl DateTimeBuilder {
         self.set_time(time)
     }
 
-    /// Build a `Zoned` object from the pieces accumulated in this builder.
+    /// Maps the custom relative units, given by their index in the options,
+    /// to those of other options, or returns `None` if one of them has no
+    /// counterpart.
+    pub(super) fn map_custom_units(
+        mut self,
+        mut f: impl FnMut(usize) -> Option<usize>,
+    ) -> Option<Self> {
+        for rel in &mut self.relative {
+            if let relative::Relative::Custom(unit, _) = rel {
+                *unit = f(*unit)?;
+            }
+        }
+        Some(self)
+    }
+
+    /// Overlays the items of `other` on those of this builder, as defaults
+    /// overridden by later input.
+    ///
+    /// An item of `other` replaces the item of the same kind: its calendar
+    /// date replaces a date, an ordinal weekday, a holiday, a keyword, a
+    /// fiscal period or a period, and its offset replaces an offset, as part
+    /// of the time of day or not. The relative adjustments of both are
+    /// applied, those of `other` last. A timestamp replaces all the other
+    /// items, and is dropped if `other` has any besides a timezone rule.
+    pub(super) fn merge(self, other: Self) -> Self {
+        if other.timestamp.is_some() {
+            return Self {
+                base: other.base.or(self.base),
+                ..other
+            };
+        }
+
+        let other_items = other.has_date()
+            || other.time.is_some()
+            || other.weekday.is_some()
+            || other.offset.is_some()
+            || !other.relative.is_empty();
+
+        let mut merged = if other.has_date() {
+            Self {
+                date: other.date,
+                month_only: other.month_only,
+                nth_weekday: other.nth_weekday,
+                holiday: other.holiday,
+                keyword: other.keyword,
+                fiscal: other.fiscal,
+                period: other.period,
+                ..self
+            }
+        } else {
+            self
+        };
+
+        let other_offset =
+            other.offset.is_some() || other.time.as_ref().is_some_and(|t| t.offset.is_some());
+        if other_offset {
+            merged.offset = other.offset;
+            if let Some(time) = merged.time.as_mut() {
+                time.offset = None;
+            }
+        }
+        if let Some(mut time) = other.time {
+            if !other_offset {
+                time.offset = merged.time.and_then(|t| t.offset);
+            }
+            merged.time = Some(time);
+        }
+        if other.timezone.is_some() {
+            merged.timezone = other.timezone;
+            merged.zone_annotation = other.zone_annotation;
+            merged.rule_abbreviation = other.rule_abbreviation;
+        }
+
+        Self {
+            base: other.base.or(merged.base),
+            timestamp: merged.timestamp.filter(|_| !other_items),
+            weekday: other.weekday.or(merged.weekday),
+            relative: [merged.relative, other.relative].concat(),
+            ..merged
+        }
+    }
+
+    /// Build a `Zoned` object from the pieces accumulated in this builder,
+    /// through the configured resolution hook if there is one.
+    ///
+    /// The second element of the result tells whether the local time was
+    /// skipped or repeated by a daylight saving time transition.
+    pub(super) fn build_with_dst(
+        mut self,
+        options: &Options,
+    ) -> Result<(Zoned, DstAmbiguity), error::Error> {
+        let Some(hook) = &options.resolution_hook else {
+            return self.resolve(options);
+        };
+
+        let base = self.choose_base(options);
+        let dst = Cell::new(DstAmbiguity::None);
+        let dt = hook(Specification::new(self.set_base(base), options, &dst))?;
+        Ok((dt, dst.take()))
+    }
+
+    /// Resolve the pieces accumulated in this builder to a `Zoned` object.
     ///
     /// Resolution order (mirrors GNU `date` semantics):
     ///
     /// 1. Base instant.
-    ///   - a. If `self.base` is provided, start with it.
+    ///   - a. If `self.base` is provided, start with it, converted to the
+    ///     timezone of the `timezone` rule if one is present.
     ///   - b. Else if a `timezone` rule is present, start with "now" in that
     ///     timezone.
     ///   - c. Else start with current system local time.
@@ -174,68 +440,174 @@ impl DateTimeBuilder {
     ///   - a. If `self.timestamp` is set, it fully determines the result.
     ///
     /// 3. Time of day truncation.
-    ///   - a. If any of date, time, weekday, offset, timezone is set, zero the
-    ///     time of day to 00:00:00 before applying fields.
+    ///   - a. If any of date, time, weekday, offset is set, or if there is no
+    ///     item at all besides a `timezone` rule (an empty string is the
+    ///     beginning of today), zero the time of day to 00:00:00 before
+    ///     applying fields, unless the time of day of the base is kept.
     ///
     /// 4. Fieldwise resolution (applied to the base instant).
     ///   - a. Apply date. If year is absent in the parsed date, inherit the year
-    ///     from the base instant.
+    ///     from the base instant (or, when recent years are inferred, the latest
+    ///     year up to that of the base instant in which the date exists). An
+    ///     ordinal weekday within a month (e.g., "last friday of march"), a
+    ///     holiday (resolved through the configured holiday provider), a custom
+    ///     keyword computing a date from the base date and a fiscal period
+    ///     (resolved against the configured fiscal year start) are applied
+    ///     here as well, and so is the first day of a whole period (e.g.,
+    ///     "next month").
     ///   - b. Apply time. If time carries an explicit numeric offset, apply the
-    ///     offset before setting time.
-    ///   - c. Apply weekday (e.g., "next Friday" or "last Monday").
+    ///     offset before setting time. When recent years are inferred, a date
+    ///     without a year that ends up after the base instant is moved to the
+    ///     previous year in which it exists.
+    ///   - c. Apply weekday (e.g., "next Friday" or "last Monday"). As in GNU
+    ///     `date`, a weekday is ignored when a date is given, including an
+    ///     ordinal weekday within a month, a holiday or a fiscal period, or
+    ///     rejected if it is not the day of the week of the date and weekdays
+    ///     are strict. A weekday given with a whole period moves within it
+    ///     (e.g., "next week friday").
     ///   - d. Apply relative adjustments (e.g., "+3 days", "-2 months").
-    ///   - e. Apply final fixed offset if present.
-    pub(super) fn build(self) -> Result<Zoned, error::Error> {
+    ///   - e. Apply final fixed offset if present. Depending on the configured
+    ///     zone resolution, the offset of a timezone abbreviation is checked
+    ///     against, or replaced by, the rules of its IANA zone.
+    ///   - f. Keep the timezone of a time zone annotation if the offset agrees
+    ///     with it or is "Z", or reject the offset if it does not agree with a
+    ///     critical annotation.
+    ///
+    /// 5. Truncation to precision.
+    ///   - a. If enabled, truncate the result to the most specific unit given
+    ///     by the items (e.g., to the hour for "10am", or to the first day of
+    ///     the month for "+1 month").
+    ///
+    /// 6. Rounding.
+    ///   - a. If configured, round the result to a multiple of a unit of at
+    ///     most a day (e.g., to the nearest quarter of an hour).
+    ///
+    /// The second element of the result tells whether the local time was
+    /// skipped or repeated by a daylight saving time transition. Failures
+    /// are resolution errors, as the input was parsed.
+    pub(super) fn resolve(self, options: &Options) -> Result<(Zoned, DstAmbiguity), error::Error> {
+        self.resolve_items(options).map_err(error::Error::resolving)
+    }
+
+    fn resolve_items(mut self, options: &Options) -> Result<(Zoned, DstAmbiguity), error::Error> {
+        let mut dst = DstAmbiguity::None;
+        let unit = self.unit();
+
         // 1. Choose the base instant.
-        let base = match (self.base, &self.timezone) {
-            (Some(b), _) => b,
-            (None, Some(tz)) => jiff::Timestamp::now().to_zoned(tz.clone()),
-            (None, None) => Zoned::now(),
-        };
+        let base = self.choose_base(options);
+        let now = base.timestamp();
 
         // 2. Absolute timestamp override everything else.
         if let Some(ts) = self.timestamp {
-            let ts = jiff::Timestamp::try_from(ts)?;
-            return Ok(ts.to_zoned(base.offset().to_time_zone()));
+            let negative = ts.is_negative();
+            let ts = match jiff::Timestamp::try_from(ts) {
+                Ok(ts) => ts,
+                Err(_) => saturate(options, negative)?,
+            };
+            return Ok((ts.to_zoned(base.offset().to_time_zone()), dst));
         }
 
         // 3. Determine whether to truncate the time of day.
-        let need_midnight = self.date.is_some()
+        let need_midnight = !self.keep_time
+            && (self.date.is_some()
+            || self.nth_weekday.is_some()
+            || self.holiday.is_some()
+            || self.keyword.is_some()
+            || self.fiscal.is_some()
+            || self.period.is_some()
             || self.time.is_some()
             || self.weekday.is_some()
             || self.offset.is_some()
-            || self.timezone.is_some();
+            // An empty string, possibly after a timezone rule, is the
+            // beginning of today.
+            || self.relative.is_empty());
 
         let mut dt = if need_midnight {
-            base.with().time(civil::time(0, 0, 0, 0)).build()?
+            with_datetime(
+                &mut dst,
+                &base,
+                base.date().to_datetime(civil::Time::midnight()),
+                options,
+            )?
         } else {
             base
         };
 
         // 4a. Apply date.
+        let date_given = self.has_date() && self.period.is_none();
+        let infer_year =
+            options.infer_recent_year && self.date.as_ref().is_some_and(|date| date.year.is_none());
         if let Some(date) = self.date {
             let d: civil::Date = if date.year.is_some() {
                 date.try_into()?
+            } else if infer_year {
+                latest_date(date.month as i8, date.day as i8, dt.date().year())?
             } else {
-                date.with_year(dt.date().year() as u16).try_into()?
+                let (month, day) = (date.month, date.day);
+                match date.with_year(dt.date().year() as u16).try_into() {
+                    Ok(d) => d,
+                    Err(_) if (month, day) == (2, 29) => leap_day(dt.date().year(), options)?,
+                    Err(e) => return Err(e.into()),
+                }
             };
-            dt = dt.with().date(d).build()?;
+            dt = with_datetime(&mut dst, &dt, d.to_datetime(dt.time()), options)?;
+        } else if let Some(nth_weekday) = self.nth_weekday {
+            let d = nth_weekday.resolve(dt.date())?;
+            dt = with_datetime(&mut dst, &dt, d.to_datetime(dt.time()), options)?;
+        } else if let Some(holiday) = self.holiday {
+            let year = holiday.year.map_or(dt.date().year(), |y| y as i16);
+            let d = options
+                .holidays
+                .as_ref()
+                .and_then(|provider| provider.date(&holiday.name, year))
+                .ok_or("holiday does not occur in the given year")?;
+            dt = with_datetime(&mut dst, &dt, d.to_datetime(dt.time()), options)?;
+        } else if let Some(name) = self.keyword {
+            let d = match options.keywords.get(&name) {
+                Some(Keyword::DateWith(f)) => f(dt.date()),
+                _ => None,
+            }
+            .ok_or("keyword does not resolve to a date")?;
+            dt = with_datetime(&mut dst, &dt, d.to_datetime(dt.time()), options)?;
+        } else if let Some(fiscal) = self.fiscal {
+            let start_month = options
+                .fiscal_year_start
+                .ok_or("fiscal year start is not configured")?;
+            let d = fiscal.resolve(dt.date(), start_month)?;
+            dt = with_datetime(&mut dst, &dt, d.to_datetime(dt.time()), options)?;
+        } else if let Some(period) = self.period {
+            let d = period.resolve(dt.date())?;
+            dt = with_datetime(&mut dst, &dt, d.to_datetime(dt.time()), options)?;
         }
 
         // 4b. Apply time.
         if let Some(time) = self.time.clone() {
             if let Some(offset) = &time.offset {
-                dt = dt.datetime().to_zoned(offset.try_into()?)?;
+                dt = dt.datetime().to_zoned(offset_time_zone(offset, options)?)?;
             }
 
             let t: civil::Time = time.try_into()?;
-            dt = dt.with().time(t).build()?;
+            dt = with_datetime(&mut dst, &dt, dt.date().to_datetime(t), options)?;
         }
 
-        // 4c. Apply weekday.
-        if let Some(weekday::Weekday { mut offset, day }) = self.weekday {
-            if self.time.is_none() {
-                dt = dt.with().time(civil::time(0, 0, 0, 0)).build()?;
+        if infer_year && dt.timestamp() > now {
+            let d = latest_date(dt.month(), dt.day(), dt.year() - 1)?;
+            dt = with_datetime(&mut dst, &dt, d.to_datetime(dt.time()), options)?;
+        }
+
+        // 4c. Apply weekday, unless a date is given.
+        if let Some(weekday::Weekday { day, .. }) = self.weekday.as_ref().filter(|_| date_given) {
+            if options.strict_weekday && dt.date().weekday() != (*day).into() {
+                return Err("weekday does not match the date".into());
+            }
+        } else if let Some(weekday::Weekday { mut offset, day }) = self.weekday {
+            if self.time.is_none() && !self.keep_time {
+                dt = with_datetime(
+                    &mut dst,
+                    &dt,
+                    dt.date().to_datetime(civil::Time::midnight()),
+                    options,
+                )?;
             }
 
             let target = day.into();
@@ -266,36 +638,550 @@ impl DateTimeBuilder {
             //
             // Example 4: next Thursday (x = 1, day = Thursday)
             //            delta = (3 - 3) % 7 + (1) * 7 = 7
-            let delta = (target.since(civil::Weekday::Monday) as i32
-                - dt.date().weekday().since(civil::Weekday::Monday) as i32)
-                .rem_euclid(7)
+            let delta = weekday::days_until(dt.date().weekday(), target)
                 + offset.checked_mul(7).ok_or("multiplication overflow")?;
 
-            dt = dt.checked_add(Span::new().try_days(delta)?)?;
+            dt = add_calendar(&mut dst, &dt, Span::new().try_days(delta)?, options)?;
         }
 
-        // 4d. Apply relative adjustments.
+        // 4d. Apply relative adjustments. An adjustment beyond the supported
+        // range of dates is an error, or stops at its first or last instant
+        // when saturating.
         for rel in self.relative {
-            dt = dt.checked_add::<Span>(if let relative::Relative::Months(x) = rel {
-                // *NOTE* This is done in this way to conform to GNU behavior.
-                let days = dt.date().last_of_month().day() as i32;
-                Span::new().try_days(days.checked_mul(x).ok_or("multiplication overflow")?)?
-            } else {
-                rel.try_into()?
-            })?;
+            dt = match add_relative(&mut dst, &dt, rel, options) {
+                Ok(dt) => dt,
+                Err(error::Error::OutOfRange) => {
+                    dst = DstAmbiguity::None;
+                    saturate(options, rel.is_negative())?.to_zoned(dt.time_zone().clone())
+                }
+                Err(e) => return Err(e),
+            };
         }
 
-        // 4e. Apply final fixed offset.
+        // An offset given with a time zone annotation, and whether it is "Z".
+        let annotated_offset = self
+            .offset
+            .as_ref()
+            .or_else(|| self.time.as_ref().and_then(|t| t.offset.as_ref()))
+            .filter(|_| self.zone_annotation.is_some())
+            .map(|offset| offset.name.as_deref() == Some("Z"));
+
+        // 4e. Apply final fixed offset, or the zone of a timezone
+        // abbreviation.
         if let Some(offset) = self.offset {
+            if let Some(zone) = offset.zone {
+                let tz = jiff::tz::TimeZone::get(zone)?;
+                match options.zone_resolution {
+                    ZoneResolution::Compute => {
+                        return Ok(options.dst_resolution.resolve(dt.datetime(), tz, None)?);
+                    }
+                    _ => {
+                        let fixed = dt.datetime().to_zoned((&offset).try_into()?)?;
+                        if tz.to_offset(fixed.timestamp()) != fixed.offset() {
+                            return Err(
+                                "timezone abbreviation is not in effect at the given time".into()
+                            );
+                        }
+                    }
+                }
+            }
+
             let (offset, hour_adjustment) = offset.normalize();
+            if hour_adjustment != 0 {
+                options.quirk(Quirk::OffsetClamped);
+            }
             dt = dt.checked_add(Span::new().hours(hour_adjustment))?;
-            dt = dt.datetime().to_zoned((&offset).try_into()?)?;
+            dt = dt
+                .datetime()
+                .to_zoned(offset_time_zone(&offset, options)?)?;
+            dst = DstAmbiguity::None;
+        }
+
+        // 4f. Keep the timezone of a time zone annotation that agrees with the
+        // offset, or that follows "Z". A critical annotation that does not
+        // agree with the offset is an error.
+        if let (Some(utc), Some(tz)) = (annotated_offset, &self.timezone) {
+            if utc || tz.to_offset(dt.timestamp()) == dt.offset() {
+                dt = dt.with_time_zone(tz.clone());
+            } else if self.zone_annotation == Some(true) {
+                return Err("time zone annotation does not agree with the offset".into());
+            }
+        }
+
+        // 5. Truncate to the most specific unit given.
+        if let Some(unit) = unit.filter(|_| options.truncate) {
+            dt = truncate(&mut dst, &dt, unit, options)?;
+        }
+
+        // 6. Round.
+        if let Some(round) = options.round {
+            dt = dt.round(round)?;
+            dst = DstAmbiguity::None;
+        }
+
+        Ok((dt, dst))
+    }
+
+    /// Build a `Zoned` object as [`build`](Self::build) does, along with the
+    /// name of the timezone that was parsed: the abbreviation of a timezone
+    /// item, or that of the timezone rule if the result is in its timezone.
+    /// There is no name if the result has a numeric offset.
+    pub(super) fn build_with_zone(
+        self,
+        options: &Options,
+    ) -> Result<(Zoned, Option<String>), error::Error> {
+        let offset_name = self.offset.as_ref().map(|offset| offset.name.clone());
+        let in_rule_zone = self.timezone.is_some()
+            && self.timestamp.is_none()
+            && self.time.as_ref().and_then(|t| t.offset.as_ref()).is_none();
+        let rule_abbreviation = self.rule_abbreviation.clone();
+
+        let dt = self.build(options)?;
+        let name = match offset_name {
+            Some(name) => name,
+            None if in_rule_zone => Some(rule_abbreviation.unwrap_or_else(|| {
+                dt.time_zone()
+                    .to_offset_info(dt.timestamp())
+                    .abbreviation()
+                    .to_string()
+            })),
+            None => None,
+        };
+        Ok((dt, name))
+    }
+
+    /// Build a [`ParsedDateTime`] from the `Zoned` object and the timezone
+    /// name [`build_with_zone`](Self::build_with_zone) returns, and the parts
+    /// of the date and time that were given.
+    pub(super) fn build_detailed(self, options: &Options) -> Result<ParsedDateTime, error::Error> {
+        let precision = self.precision();
+        let (dt, name) = self.build_with_zone(options)?;
+        Ok(ParsedDateTime::new(dt, name, precision))
+    }
+
+    /// Returns the parts of the date and time that were given.
+    /// Returns the most specific unit given by the items, if any. A time of
+    /// day is as specific as its last non-zero field, and at least gives the
+    /// hour. Relative items that do not move the date (e.g., "now") give no
+    /// unit.
+    fn unit(&self) -> Option<jiff::Unit> {
+        if self.timestamp.is_some() {
+            return Some(jiff::Unit::Nanosecond);
+        }
+
+        let time = self.time.as_ref().map(|t| {
+            if t.nanosecond != 0 {
+                jiff::Unit::Nanosecond
+            } else if t.second != 0 {
+                jiff::Unit::Second
+            } else if t.minute != 0 {
+                jiff::Unit::Minute
+            } else {
+                jiff::Unit::Hour
+            }
+        });
+        let date = match &self.period {
+            Some(period) => Some(period.unit.into()),
+            None if self.month_only && self.weekday.is_none() => Some(jiff::Unit::Month),
+            None if self.has_date() || self.weekday.is_some() => Some(jiff::Unit::Day),
+            None => None,
+        };
+
+        time.into_iter()
+            .chain(date)
+            .chain(self.relative.iter().filter_map(relative::Relative::unit))
+            .min()
+    }
+
+    pub(super) fn precision(&self) -> Precision {
+        Precision {
+            date: self.timestamp.is_some() || self.has_date(),
+            time: self.timestamp.is_some() || self.time.is_some(),
+            offset: self.timestamp.is_some()
+                || self.offset.is_some()
+                || self.timezone.is_some()
+                || self.time.as_ref().and_then(|t| t.offset.as_ref()).is_some(),
+            unit: self.unit(),
+        }
+    }
+
+    /// Fill a chrono `Parsed` with the fields given by the items: the date,
+    /// the time of day, the day of the week of a date, the offset and the
+    /// timestamp. Items that depend on a base date and time (e.g., relative
+    /// items, a day of the week alone or a holiday) cannot be represented.
+    #[cfg(feature = "chrono")]
+    pub(super) fn to_chrono_parsed(&self) -> Result<chrono::format::Parsed, error::Error> {
+        if !self.relative.is_empty()
+            || self.nth_weekday.is_some()
+            || self.holiday.is_some()
+            || self.keyword.is_some()
+            || self.fiscal.is_some()
+            || self.period.is_some()
+            || self.timezone.is_some()
+            || (self.weekday.is_some() && self.date.is_none())
+        {
+            return Err("items cannot be represented by chrono".into());
+        }
+
+        let mut parsed = chrono::format::Parsed::new();
+        let field = |result: chrono::ParseResult<()>| {
+            result.map_err(|_| error::Error::from("field is out of the range of chrono"))
+        };
+        if let Some(ts) = &self.timestamp {
+            let ts = jiff::Timestamp::try_from(ts.clone()).map_err(|_| error::Error::OutOfRange)?;
+            field(parsed.set_timestamp(ts.as_second()))?;
+            field(parsed.set_nanosecond(ts.subsec_nanosecond().into()))?;
+            field(parsed.set_offset(0))?;
+        }
+        if let Some(date) = &self.date {
+            if let Some(year) = date.year {
+                field(parsed.set_year(year.into()))?;
+            }
+            field(parsed.set_month(date.month.into()))?;
+            field(parsed.set_day(date.day.into()))?;
+        }
+        if let Some(weekday) = &self.weekday {
+            field(parsed.set_weekday(weekday.day.into()))?;
+        }
+        if let Some(time) = &self.time {
+            field(parsed.set_hour(time.hour.into()))?;
+            field(parsed.set_minute(time.minute.into()))?;
+            field(parsed.set_second(time.second.into()))?;
+            field(parsed.set_nanosecond(time.nanosecond.into()))?;
+        }
+        // A timezone item overrides the offset of a time of day.
+        let offset = self
+            .offset
+            .as_ref()
+            .or_else(|| self.time.as_ref().and_then(|t| t.offset.as_ref()));
+        if let Some(offset) = offset {
+            let tz: jiff::tz::TimeZone = offset.try_into()?;
+            let offset = tz.to_fixed_offset().map_err(|_| "offset is invalid")?;
+            field(parsed.set_offset(offset.seconds().into()))?;
+        }
+        Ok(parsed)
+    }
+
+    /// Build a `Zoned` object as [`build_with_dst`](Self::build_with_dst)
+    /// does, without telling whether the local time was ambiguous.
+    pub(super) fn build(self, options: &Options) -> Result<Zoned, error::Error> {
+        self.build_with_dst(options).map(|(dt, _)| dt)
+    }
+}
+
+/// Replace the date and time of day of `dt`, resolving a time in a gap or a
+/// fold of its timezone with the configured strategy, and record in `dst`
+/// whether it was ambiguous.
+fn with_datetime(
+    dst: &mut DstAmbiguity,
+    dt: &Zoned,
+    datetime: civil::DateTime,
+    options: &Options,
+) -> Result<Zoned, error::Error> {
+    let (zoned, ambiguity) = options
+        .dst_resolution
+        .resolve(datetime, dt.time_zone().clone(), Some(dt.offset()))
+        .map_err(|e| resolve_error(e, datetime, dt.time_zone()))?;
+    *dst = ambiguity;
+    Ok(zoned)
+}
+
+/// Tell a local time beyond the supported range of instants apart from one
+/// rejected by the daylight saving time resolution strategy.
+fn resolve_error(
+    err: jiff::Error,
+    datetime: civil::DateTime,
+    tz: &jiff::tz::TimeZone,
+) -> error::Error {
+    if tz.to_ambiguous_timestamp(datetime).compatible().is_err() {
+        error::Error::OutOfRange
+    } else {
+        err.into()
+    }
+}
+
+/// Add a span of calendar units (days, months or years) to `dt`, resolving a
+/// time in a gap or a fold of its timezone with the configured strategy, and
+/// record in `dst` whether it was ambiguous.
+fn add_calendar(
+    dst: &mut DstAmbiguity,
+    dt: &Zoned,
+    span: Span,
+    options: &Options,
+) -> Result<Zoned, error::Error> {
+    let datetime = dt
+        .datetime()
+        .checked_add(span)
+        .map_err(|_| error::Error::OutOfRange)?;
+    with_calendar_datetime(dst, dt, datetime, options)
+}
+
+/// Replace the date and time of day of `dt` with `datetime`, the result of
+/// calendar arithmetic, as [`add_calendar`] does.
+fn with_calendar_datetime(
+    dst: &mut DstAmbiguity,
+    dt: &Zoned,
+    datetime: civil::DateTime,
+    options: &Options,
+) -> Result<Zoned, error::Error> {
+    let (zoned, ambiguity) = options
+        .dst_resolution
+        .resolve(datetime, dt.time_zone().clone(), None)
+        .map_err(|e| resolve_error(e, datetime, dt.time_zone()))?;
+    *dst = ambiguity;
+    Ok(zoned)
+}
+
+/// Add a span of months or years to `dt` as [`add_calendar`] does, and move a
+/// day that does not exist in the target month (e.g., February 29th a year
+/// later) according to the configured month arithmetic: past the end of the
+/// month by the number of missing days as GNU `date` does (e.g., to March
+/// 1st), to the end of the month, or to an error. Relative years clamp the
+/// day to the end of the month by default.
+fn add_months(
+    dst: &mut DstAmbiguity,
+    dt: &Zoned,
+    span: Span,
+    options: &Options,
+) -> Result<Zoned, error::Error> {
+    // Adding months to a civil date clamps the day to the end of the month.
+    let clamped = dt
+        .date()
+        .checked_add(span)
+        .map_err(|_| error::Error::OutOfRange)?;
+    let missing = dt.day() - clamped.day();
+    if missing == 0 {
+        return add_calendar(dst, dt, span, options);
+    }
+    match options.month_arithmetic {
+        MonthArithmetic::Overflow => {
+            options.quirk(Quirk::MonthOverflow);
+            let datetime = clamped
+                .checked_add(Span::new().days(missing))
+                .map_err(|_| error::Error::OutOfRange)?
+                .to_datetime(dt.time());
+            with_calendar_datetime(dst, dt, datetime, options)
+        }
+        MonthArithmetic::GnuOvershoot | MonthArithmetic::ClampToMonthEnd => {
+            add_calendar(dst, dt, span, options)
+        }
+        MonthArithmetic::Error => Err("day does not exist in the target month".into()),
+    }
+}
+
+/// Return the span that `months` relative months add to `date`.
+///
+/// *NOTE* A month is the number of days in the month of `date` (e.g., "jan 31
+/// +1 month" is March 3rd), which diverges from GNU `date` when the day exists
+/// in the target month (e.g., "-1 month" from November 14th is October 15th).
+fn months_span(date: civil::Date, months: i32) -> Option<Span> {
+    let days = i32::from(date.last_of_month().day());
+    Span::new().try_days(days.checked_mul(months)?).ok()
+}
+
+/// Return the calendar span from `a` to `b` (negative if `b` is before `a`),
+/// in years, months, days and smaller units, counted in the timezone of `a`.
+/// Months are counted one at a time as a relative month is added (see
+/// [`months_span`]).
+pub(super) fn calendar_span(a: &Zoned, b: &Zoned) -> Result<Span, error::Error> {
+    if b < a {
+        return Ok(calendar_span(b, a)?.negate());
+    }
+    let b = b.with_time_zone(a.time_zone().clone());
+
+    let mut years = (b.year() - a.year()).max(0);
+    while years > 0 && a.checked_add(Span::new().years(years))? > b {
+        years -= 1;
+    }
+    let mut dt = a.checked_add(Span::new().years(years))?;
+
+    let mut months = 0;
+    loop {
+        let span = months_span(dt.date(), 1).ok_or(error::Error::OutOfRange)?;
+        match dt.checked_add(span) {
+            Ok(next) if next <= b => {
+                months += 1;
+                dt = next;
+            }
+            _ => break,
+        }
+    }
+
+    let mut days = dt.date().until(b.date())?.get_days();
+    if dt.checked_add(Span::new().days(days))? > b {
+        days -= 1;
+    }
+    dt = dt.checked_add(Span::new().days(days))?;
+
+    let rest = b.timestamp().duration_since(dt.timestamp());
+    let seconds = rest.as_secs();
+    Ok(Span::new()
+        .years(years)
+        .months(months)
+        .days(days)
+        .hours(seconds / 3600)
+        .minutes(seconds / 60 % 60)
+        .seconds(seconds % 60)
+        .nanoseconds(rest.subsec_nanos()))
+}
+
+/// Apply the relative adjustment `rel` to `dt`. An adjustment beyond the
+/// supported range of dates is an [`error::Error::OutOfRange`].
+fn add_relative(
+    dst: &mut DstAmbiguity,
+    dt: &Zoned,
+    rel: relative::Relative,
+    options: &Options,
+) -> Result<Zoned, error::Error> {
+    Ok(match rel {
+        relative::Relative::Months(x) => match options.month_arithmetic {
+            MonthArithmetic::GnuOvershoot => {
+                let span = months_span(dt.date(), x).ok_or(error::Error::OutOfRange)?;
+                let result = add_calendar(dst, dt, span, options)?;
+                if result.day() != dt.day() {
+                    options.quirk(Quirk::MonthOverflow);
+                }
+                result
+            }
+            _ => {
+                let span = Span::new()
+                    .try_months(x)
+                    .map_err(|_| error::Error::OutOfRange)?;
+                add_months(dst, dt, span, options)?
+            }
+        },
+        relative::Relative::Years(x) => {
+            let span = Span::new()
+                .try_years(x)
+                .map_err(|_| error::Error::OutOfRange)?;
+            add_months(dst, dt, span, options)?
+        }
+        relative::Relative::BusinessDays(x) => {
+            let d = add_business_days(dt.date(), x, options.business_calendar.as_deref())?;
+            with_datetime(dst, dt, d.to_datetime(dt.time()), options)?
+        }
+        relative::Relative::Weekdays(x) => {
+            let d = add_business_days(dt.date(), x, None)?;
+            with_datetime(dst, dt, d.to_datetime(dt.time()), options)?
+        }
+        relative::Relative::Custom(unit, x) => {
+            let (_, unit) = options
+                .relative_units
+                .get(unit)
+                .ok_or("relative unit is not registered")?;
+            unit.add(dt, x).ok_or(error::Error::OutOfRange)?
         }
+        relative::Relative::Days(_) => {
+            let span = rel.try_into().map_err(|_| error::Error::OutOfRange)?;
+            add_calendar(dst, dt, span, options)?
+        }
+        _ => {
+            let span: Span = rel.try_into().map_err(|_| error::Error::OutOfRange)?;
+            *dst = std::mem::take(dst).checked_add(span)?;
+            dt.checked_add(span).map_err(|_| error::Error::OutOfRange)?
+        }
+    })
+}
+
+/// Truncate `dt` to `unit` in its timezone (e.g., to the first day of its
+/// month for months, or to the Monday of its week for weeks).
+fn truncate(
+    dst: &mut DstAmbiguity,
+    dt: &Zoned,
+    unit: jiff::Unit,
+    options: &Options,
+) -> Result<Zoned, error::Error> {
+    let date = dt.date();
+    let datetime = match unit {
+        jiff::Unit::Year => date.first_of_year().to_datetime(civil::Time::midnight()),
+        jiff::Unit::Month => date.first_of_month().to_datetime(civil::Time::midnight()),
+        jiff::Unit::Week => date
+            .checked_sub(Span::new().days(date.weekday().to_monday_zero_offset()))?
+            .to_datetime(civil::Time::midnight()),
+        jiff::Unit::Day => date.to_datetime(civil::Time::midnight()),
+        _ => date.to_datetime(
+            dt.time().round(
+                civil::TimeRound::new()
+                    .smallest(unit)
+                    .mode(jiff::RoundMode::Trunc),
+            )?,
+        ),
+    };
+    with_datetime(dst, dt, datetime, options)
+}
+
+/// Return the date of February 29th, given without a year, in `year`, a year
+/// in which it does not exist: the last day of February when clamping to the
+/// end of the month, or else an error, as in GNU `date`.
+fn leap_day(year: i16, options: &Options) -> Result<civil::Date, error::Error> {
+    match options.month_arithmetic {
+        MonthArithmetic::ClampToMonthEnd => Ok(civil::date(year, 2, 28)),
+        _ => Err("February 29th does not exist in the year of the base date".into()),
+    }
+}
+
+/// Return the first or last supported instant, depending on the direction
+/// (`negative`) in which the range was exceeded, when saturating, or else an
+/// [`error::Error::OutOfRange`].
+fn saturate(options: &Options, negative: bool) -> Result<jiff::Timestamp, error::Error> {
+    match (options.saturate, negative) {
+        (false, _) => Err(error::Error::OutOfRange),
+        (true, true) => Ok(jiff::Timestamp::MIN),
+        (true, false) => Ok(jiff::Timestamp::MAX),
+    }
+}
+
+/// Return the date of `month` and `day` in the latest year no later than
+/// `year` in which it exists (e.g., the latest leap year for February 29).
+fn latest_date(month: i8, day: i8, year: i16) -> Result<civil::Date, &'static str> {
+    // Leap years are at most eight years apart.
+    (year.saturating_sub(8)..=year)
+        .rev()
+        .find_map(|year| civil::Date::new(year, month, day).ok())
+        .ok_or("date is not valid")
+}
 
-        Ok(dt)
+/// Return the timezone of a fixed offset. With RFC 2822 zones, "-0000" is UTC
+/// in an unknown local timezone.
+fn offset_time_zone(
+    offset: &offset::Offset,
+    options: &Options,
+) -> Result<jiff::tz::TimeZone, &'static str> {
+    if options.rfc2822_zones && offset.is_unknown_local() {
+        Ok(jiff::tz::TimeZone::unknown())
+    } else {
+        offset.try_into()
     }
 }
 
+/// Move `date` by the given number of business days, skipping weekends and the
+/// holidays of `calendar`, if any. A date that is not itself a business day
+/// moves to the nearest business day in the direction of travel on the first
+/// step.
+fn add_business_days(
+    mut date: civil::Date,
+    days: i32,
+    calendar: Option<&dyn HolidayCalendar>,
+) -> Result<civil::Date, error::Error> {
+    let is_business_day = |d: civil::Date| {
+        !matches!(
+            d.weekday(),
+            civil::Weekday::Saturday | civil::Weekday::Sunday
+        ) && !calendar.is_some_and(|calendar| calendar.is_holiday(d))
+    };
+
+    let step = Span::new().days(days.signum());
+    let next = |date: civil::Date| date.checked_add(step).map_err(|_| error::Error::OutOfRange);
+    for _ in 0..days.unsigned_abs() {
+        date = next(date)?;
+        while !is_business_day(date) {
+            date = next(date)?;
+        }
+    }
+
+    Ok(date)
+}
+
 impl TryFrom<Vec<Item>> for DateTimeBuilder {
     type Error = &'static str;
 
@@ -307,11 +1193,24 @@ impl TryFrom<Vec<Item>> for DateTimeBuilder {
                 Item::Timestamp(ts) => builder.set_timestamp(ts)?,
                 Item::DateTime(dt) => builder.set_date(dt.date)?.set_time(dt.time)?,
                 Item::Date(d) => builder.set_date(d)?,
+                Item::Month(d) => builder.set_month(d)?,
+                Item::NthWeekday(nth_weekday) => builder.set_nth_weekday(nth_weekday)?,
+                Item::Holiday(holiday) => builder.set_holiday(holiday)?,
+                Item::Keyword(keyword::Word::Date(d)) => builder.set_date(d)?,
+                Item::Keyword(keyword::Word::Time(t)) => builder.set_time(t)?,
+                Item::Keyword(keyword::Word::DateWith(name)) => builder.set_keyword(name)?,
+                Item::Fiscal(fiscal) => builder.set_fiscal(fiscal)?,
+                Item::Period(period) => builder.set_period(period)?,
                 Item::Time(t) => builder.set_time(t)?,
                 Item::Weekday(weekday) => builder.set_weekday(weekday)?,
                 Item::Offset(offset) => builder.set_offset(offset)?,
                 Item::Relative(rel) => builder.push_relative(rel)?,
+                Item::Relatives(rels) => rels
+                    .into_iter()
+                    .try_fold(builder, |builder, rel| builder.push_relative(rel))?,
                 Item::TimeZone(tz) => builder.set_timezone(tz)?,
+                Item::Annotations(Some(zone)) => builder.set_zone_annotation(zone)?,
+                Item::Annotations(None) => builder,
                 Item::Pure(pure) => builder.set_pure(pure)?,
             }
         }
@@ -0,0 +1,134 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a date item in a non-Gregorian calendar.
+//!
+//! When a [`Calendar`] is selected, a date may be written as a day, a month
+//! name of that calendar and a year, e.g., "10 Tishrei 5785" or "15 Farvardin
+//! 1403". The date is converted to the Gregorian calendar.
+
+use winnow::{
+    ascii::multispace1,
+    combinator::{alt, eof, peek},
+    error::{ContextError, ErrMode},
+    ModalResult, Parser,
+};
+
+use crate::Calendar;
+
+use super::{
+    date::Date,
+    primitive::{ctx_err, dec_uint, s, space},
+};
+
+/// Parse a date of `calendar`.
+pub(super) fn parse(input: &mut &str, calendar: Calendar) -> ModalResult<Date> {
+    let day = s(dec_uint).parse_next(input)?;
+    let month = month_name(input, calendar)?;
+    let year = s(dec_uint).parse_next(input)?;
+    // The year must be followed by a space or end of input.
+    peek(alt((multispace1, eof))).parse_next(input)?;
+
+    let date = calendar
+        .to_gregorian(year, month, day)
+        .ok_or_else(|| ErrMode::Cut(ctx_err("date is not valid in the calendar")))?;
+
+    Ok(Date {
+        day: date.day() as u8,
+        month: date.month() as u8,
+        year: Some(
+            u16::try_from(date.year())
+                .map_err(|_| ErrMode::Cut(ctx_err("year must be no greater than 9999")))?,
+        ),
+        two_digit_year: false,
+    })
+}
+
+/// Parse a month name of `calendar`.
+fn month_name(input: &mut &str, calendar: Calendar) -> ModalResult<u8> {
+    space.parse_next(input)?;
+    for (name, month) in calendar.month_names() {
+        if let Some(rest) = input.strip_prefix(name) {
+            // The name must not be the prefix of a longer word.
+            if !rest.starts_with(|c: char| c.is_alphanumeric() || c == '\'') {
+                *input = rest;
+                return Ok(*month);
+            }
+        }
+    }
+
+    Err(ErrMode::Backtrack(ContextError::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+        Calendar,
+    };
+
+    use super::{parse, Date};
+
+    fn date(year: u16, month: u8, day: u8) -> Date {
+        Date {
+            day,
+            month,
+            year: Some(year),
+            two_digit_year: false,
+        }
+    }
+
+    #[test]
+    fn calendars() {
+        for (calendar, input, expected, rest) in [
+            (Calendar::Hebrew, "10 tishrei 5785", date(2024, 10, 12), ""),
+            (Calendar::Hebrew, "14 adar ii 5784", date(2024, 3, 24), ""),
+            (
+                Calendar::Hebrew,
+                "25 kislev 5785 10:00",
+                date(2024, 12, 26),
+                " 10:00",
+            ),
+            (Calendar::Islamic, "1 ramadan 1446", date(2025, 3, 1), ""),
+            (Calendar::Islamic, "1 rabi ii 1446", date(2024, 10, 5), ""),
+            (Calendar::Persian, "15 farvardin 1403", date(2024, 4, 3), ""),
+            (Calendar::Persian, "1  mehr  1403", date(2024, 9, 22), ""),
+        ] {
+            let mut s = input;
+            assert_eq!(parse(&mut s, calendar).unwrap(), expected, "{input}");
+            assert_eq!(s, rest, "{input}");
+        }
+
+        for (calendar, input) in [
+            (Calendar::Hebrew, "1 adar ii 5785"),
+            (Calendar::Hebrew, "1 avx 5785"),
+            (Calendar::Hebrew, "1 farvardin 1403"),
+            (Calendar::Islamic, "30 safar 1446"),
+            (Calendar::Persian, "15 farvardin"),
+            (Calendar::Persian, "15 farvardin 1403x"),
+        ] {
+            let mut s = input;
+            assert!(parse(&mut s, calendar).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "calendars")]
+    fn calendar() {
+        let options = Options {
+            calendar: Some(crate::Calendar::Persian),
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        let result = parse_at_date(now.clone(), "15 Farvardin 1403 12:00", &options).unwrap();
+        assert_eq!(
+            result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+            "2024-04-03 12:00:00"
+        );
+
+        // Calendars are opt-in.
+        assert!(parse_at_date(now, "15 farvardin 1403", &Options::default()).is_err());
+    }
+}
@@ -0,0 +1,339 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! winnow combinators for the items of the GNU `date` grammar.
+//!
+//! These parsers let other winnow grammars (e.g., a crontab or a
+//! configuration language) embed date and time items without re-implementing
+//! them. Each of them parses a single item, with GNU semantics and without
+//! resolving it against a base date: it skips leading whitespace and comments,
+//! is case-insensitive, and leaves the input after the item.
+//!
+//! # Examples
+//!
+//! ```
+//! use parse_datetime::combinators::{date, time, Date};
+//! use winnow::{combinator::separated_pair, ModalResult, Parser};
+//!
+//! // A hypothetical "<date> at <time>" field of a configuration file.
+//! fn field(input: &mut &str) -> ModalResult<(Date, u8)> {
+//!     separated_pair(date, "at", time.map(|t| t.hour)).parse_next(input)
+//! }
+//!
+//! let mut input = "Jun 15, 2025 at 10:30pm; next";
+//! let (date, hour) = field(&mut input).unwrap();
+//! assert_eq!((date.year, date.month, date.day, hour), (Some(2025), 6, 15, 22));
+//! assert_eq!(input, "; next");
+//! ```
+
+use winnow::{
+    error::{ContextError, ErrMode},
+    ModalResult, Parser,
+};
+
+use crate::parser::Options;
+
+use super::{combined, date, epoch, offset, primitive::ctx_err, relative, time, weekday};
+
+/// A calendar date item, e.g., "2025-06-15", "6/15" or "15 june 2025".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Date {
+    /// The year, if one was given.
+    pub year: Option<u16>,
+    /// The month, between 1 and 12.
+    pub month: u8,
+    /// The day of the month, between 1 and 31.
+    pub day: u8,
+}
+
+/// A time of day item, e.g., "10:30", "10:30:15.5pm" or "22:30+05:30".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Time {
+    /// The hour, between 0 and 23.
+    pub hour: u8,
+    /// The minute, between 0 and 59.
+    pub minute: u8,
+    /// The second, between 0 and 59.
+    pub second: u8,
+    /// The fractional part of the second, in nanoseconds.
+    pub nanosecond: u32,
+    /// The offset from UTC, in seconds, if one was given.
+    pub offset_seconds: Option<i32>,
+}
+
+/// A combined date and time of day item, e.g., "2025-06-15T10:30:00Z".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DateTime {
+    /// The date.
+    pub date: Date,
+    /// The time of day.
+    pub time: Time,
+}
+
+/// A day of the week item, e.g., "friday", "next tue" or "2 mon".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Weekday {
+    /// The ordinal of the day (e.g., 1 for "next friday" and -1 for "last
+    /// friday"), or 0 if none was given.
+    pub ordinal: i32,
+    /// The day of the week.
+    pub day: jiff::civil::Weekday,
+}
+
+/// A relative item, e.g., "+3 days", "2 hours ago" or "tomorrow".
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Relative {
+    /// A number of years.
+    Years(i32),
+    /// A number of months.
+    Months(i32),
+    /// A number of days, which weeks and fortnights are given in.
+    Days(i32),
+    /// A number of hours.
+    Hours(i32),
+    /// A number of minutes.
+    Minutes(i32),
+    /// A number of seconds, and a fraction in nanoseconds that is always
+    /// positive (e.g., "-1.5 seconds" is -2 seconds and 500,000,000
+    /// nanoseconds).
+    Seconds(i64, u32),
+    /// A number of business days, which skip weekends.
+    BusinessDays(i32),
+    /// A number of weekdays, which skip weekends but not holidays.
+    Weekdays(i32),
+}
+
+/// Parse a calendar date item.
+pub fn date(input: &mut &str) -> ModalResult<Date> {
+    lowercase(input, date::parse).map(Date::from)
+}
+
+/// Parse a time of day item, with an optional offset.
+pub fn time(input: &mut &str) -> ModalResult<Time> {
+    lowercase(input, |i| time::parse(i, &Options::default()))?.try_into()
+}
+
+/// Parse a combined date and time of day item in the ISO 8601 format.
+pub fn datetime(input: &mut &str) -> ModalResult<DateTime> {
+    let datetime = lowercase(input, combined::parse)?;
+    Ok(DateTime {
+        date: datetime.date.into(),
+        time: datetime.time.try_into()?,
+    })
+}
+
+/// Parse a day of the week item.
+pub fn weekday(input: &mut &str) -> ModalResult<Weekday> {
+    lowercase(input, weekday::parse).map(|weekday| Weekday {
+        ordinal: weekday.offset,
+        day: weekday.day.into(),
+    })
+}
+
+/// Parse a relative item.
+pub fn relative(input: &mut &str) -> ModalResult<Relative> {
+    let relative = |i: &mut &str| {
+        relative::parse
+            .verify_map(|relative| match relative {
+                relative::Relative::Years(n) => Some(Relative::Years(n)),
+                relative::Relative::Months(n) => Some(Relative::Months(n)),
+                relative::Relative::Days(n) => Some(Relative::Days(n)),
+                relative::Relative::Hours(n) => Some(Relative::Hours(n)),
+                relative::Relative::Minutes(n) => Some(Relative::Minutes(n)),
+                relative::Relative::Seconds(n, nanos) => Some(Relative::Seconds(n, nanos)),
+                relative::Relative::BusinessDays(n) => Some(Relative::BusinessDays(n)),
+                relative::Relative::Weekdays(n) => Some(Relative::Weekdays(n)),
+                // Custom units are only parsed when they are registered.
+                relative::Relative::Custom(..) => None,
+            })
+            .parse_next(i)
+    };
+    lowercase(input, relative)
+}
+
+/// Parse a timezone item (e.g., "utc", "est" or "utc+05:30"), and return its
+/// offset from UTC in seconds.
+pub fn offset(input: &mut &str) -> ModalResult<i32> {
+    let offset = lowercase(input, |i| offset::parse(i, &Options::default()))?;
+    offset_seconds(&offset)
+}
+
+/// Parse an epoch timestamp item, e.g., "@1700000000".
+pub fn timestamp(input: &mut &str) -> ModalResult<jiff::Timestamp> {
+    lowercase(input, epoch::parse)?
+        .try_into()
+        .map_err(|e| ErrMode::Cut(ctx_err(e)))
+}
+
+/// The number of words of the input that are lowercased for an item parser,
+/// at first. Items span a few words, and the grammar looks ahead by at most
+/// one word after an item.
+const WINDOW_WORDS: usize = 8;
+
+/// The size of the buffer on the stack that a window of the input is
+/// lowercased into, if it fits.
+const BUFFER_LEN: usize = 128;
+
+/// Run an item parser on the input in lowercase, as the grammar expects, and
+/// advance the input by as much as the parser consumed.
+///
+/// Only a window of the first words of the input is lowercased, so that the
+/// cost of a call does not depend on the length of the rest of the input. The
+/// window grows if the parser consumes it up to its last two words, whose
+/// parsing may depend on the words that follow.
+fn lowercase<O>(
+    input: &mut &str,
+    mut parser: impl FnMut(&mut &str) -> ModalResult<O>,
+) -> ModalResult<O> {
+    let mut words = WINDOW_WORDS;
+    loop {
+        let window = &input[..window_len(input, words)];
+        let whole = window.len() == input.len();
+
+        // Lowercasing ASCII letters keeps the byte offsets.
+        let mut buffer = [0; BUFFER_LEN];
+        let owned;
+        let lower = if !window.bytes().any(|b| b.is_ascii_uppercase()) {
+            window
+        } else if let Some(buffer) = buffer.get_mut(..window.len()) {
+            buffer.copy_from_slice(window.as_bytes());
+            buffer.make_ascii_lowercase();
+            std::str::from_utf8(buffer).map_err(|_| ErrMode::Backtrack(ContextError::new()))?
+        } else {
+            owned = window.to_ascii_lowercase();
+            owned.as_str()
+        };
+
+        let mut rest = lower;
+        let output = parser.parse_next(&mut rest)?;
+        if whole || rest.split_whitespace().nth(1).is_some() {
+            *input = &input[window.len() - rest.len()..];
+            return Ok(output);
+        }
+        words *= 2;
+    }
+}
+
+/// Return the length of the first `words` words of `input`, with the
+/// whitespace around them.
+fn window_len(input: &str, words: usize) -> usize {
+    let mut rest = input;
+    for _ in 0..words {
+        rest = rest
+            .trim_start()
+            .trim_start_matches(|c: char| !c.is_whitespace());
+    }
+    input.len() - rest.trim_start().len()
+}
+
+fn offset_seconds(offset: &offset::Offset) -> ModalResult<i32> {
+    jiff::tz::TimeZone::try_from(offset)
+        .ok()
+        .and_then(|tz| tz.to_fixed_offset().ok())
+        .map(|offset| offset.seconds())
+        .ok_or_else(|| ErrMode::Cut(ctx_err("offset is invalid")))
+}
+
+impl From<date::Date> for Date {
+    fn from(date: date::Date) -> Self {
+        Date {
+            year: date.year,
+            month: date.month,
+            day: date.day,
+        }
+    }
+}
+
+impl TryFrom<time::Time> for Time {
+    type Error = ErrMode<ContextError>;
+
+    fn try_from(time: time::Time) -> Result<Self, Self::Error> {
+        Ok(Time {
+            hour: time.hour,
+            minute: time.minute,
+            second: time.second,
+            nanosecond: time.nanosecond,
+            offset_seconds: time.offset.as_ref().map(offset_seconds).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use winnow::{
+        combinator::{preceded, separated},
+        ModalResult, Parser,
+    };
+
+    use super::{date, datetime, offset, relative, time, timestamp, weekday, Relative};
+
+    #[test]
+    fn items() {
+        let mut input = "Jun 15, 2025 rest";
+        let d = date(&mut input).unwrap();
+        assert_eq!((d.year, d.month, d.day), (Some(2025), 6, 15));
+        assert_eq!(input, "rest");
+
+        let mut input = " 10:30:15.5PM EST";
+        let t = time(&mut input).unwrap();
+        assert_eq!((t.hour, t.minute, t.second), (22, 30, 15));
+        assert_eq!((t.nanosecond, t.offset_seconds), (500_000_000, None));
+        assert_eq!(input, " EST");
+
+        let mut input = "2025-06-15T10:30:00+05:30";
+        let dt = datetime(&mut input).unwrap();
+        assert_eq!((dt.date.month, dt.time.hour), (6, 10));
+        assert_eq!(dt.time.offset_seconds, Some(5 * 3600 + 30 * 60));
+
+        let mut input = "Next Friday";
+        let w = weekday(&mut input).unwrap();
+        assert_eq!((w.ordinal, w.day), (1, jiff::civil::Weekday::Friday));
+
+        let mut input = "2 HOURS AGO";
+        assert_eq!(relative(&mut input).unwrap(), Relative::Hours(-2));
+
+        let mut input = "UTC+1";
+        assert_eq!(offset(&mut input).unwrap(), 3600);
+
+        let mut input = "@1700000000";
+        assert_eq!(timestamp(&mut input).unwrap().as_second(), 1_700_000_000);
+
+        for mut input in ["", "foo", "25:00"] {
+            assert!(time(&mut input).is_err());
+        }
+    }
+
+    #[test]
+    fn long_input() {
+        // Only the first words of the input are lowercased.
+        let rest = " WORD".repeat(100_000);
+        let input = format!("Jun 15, 2025{rest}");
+        let mut i = input.as_str();
+        let d = date(&mut i).unwrap();
+        assert_eq!((d.year, d.month, d.day), (Some(2025), 6, 15));
+        assert_eq!(i, &rest[1..]);
+
+        assert_eq!(super::window_len("a  b c", 2), 5);
+        assert_eq!(super::window_len(" a b", 8), 4);
+    }
+
+    #[test]
+    fn embedded() {
+        // "skip" followed by a comma-separated list of dates.
+        fn skip_list(input: &mut &str) -> ModalResult<Vec<(u8, u8)>> {
+            preceded("skip", separated(1.., date.map(|d| (d.month, d.day)), ',')).parse_next(input)
+        }
+
+        let mut input = "skip Dec 25, Jan 1,7/4";
+        assert_eq!(
+            skip_list(&mut input).unwrap(),
+            vec![(12, 25), (1, 1), (7, 4)]
+        );
+        assert_eq!(input, "");
+    }
+}
@@ -14,14 +14,22 @@
 //! > ISO 8601 fractional minutes and hours are not supported. Typically, hosts
 //! > support nanosecond timestamp resolution; excess precision is silently
 //! > discarded.
+//!
+//! Unlike GNU `date`, ISO 8601 fractional minutes (e.g., "10:30.5") and, after
+//! the ‘T’, fractional hours (e.g., "2022-11-14T10.5") are accepted.
 use winnow::{
-    combinator::{alt, trace},
+    combinator::{alt, preceded, trace},
     seq, ModalResult, Parser,
 };
 
 use crate::items::space;
 
-use super::{date, primitive::s, time};
+use super::{
+    date,
+    offset::{timezone_offset, Offset},
+    primitive::s,
+    time,
+};
 
 #[derive(PartialEq, Debug, Clone, Default)]
 pub(crate) struct DateTime {
@@ -30,11 +38,23 @@ pub(crate) struct DateTime {
 }
 
 pub(crate) fn parse(input: &mut &str) -> ModalResult<DateTime> {
+    with_offset(input, timezone_offset)
+}
+
+/// Parse a date and time, with the offset of its time of day parsed by
+/// `offset`.
+pub(super) fn with_offset(
+    input: &mut &str,
+    offset: fn(&mut &str) -> ModalResult<Offset>,
+) -> ModalResult<DateTime> {
+    let iso = |i: &mut &str| time::iso_with(i, offset);
     seq!(DateTime {
         date: trace("iso_date", alt((date::iso1, date::iso2))),
         // Note: the `T` is lowercased by the main parse function
-        _: alt((s('t').void(), (' ', space).void())),
-        time: trace("iso_time", time::iso),
+        time: alt((
+            preceded(s('t'), trace("iso_time", alt((iso, time::fractional_hour))),),
+            preceded((' ', space), trace("iso_time", iso)),
+        )),
     })
     .parse_next(input)
 }
@@ -51,6 +71,7 @@ mod tests {
                 day: 10,
                 month: 10,
                 year: Some(2022),
+                two_digit_year: false,
             },
             time: Time {
                 hour: 10,
@@ -73,4 +94,25 @@ mod tests {
             assert_eq!(parse(&mut s).ok(), reference, "Failed string: {old_s}")
         }
     }
+
+    #[test]
+    fn fractional() {
+        for (mut s, (hour, minute, second)) in [
+            ("2022-10-10t10.5", (10, 30, 0)),
+            ("2022-10-10t10:30.5", (10, 30, 30)),
+            ("2022-10-10 10:30,5", (10, 30, 30)),
+        ] {
+            let old_s = s.to_owned();
+            let time = parse(&mut s).unwrap().time;
+            assert_eq!(
+                (time.hour, time.minute, time.second),
+                (hour, minute, second),
+                "Failed string: {old_s}"
+            );
+        }
+
+        // A fractional hour is only accepted after the "T".
+        let mut s = "2022-10-10 10.5";
+        assert!(parse(&mut s).is_err());
+    }
 }
@@ -27,24 +27,69 @@
 //! > ‘September’.
 
 use winnow::{
-    ascii::{alpha1, multispace1},
-    combinator::{alt, eof, opt, preceded, terminated},
-    error::ErrMode,
+    ascii::{alpha1, digit1, multispace1},
+    combinator::{alt, eof, not, opt, preceded, terminated},
+    error::{ContextError, ErrMode, FromExternalError},
     stream::AsChar,
-    token::take_while,
+    token::{one_of, take_while},
     ModalResult, Parser,
 };
 
+use crate::{Component, InvalidComponent};
+
 use super::{
+    error::Error,
     primitive::{ctx_err, dec_uint, s},
-    year::{year_from_str, year_str},
+    year::{year_from_str, year_str, Year},
 };
 
+/// The reason a date is not valid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum DateError {
+    /// The year is not valid (e.g., more than 9999).
+    Year(&'static str),
+    /// The month is out of range (e.g., 13).
+    Month(u8),
+    /// The day is out of range (e.g., 32).
+    DayOutOfRange(u8),
+    /// The day does not exist in its month (e.g., February 30th).
+    DayOfMonth(u8),
+}
+
+impl DateError {
+    fn reason(self) -> &'static str {
+        match self {
+            DateError::Year(reason) => reason,
+            DateError::Month(_) => "month must be between 1 and 12",
+            DateError::DayOutOfRange(_) => "day must be between 1 and 31",
+            DateError::DayOfMonth(_) => "day is not valid for the given month",
+        }
+    }
+
+    /// Returns the component out of range and its value, or `None` if the
+    /// date is not well formed.
+    fn component(self) -> Option<InvalidComponent> {
+        let (component, value) = match self {
+            DateError::Year(_) => return None,
+            DateError::Month(month) => (Component::Month, month),
+            DateError::DayOutOfRange(day) | DateError::DayOfMonth(day) => (Component::Day, day),
+        };
+        Some(InvalidComponent::new(
+            component,
+            value.into(),
+            self.reason(),
+        ))
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Debug, Default)]
 pub(crate) struct Date {
     pub(crate) day: u8,
     pub(crate) month: u8,
     pub(crate) year: Option<u16>,
+    /// The year was given with two digits, and taken as a year of the 20th or
+    /// 21st century.
+    pub(crate) two_digit_year: bool,
 }
 
 impl Date {
@@ -53,12 +98,13 @@ impl Date {
             day: self.day,
             month: self.month,
             year: Some(year),
+            two_digit_year: false,
         }
     }
 }
 
 impl TryFrom<(&str, u8, u8)> for Date {
-    type Error = &'static str;
+    type Error = DateError;
 
     /// Create a `Date` from a tuple of `(year, month, day)`.
     ///
@@ -67,49 +113,60 @@ impl TryFrom<(&str, u8, u8)> for Date {
     /// details.
     fn try_from(value: (&str, u8, u8)) -> Result<Self, Self::Error> {
         let (year_str, month, day) = value;
-        let year = year_from_str(year_str)?;
+        let Year {
+            value: year,
+            expanded,
+        } = year_from_str(year_str).map_err(DateError::Year)?;
 
         if !(1..=12).contains(&month) {
-            return Err("month must be between 1 and 12");
+            return Err(DateError::Month(month));
         }
 
         let is_leap_year = (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0);
 
-        if !(1..=31).contains(&day)
-            || (month == 2 && day > (if is_leap_year { 29 } else { 28 }))
+        if !(1..=31).contains(&day) {
+            return Err(DateError::DayOutOfRange(day));
+        }
+
+        if (month == 2 && day > (if is_leap_year { 29 } else { 28 }))
             || ((month == 4 || month == 6 || month == 9 || month == 11) && day > 30)
         {
-            return Err("day is not valid for the given month");
+            return Err(DateError::DayOfMonth(day));
         }
 
         Ok(Date {
             day,
             month,
             year: Some(year),
+            two_digit_year: expanded,
         })
     }
 }
 
 impl TryFrom<(u8, u8)> for Date {
-    type Error = &'static str;
+    type Error = DateError;
 
     /// Create a `Date` from a tuple of `(month, day)`.
     fn try_from((month, day): (u8, u8)) -> Result<Self, Self::Error> {
         if !(1..=12).contains(&month) {
-            return Err("month must be between 1 and 12");
+            return Err(DateError::Month(month));
+        }
+
+        if !(1..=31).contains(&day) {
+            return Err(DateError::DayOutOfRange(day));
         }
 
-        if !(1..=31).contains(&day)
-            || (month == 2 && day > 29)
+        if (month == 2 && day > 29)
             || ((month == 4 || month == 6 || month == 9 || month == 11) && day > 30)
         {
-            return Err("day is not valid for the given month");
+            return Err(DateError::DayOfMonth(day));
         }
 
         Ok(Date {
             day,
             month,
             year: None,
+            two_digit_year: false,
         })
     }
 }
@@ -138,9 +195,26 @@ pub(super) fn iso1(input: &mut &str) -> ModalResult<Date> {
     let (year, _, month, _, day) =
         (year_str, s('-'), s(dec_uint), s('-'), s(dec_uint)).parse_next(input)?;
 
-    (year, month, day)
-        .try_into()
-        .map_err(|e| ErrMode::Cut(ctx_err(e)))
+    (year, month, day).try_into().map_err(invalid_date)
+}
+
+/// Parse `[year]-[month]` (e.g., "2024-07"), with a four-digit year and a
+/// two-digit month, as the first day of the month.
+///
+/// GNU `date` rejects it, while it is always accepted here.
+pub(super) fn year_month(input: &mut &str) -> ModalResult<Date> {
+    let (year, _, month) = terminated(
+        s((
+            take_while(4, AsChar::is_dec_digit),
+            '-',
+            take_while(2, AsChar::is_dec_digit),
+        )),
+        not(one_of(|c: char| c.is_ascii_alphanumeric() || c == '-')),
+    )
+    .parse_next(input)?;
+    let month = month_from_str(month)?;
+
+    (year, month, 1).try_into().map_err(invalid_date)
 }
 
 /// Parse `[year][month][day]`
@@ -154,9 +228,7 @@ pub(super) fn iso2(input: &mut &str) -> ModalResult<Date> {
     let month = month_from_str(&date_str[len - 4..len - 2])?;
     let day = day_from_str(&date_str[len - 2..])?;
 
-    (year, month, day)
-        .try_into()
-        .map_err(|e| ErrMode::Cut(ctx_err(e)))
+    (year, month, day).try_into().map_err(invalid_date)
 }
 
 /// Parse `[year]/[month]/[day]` or `[month]/[day]/[year]` or `[month]/[day]`.
@@ -176,21 +248,17 @@ fn us(input: &mut &str) -> ModalResult<Date> {
             // GNU quirk: interpret as [year]/[month]/[day] if the first part is at
             // least 4 characters long.
             let day = day_from_str(s2)?;
-            (s1, n, day)
-                .try_into()
-                .map_err(|e| ErrMode::Cut(ctx_err(e)))
+            (s1, n, day).try_into().map_err(invalid_date)
         }
         Some(s2) => {
             // [month]/[day]/[year]
             let month = month_from_str(s1)?;
-            (s2, month, n)
-                .try_into()
-                .map_err(|e| ErrMode::Cut(ctx_err(e)))
+            (s2, month, n).try_into().map_err(invalid_date)
         }
         None => {
             // [month]/[day]
             let month = month_from_str(s1)?;
-            (month, n).try_into().map_err(|e| ErrMode::Cut(ctx_err(e)))
+            (month, n).try_into().map_err(invalid_date)
         }
     }
 }
@@ -211,12 +279,8 @@ fn literal1(input: &mut &str) -> ModalResult<Date> {
         .parse_next(input)?;
 
     match year {
-        Some(year) => (year, month, day)
-            .try_into()
-            .map_err(|e| ErrMode::Cut(ctx_err(e))),
-        None => (month, day)
-            .try_into()
-            .map_err(|e| ErrMode::Cut(ctx_err(e))),
+        Some(year) => (year, month, day).try_into().map_err(invalid_date),
+        None => (month, day).try_into().map_err(invalid_date),
     }
 }
 
@@ -240,20 +304,33 @@ fn literal2(input: &mut &str) -> ModalResult<Date> {
         .parse_next(input)?;
 
     match year {
-        Some(year) => (year, month, day)
-            .try_into()
-            .map_err(|e| ErrMode::Cut(ctx_err(e))),
-        None => (month, day)
-            .try_into()
-            .map_err(|e| ErrMode::Cut(ctx_err(e))),
+        Some(year) => (year, month, day).try_into().map_err(invalid_date),
+        None => (month, day).try_into().map_err(invalid_date),
     }
 }
 
+/// Parse the name of a month on its own (e.g., "july"), as the first day of
+/// that month. A month followed by a number other than the hour of a time
+/// is left to the other formats (e.g., "july 4", but not "july 10:00").
+///
+/// Only recognized with [`Parser::natural_language`](crate::Parser::natural_language),
+/// as GNU `date` rejects a month on its own.
+pub(super) fn month_only(input: &mut &str) -> ModalResult<Date> {
+    terminated(literal_month, not((s(digit1), not(':'))))
+        .map(|month| Date {
+            day: 1,
+            month,
+            year: None,
+            two_digit_year: false,
+        })
+        .parse_next(input)
+}
+
 /// Parse the name of a month (case-insensitive)
-fn literal_month(input: &mut &str) -> ModalResult<u8> {
-    s(alpha1)
-        .verify_map(|s: &str| {
-            Some(match s {
+pub(super) fn literal_month(input: &mut &str) -> ModalResult<u8> {
+    s((alpha1, opt('.')))
+        .verify_map(|(s, period): (&str, Option<char>)| {
+            let month = match s {
                 "january" | "jan" => 1,
                 "february" | "feb" => 2,
                 "march" | "mar" => 3,
@@ -267,7 +344,9 @@ fn literal_month(input: &mut &str) -> ModalResult<u8> {
                 "november" | "nov" => 11,
                 "december" | "dec" => 12,
                 _ => return None,
-            })
+            };
+            // Only abbreviations may be followed by a period.
+            (period.is_none() || s.len() == 3 || s == "sept").then_some(month)
         })
         .parse_next(input)
 }
@@ -277,6 +356,19 @@ fn month_from_str(s: &str) -> ModalResult<u8> {
         .map_err(|_| ErrMode::Cut(ctx_err("month must be a valid u8 number")))
 }
 
+/// Fail without backtracking on a date that is not valid. A month or a day out
+/// of range (e.g., "2025-13-01" or "2025-02-30") is well formed, and so is an
+/// invalid date error, naming the component, rather than a syntax error.
+fn invalid_date(err: DateError) -> ErrMode<ContextError> {
+    match err.component() {
+        Some(component) => ErrMode::Cut(ContextError::from_external_error(
+            &"",
+            Error::InvalidDate(component),
+        )),
+        None => ErrMode::Cut(ctx_err(err.reason())),
+    }
+}
+
 fn day_from_str(s: &str) -> ModalResult<u8> {
     s.parse::<u8>()
         .map_err(|_| ErrMode::Cut(ctx_err("day must be a valid u8 number")))
@@ -284,6 +376,11 @@ fn day_from_str(s: &str) -> ModalResult<u8> {
 
 #[cfg(test)]
 mod tests {
+    use crate::{
+        items::{at, error::Error, parse_at_date},
+        parser::Options,
+    };
+
     use super::{parse, Date};
 
     // Test cases from the GNU docs:
@@ -306,6 +403,7 @@ mod tests {
             year: Some(1),
             month: 2,
             day: 3,
+            two_digit_year: false,
         };
 
         for mut s in ["1-2-3", "1 - 2 - 3", "1-02-03", "1-002-003", "001-02-03"] {
@@ -319,6 +417,7 @@ mod tests {
             year: Some(2001),
             month: 2,
             day: 3,
+            two_digit_year: true,
         };
 
         for mut s in ["01-2-3", "01-02-03"] {
@@ -332,6 +431,7 @@ mod tests {
             year: Some(1970),
             month: 2,
             day: 3,
+            two_digit_year: true,
         };
 
         for mut s in ["70-2-3", "70-02-03"] {
@@ -351,6 +451,7 @@ mod tests {
             year: Some(1),
             month: 2,
             day: 3,
+            two_digit_year: false,
         };
 
         for mut s in ["10203", "0010203", "00010203", "000010203"] {
@@ -364,6 +465,7 @@ mod tests {
             year: Some(2001),
             month: 2,
             day: 3,
+            two_digit_year: true,
         };
 
         let mut s = "010203";
@@ -376,6 +478,7 @@ mod tests {
             year: Some(1970),
             month: 2,
             day: 3,
+            two_digit_year: true,
         };
 
         let mut s = "700203";
@@ -394,6 +497,7 @@ mod tests {
             year: Some(1),
             month: 2,
             day: 3,
+            two_digit_year: false,
         };
 
         for mut s in ["2/3/1", "2 / 3 / 1", "02/03/ 001", "0001/2/3"] {
@@ -405,6 +509,7 @@ mod tests {
             year: None,
             month: 2,
             day: 3,
+            two_digit_year: false,
         };
 
         for mut s in ["2/3", "2 / 3"] {
@@ -418,6 +523,7 @@ mod tests {
             year: Some(2001),
             month: 2,
             day: 3,
+            two_digit_year: true,
         };
 
         let mut s = "2/3/01";
@@ -430,6 +536,7 @@ mod tests {
             year: Some(1970),
             month: 2,
             day: 3,
+            two_digit_year: true,
         };
 
         let mut s = "2/3/70";
@@ -448,6 +555,7 @@ mod tests {
             year: Some(2022),
             month: 11,
             day: 14,
+            two_digit_year: false,
         };
 
         for mut s in [
@@ -457,6 +565,8 @@ mod tests {
             "14-nov2022",
             "14nov2022",
             "14nov      2022",
+            "14 nov. 2022",
+            "14-nov.-2022",
         ] {
             let old_s = s.to_owned();
             assert_eq!(parse(&mut s).unwrap(), reference, "Format string: {old_s}");
@@ -466,9 +576,10 @@ mod tests {
             year: None,
             month: 11,
             day: 14,
+            two_digit_year: false,
         };
 
-        for mut s in ["14 november", "14 nov", "14-nov", "14nov"] {
+        for mut s in ["14 november", "14 nov", "14-nov", "14nov", "14 nov."] {
             let old_s = s.to_owned();
             assert_eq!(parse(&mut s).unwrap(), reference, "Format string: {old_s}");
         }
@@ -477,6 +588,7 @@ mod tests {
             year: None,
             month: 11,
             day: 14,
+            two_digit_year: false,
         };
 
         // Year must be followed by a space or end of input.
@@ -497,6 +609,7 @@ mod tests {
             year: Some(2022),
             month: 11,
             day: 14,
+            two_digit_year: false,
         };
 
         for mut s in [
@@ -506,6 +619,7 @@ mod tests {
             "nov 14 2022",
             "nov14 2022",
             "nov14, 2022",
+            "nov. 14, 2022",
         ] {
             let old_s = s.to_owned();
             assert_eq!(parse(&mut s).unwrap(), reference, "Format string: {old_s}");
@@ -515,6 +629,7 @@ mod tests {
             year: None,
             month: 11,
             day: 14,
+            two_digit_year: false,
         };
 
         for mut s in ["november 14", "nov 14", "nov14"] {
@@ -526,8 +641,23 @@ mod tests {
             year: None,
             month: 11,
             day: 14,
+            two_digit_year: false,
         };
 
+        for (mut s, month) in [("sept 14", 9), ("sept. 14", 9), ("may. 14", 5)] {
+            let old_s = s.to_owned();
+            assert_eq!(
+                parse(&mut s).unwrap(),
+                Date { month, ..reference },
+                "Format string: {old_s}"
+            );
+        }
+
+        // Only abbreviations may be followed by a period.
+        for mut s in ["november. 14", "june. 14"] {
+            assert!(parse(&mut s).is_err(), "Format string: {s}");
+        }
+
         // There must be some space between the comma and the year.
         let mut s = "november 14,2022";
         let old_s = s.to_owned();
@@ -552,19 +682,17 @@ mod tests {
             year: Some(2022),
             month: 11,
             day: 14,
+            two_digit_year: false,
         };
 
         for mut s in [
             "2022-11-14",
             "2022    -  11  -   14",
-            "22-11-14",
             "2022---11----14",
-            "22(comment 1)-11(comment 2)-14",
             "11/14/2022",
             "11--/14--/2022",
             "11(comment 1)/(comment 2)14(comment 3)/(comment 4)2022",
             "11   /  14   /      2022",
-            "11/14/22",
             "14 november 2022",
             "14 nov 2022",
             "november 14, 2022",
@@ -577,6 +705,15 @@ mod tests {
             let old_s = s.to_owned();
             assert_eq!(parse(&mut s).unwrap(), reference, "Format string: {old_s}");
         }
+
+        let reference = Date {
+            two_digit_year: true,
+            ..reference
+        };
+        for mut s in ["22-11-14", "22(comment 1)-11(comment 2)-14", "11/14/22"] {
+            let old_s = s.to_owned();
+            assert_eq!(parse(&mut s).unwrap(), reference, "Format string: {old_s}");
+        }
     }
 
     #[test]
@@ -585,6 +722,7 @@ mod tests {
             year: None,
             month: 11,
             day: 14,
+            two_digit_year: false,
         };
         for mut s in [
             "11/14",
@@ -601,4 +739,45 @@ mod tests {
             assert_eq!(parse(&mut s).unwrap(), reference);
         }
     }
+
+    #[test]
+    fn year_month() {
+        let reference = Date {
+            year: Some(2024),
+            month: 7,
+            day: 1,
+            two_digit_year: false,
+        };
+        for mut s in ["2024-07", " 2024-07", "2024-07 10:00"] {
+            assert_eq!(super::year_month(&mut s).unwrap(), reference);
+        }
+
+        for mut s in ["2024-7", "24-07", "2024-07-15", "2024-071", "2024-07t10:00"] {
+            assert!(super::year_month(&mut s).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn year_month_resolved() {
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("2024-07", "2024-07-01 00:00:00"),
+            ("2024-12 10:30", "2024-12-01 10:30:00"),
+            ("2024-02 +1 month", "2024-03-01 00:00:00"),
+            ("2024-07-15", "2024-07-15 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        assert!(matches!(
+            parse_at_date(now, "2024-13", &Options::default()),
+            Err(Error::InvalidDate(_))
+        ));
+    }
 }
@@ -16,15 +16,19 @@
 //! > example, on most hosts ‘@1483228799’ represents 2016-12-31 23:59:59 UTC,
 //! > ‘@1483228800’ represents 2017-01-01 00:00:00 UTC, and there is no way to
 //! > represent the intervening leap second 2016-12-31 23:59:60 UTC.
+//!
+//! As an extension, the number may be followed by a unit: `s` (seconds, the
+//! default), `ms` (milliseconds), `us` (microseconds) or `ns` (nanoseconds),
+//! e.g., `@1690466034123ms`.
 
 use winnow::{
-    ascii::digit1,
-    combinator::{opt, preceded},
+    ascii::{digit1, multispace1},
+    combinator::{alt, eof, opt, peek, preceded},
     token::one_of,
     ModalResult, Parser,
 };
 
-use super::primitive::{dec_uint, plus_or_minus, s};
+use super::primitive::{dec_uint, dec_uint_str, plus_or_minus, s};
 
 /// Represents a timestamp with nanosecond accuracy.
 ///
@@ -33,41 +37,129 @@ use super::primitive::{dec_uint, plus_or_minus, s};
 /// - `nanosecond` is always in the range of `0..1_000_000_000`.
 /// - Negative timestamps are represented by a negative `second` value and a
 ///   positive `nanosecond` value.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub(super) struct Timestamp {
-    second: i64,
+    second: i128,
     nanosecond: u32,
 }
 
+impl Timestamp {
+    /// Create a timestamp from a number of nanoseconds since the Unix epoch.
+    /// The timestamp may be out of the supported range, which is only checked
+    /// when it is resolved.
+    pub(super) fn from_nanos(nanos: i128) -> Self {
+        Timestamp {
+            second: nanos.div_euclid(NANOS_PER_SEC),
+            nanosecond: nanos.rem_euclid(NANOS_PER_SEC) as u32,
+        }
+    }
+
+    /// Count the timestamp from `origin` instead of from the Unix epoch.
+    pub(super) fn since(self, origin: jiff::Timestamp) -> Self {
+        let nanos = self.second * NANOS_PER_SEC + i128::from(self.nanosecond);
+        Self::from_nanos(nanos.saturating_add(origin.as_nanosecond()))
+    }
+
+    /// Whether the timestamp is before the Unix epoch.
+    pub(super) fn is_negative(&self) -> bool {
+        self.second < 0
+    }
+}
+
 impl TryFrom<Timestamp> for jiff::Timestamp {
     type Error = &'static str;
 
     fn try_from(ts: Timestamp) -> Result<Self, Self::Error> {
         jiff::Timestamp::new(
-            ts.second,
+            i64::try_from(ts.second).map_err(|_| "timestamp value is out of valid range")?,
             i32::try_from(ts.nanosecond).map_err(|_| "nanosecond in timestamp exceeds i32::MAX")?,
         )
         .map_err(|_| "timestamp value is out of valid range")
     }
 }
 
+pub(super) const NANOS_PER_SEC: i128 = 1_000_000_000;
+
 /// Parse a timestamp in the form of `@1234567890` or `@-1234567890.12345` or
-/// `@1234567890,12345`.
+/// `@1234567890,12345`, optionally followed by a unit (e.g.,
+/// `@1234567890123ms`).
 pub(super) fn parse(input: &mut &str) -> ModalResult<Timestamp> {
-    (s("@"), opt(plus_or_minus), s(sec_and_nsec))
-        .verify_map(|(_, sign, (sec, nsec))| {
-            let sec = i64::try_from(sec).ok()?;
-            let (second, nanosecond) = match (sign, nsec) {
-                (Some('-'), 0) => (-sec, 0),
-                // Truncate towards minus infinity.
-                (Some('-'), _) => ((-sec).checked_sub(1)?, 1_000_000_000 - nsec),
-                _ => (sec, nsec),
+    preceded(s("@"), bare).parse_next(input)
+}
+
+/// Parse a timestamp without its leading `@` (e.g., `1234567890` or
+/// `-1234567890.12345ms`), as a bare number is read when an epoch origin is
+/// configured.
+pub(super) fn bare(input: &mut &str) -> ModalResult<Timestamp> {
+    (opt(plus_or_minus), s(wide_sec_and_nsec), opt(unit))
+        .map(|(sign, (whole, fraction), unit)| {
+            // The fraction is given in nanoseconds of the unit. Any precision
+            // beyond a nanosecond is dropped. A count too large for the
+            // arithmetic saturates, as it is out of range all the same.
+            let unit = unit.unwrap_or(NANOS_PER_SEC);
+            let nanos = whole
+                .saturating_mul(unit)
+                .saturating_add(i128::from(fraction) * unit / NANOS_PER_SEC);
+            let nanos = if sign == Some('-') { -nanos } else { nanos };
+
+            // Truncate towards minus infinity.
+            Timestamp::from_nanos(nanos)
+        })
+        .parse_next(input)
+}
+
+/// Parse an integer timestamp without a unit, with or without its leading `@`
+/// (e.g., `@1690466034123` or `1690466034123`), and detect its unit from its
+/// magnitude:
+///
+/// - below 10^11, seconds (up to the year 5138),
+/// - below 10^14, milliseconds (from 1973-03-03),
+/// - below 10^17, microseconds,
+/// - otherwise, nanoseconds.
+///
+/// Without its `@`, the integer must have at least 9 digits, so that it is not
+/// taken for a year, a time of day or a date (e.g., `20240115`).
+pub(super) fn magnitude(input: &mut &str) -> ModalResult<Timestamp> {
+    (
+        opt(s("@")),
+        opt(plus_or_minus),
+        s(dec_uint_str),
+        // The integer must end here, so that a fraction or a unit is not left
+        // over (e.g., "@1690466034.5").
+        peek(alt((multispace1, eof))),
+    )
+        .verify_map(|(at, sign, digits, _): (_, _, &str, _)| {
+            if at.is_none() && digits.len() < 9 {
+                return None;
+            }
+            let whole: i128 = digits.parse().ok()?;
+            let unit = match whole {
+                0..=99_999_999_999 => NANOS_PER_SEC,
+                100_000_000_000..=99_999_999_999_999 => 1_000_000,
+                100_000_000_000_000..=99_999_999_999_999_999 => 1_000,
+                _ => 1,
             };
-            Some(Timestamp { second, nanosecond })
+            let nanos = whole.checked_mul(unit)?;
+            Some(Timestamp::from_nanos(if sign == Some('-') {
+                -nanos
+            } else {
+                nanos
+            }))
         })
         .parse_next(input)
 }
 
+/// Parse the unit of a timestamp, as a number of nanoseconds.
+fn unit(input: &mut &str) -> ModalResult<i128> {
+    alt((
+        "ms".value(1_000_000),
+        "us".value(1_000),
+        "ns".value(1),
+        "s".value(NANOS_PER_SEC),
+    ))
+    .parse_next(input)
+}
+
 /// Parse a second value in the form of `1234567890` or `1234567890.12345` or
 /// `1234567890,12345`.
 ///
@@ -76,23 +168,45 @@ pub(super) fn parse(input: &mut &str) -> ModalResult<Timestamp> {
 /// (padded with zeros on the right if fewer digits are present). If the second
 /// part is omitted, it defaults to 0 nanoseconds.
 pub(super) fn sec_and_nsec(input: &mut &str) -> ModalResult<(u64, u32)> {
-    (dec_uint, opt(preceded(one_of(['.', ',']), digit1)))
-        .verify_map(|(sec, opt_nsec_str)| match opt_nsec_str {
-            Some(nsec_str) if nsec_str.len() >= 9 => Some((sec, nsec_str[..9].parse().ok()?)),
+    (dec_uint, nsec).parse_next(input)
+}
+
+/// Parse a second value as [`sec_and_nsec`] does, with whole seconds of any
+/// magnitude: those beyond `i128` saturate to `i128::MAX`, so that a timestamp
+/// out of range is rejected or saturated when it is resolved, and not taken
+/// for a syntax error.
+fn wide_sec_and_nsec(input: &mut &str) -> ModalResult<(i128, u32)> {
+    (
+        dec_uint_str.map(|sec: &str| sec.parse().unwrap_or(i128::MAX)),
+        nsec,
+    )
+        .parse_next(input)
+}
+
+/// Parse the optional fractional part of a second value, as nanoseconds.
+fn nsec(input: &mut &str) -> ModalResult<u32> {
+    opt(preceded(one_of(['.', ',']), digit1))
+        .verify_map(|opt_nsec_str: Option<&str>| match opt_nsec_str {
+            Some(nsec_str) if nsec_str.len() >= 9 => nsec_str[..9].parse().ok(),
             Some(nsec_str) => {
                 let multiplier = 10_u32.pow(9 - nsec_str.len() as u32);
-                Some((sec, nsec_str.parse::<u32>().ok()?.checked_mul(multiplier)?))
+                nsec_str.parse::<u32>().ok()?.checked_mul(multiplier)
             }
-            None => Some((sec, 0)),
+            None => Some(0),
         })
         .parse_next(input)
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+    };
+
     use super::*;
 
-    fn ts(second: i64, nanosecond: u32) -> Timestamp {
+    fn ts(second: i128, nanosecond: u32) -> Timestamp {
         Timestamp { second, nanosecond }
     }
 
@@ -134,4 +248,144 @@ mod tests {
             assert_eq!(parse(&mut s).unwrap(), expected, "{input}");
         }
     }
+
+    #[test]
+    fn timestamp_with_unit() {
+        for (input, expected) in [
+            ("@1690466034s", ts(1690466034, 0)),
+            ("@1690466034123ms", ts(1690466034, 123000000)),
+            ("@1690466034123456us", ts(1690466034, 123456000)),
+            ("@1690466034123456789ns", ts(1690466034, 123456789)),
+            ("@-1690466034123ms", ts(-1690466035, 877000000)),
+            ("@1690466034123.5ms", ts(1690466034, 123500000)),
+            ("@1.5ns", ts(0, 1)),
+            ("@-1.5ns", ts(-1, 999999999)),
+        ] {
+            let mut s = input;
+            assert_eq!(parse(&mut s).unwrap(), expected, "{input}");
+            assert_eq!(s, "", "{input}");
+        }
+
+        // An unknown unit is left unparsed.
+        let mut s = "@1690466034min";
+        assert_eq!(parse(&mut s).unwrap(), ts(1690466034, 0));
+        assert_eq!(s, "min");
+    }
+    #[test]
+    fn timestamp_magnitude() {
+        for (input, expected) in [
+            ("@1690466034", ts(1690466034, 0)),
+            ("1690466034", ts(1690466034, 0)),
+            ("@0", ts(0, 0)),
+            ("99999999999", ts(99999999999, 0)),
+            ("100000000000", ts(100000000, 0)),
+            ("1690466034123", ts(1690466034, 123000000)),
+            ("1690466034123456", ts(1690466034, 123456000)),
+            ("1690466034123456789", ts(1690466034, 123456789)),
+            ("-1690466034123", ts(-1690466035, 877000000)),
+            ("@ -1690466034123 ", ts(-1690466035, 877000000)),
+        ] {
+            let mut s = input;
+            assert_eq!(magnitude(&mut s).unwrap(), expected, "{input}");
+        }
+
+        for input in [
+            "12345678",
+            "20240115",
+            "@1690466034.5",
+            "@1690466034123ms",
+            "1690466034abc",
+        ] {
+            let mut s = input;
+            assert!(magnitude(&mut s).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn epoch_origin() {
+        // Cocoa's reference date.
+        let options = Options {
+            epoch_origin: Some("2001-01-01T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("@0", "2001-01-01 00:00:00.000"),
+            ("694224000", "2023-01-01 00:00:00.000"),
+            (" 694224000.25 ", "2023-01-01 00:00:00.250"),
+            ("-86400", "2000-12-31 00:00:00.000"),
+            ("@1500ms", "2001-01-01 00:00:01.500"),
+            ("1230", "2001-01-01 00:20:30.000"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S%.3f").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // Other timestamps are not counted from the origin.
+        let result = parse_at_date(now.clone(), "@400000000000000a", &options).unwrap();
+        assert_eq!(result.timestamp().as_second(), 0);
+        // Numbers in other items are unaffected.
+        let result = parse_at_date(now.clone(), "1230 +1 day", &options).unwrap();
+        assert_eq!(result.strftime("%H:%M").to_string(), "12:30");
+        assert!(parse_at_date(now.clone(), "1230 694224000", &options).is_err());
+
+        let machine = Options {
+            machine_input: true,
+            ..options
+        };
+        let result = parse_at_date(now.clone(), "@694224000", &machine).unwrap();
+        assert_eq!(result.strftime("%Y-%m-%d").to_string(), "2023-01-01");
+        assert!(parse_at_date(now.clone(), "694224000", &machine).is_err());
+
+        // Without an origin, a bare number is a time of day.
+        let result = parse_at_date(now, "1230", &Options::default()).unwrap();
+        assert_eq!(result.strftime("%H:%M").to_string(), "12:30");
+    }
+
+    #[test]
+    fn detect_epoch_unit() {
+        let options = Options {
+            detect_epoch_unit: true,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for input in [
+            "1690466034",
+            "@1690466034",
+            "1690466034000",
+            "1690466034000000",
+            "@1690466034000000000",
+            "@1690466034s",
+            "@1690466034000ms",
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(result.timestamp().as_second(), 1690466034, "{input}");
+        }
+
+        // Short numbers keep their GNU meaning.
+        let result = parse_at_date(now.clone(), "1230", &options).unwrap();
+        assert_eq!(result.strftime("%H:%M").to_string(), "12:30");
+        let result = parse_at_date(now.clone(), "20240115", &options).unwrap();
+        assert_eq!(result.strftime("%Y-%m-%d").to_string(), "2024-01-15");
+        // A fraction is in seconds.
+        let result = parse_at_date(now.clone(), "@100000000000.5", &options).unwrap();
+        assert_eq!(result.timestamp().as_second(), 100000000000);
+
+        // The detected unit applies before the origin.
+        let options = Options {
+            epoch_origin: Some("2001-01-01T00:00:00Z".parse().unwrap()),
+            ..options
+        };
+        let result = parse_at_date(now.clone(), "694224000000", &options).unwrap();
+        assert_eq!(result.strftime("%Y-%m-%d").to_string(), "2023-01-01");
+
+        // Detection is opt-in.
+        assert!(parse_at_date(now, "1690466034000", &Options::default()).is_err());
+    }
 }
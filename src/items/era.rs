@@ -0,0 +1,250 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore meiji taisho showa heisei reiwa gannen
+
+//! Parse a Japanese era date item.
+//!
+//! With the `calendars` feature, [`Parser::eras`](crate::Parser::eras)
+//! enables Japanese era dates in the kanji notation (e.g., "令和6年5月1日",
+//! with "元年" for the first year of an era) and in the romanized notation
+//! (e.g., "R6.5.1", "H31/4/30" or "S64-1-7"). The date is converted to the
+//! Gregorian calendar.
+
+use winnow::{
+    combinator::{alt, opt},
+    error::{ContextError, ErrMode},
+    stream::AsChar,
+    token::one_of,
+    ModalResult, Parser,
+};
+
+use super::{
+    date::Date,
+    primitive::{ctx_err, dec_uint, s},
+};
+
+struct Era {
+    name: &'static str,
+    abbreviation: char,
+    /// The first day of the era, as (year, month, day).
+    start: (i16, i8, i8),
+}
+
+/// The eras since the adoption of the Gregorian calendar, in order.
+const ERAS: [Era; 5] = [
+    Era {
+        name: "明治",
+        abbreviation: 'm',
+        start: (1868, 1, 25),
+    },
+    Era {
+        name: "大正",
+        abbreviation: 't',
+        start: (1912, 7, 30),
+    },
+    Era {
+        name: "昭和",
+        abbreviation: 's',
+        start: (1926, 12, 25),
+    },
+    Era {
+        name: "平成",
+        abbreviation: 'h',
+        start: (1989, 1, 8),
+    },
+    Era {
+        name: "令和",
+        abbreviation: 'r',
+        start: (2019, 5, 1),
+    },
+];
+
+/// Parse a Japanese era date.
+pub(super) fn parse(input: &mut &str) -> ModalResult<Date> {
+    let (era, year, month, day) = alt((kanji, romanized)).parse_next(input)?;
+    to_gregorian(era, year, month, day).map_err(|e| ErrMode::Cut(ctx_err(e)))
+}
+
+/// Parse a date in kanji notation, e.g., "令和6年5月1日".
+fn kanji(input: &mut &str) -> ModalResult<(usize, u16, u8, u8)> {
+    (
+        s(alt(ERAS.map(|era| era.name))).map(era_index),
+        alt((s("元").value(1), s(dec_uint))),
+        s("年"),
+        s(dec_uint),
+        s("月"),
+        s(dec_uint),
+        s("日"),
+    )
+        .map(|(era, year, _, month, _, day, _)| (era, year, month, day))
+        .parse_next(input)
+}
+
+/// Parse a date in romanized notation, e.g., "R6.5.1".
+fn romanized(input: &mut &str) -> ModalResult<(usize, u16, u8, u8)> {
+    let era = s(one_of(|c: char| {
+        ERAS.iter().any(|era| era.abbreviation == c)
+    }))
+    .map(|c: char| {
+        ERAS.iter()
+            .position(|era| era.abbreviation == c)
+            .unwrap_or_default()
+    })
+    .parse_next(input)?;
+    let (year, sep) = (dec_uint, one_of(['.', '/', '-'])).parse_next(input)?;
+    let (month, _, day) = (dec_uint, sep, dec_uint).parse_next(input)?;
+
+    // Reject trailing digits or letters, e.g., "r6.5.1x".
+    if opt(one_of(AsChar::is_alphanum))
+        .parse_next(input)?
+        .is_some()
+    {
+        return Err(ErrMode::Backtrack(ContextError::new()));
+    }
+
+    Ok((era, year, month, day))
+}
+
+fn era_index(name: &str) -> usize {
+    ERAS.iter()
+        .position(|era| era.name == name)
+        .unwrap_or_default()
+}
+
+/// Convert a date of the era at `index` to a Gregorian date, checking that it
+/// falls within the era.
+fn to_gregorian(index: usize, year: u16, month: u8, day: u8) -> Result<Date, &'static str> {
+    let (start_year, start_month, start_day) = ERAS[index].start;
+    let start = jiff::civil::date(start_year, start_month, start_day);
+
+    let gregorian_year = i16::try_from(year)
+        .ok()
+        .filter(|y| *y >= 1)
+        .and_then(|y| y.checked_add(start_year - 1))
+        .ok_or("year is not valid for the era")?;
+    let date = jiff::civil::Date::new(
+        gregorian_year,
+        i8::try_from(month).map_err(|_| "month must be between 1 and 12")?,
+        i8::try_from(day).map_err(|_| "day is not valid for the given month")?,
+    )
+    .map_err(|_| "day is not valid for the given month")?;
+
+    let end = ERAS.get(index + 1).map(|next| {
+        let (y, m, d) = next.start;
+        jiff::civil::date(y, m, d)
+    });
+    if date < start || end.is_some_and(|end| date >= end) {
+        return Err("date is not within the era");
+    }
+
+    Ok(Date {
+        day,
+        month,
+        year: Some(gregorian_year as u16),
+        two_digit_year: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+    };
+
+    use super::{parse, Date};
+
+    fn date(year: u16, month: u8, day: u8) -> Date {
+        Date {
+            day,
+            month,
+            year: Some(year),
+            two_digit_year: false,
+        }
+    }
+
+    #[test]
+    fn kanji() {
+        for (input, expected) in [
+            ("令和6年5月1日", date(2024, 5, 1)),
+            ("令和 6 年 5 月 1 日", date(2024, 5, 1)),
+            ("令和元年5月1日", date(2019, 5, 1)),
+            ("平成31年4月30日", date(2019, 4, 30)),
+            ("昭和64年1月7日", date(1989, 1, 7)),
+            ("大正元年7月30日", date(1912, 7, 30)),
+            ("明治45年7月29日", date(1912, 7, 29)),
+        ] {
+            let mut s = input;
+            assert_eq!(parse(&mut s).unwrap(), expected, "{input}");
+            assert!(s.is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn romanized() {
+        for (input, expected, rest) in [
+            ("r6.5.1", date(2024, 5, 1), ""),
+            ("r6/5/1 10:00", date(2024, 5, 1), " 10:00"),
+            ("h31-4-30", date(2019, 4, 30), ""),
+            ("s64.1.7", date(1989, 1, 7), ""),
+            ("h1.1.8", date(1989, 1, 8), ""),
+        ] {
+            let mut s = input;
+            assert_eq!(parse(&mut s).unwrap(), expected, "{input}");
+            assert_eq!(s, rest, "{input}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            // Outside of the era.
+            "平成31年5月1日",
+            "s64.1.8",
+            "r1.4.30",
+            "h0.1.8",
+            // Not a valid date.
+            "r6.2.30",
+            "r6.13.1",
+            // Malformed.
+            "r6.5/1",
+            "r6.5.1x",
+            "x6.5.1",
+            "令和6年5月",
+        ] {
+            let mut s = input;
+            assert!(parse(&mut s).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "calendars")]
+    fn japanese_era() {
+        let options = Options {
+            eras: true,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("令和6年5月1日", "2024-05-01 00:00:00"),
+            ("R6.5.1 10:00", "2024-05-01 10:00:00"),
+            ("H31/4/30 +1 day", "2019-05-01 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+            // Eras are opt-in.
+            assert!(
+                parse_at_date(now.clone(), input, &Options::default()).is_err(),
+                "{input}"
+            );
+        }
+
+        assert!(parse_at_date(now, "h31.5.1", &options).is_err());
+    }
+}
@@ -2,9 +2,30 @@ use std::fmt;
 
 use winnow::error::{ContextError, ErrMode};
 
+use crate::{InvalidComponent, ParseDateTimeError, ParseError};
+
 #[derive(Debug)]
 pub(crate) enum Error {
-    ParseError(String),
+    Parse(ParseError),
+    Resolve(String),
+    InvalidDate(InvalidComponent),
+    InvalidTime(InvalidComponent),
+    InputTooLong,
+    TooManyItems,
+    BudgetExceeded,
+    Rejected,
+    OutOfRange,
+}
+
+impl Error {
+    /// Report a failure of the resolution phase as a resolution error rather
+    /// than a syntax error.
+    pub(crate) fn resolving(self) -> Self {
+        match self {
+            Error::Parse(e) => Error::Resolve(e.reason),
+            err => err,
+        }
+    }
 }
 
 impl std::error::Error for Error {}
@@ -12,27 +33,188 @@ impl std::error::Error for Error {}
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Error::ParseError(reason) => {
-                write!(f, "{reason}")
-            }
+            Error::Parse(e) => write!(f, "{e}"),
+            Error::Resolve(reason) => write!(f, "{reason}"),
+            Error::InvalidDate(e) | Error::InvalidTime(e) => write!(f, "{e}"),
+            Error::InputTooLong => write!(f, "input is too long"),
+            Error::TooManyItems => write!(f, "input has too many items"),
+            Error::BudgetExceeded => write!(f, "input takes too many steps to parse"),
+            Error::Rejected => write!(f, "input is rejected by the resolution hook"),
+            Error::OutOfRange => write!(f, "date and time is out of range"),
         }
     }
 }
 
 impl From<&'static str> for Error {
     fn from(reason: &'static str) -> Self {
-        Error::ParseError(reason.to_owned())
+        Error::Parse(ParseError::new(reason))
     }
 }
 
 impl From<ErrMode<ContextError>> for Error {
     fn from(err: ErrMode<ContextError>) -> Self {
-        Error::ParseError(err.to_string())
+        // A limit that was exceeded, a well-formed date that does not exist,
+        // or a syntax error located in the input, is the cause of a cut error.
+        if let ErrMode::Cut(e) = &err {
+            match e.cause().and_then(|cause| cause.downcast_ref::<Error>()) {
+                Some(Error::Parse(e)) => return Error::Parse(e.clone()),
+                Some(Error::Resolve(reason)) => return Error::Resolve(reason.clone()),
+                Some(Error::InvalidDate(e)) => return Error::InvalidDate(e.clone()),
+                Some(Error::InvalidTime(e)) => return Error::InvalidTime(e.clone()),
+                Some(Error::InputTooLong) => return Error::InputTooLong,
+                Some(Error::TooManyItems) => return Error::TooManyItems,
+                Some(Error::BudgetExceeded) => return Error::BudgetExceeded,
+                Some(Error::OutOfRange) => return Error::OutOfRange,
+                _ => {}
+            }
+        }
+        match err {
+            ErrMode::Backtrack(e) | ErrMode::Cut(e) => Error::Parse(ParseError::new(reason(&e))),
+            ErrMode::Incomplete(_) => Error::Parse(ParseError::new("unexpected end of input")),
+        }
+    }
+}
+
+/// Returns the reason of a syntax error, from the labels, expected values and
+/// cause that it was given (e.g., "expected a day of the month"), or
+/// "unexpected input" if it has none.
+pub(crate) fn reason(err: &ContextError) -> String {
+    let reason = err.to_string();
+    if reason.is_empty() {
+        "unexpected input".to_owned()
+    } else {
+        reason.lines().collect::<Vec<_>>().join(", ")
+    }
+}
+
+/// The words of the grammar that a misspelled word is compared with.
+const WORDS: [&str; 40] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+    "year",
+    "month",
+    "fortnight",
+    "week",
+    "day",
+    "hour",
+    "minute",
+    "second",
+    "years",
+    "months",
+    "fortnights",
+    "weeks",
+    "days",
+    "hours",
+    "minutes",
+    "seconds",
+    "today",
+    "tomorrow",
+    "yesterday",
+    "ago",
+    "next",
+];
+
+/// Returns the words of the grammar within a small edit distance of the
+/// letters that `word` starts with, closest first, or none if those letters
+/// are a word of the grammar or too short to be compared.
+pub(crate) fn suggestions(word: &str) -> Vec<String> {
+    let end = word
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(word.len());
+    let word = &word[..end];
+    if word.len() < 3 || WORDS.contains(&word) {
+        return Vec::new();
+    }
+
+    let max = if word.len() <= 4 { 1 } else { 2 };
+    let mut close: Vec<(usize, &str)> = WORDS
+        .iter()
+        .map(|&w| (distance(word, w), w))
+        .filter(|&(d, _)| d <= max)
+        .collect();
+    close.sort_by_key(|&(d, _)| d);
+    close
+        .into_iter()
+        .take(3)
+        .map(|(_, w)| w.to_owned())
+        .collect()
+}
+
+/// Returns the Levenshtein distance between two ASCII words.
+fn distance(a: &str, b: &str) -> usize {
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.bytes().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.bytes().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
+    }
+    row[b.len()]
+}
+
+impl From<ParseDateTimeError> for Error {
+    fn from(err: ParseDateTimeError) -> Self {
+        match err {
+            ParseDateTimeError::Parse(e) => Error::Parse(e),
+            ParseDateTimeError::Resolve(e) => Error::Resolve(e.to_string()),
+            ParseDateTimeError::InvalidDate(e) => Error::InvalidDate(e),
+            ParseDateTimeError::InvalidTime(e) => Error::InvalidTime(e),
+            ParseDateTimeError::InputTooLong => Error::InputTooLong,
+            ParseDateTimeError::TooManyItems => Error::TooManyItems,
+            ParseDateTimeError::BudgetExceeded => Error::BudgetExceeded,
+            ParseDateTimeError::Rejected => Error::Rejected,
+            ParseDateTimeError::OutOfRange => Error::OutOfRange,
+        }
     }
 }
 
 impl From<jiff::Error> for Error {
     fn from(err: jiff::Error) -> Self {
-        Error::ParseError(err.to_string())
+        Error::Parse(ParseError::new(err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{distance, suggestions};
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance("tomorow", "tomorrow"), 1);
+        assert_eq!(distance("wensday", "wednesday"), 2);
+        assert_eq!(distance("day", "day"), 0);
+        assert_eq!(distance("", "ago"), 3);
+    }
+
+    #[test]
+    fn test_suggestions() {
+        assert_eq!(suggestions("tomorow"), ["tomorrow"]);
+        assert_eq!(suggestions("wensday,"), ["wednesday"]);
+        assert_eq!(suggestions("munday"), ["monday", "sunday"]);
+        assert_eq!(suggestions("hourz"), ["hour", "hours"]);
+        assert!(suggestions("tomorrow").is_empty());
+        assert!(suggestions("foobar").is_empty());
+        assert!(suggestions("xy").is_empty());
+        assert!(suggestions("123").is_empty());
     }
 }
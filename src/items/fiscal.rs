@@ -0,0 +1,291 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a fiscal period item.
+//!
+//! Once [`Parser::fiscal_year_start`](crate::Parser::fiscal_year_start) sets
+//! the first month of the fiscal year, these resolve to the first day of the
+//! period they name:
+//!
+//! - a fiscal year, e.g., "fy2025" or "fy 25",
+//! - a fiscal quarter, e.g., "fy2025 q2" or "q2 fy2025",
+//! - the current fiscal year or quarter, e.g., "start of fiscal year" or
+//!   "beginning of the fiscal quarter".
+//!
+//! A fiscal year is named after the calendar year in which it ends. For
+//! example, if the fiscal year starts in October, "fy2025" runs from October
+//! 2024 to September 2025.
+
+use jiff::{civil, Span};
+use winnow::{
+    ascii::multispace1,
+    combinator::{alt, eof, not, opt, peek, preceded, terminated},
+    error::ErrMode,
+    stream::AsChar,
+    token::one_of,
+    ModalResult, Parser,
+};
+
+use super::{
+    primitive::{ctx_err, s},
+    year::{year_from_str, year_str, Year},
+};
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub(crate) enum Fiscal {
+    /// A fiscal year, or a quarter of it, and whether the year was given with
+    /// two digits and taken as a year of the 20th or 21st century.
+    Period {
+        year: u16,
+        quarter: Option<u8>,
+        two_digit_year: bool,
+    },
+    /// The fiscal year containing the base date.
+    CurrentYear,
+    /// The fiscal quarter containing the base date.
+    CurrentQuarter,
+}
+
+impl Fiscal {
+    /// Resolve the period to its first day, for fiscal years starting in
+    /// `start_month`.
+    pub(super) fn resolve(
+        &self,
+        base: civil::Date,
+        start_month: i8,
+    ) -> Result<civil::Date, &'static str> {
+        // The first day of the fiscal year, and the number of months elapsed
+        // since then.
+        let (start, months) = match *self {
+            Fiscal::Period { year, quarter, .. } => {
+                let year = if start_month == 1 {
+                    year as i16
+                } else {
+                    year as i16 - 1
+                };
+                let quarter = quarter.map_or(0, |q| q as i64 - 1);
+                (civil::Date::new(year, start_month, 1), quarter * 3)
+            }
+            Fiscal::CurrentYear | Fiscal::CurrentQuarter => {
+                let year = if base.month() >= start_month {
+                    base.year()
+                } else {
+                    base.year() - 1
+                };
+                let months = (base.month() - start_month).rem_euclid(12) as i64;
+                let months = match self {
+                    Fiscal::CurrentQuarter => months / 3 * 3,
+                    _ => 0,
+                };
+                (civil::Date::new(year, start_month, 1), months)
+            }
+        };
+
+        start
+            .ok()
+            .and_then(|d| d.checked_add(Span::new().months(months)).ok())
+            .ok_or("fiscal period is not valid")
+    }
+}
+
+/// Parse a fiscal period item.
+pub(super) fn parse(input: &mut &str) -> ModalResult<Fiscal> {
+    alt((current, period)).parse_next(input)
+}
+
+/// Parse the current fiscal year or quarter, e.g., "start of fiscal year".
+fn current(input: &mut &str) -> ModalResult<Fiscal> {
+    preceded(
+        (
+            alt((s("start"), s("beginning"))),
+            s("of"),
+            opt(alt((s("the"), s("this")))),
+            s("fiscal"),
+        ),
+        alt((
+            s("year").value(Fiscal::CurrentYear),
+            s("quarter").value(Fiscal::CurrentQuarter),
+        )),
+    )
+    .parse_next(input)
+}
+
+/// Parse a fiscal year, optionally with a quarter, e.g., "fy2025 q2".
+fn period(input: &mut &str) -> ModalResult<Fiscal> {
+    let (quarter, year) = alt((
+        (quarter.map(Some), fiscal_year),
+        (fiscal_year, opt(quarter)).map(|(year, quarter)| (quarter, year)),
+    ))
+    .parse_next(input)?;
+
+    Ok(Fiscal::Period {
+        year: year.value,
+        quarter,
+        two_digit_year: year.expanded,
+    })
+}
+
+/// Parse a fiscal year, e.g., "fy2025" or "fy 25".
+fn fiscal_year(input: &mut &str) -> ModalResult<Year> {
+    // The year must be followed by a space, a quarter, or end of input.
+    let year = preceded(
+        s("fy"),
+        terminated(year_str, peek(alt((multispace1, eof, "q")))),
+    )
+    .parse_next(input)?;
+
+    year_from_str(year).map_err(|e| ErrMode::Cut(ctx_err(e)))
+}
+
+/// Parse a quarter, e.g., "q2".
+fn quarter(input: &mut &str) -> ModalResult<u8> {
+    preceded(
+        s("q"),
+        terminated(one_of('1'..='4'), not(one_of(AsChar::is_alphanum))),
+    )
+    .map(|c: char| c as u8 - b'0')
+    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+    };
+
+    use super::{parse, Fiscal};
+
+    #[test]
+    fn fiscal() {
+        let period = |year, quarter| Fiscal::Period {
+            year,
+            quarter,
+            two_digit_year: false,
+        };
+        for (input, expected, rest) in [
+            ("fy2025", period(2025, None), ""),
+            (
+                "fy 25",
+                Fiscal::Period {
+                    year: 2025,
+                    quarter: None,
+                    two_digit_year: true,
+                },
+                "",
+            ),
+            ("fy2025 q2", period(2025, Some(2)), ""),
+            ("fy2025q4 10:00", period(2025, Some(4)), " 10:00"),
+            ("q1 fy2024", period(2024, Some(1)), ""),
+            ("start of fiscal year", Fiscal::CurrentYear, ""),
+            (
+                "beginning of the fiscal quarter",
+                Fiscal::CurrentQuarter,
+                "",
+            ),
+        ] {
+            let mut s = input;
+            assert_eq!(parse(&mut s).unwrap(), expected, "{input}");
+            assert_eq!(s, rest, "{input}");
+        }
+
+        for input in [
+            "fy",
+            "fy2025x",
+            "q2",
+            "q5 fy2025",
+            "q12 fy2025",
+            "start of year",
+        ] {
+            let mut s = input;
+            assert!(parse(&mut s).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        let base = date(2025, 2, 14);
+        for (fiscal, start_month, expected) in [
+            (
+                Fiscal::Period {
+                    year: 2025,
+                    quarter: None,
+                    two_digit_year: false,
+                },
+                1,
+                date(2025, 1, 1),
+            ),
+            (
+                Fiscal::Period {
+                    year: 2025,
+                    quarter: None,
+                    two_digit_year: false,
+                },
+                10,
+                date(2024, 10, 1),
+            ),
+            (
+                Fiscal::Period {
+                    year: 2025,
+                    quarter: Some(2),
+                    two_digit_year: false,
+                },
+                10,
+                date(2025, 1, 1),
+            ),
+            (
+                Fiscal::Period {
+                    year: 2025,
+                    quarter: Some(4),
+                    two_digit_year: false,
+                },
+                4,
+                date(2025, 1, 1),
+            ),
+            (Fiscal::CurrentYear, 1, date(2025, 1, 1)),
+            (Fiscal::CurrentYear, 4, date(2024, 4, 1)),
+            (Fiscal::CurrentQuarter, 4, date(2025, 1, 1)),
+            (Fiscal::CurrentQuarter, 7, date(2025, 1, 1)),
+            (Fiscal::CurrentQuarter, 12, date(2024, 12, 1)),
+            (Fiscal::CurrentQuarter, 2, date(2025, 2, 1)),
+        ] {
+            assert_eq!(
+                fiscal.resolve(base, start_month).unwrap(),
+                expected,
+                "{fiscal:?} starting in {start_month}"
+            );
+        }
+    }
+
+    #[test]
+    fn fiscal_resolved() {
+        let options = Options {
+            fiscal_year_start: Some(10),
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("fy2025", "2024-10-01 00:00:00"),
+            ("FY2025 Q2", "2025-01-01 00:00:00"),
+            ("q3 fy26 12:00", "2026-04-01 12:00:00"),
+            ("start of fiscal year", "2024-10-01 00:00:00"),
+            ("start of the fiscal quarter", "2025-04-01 00:00:00"),
+            ("start of fiscal year +1 year", "2025-10-01 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // Fiscal periods are opt-in.
+        assert!(parse_at_date(now.clone(), "fy2025", &Options::default()).is_err());
+        // A fiscal period specifies a date.
+        assert!(parse_at_date(now, "fy2025 2025-01-01", &options).is_err());
+    }
+}
@@ -0,0 +1,199 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse an ASN.1 GeneralizedTime, as LDAP directories store it.
+//!
+//! RFC 4517 (section 3.3.13) specifies:
+//!
+//! ```abnf
+//! GeneralizedTime = century year month day hour
+//!                      [ minute [ second / leap-second ] ]
+//!                      [ fraction ]
+//!                      g-time-zone
+//!
+//! century = 2(%x30-39) ; "00" to "99"
+//! year    = 2(%x30-39) ; "00" to "99"
+//! month   =   ( %x30 %x31-39 ) ; "01" (January) to "09"
+//!           / ( %x31 %x30-32 ) ; "10" to "12"
+//! day     =   ( %x30 %x31-39 )    ; "01" to "09"
+//!           / ( %x31-32 %x30-39 ) ; "10" to "29"
+//!           / ( %x33 %x30-31 )    ; "30" to "31"
+//! hour    = ( %x30-31 %x30-39 ) / ( %x32 %x30-33 ) ; "00" to "23"
+//! minute  = %x30-35 %x30-39                        ; "00" to "59"
+//!
+//! second      = ( %x30-35 %x30-39 ) ; "00" to "59"
+//! leap-second = ( %x36 %x30 )       ; "60"
+//!
+//! fraction        = ( DOT / COMMA ) 1*(%x30-39)
+//! g-time-zone     = %x5A  ; "Z"
+//!                   / g-differential
+//! g-differential  = ( MINUS / PLUS ) hour [ minute ]
+//! ```
+//!
+//! The fraction is a fraction of the last unit given: of the hour if the
+//! minutes are omitted (e.g., "2024070214.5Z" is 14:30), of the minute if the
+//! seconds are omitted, and of the second otherwise. Digits beyond nanoseconds
+//! are discarded.
+//!
+//! A leap second (second 60) is rejected, as it cannot be represented.
+
+use jiff::{civil, tz::TimeZone};
+use winnow::{
+    combinator::{alt, eof, opt, preceded, terminated},
+    error::{ContextError, ErrMode, FromExternalError},
+    stream::AsChar,
+    token::{one_of, take_while},
+    ModalResult, Parser,
+};
+
+use super::{
+    epoch::NANOS_PER_SEC,
+    error::Error,
+    primitive::{digits, fixed_offset, fraction_of},
+};
+
+/// Parse a GeneralizedTime, as its civil date and time and the timezone of its
+/// offset ("Z" is UTC).
+pub(super) fn parse(input: &mut &str) -> ModalResult<(civil::DateTime, TimeZone)> {
+    let (year, month, day, hour) = (
+        digits(4),
+        digits(2).verify(|m| (1..=12).contains(m)),
+        digits(2).verify(|d| (1..=31).contains(d)),
+        digits(2).verify(|h| *h < 24),
+    )
+        .parse_next(input)?;
+    let (minute, second, fraction, tz) = terminated(
+        (
+            opt((
+                digits(2).verify(|m| *m < 60),
+                opt(digits(2).verify(|s| *s < 60)),
+            )),
+            opt(preceded(
+                one_of(['.', ',']),
+                take_while(1.., AsChar::is_dec_digit),
+            )),
+            zone,
+        )
+            .map(|(minute_second, fraction, tz)| {
+                let (minute, second) = match minute_second {
+                    Some((minute, second)) => (Some(minute), second),
+                    None => (None, None),
+                };
+                (minute, second, fraction, tz)
+            }),
+        eof,
+    )
+    .parse_next(input)?;
+
+    let date = civil::Date::new(year as i16, month as i8, day as i8).map_err(|_| {
+        ErrMode::Cut(ContextError::from_external_error(
+            input,
+            Error::Resolve("date is not valid".to_owned()),
+        ))
+    })?;
+
+    // The fraction of the last unit given, in nanoseconds.
+    let unit = match (minute, second) {
+        (None, _) => 3600,
+        (Some(_), None) => 60,
+        (Some(_), Some(_)) => 1,
+    };
+    let nanos = fraction.map_or(0, |f| fraction_of(f, unit * NANOS_PER_SEC));
+    let seconds = i128::from(minute.unwrap_or(0)) * 60 + i128::from(second.unwrap_or(0));
+    let nanos = seconds * NANOS_PER_SEC + nanos;
+    let time = civil::time(
+        hour as i8,
+        (nanos / (60 * NANOS_PER_SEC)) as i8,
+        (nanos / NANOS_PER_SEC % 60) as i8,
+        (nanos % NANOS_PER_SEC) as i32,
+    );
+    Ok((date.to_datetime(time), tz))
+}
+
+/// Parse the timezone, "Z" or an offset of hours and optional minutes.
+fn zone(input: &mut &str) -> ModalResult<TimeZone> {
+    alt((
+        'Z'.value(TimeZone::UTC),
+        (
+            one_of(['+', '-']),
+            digits(2).verify(|h| *h < 24),
+            opt(digits(2).verify(|m| *m < 60)),
+        )
+            .try_map(|(sign, hours, minutes)| {
+                fixed_offset(sign, hours, minutes.unwrap_or(0)).map(TimeZone::fixed)
+            }),
+    ))
+    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    fn generalized_time(input: &str) -> Option<String> {
+        let (datetime, tz) = parse(&mut &*input).ok()?;
+        let zoned = datetime.to_zoned(tz).ok()?;
+        Some(
+            zoned
+                .timestamp()
+                .display_with_offset(zoned.offset())
+                .to_string(),
+        )
+    }
+
+    #[test]
+    fn valid() {
+        for (input, expected) in [
+            ("20240702143000Z", "2024-07-02T14:30:00+00:00"),
+            ("20240702143000.123+0200", "2024-07-02T14:30:00.123+02:00"),
+            ("20240702143000,5-05", "2024-07-02T14:30:00.5-05:00"),
+            ("202407021430Z", "2024-07-02T14:30:00+00:00"),
+            ("2024070214Z", "2024-07-02T14:00:00+00:00"),
+            ("2024070214.5Z", "2024-07-02T14:30:00+00:00"),
+            ("2024070214,25+0530", "2024-07-02T14:15:00+05:30"),
+            ("202407021430.5Z", "2024-07-02T14:30:30+00:00"),
+            (
+                "2024070214.999999999999Z",
+                "2024-07-02T14:59:59.999999996+00:00",
+            ),
+            (
+                "20240702143000.1234567891Z",
+                "2024-07-02T14:30:00.123456789+00:00",
+            ),
+            ("19991231235959Z", "1999-12-31T23:59:59+00:00"),
+            ("19991231235959-2359", "1999-12-31T23:59:59-23:59"),
+        ] {
+            assert_eq!(
+                generalized_time(input).as_deref(),
+                Some(expected),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "",
+            "20240702143000",
+            "20240702143000z",
+            "202407021430001Z",
+            "2024070214300Z",
+            "20240702Z",
+            "240702143000Z",
+            "20240702143000.Z",
+            "20240702143000+2",
+            "20240702143000+02:00",
+            "20240702143060Z",
+            "20240702146000Z",
+            "20240702240000Z",
+            "20241302143000Z",
+            "20230229143000Z",
+            "2024-07-02T14:30:00Z",
+            " 20240702143000Z",
+            "20240702143000Z ",
+        ] {
+            assert_eq!(generalized_time(input), None, "{input}");
+        }
+    }
+}
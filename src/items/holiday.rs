@@ -0,0 +1,191 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a holiday item.
+//!
+//! With a provider set by [`Parser::holidays`](crate::Parser::holidays), the
+//! names it knows (e.g., "christmas" or "new year's eve") resolve to a
+//! calendar date, in the year that follows the name (e.g., "christmas 2025")
+//! or else in the year of the base date.
+
+use winnow::{
+    ascii::multispace1,
+    combinator::{alt, eof, opt, terminated},
+    error::{ContextError, ErrMode},
+    stream::Stream,
+    token::take_while,
+    ModalResult, Parser,
+};
+
+use crate::HolidayProvider;
+
+use super::{
+    primitive::{ctx_err, s},
+    year::{year_from_str, year_str},
+};
+
+/// The maximum number of words in a holiday name.
+const MAX_WORDS: usize = 4;
+
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub(crate) struct Holiday {
+    pub(crate) name: String,
+    pub(crate) year: Option<u16>,
+    /// The year was given with two digits, and taken as a year of the 20th or
+    /// 21st century.
+    pub(crate) two_digit_year: bool,
+}
+
+/// Parse the longest holiday name known to `provider`, followed by an
+/// optional year.
+pub(super) fn parse(input: &mut &str, provider: &dyn HolidayProvider) -> ModalResult<Holiday> {
+    let mut words = Vec::new();
+    let mut checkpoints = Vec::new();
+    while words.len() < MAX_WORDS {
+        match word.parse_next(input) {
+            Ok(w) => {
+                words.push(w);
+                checkpoints.push(input.checkpoint());
+            }
+            Err(_) => break,
+        }
+    }
+
+    let n = (1..=words.len())
+        .rev()
+        .find(|n| provider.contains(&words[..*n].join(" ")))
+        .ok_or(ErrMode::Backtrack(ContextError::new()))?;
+    input.reset(&checkpoints[n - 1]);
+
+    // The year must be followed by a space or end of input.
+    let year = opt(terminated(year_str, alt((multispace1, eof))))
+        .parse_next(input)?
+        .map(year_from_str)
+        .transpose()
+        .map_err(|e| ErrMode::Cut(ctx_err(e)))?;
+
+    Ok(Holiday {
+        name: words[..n].join(" "),
+        year: year.map(|year| year.value),
+        two_digit_year: year.is_some_and(|year| year.expanded),
+    })
+}
+
+/// Parse a word of a holiday name, which may contain apostrophes.
+fn word<'a>(input: &mut &'a str) -> ModalResult<&'a str> {
+    s(take_while(1.., |c: char| {
+        c.is_ascii_alphabetic() || c == '\''
+    }))
+    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+        Holidays,
+    };
+
+    use super::{parse, Holiday};
+
+    fn holiday(name: &str, year: Option<u16>) -> Holiday {
+        Holiday {
+            name: name.to_owned(),
+            year,
+            two_digit_year: false,
+        }
+    }
+
+    #[test]
+    fn holiday_names() {
+        let provider = Holidays::builtin();
+
+        for (input, expected, rest) in [
+            ("christmas", holiday("christmas", None), ""),
+            ("christmas   eve", holiday("christmas eve", None), ""),
+            (
+                "new year's eve 2024",
+                holiday("new year's eve", Some(2024)),
+                "",
+            ),
+            (
+                "christmas 24 10:00",
+                Holiday {
+                    two_digit_year: true,
+                    ..holiday("christmas", Some(2024))
+                },
+                "10:00",
+            ),
+            ("christmas 10:00", holiday("christmas", None), " 10:00"),
+            (
+                "new year next week",
+                holiday("new year", None),
+                " next week",
+            ),
+        ] {
+            let mut s = input;
+            assert_eq!(parse(&mut s, &provider).unwrap(), expected, "{input}");
+            assert_eq!(s, rest, "{input}");
+        }
+
+        for input in ["easter", "new", "year's eve"] {
+            let mut s = input;
+            assert!(parse(&mut s, &provider).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn holiday_resolved() {
+        let options = Options {
+            holidays: Some(Box::new(crate::Holidays::builtin())),
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("christmas", "2025-12-25 00:00:00"),
+            ("Christmas Eve 18:00", "2025-12-24 18:00:00"),
+            ("new year's eve 2030", "2030-12-31 00:00:00"),
+            ("halloween +1 day", "2025-11-01 00:00:00"),
+            ("new year next year", "2026-01-01 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // Holidays are opt-in.
+        assert!(parse_at_date(now.clone(), "christmas", &Options::default()).is_err());
+        // A holiday specifies a date.
+        assert!(parse_at_date(now, "christmas 2025-01-01", &options).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "holiday-data")]
+    fn holiday_regions() {
+        let options = Options {
+            holidays: Some(Box::new(crate::Holidays::region("US").unwrap())),
+            business_calendar: Some(Box::new(crate::Holidays::region("US").unwrap())),
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("thanksgiving 2025", "2025-11-27 00:00:00"),
+            ("thanksgiving 2025 +1 business day", "2025-11-28 00:00:00"),
+            // Independence Day 2026 is observed on Friday, July 3.
+            ("2026-07-02 +1 business day", "2026-07-06 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+}
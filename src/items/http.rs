@@ -0,0 +1,276 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse the value of an HTTP `Retry-After` header field.
+//!
+//! RFC 7231 (section 7.1.3) specifies:
+//!
+//! ```abnf
+//! Retry-After = HTTP-date / delay-seconds
+//! delay-seconds = 1*DIGIT
+//! ```
+//!
+//! and (section 7.1.1.1):
+//!
+//! ```abnf
+//! HTTP-date    = IMF-fixdate / obs-date
+//! IMF-fixdate  = day-name "," SP date1 SP time-of-day SP GMT
+//!              ; e.g., Sun, 06 Nov 1994 08:49:37 GMT
+//! date1        = day SP month SP year
+//! obs-date     = rfc850-date / asctime-date
+//! rfc850-date  = day-name-l "," SP date2 SP time-of-day SP GMT
+//!              ; e.g., Sunday, 06-Nov-94 08:49:37 GMT
+//! date2        = day "-" month "-" 2DIGIT
+//! asctime-date = day-name SP date3 SP time-of-day SP year
+//!              ; e.g., Sun Nov  6 08:49:37 1994
+//! date3        = month SP ( 2DIGIT / ( SP DIGIT ) )
+//! ```
+//!
+//! The names are case-sensitive, as the RFC requires, and the day name is not
+//! checked against the date. Spaces around the value are ignored, as they are
+//! not part of a header field value.
+//!
+//! A two-digit year is the year with these last two digits that is not more
+//! than 50 years after the base date, and a delay too large to be represented
+//! is capped to 2147483648 seconds, as RFC 7234 (section 1.2.1) recommends for
+//! delta-seconds.
+
+use jiff::{civil, tz::TimeZone, SignedDuration, Zoned};
+use winnow::{
+    ascii::space0,
+    combinator::{alt, delimited, eof, preceded, terminated},
+    error::{ContextError, ErrMode, FromExternalError},
+    stream::AsChar,
+    token::take_while,
+    ModalResult, Parser,
+};
+
+use super::{
+    error::Error,
+    primitive::{digits, name, DAY_NAMES, MONTH_NAMES},
+};
+
+/// The largest delay, in seconds.
+const MAX_DELAY: u64 = 1 << 31;
+
+const LONG_DAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// The value of a `Retry-After` header field.
+#[derive(Debug, PartialEq)]
+pub(super) enum RetryAfter {
+    /// An HTTP date, in UTC. The year of the RFC 850 format has two digits,
+    /// and is resolved against the base date.
+    Date {
+        datetime: civil::DateTime,
+        two_digit_year: bool,
+    },
+    /// A delay, in seconds.
+    Delay(u64),
+}
+
+impl RetryAfter {
+    /// Resolve the value against the base date and time, in the timezone of the
+    /// base.
+    pub(super) fn resolve(self, base: &Zoned) -> Result<Zoned, Error> {
+        match self {
+            Self::Delay(seconds) => base
+                .checked_add(SignedDuration::from_secs(seconds.min(MAX_DELAY) as i64))
+                .map_err(|_| Error::OutOfRange),
+            Self::Date {
+                datetime,
+                two_digit_year,
+            } => {
+                let datetime = if two_digit_year {
+                    let limit = base.with_time_zone(TimeZone::UTC).year() + 50;
+                    let year = limit - (limit - datetime.year()).rem_euclid(100);
+                    datetime
+                        .with()
+                        .year(year)
+                        .build()
+                        .map_err(|_| Error::Resolve("date is not valid".to_owned()))?
+                } else {
+                    datetime
+                };
+                let zoned = datetime
+                    .to_zoned(TimeZone::UTC)
+                    .map_err(|_| Error::OutOfRange)?;
+                Ok(zoned.with_time_zone(base.time_zone().clone()))
+            }
+        }
+    }
+}
+
+/// Parse the value of a `Retry-After` header field.
+pub(super) fn parse(input: &mut &str) -> ModalResult<RetryAfter> {
+    delimited(
+        space0,
+        alt((
+            take_while(1.., AsChar::is_dec_digit)
+                .map(|digits: &str| RetryAfter::Delay(digits.parse().unwrap_or(u64::MAX))),
+            http_date,
+        )),
+        (space0, eof),
+    )
+    .parse_next(input)
+}
+
+/// Parse an HTTP date in any of its three formats.
+fn http_date(input: &mut &str) -> ModalResult<RetryAfter> {
+    let (year, month, day, time, two_digit_year) = alt((
+        // IMF-fixdate
+        (
+            terminated(name(&DAY_NAMES, false), ", "),
+            terminated(digits(2), ' '),
+            terminated(name(&MONTH_NAMES, false), ' '),
+            terminated(digits(4), ' '),
+            terminated(time_of_day, " GMT"),
+        )
+            .map(|(_, day, month, year, time)| (year, month, day, time, false)),
+        // rfc850-date
+        (
+            terminated(name(&LONG_DAY_NAMES, false), ", "),
+            terminated(digits(2), '-'),
+            terminated(name(&MONTH_NAMES, false), '-'),
+            terminated(digits(2), ' '),
+            terminated(time_of_day, " GMT"),
+        )
+            .map(|(_, day, month, year, time)| (year, month, day, time, true)),
+        // asctime-date
+        (
+            terminated(name(&DAY_NAMES, false), ' '),
+            terminated(name(&MONTH_NAMES, false), ' '),
+            terminated(alt((digits(2), preceded(' ', digits(1)))), ' '),
+            terminated(time_of_day, ' '),
+            digits(4),
+        )
+            .map(|(_, month, day, time, year)| (year, month, day, time, false)),
+    ))
+    .parse_next(input)?;
+
+    let date = civil::Date::new(year as i16, month as i8, day as i8).map_err(|_| {
+        ErrMode::Cut(ContextError::from_external_error(
+            input,
+            Error::Resolve("date is not valid".to_owned()),
+        ))
+    })?;
+    Ok(RetryAfter::Date {
+        datetime: date.to_datetime(time),
+        two_digit_year,
+    })
+}
+
+/// Parse a time of day, "hh:mm:ss". Second 60 is rejected, as a leap second
+/// cannot be represented.
+fn time_of_day(input: &mut &str) -> ModalResult<civil::Time> {
+    (
+        digits(2).verify(|h| *h < 24),
+        ':',
+        digits(2).verify(|m| *m < 60),
+        ':',
+        digits(2).verify(|s| *s < 60),
+    )
+        .map(|(hour, _, minute, _, second)| civil::time(hour as i8, minute as i8, second as i8, 0))
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::{civil::date, Zoned};
+
+    use super::{parse, RetryAfter};
+
+    fn resolve(input: &str, base: &Zoned) -> String {
+        let value = parse(&mut &*input).unwrap();
+        value.resolve(base).unwrap().to_string()
+    }
+
+    #[test]
+    fn delay() {
+        let base: Zoned = "2024-01-02T10:00:00+01:00[Europe/Paris]".parse().unwrap();
+        for (input, expected) in [
+            ("0", "2024-01-02T10:00:00+01:00[Europe/Paris]"),
+            ("120", "2024-01-02T10:02:00+01:00[Europe/Paris]"),
+            (" 3600 ", "2024-01-02T11:00:00+01:00[Europe/Paris]"),
+            ("2147483648", "2092-01-20T13:14:08+01:00[Europe/Paris]"),
+            (
+                "99999999999999999999999",
+                "2092-01-20T13:14:08+01:00[Europe/Paris]",
+            ),
+        ] {
+            assert_eq!(resolve(input, &base), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn http_date() {
+        let base: Zoned = "2024-01-02T10:00:00+01:00[Europe/Paris]".parse().unwrap();
+        for (input, expected) in [
+            (
+                "Sun, 06 Nov 1994 08:49:37 GMT",
+                "1994-11-06T09:49:37+01:00[Europe/Paris]",
+            ),
+            (
+                "Sunday, 06-Nov-94 08:49:37 GMT",
+                "1994-11-06T09:49:37+01:00[Europe/Paris]",
+            ),
+            (
+                "Sun Nov  6 08:49:37 1994",
+                "1994-11-06T09:49:37+01:00[Europe/Paris]",
+            ),
+            (
+                "Wed, 03 Jan 2024 12:00:00 GMT",
+                "2024-01-03T13:00:00+01:00[Europe/Paris]",
+            ),
+            // A two-digit year is not more than 50 years after the base.
+            (
+                "Sunday, 06-Nov-74 08:49:37 GMT",
+                "2074-11-06T09:49:37+01:00[Europe/Paris]",
+            ),
+            (
+                "Sunday, 06-Nov-75 08:49:37 GMT",
+                "1975-11-06T09:49:37+01:00[Europe/Paris]",
+            ),
+        ] {
+            assert_eq!(resolve(input, &base), expected, "{input}");
+        }
+
+        assert_eq!(
+            parse(&mut "Thu Feb 29 00:00:00 2024").unwrap(),
+            RetryAfter::Date {
+                datetime: date(2024, 2, 29).at(0, 0, 0, 0),
+                two_digit_year: false,
+            }
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "",
+            "-1",
+            "1.5",
+            "120s",
+            "now",
+            "Sun, 06 Nov 1994 08:49:37 UTC",
+            "Sun, 06 Nov 1994 08:49:37",
+            "sun, 06 nov 1994 08:49:37 GMT",
+            "Sun, 6 Nov 1994 08:49:37 GMT",
+            "Sunday, 06 Nov 1994 08:49:37 GMT",
+            "Sun, 06-Nov-94 08:49:37 GMT",
+            "Sun Nov 6 08:49:37 1994",
+            "Sun, 06 Nov 1994 08:49:60 GMT",
+            "Sun, 31 Nov 1994 08:49:37 GMT",
+            "1994-11-06T08:49:37Z",
+        ] {
+            assert!(parse(&mut &*input).is_err(), "{input}");
+        }
+    }
+}
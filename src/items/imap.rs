@@ -0,0 +1,168 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse an IMAP date and time, such as the `INTERNALDATE` of a message.
+//!
+//! RFC 3501 (section 9) specifies:
+//!
+//! ```abnf
+//! date-time       = DQUOTE date-day-fixed "-" date-month "-" date-year
+//!                   SP time SP zone DQUOTE
+//! date-day-fixed  = (SP DIGIT) / 2DIGIT
+//! date-month      = "Jan" / "Feb" / "Mar" / "Apr" / "May" / "Jun" /
+//!                   "Jul" / "Aug" / "Sep" / "Oct" / "Nov" / "Dec"
+//! date-year       = 4DIGIT
+//! time            = 2DIGIT ":" 2DIGIT ":" 2DIGIT
+//! zone            = ("+" / "-") 4DIGIT
+//! ```
+//!
+//! The quotes may be omitted, as they are when the value is taken out of an
+//! IMAP response, and the month is case-insensitive, as are the strings of
+//! ABNF. A single-digit day may also be given without its leading space.
+
+use jiff::{civil, tz::TimeZone};
+use winnow::{
+    ascii::Caseless,
+    combinator::{alt, delimited, eof, preceded, terminated},
+    error::{ContextError, ErrMode, FromExternalError},
+    token::one_of,
+    ModalResult, Parser,
+};
+
+use super::{
+    error::Error,
+    primitive::{digits, fixed_offset},
+};
+
+/// Parse an IMAP date and time, in quotes or not, as its civil date and time
+/// and the timezone of its offset.
+pub(super) fn parse(input: &mut &str) -> ModalResult<(civil::DateTime, TimeZone)> {
+    terminated(alt((delimited('"', datetime, '"'), datetime)), eof).parse_next(input)
+}
+
+/// Parse an IMAP date and time without its quotes.
+fn datetime(input: &mut &str) -> ModalResult<(civil::DateTime, TimeZone)> {
+    let (day, _, month, _, year, _, time, _, tz) = (
+        alt((digits(2), preceded(' ', digits(1)), digits(1))),
+        '-',
+        month,
+        '-',
+        digits(4),
+        ' ',
+        time,
+        ' ',
+        zone,
+    )
+        .parse_next(input)?;
+
+    let date = civil::Date::new(year as i16, month, day as i8).map_err(|_| {
+        ErrMode::Cut(ContextError::from_external_error(
+            input,
+            Error::Resolve("date is not valid".to_owned()),
+        ))
+    })?;
+    Ok((date.to_datetime(time), tz))
+}
+
+/// Parse the abbreviated name of a month.
+fn month(input: &mut &str) -> ModalResult<i8> {
+    alt((
+        Caseless("jan").value(1),
+        Caseless("feb").value(2),
+        Caseless("mar").value(3),
+        Caseless("apr").value(4),
+        Caseless("may").value(5),
+        Caseless("jun").value(6),
+        Caseless("jul").value(7),
+        Caseless("aug").value(8),
+        Caseless("sep").value(9),
+        Caseless("oct").value(10),
+        Caseless("nov").value(11),
+        Caseless("dec").value(12),
+    ))
+    .parse_next(input)
+}
+
+/// Parse a time of day, "hh:mm:ss". Second 60 is rejected, as a leap second
+/// cannot be represented.
+fn time(input: &mut &str) -> ModalResult<civil::Time> {
+    (
+        digits(2).verify(|h| *h < 24),
+        ':',
+        digits(2).verify(|m| *m < 60),
+        ':',
+        digits(2).verify(|s| *s < 60),
+    )
+        .map(|(hour, _, minute, _, second)| civil::time(hour as i8, minute as i8, second as i8, 0))
+        .parse_next(input)
+}
+
+/// Parse a zone, "+hhmm" or "-hhmm".
+fn zone(input: &mut &str) -> ModalResult<TimeZone> {
+    (
+        one_of(['+', '-']),
+        digits(2).verify(|h| *h < 24),
+        digits(2).verify(|m| *m < 60),
+    )
+        .try_map(|(sign, hours, minutes)| fixed_offset(sign, hours, minutes).map(TimeZone::fixed))
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    fn imap(input: &str) -> Option<String> {
+        let (datetime, tz) = parse(&mut &*input).ok()?;
+        let zoned = datetime.to_zoned(tz).ok()?;
+        Some(
+            zoned
+                .timestamp()
+                .display_with_offset(zoned.offset())
+                .to_string(),
+        )
+    }
+
+    #[test]
+    fn valid() {
+        for (input, expected) in [
+            ("01-Jul-2024 12:34:56 +0000", "2024-07-01T12:34:56+00:00"),
+            (
+                "\"01-Jul-2024 12:34:56 +0000\"",
+                "2024-07-01T12:34:56+00:00",
+            ),
+            (
+                "\" 1-Jul-2024 12:34:56 -0700\"",
+                "2024-07-01T12:34:56-07:00",
+            ),
+            ("1-JUL-2024 12:34:56 +0530", "2024-07-01T12:34:56+05:30"),
+            ("01-Jul-2024 12:34:56 -2359", "2024-07-01T12:34:56-23:59"),
+            ("29-feb-2024 00:00:00 +0100", "2024-02-29T00:00:00+01:00"),
+        ] {
+            assert_eq!(imap(input).as_deref(), Some(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "",
+            "01-Jul-2024",
+            "01-Jul-2024 12:34 +0000",
+            "01-Jul-2024 12:34:56",
+            "01-Jul-2024 12:34:56 GMT",
+            "01-Jul-2024 12:34:56 +00:00",
+            "01-July-2024 12:34:56 +0000",
+            "01-Jul-24 12:34:56 +0000",
+            "01 Jul 2024 12:34:56 +0000",
+            "01-Jul-2024  12:34:56 +0000",
+            "\"01-Jul-2024 12:34:56 +0000",
+            "01-Jul-2024 12:34:56 +0000 ",
+            "01-Jul-2024 24:00:00 +0000",
+            "29-Feb-2023 12:34:56 +0000",
+            "32-Jul-2024 12:34:56 +0000",
+        ] {
+            assert_eq!(imap(input), None, "{input}");
+        }
+    }
+}
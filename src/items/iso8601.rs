@@ -0,0 +1,868 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse an ISO 8601 value.
+//!
+//! Unlike the items of the GNU grammar, ISO 8601 values are parsed as a whole,
+//! strictly, and without a base date and time:
+//!
+//! - dates: calendar ("2022-11-14", "20221114", "2022-11"), ordinal
+//!   ("2022-318", "2022318"), week ("2022-W46-1", "2022W461", "2022-W46") and
+//!   year ("2022") dates, with an expanded year in the extended format (e.g.,
+//!   "+02022-11-14"),
+//! - times of day: "10:20:30", "10:20", "T10", "T102030", with a fraction of
+//!   the last component after a comma or a period (e.g., "10:20,5" is
+//!   10:20:30), and an optional offset ("Z", "+01:00", "+0100" or "+01"),
+//! - dates and times: a complete date, "T" and a time of day, both in the
+//!   basic or both in the extended format,
+//! - durations: "P1Y2M3DT4H5M6S", "P2W", with a fraction of the last
+//!   component, or the alternative format "P0001-02-03T04:05:06", and a minus
+//!   sign for a negative duration (e.g., "-P1D"),
+//! - intervals: two dates and times, dates or durations separated by "/" or
+//!   "--", but not two durations, where the end may omit the components it
+//!   shares with the start (e.g., "2022-11-14T10:00/12:00"),
+//! - recurring intervals: "R", an optional number of repetitions, "/" and an
+//!   interval (e.g., "R5/2022-11-14/P1W").
+//!
+//! A time of day of 24:00 and a leap second are not supported. Designators ("T", "Z", "P", "W" and units) are uppercase.
+
+use jiff::{
+    civil::{self, ISOWeekDate, Weekday},
+    tz::{Offset, OffsetConflict, TimeZone},
+    SignedDuration, Span, Unit, Zoned,
+};
+use winnow::{
+    ascii::digit1,
+    combinator::{alt, delimited, empty, eof, not, opt, peek, preceded, terminated},
+    error::{ContextError, ErrMode, FromExternalError},
+    stream::AsChar,
+    token::{one_of, take_while},
+    ModalResult, Parser,
+};
+
+use crate::{Iso8601Value, RepeatingInterval, TemporalValue};
+
+use super::{
+    annotation,
+    error::Error,
+    primitive::{digits, fixed_offset, fraction_of},
+};
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+const NANOS_PER_MIN: i128 = 60 * NANOS_PER_SEC;
+const NANOS_PER_HOUR: i128 = 60 * NANOS_PER_MIN;
+const NANOS_PER_DAY: i128 = 24 * NANOS_PER_HOUR;
+
+/// The format of a date or time of day, which must be the same for the date
+/// and the time of day of a date and time.
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Basic,
+    Extended,
+    /// A year or an hour alone, which is in both formats.
+    Either,
+}
+
+impl Format {
+    fn agrees_with(self, other: Format) -> bool {
+        self == Format::Either || other == Format::Either || self == other
+    }
+}
+
+/// A date, the format it is in, and whether it is complete (i.e., not of
+/// reduced precision), as required in a date and time.
+type ParsedDate = (civil::Date, Format, bool);
+
+pub(super) fn parse(input: &mut &str) -> ModalResult<Iso8601Value> {
+    terminated(alt((repeating, interval, value)), eof).parse_next(input)
+}
+
+/// Parse a date, time of day or date and time, followed by optional RFC 9557
+/// annotations, as TC39 Temporal does, and classify it.
+pub(super) fn parse_temporal(input: &mut &str) -> Result<TemporalValue, Error> {
+    let (value, tz) = terminated((value, opt(annotation::parse)), eof).parse_next(input)?;
+    match (value, tz.flatten().map(|zone| zone.tz)) {
+        (Iso8601Value::Date(date), None) => Ok(TemporalValue::PlainDate(date)),
+        (Iso8601Value::Time { time, offset: None }, None) => Ok(TemporalValue::PlainTime(time)),
+        (Iso8601Value::DateTime { datetime, offset }, None) => match offset {
+            None => Ok(TemporalValue::PlainDateTime(datetime)),
+            Some(offset) => datetime
+                .to_zoned(TimeZone::fixed(offset))
+                .map(TemporalValue::ZonedDateTime)
+                .map_err(|_| Error::OutOfRange),
+        },
+        (Iso8601Value::Date(date), Some(tz)) => date
+            .to_zoned(tz)
+            .map(TemporalValue::ZonedDateTime)
+            .map_err(|_| Error::OutOfRange),
+        (Iso8601Value::DateTime { datetime, offset }, Some(tz)) => {
+            let ambiguous = match offset {
+                None => tz.to_ambiguous_zoned(datetime),
+                Some(offset) => OffsetConflict::Reject
+                    .resolve(datetime, offset, tz)
+                    .map_err(|_| {
+                        Error::Resolve("offset does not match the time zone".to_owned())
+                    })?,
+            };
+            ambiguous
+                .compatible()
+                .map(TemporalValue::ZonedDateTime)
+                .map_err(|_| Error::OutOfRange)
+        }
+        // A time of day with an offset or a time zone annotation has no
+        // Temporal type.
+        (Iso8601Value::Time { .. }, _) => Err("time of day has an offset or a time zone".into()),
+        _ => Err("input is not a date or a time of day".into()),
+    }
+}
+
+/// Resolve a recurring interval, with the dates and times that have no offset
+/// in `tz`.
+pub(super) fn repeating_interval(
+    value: Iso8601Value,
+    tz: &TimeZone,
+) -> Result<RepeatingInterval, Error> {
+    let Iso8601Value::Repeating {
+        repetitions,
+        interval,
+    } = value
+    else {
+        return Err("input is not a recurring interval".into());
+    };
+    let Iso8601Value::Interval { start, end } = *interval else {
+        return Err("input is not a recurring interval".into());
+    };
+
+    let (anchor, span, from_end) = match (*start, *end) {
+        (Iso8601Value::Duration(span), end) => (zoned(end, tz)?, span, true),
+        (start, Iso8601Value::Duration(span)) => (zoned(start, tz)?, span, false),
+        (start, end) => {
+            let start = zoned(start, tz)?;
+            let span = start
+                .until((Unit::Year, &zoned(end, tz)?))
+                .map_err(|_| Error::OutOfRange)?;
+            (start, span, false)
+        }
+    };
+    if span.signum() <= 0 {
+        return Err(Error::Resolve(
+            "interval is empty or ends before it starts".to_owned(),
+        ));
+    }
+
+    Ok(RepeatingInterval::new(anchor, span, repetitions, from_end))
+}
+
+/// Resolve a bound of an interval, a date and time or a date, in `tz` if it
+/// has no offset.
+fn zoned(value: Iso8601Value, tz: &TimeZone) -> Result<Zoned, Error> {
+    let (datetime, tz) = match value {
+        Iso8601Value::DateTime { datetime, offset } => {
+            (datetime, offset.map_or_else(|| tz.clone(), TimeZone::fixed))
+        }
+        Iso8601Value::Date(date) => (date.to_datetime(civil::Time::midnight()), tz.clone()),
+        _ => return Err("interval bound is not a date".into()),
+    };
+    datetime.to_zoned(tz).map_err(|_| Error::OutOfRange)
+}
+
+fn repeating(input: &mut &str) -> ModalResult<Iso8601Value> {
+    let repetitions = delimited('R', opt(digit1.try_map(str::parse)), '/').parse_next(input)?;
+    let interval = interval(input)?;
+    Ok(Iso8601Value::Repeating {
+        repetitions,
+        interval: Box::new(interval),
+    })
+}
+
+fn value(input: &mut &str) -> ModalResult<Iso8601Value> {
+    alt((
+        bound,
+        preceded('T', time).map(|(time, offset, _)| Iso8601Value::Time { time, offset }),
+        time.verify(|(_, _, format)| *format == Format::Extended)
+            .map(|(time, offset, _)| Iso8601Value::Time { time, offset }),
+    ))
+    .parse_next(input)
+}
+
+/// Parse a value that may start or end an interval.
+fn bound(input: &mut &str) -> ModalResult<Iso8601Value> {
+    alt((
+        duration.map(Iso8601Value::Duration),
+        datetime,
+        date.map(|(date, _, _)| Iso8601Value::Date(date)),
+    ))
+    .parse_next(input)
+}
+
+fn interval(input: &mut &str) -> ModalResult<Iso8601Value> {
+    let start = terminated(bound, alt(("/", "--"))).parse_next(input)?;
+    let end = alt((bound, |i: &mut &str| reduced_end(i, &start))).parse_next(input)?;
+    match (&start, &end) {
+        (Iso8601Value::Duration(_), Iso8601Value::Duration(_)) => Err(ErrMode::Cut(
+            ContextError::from_external_error(input, Error::from("interval has two durations")),
+        )),
+        _ => Ok(Iso8601Value::Interval {
+            start: Box::new(start),
+            end: Box::new(end),
+        }),
+    }
+}
+
+/// Parse the end of an interval that omits the components it shares with its
+/// start: a time of day after a date and time, or a month and day, or a day,
+/// after a date.
+fn reduced_end(input: &mut &str, start: &Iso8601Value) -> ModalResult<Iso8601Value> {
+    match start {
+        Iso8601Value::DateTime { datetime, offset } => (opt('T'), time)
+            .map(|(_, (time, end_offset, _))| Iso8601Value::DateTime {
+                datetime: datetime.date().to_datetime(time),
+                offset: end_offset.or(*offset),
+            })
+            .parse_next(input),
+        Iso8601Value::Date(date) => {
+            let (month, day) = alt((
+                (digits(2), '-', digits(2)).map(|(m, _, d)| (m, d)),
+                digits(2).map(|d| (date.month() as u16, d)),
+            ))
+            .parse_next(input)?;
+            new_date(input, date.year(), month, day).map(Iso8601Value::Date)
+        }
+        _ => Err(ErrMode::Backtrack(ContextError::new())),
+    }
+}
+
+fn datetime(input: &mut &str) -> ModalResult<Iso8601Value> {
+    (date, 'T', time)
+        .verify(|((_, date_format, complete), _, (_, _, time_format))| {
+            *complete && date_format.agrees_with(*time_format)
+        })
+        .map(
+            |((date, _, _), _, (time, offset, _))| Iso8601Value::DateTime {
+                datetime: date.to_datetime(time),
+                offset,
+            },
+        )
+        .parse_next(input)
+}
+
+/// Parse a date in the extended or basic format, or a year alone.
+fn date(input: &mut &str) -> ModalResult<ParsedDate> {
+    alt((
+        extended_date,
+        basic_date,
+        terminated(digits(4), not_digit).try_map(|year| {
+            civil::Date::new(year as i16, 1, 1).map(|date| (date, Format::Either, false))
+        }),
+    ))
+    .parse_next(input)
+}
+
+fn extended_date(input: &mut &str) -> ModalResult<ParsedDate> {
+    let year = alt((
+        (one_of(['+', '-']), take_while(4.., AsChar::is_dec_digit)).try_map(
+            |(sign, digits): (char, &str)| {
+                digits
+                    .parse::<i64>()
+                    .map(|y| if sign == '-' { -y } else { y })
+            },
+        ),
+        digits(4).map(i64::from),
+    ))
+    .parse_next(input)?;
+    '-'.parse_next(input)?;
+    let start = *input;
+    let parsed = alt((
+        (digits(2), '-', digits(2), not_digit).map(|(m, _, d, _)| DateFields::Calendar(m, d, true)),
+        week(true).map(|(w, d)| DateFields::Week(w, d)),
+        terminated(digits(3), not_digit).map(DateFields::Ordinal),
+        terminated(digits(2), not_digit).map(|m| DateFields::Calendar(m, 1, false)),
+    ))
+    .parse_next(input)?;
+    parsed
+        .resolve(&mut &*start, year)
+        .map(|(date, complete)| (date, Format::Extended, complete))
+}
+
+fn basic_date(input: &mut &str) -> ModalResult<ParsedDate> {
+    let year = digits(4).parse_next(input)?;
+    let start = *input;
+    let parsed = alt((
+        (digits(2), digits(2), not_digit).map(|(m, d, _)| DateFields::Calendar(m, d, true)),
+        week(false).map(|(w, d)| DateFields::Week(w, d)),
+        terminated(digits(3), not_digit).map(DateFields::Ordinal),
+    ))
+    .parse_next(input)?;
+    parsed
+        .resolve(&mut &*start, year.into())
+        .map(|(date, complete)| (date, Format::Basic, complete))
+}
+
+/// Parse the week and the optional day of a week date, e.g., "W46-1" in the
+/// extended format, or "W461" in the basic format.
+fn week<'a>(extended: bool) -> impl Parser<&'a str, (u16, Option<u16>), ErrMode<ContextError>> {
+    let separator = if extended { "-" } else { "" };
+    (
+        'W',
+        digits(2),
+        opt(preceded(separator, digits(1))),
+        not_digit,
+    )
+        .map(|(_, week, day, _)| (week, day))
+}
+
+/// The components of a date, which may not exist.
+enum DateFields {
+    /// A month, a day, and whether the day was given.
+    Calendar(u16, u16, bool),
+    /// A week, and a day of the week (Monday is 1).
+    Week(u16, Option<u16>),
+    /// A day of the year.
+    Ordinal(u16),
+}
+
+impl DateFields {
+    fn resolve(self, input: &mut &str, year: i64) -> ModalResult<(civil::Date, bool)> {
+        if !(-9999..=9999).contains(&year) {
+            return Err(ErrMode::Cut(ContextError::from_external_error(
+                input,
+                Error::OutOfRange,
+            )));
+        }
+        let year = year as i16;
+        match self {
+            DateFields::Calendar(month, day, complete) => {
+                new_date(input, year, month, day).map(|date| (date, complete))
+            }
+            DateFields::Week(week, day) => {
+                let weekday = match day {
+                    Some(day @ 1..=7) => Weekday::from_monday_one_offset(day as i8).ok(),
+                    Some(_) => None,
+                    None => Some(Weekday::Monday),
+                };
+                let date = match (week, weekday) {
+                    (1..=53, Some(weekday)) => ISOWeekDate::new(year, week as i8, weekday)
+                        .map_err(|_| invalid_date(input))?
+                        .date(),
+                    _ => return Err(ErrMode::Cut(ContextError::new())),
+                };
+                Ok((date, day.is_some()))
+            }
+            DateFields::Ordinal(day @ 1..=366) => civil::Date::new(year, 1, 1)
+                .and_then(|date| date.with().day_of_year(day as i16).build())
+                .map(|date| (date, true))
+                .map_err(|_| invalid_date(input)),
+            DateFields::Ordinal(_) => Err(ErrMode::Cut(ContextError::new())),
+        }
+    }
+}
+
+/// Build a calendar date. A month or a day out of its range is a syntax
+/// error, and a day that does not exist in its month a resolution error.
+fn new_date(input: &mut &str, year: i16, month: u16, day: u16) -> ModalResult<civil::Date> {
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return Err(ErrMode::Cut(ContextError::new()));
+    }
+    civil::Date::new(year, month as i8, day as i8).map_err(|_| invalid_date(input))
+}
+
+fn invalid_date(input: &mut &str) -> ErrMode<ContextError> {
+    ErrMode::Cut(ContextError::from_external_error(
+        input,
+        Error::Resolve("date is not valid".to_owned()),
+    ))
+}
+
+/// Parse a time of day in the extended or basic format, with an optional
+/// fraction of its last component and an optional offset.
+fn time(input: &mut &str) -> ModalResult<(civil::Time, Option<Offset>, Format)> {
+    let hour = digits(2).verify(|h| *h < 24).parse_next(input)?;
+    let (format, minute, second) = alt((
+        (':', digits(2), opt(preceded(':', digits(2))))
+            .map(|(_, m, s)| (Format::Extended, Some(m), s)),
+        (digits(2), opt(digits(2))).map(|(m, s)| (Format::Basic, Some(m), s)),
+        empty.value((Format::Either, None, None)),
+    ))
+    .verify(|(_, m, s)| m.map_or(true, |m| m < 60) && s.map_or(true, |s| s < 60))
+    .parse_next(input)?;
+    let fraction = opt(preceded(one_of(['.', ',']), digit1)).parse_next(input)?;
+    let offset = opt(offset).parse_next(input)?;
+
+    let unit = match (minute, second) {
+        (_, Some(_)) => NANOS_PER_SEC,
+        (Some(_), None) => NANOS_PER_MIN,
+        (None, None) => NANOS_PER_HOUR,
+    };
+    let nanos = i128::from(hour) * NANOS_PER_HOUR
+        + i128::from(minute.unwrap_or(0)) * NANOS_PER_MIN
+        + i128::from(second.unwrap_or(0)) * NANOS_PER_SEC
+        + fraction.map_or(0, |f| fraction_of(f, unit));
+    let time = civil::Time::midnight()
+        .checked_add(SignedDuration::from_nanos(nanos as i64))
+        .map_err(|_| ErrMode::Cut(ContextError::new()))?;
+    Ok((time, offset, format))
+}
+
+/// Parse an offset from UTC: "Z", or a sign and hours, optionally followed by
+/// minutes.
+fn offset(input: &mut &str) -> ModalResult<Offset> {
+    alt((
+        'Z'.value(Offset::UTC),
+        (
+            one_of(['+', '-']),
+            digits(2).verify(|h| *h < 24),
+            opt(preceded(opt(':'), digits(2))).verify(|m| m.map_or(true, |m| m < 60)),
+        )
+            .try_map(|(sign, hours, minutes)| fixed_offset(sign, hours, minutes.unwrap_or(0))),
+    ))
+    .parse_next(input)
+}
+
+/// Parse a duration.
+fn duration(input: &mut &str) -> ModalResult<Span> {
+    let negative = opt('-').parse_next(input)?.is_some();
+    'P'.parse_next(input)?;
+    let span = alt((alternative_duration, weeks, designators)).parse_next(input)?;
+    Ok(if negative { span.negate() } else { span })
+}
+
+/// Parse a duration of weeks (e.g., "2W"), which has no other component.
+fn weeks(input: &mut &str) -> ModalResult<Span> {
+    let (n, fraction, _) = (number, opt(fraction), 'W').parse_next(input)?;
+    let span = Span::new().try_weeks(n).map_err(|_| out_of_range(input))?;
+    match fraction {
+        Some(fraction) => add_fraction(input, span, fraction, 7 * NANOS_PER_DAY),
+        None => Ok(span),
+    }
+}
+
+/// Parse the components of a duration (e.g., "1Y2M3DT4H5M6S"). Only the last
+/// component may have a fraction, and not a year or a month.
+fn designators(input: &mut &str) -> ModalResult<Span> {
+    let mut components = Vec::new();
+    for designator in ['Y', 'M', 'D'] {
+        if let Some(c) = opt(terminated((number, opt(fraction)), designator)).parse_next(input)? {
+            components.push((designator, false, c));
+        }
+    }
+    if opt('T').parse_next(input)?.is_some() {
+        let before = components.len();
+        for designator in ['H', 'M', 'S'] {
+            if let Some(c) =
+                opt(terminated((number, opt(fraction)), designator)).parse_next(input)?
+            {
+                components.push((designator, true, c));
+            }
+        }
+        if components.len() == before {
+            return Err(ErrMode::Backtrack(ContextError::new()));
+        }
+    }
+    if components.is_empty() {
+        return Err(ErrMode::Backtrack(ContextError::new()));
+    }
+
+    let last = components.len() - 1;
+    let mut span = Span::new();
+    for (i, (designator, time, (n, fraction))) in components.into_iter().enumerate() {
+        let (result, unit) = match (designator, time) {
+            ('Y', _) => (span.try_years(n), None),
+            ('M', false) => (span.try_months(n), None),
+            ('D', _) => (span.try_days(n), Some(NANOS_PER_DAY)),
+            ('H', _) => (span.try_hours(n), Some(NANOS_PER_HOUR)),
+            ('M', true) => (span.try_minutes(n), Some(NANOS_PER_MIN)),
+            _ => (span.try_seconds(n), Some(NANOS_PER_SEC)),
+        };
+        span = result.map_err(|_| out_of_range(input))?;
+        match (fraction, unit) {
+            (None, _) => {}
+            (Some(fraction), Some(unit)) if i == last => {
+                span = add_fraction(input, span, fraction, unit)?;
+            }
+            (Some(_), _) => return Err(ErrMode::Cut(ContextError::new())),
+        }
+    }
+    Ok(span)
+}
+
+/// Parse a duration in the alternative format, as a date and time in the
+/// extended (e.g., "0001-02-03T04:05:06") or basic format.
+fn alternative_duration(input: &mut &str) -> ModalResult<Span> {
+    let (years, months, days, _, hours, minutes, seconds) = alt((
+        (
+            digits(4),
+            '-',
+            digits(2),
+            '-',
+            digits(2),
+            'T',
+            digits(2),
+            ':',
+            digits(2),
+            ':',
+            digits(2),
+        )
+            .map(|(y, _, mo, _, d, t, h, _, mi, _, s)| (y, mo, d, t, h, mi, s)),
+        (
+            digits(4),
+            digits(2),
+            digits(2),
+            'T',
+            digits(2),
+            digits(2),
+            digits(2),
+        ),
+    ))
+    .verify(|(_, mo, d, _, h, mi, s)| *mo <= 12 && *d <= 30 && *h <= 24 && *mi < 60 && *s < 60)
+    .parse_next(input)?;
+    Span::new()
+        .try_years(years)
+        .and_then(|s| s.try_months(months))
+        .and_then(|s| s.try_days(days))
+        .and_then(|s| s.try_hours(hours))
+        .and_then(|s| s.try_minutes(minutes))
+        .and_then(|s| s.try_seconds(seconds))
+        .map_err(|_| out_of_range(input))
+}
+
+/// Add the fraction of a unit of `unit` nanoseconds to `span`, as the smaller
+/// units down to nanoseconds, which are not set in `span`.
+fn add_fraction(input: &mut &str, mut span: Span, fraction: &str, unit: i128) -> ModalResult<Span> {
+    type Setter = fn(Span, i64) -> Result<Span, jiff::Error>;
+    let mut nanos = fraction_of(fraction, unit);
+    for (size, set) in [
+        (NANOS_PER_DAY, Span::try_days as Setter),
+        (NANOS_PER_HOUR, Span::try_hours),
+        (NANOS_PER_MIN, Span::try_minutes),
+        (NANOS_PER_SEC, Span::try_seconds),
+        (1, Span::try_nanoseconds),
+    ] {
+        if size < unit && nanos >= size {
+            span = set(span, (nanos / size) as i64).map_err(|_| out_of_range(input))?;
+            nanos %= size;
+        }
+    }
+    Ok(span)
+}
+
+/// Parse the fraction of the last component of a duration.
+fn fraction<'a>(input: &mut &'a str) -> ModalResult<&'a str> {
+    preceded(one_of(['.', ',']), digit1).parse_next(input)
+}
+
+/// Parse the number of a component of a duration.
+fn number(input: &mut &str) -> ModalResult<i64> {
+    digit1.try_map(str::parse::<i64>).parse_next(input)
+}
+
+/// Succeed if the input does not continue with a digit.
+fn not_digit(input: &mut &str) -> ModalResult<()> {
+    peek(not(one_of(AsChar::is_dec_digit))).parse_next(input)
+}
+
+fn out_of_range(input: &mut &str) -> ErrMode<ContextError> {
+    ErrMode::Cut(ContextError::from_external_error(input, Error::OutOfRange))
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::{
+        civil::{date, time},
+        tz::Offset,
+        ToSpan,
+    };
+
+    use crate::{Iso8601Value, TemporalValue};
+
+    use super::parse;
+
+    fn parse_str(input: &str) -> Option<Iso8601Value> {
+        parse(&mut &*input).ok()
+    }
+
+    #[test]
+    fn dates() {
+        for (input, expected) in [
+            ("2022-11-14", date(2022, 11, 14)),
+            ("20221114", date(2022, 11, 14)),
+            ("2022-11", date(2022, 11, 1)),
+            ("2022", date(2022, 1, 1)),
+            ("1020", date(1020, 1, 1)),
+            ("2022-318", date(2022, 11, 14)),
+            ("2022318", date(2022, 11, 14)),
+            ("2024-366", date(2024, 12, 31)),
+            ("2022-W46-1", date(2022, 11, 14)),
+            ("2022W461", date(2022, 11, 14)),
+            ("2022-W46", date(2022, 11, 14)),
+            ("2022W46", date(2022, 11, 14)),
+            ("2020-W53-7", date(2021, 1, 3)),
+            ("+02022-11-14", date(2022, 11, 14)),
+            ("-0044-03-15", date(-44, 3, 15)),
+        ] {
+            match parse_str(input) {
+                Some(Iso8601Value::Date(d)) => assert_eq!(d, expected, "{input}"),
+                other => panic!("{input}: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn times() {
+        for (input, expected, offset) in [
+            ("10:20:30", time(10, 20, 30, 0), None),
+            ("10:20", time(10, 20, 0, 0), None),
+            ("T10", time(10, 0, 0, 0), None),
+            ("T1020", time(10, 20, 0, 0), None),
+            ("T102030", time(10, 20, 30, 0), None),
+            ("10:20:30.25", time(10, 20, 30, 250_000_000), None),
+            ("10:20,5", time(10, 20, 30, 0), None),
+            ("T10.25", time(10, 15, 0, 0), None),
+            ("10:20:30Z", time(10, 20, 30, 0), Some(Offset::UTC)),
+            (
+                "T10:20-05:00",
+                time(10, 20, 0, 0),
+                Some(Offset::constant(-5)),
+            ),
+            (
+                "T1020+0530",
+                time(10, 20, 0, 0),
+                Some(Offset::from_seconds(19800).unwrap()),
+            ),
+            ("T10+01", time(10, 0, 0, 0), Some(Offset::constant(1))),
+        ] {
+            match parse_str(input) {
+                Some(Iso8601Value::Time { time, offset: o }) => {
+                    assert_eq!((time, o), (expected, offset), "{input}");
+                }
+                other => panic!("{input}: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn datetimes() {
+        for (input, expected, offset) in [
+            (
+                "2022-11-14T10:20:30",
+                date(2022, 11, 14).at(10, 20, 30, 0),
+                None,
+            ),
+            (
+                "20221114T102030,5+0100",
+                date(2022, 11, 14).at(10, 20, 30, 500_000_000),
+                Some(Offset::constant(1)),
+            ),
+            (
+                "2022-W46-1T10:20Z",
+                date(2022, 11, 14).at(10, 20, 0, 0),
+                Some(Offset::UTC),
+            ),
+            ("2022-318T10", date(2022, 11, 14).at(10, 0, 0, 0), None),
+        ] {
+            match parse_str(input) {
+                Some(Iso8601Value::DateTime {
+                    datetime,
+                    offset: o,
+                }) => {
+                    assert_eq!((datetime, o), (expected, offset), "{input}");
+                }
+                other => panic!("{input}: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn durations() {
+        for (input, expected) in [
+            (
+                "P1Y2M3DT4H5M6S",
+                1.year().months(2).days(3).hours(4).minutes(5).seconds(6),
+            ),
+            ("P2W", 2.weeks()),
+            ("P1M", 1.month()),
+            ("PT1M", 1.minute()),
+            ("PT36H", 36.hours()),
+            ("P1DT12H", 1.day().hours(12)),
+            ("PT0,5S", 500_000_000.nanoseconds()),
+            ("P1.5D", 1.day().hours(12)),
+            ("P0.5W", 3.days().hours(12)),
+            ("PT1.25H", 1.hour().minutes(15)),
+            ("-P1D", -1.day()),
+            (
+                "P0001-02-03T04:05:06",
+                1.year().months(2).days(3).hours(4).minutes(5).seconds(6),
+            ),
+            (
+                "P00010203T040506",
+                1.year().months(2).days(3).hours(4).minutes(5).seconds(6),
+            ),
+        ] {
+            match parse_str(input) {
+                Some(Iso8601Value::Duration(span)) => {
+                    assert_eq!(span.fieldwise(), expected.fieldwise(), "{input}");
+                }
+                other => panic!("{input}: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn intervals() {
+        for input in [
+            "2022-11-14/2022-11-21",
+            "2022-11-14--2022-11-21",
+            "2022-11-14T10:00Z/PT1H",
+            "P1W/2022-11-21",
+            "2022-11-14T10:00/12:00",
+            "2022-11-14/12-01",
+            "2022-11-14/21",
+        ] {
+            assert!(
+                matches!(parse_str(input), Some(Iso8601Value::Interval { .. })),
+                "{input}"
+            );
+        }
+
+        match parse_str("2022-11-14T10:00+01:00/12:30") {
+            Some(Iso8601Value::Interval { end, .. }) => match *end {
+                Iso8601Value::DateTime { datetime, offset } => {
+                    assert_eq!(datetime, date(2022, 11, 14).at(12, 30, 0, 0));
+                    assert_eq!(offset, Some(Offset::constant(1)));
+                }
+                other => panic!("{other:?}"),
+            },
+            other => panic!("{other:?}"),
+        }
+        match parse_str("2022-11-14/12-01") {
+            Some(Iso8601Value::Interval { end, .. }) => {
+                assert!(matches!(*end, Iso8601Value::Date(d) if d == date(2022, 12, 1)));
+            }
+            other => panic!("{other:?}"),
+        }
+    }
+
+    #[test]
+    fn repeating() {
+        for (input, expected) in [
+            ("R5/2022-11-14/P1D", Some(5)),
+            ("R/2022-11-14T10:00Z/P2W", None),
+            ("R0/P1W/2022-11-21", Some(0)),
+            ("R12/2022-11-14T10:00/12:00", Some(12)),
+        ] {
+            match parse_str(input) {
+                Some(Iso8601Value::Repeating {
+                    repetitions,
+                    interval,
+                }) => {
+                    assert_eq!(repetitions, expected, "{input}");
+                    assert!(
+                        matches!(*interval, Iso8601Value::Interval { .. }),
+                        "{input}"
+                    );
+                }
+                other => panic!("{input}: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn temporal() {
+        let classify = |input: &str| super::parse_temporal(&mut &*input).unwrap();
+
+        assert_eq!(
+            classify("2024-03-10"),
+            TemporalValue::PlainDate(date(2024, 3, 10))
+        );
+        assert_eq!(
+            classify("2024-03-10[u-ca=gregory]"),
+            TemporalValue::PlainDate(date(2024, 3, 10))
+        );
+        assert_eq!(
+            classify("T1030"),
+            TemporalValue::PlainTime(time(10, 30, 0, 0))
+        );
+        assert_eq!(
+            classify("2024-03-10T10:30"),
+            TemporalValue::PlainDateTime(date(2024, 3, 10).at(10, 30, 0, 0))
+        );
+
+        let zoned = |input: &str| match classify(input) {
+            TemporalValue::ZonedDateTime(zoned) => zoned.to_string(),
+            other => panic!("{input}: {other:?}"),
+        };
+        assert_eq!(
+            zoned("2024-03-10T10:30+01:00"),
+            "2024-03-10T10:30:00+01:00[+01:00]"
+        );
+        assert_eq!(
+            zoned("2024-03-10T10:30-04:00[America/New_York]"),
+            "2024-03-10T10:30:00-04:00[America/New_York]"
+        );
+        // A date is at the start of its day, and a time in a gap is moved
+        // forward, as with Temporal's "compatible" disambiguation.
+        assert_eq!(
+            zoned("2024-03-10[America/New_York]"),
+            "2024-03-10T00:00:00-05:00[America/New_York]"
+        );
+        assert_eq!(
+            zoned("2024-03-10T02:30[America/New_York]"),
+            "2024-03-10T03:30:00-04:00[America/New_York]"
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "",
+            "now",
+            "2022-11-14 10:20:30",
+            "2022-11-14t10:20:30",
+            "20221114T10:20:30",
+            "2022-11-14T102030",
+            "2022-11T10:00",
+            "2022T10",
+            "202211",
+            "22-11-14",
+            "2022-13-01",
+            "2022-11-32",
+            "2022-W54",
+            "2022-W46-8",
+            "2022-367",
+            "T24:00",
+            "10:20:60",
+            "10:60",
+            "P",
+            "PT",
+            "P1DT",
+            "P1.5Y",
+            "P1.5M",
+            "P1.5DT1H",
+            "P1W1D",
+            "P1D2Y",
+            "pt1h",
+            "PT1H/PT2H",
+            "2022-11-14/",
+            "/2022-11-14",
+            "R5/P1D",
+            "R-1/2022-11-14/P1D",
+            "R5/PT1H/PT2H",
+            "R5 /2022-11-14/P1D",
+            "2022-11-14T10:20:30Z ",
+        ] {
+            assert!(parse_str(input).is_none(), "{input}");
+        }
+    }
+
+    #[test]
+    fn nonexistent() {
+        for input in [
+            "2023-02-29",
+            "2023-02-29T10:00",
+            "2021-W53",
+            "2022-366",
+            "2022-11-14/11-31",
+        ] {
+            assert!(parse(&mut &*input).is_err(), "{input}");
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a Julian day item.
+//!
+//! A Julian Day (JD) is a count of days since noon UTC on January 1, 4713 BC
+//! in the proleptic Julian calendar, e.g., `JD 2460000.5` is 2023-02-25
+//! 00:00:00 UTC. A Modified Julian Day (MJD) is a count of days since midnight
+//! UTC on November 17, 1858, i.e., the Julian Day minus 2400000.5, e.g.,
+//! `MJD 60000` is 2023-02-25 00:00:00 UTC.
+//!
+//! Like a timestamp, a Julian day specifies a complete point in time and
+//! cannot be combined with any other date item.
+
+use winnow::{
+    ascii::Caseless,
+    combinator::{alt, opt},
+    ModalResult, Parser,
+};
+
+use super::{
+    epoch::{sec_and_nsec, Timestamp, NANOS_PER_SEC},
+    primitive::{plus_or_minus, s},
+};
+
+/// The Unix epoch as a Julian Day, in billionths of a day.
+const JD_UNIX_EPOCH: i128 = 2_440_587_500_000_000;
+
+/// The Unix epoch as a Modified Julian Day, in billionths of a day.
+const MJD_UNIX_EPOCH: i128 = 40_587 * NANOS_PER_SEC;
+
+/// The number of seconds in a day.
+const SECONDS_PER_DAY: i128 = 86_400;
+
+/// Parse a Julian day in the form of `JD 2460000.5` or `MJD 60000`.
+///
+/// The fractional part of the day is parsed up to 9 digits, i.e., with a
+/// precision of 86.4 microseconds.
+pub(super) fn parse(input: &mut &str) -> ModalResult<Timestamp> {
+    (
+        s(alt((
+            Caseless("mjd").value(MJD_UNIX_EPOCH),
+            Caseless("jd").value(JD_UNIX_EPOCH),
+        ))),
+        opt(plus_or_minus),
+        s(sec_and_nsec),
+    )
+        .verify_map(|(epoch, sign, (whole, fraction))| {
+            // The day count in billionths of a day.
+            let days = i128::from(whole) * NANOS_PER_SEC + i128::from(fraction);
+            let days = if sign == Some('-') { -days } else { days };
+
+            // A billionth of a day is 86400 nanoseconds.
+            Some(Timestamp::from_nanos((days - epoch) * SECONDS_PER_DAY))
+        })
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    fn timestamp(input: &str) -> jiff::Timestamp {
+        let mut s = input;
+        parse(&mut s).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn julian_day() {
+        for (input, expected) in [
+            ("JD 2440587.5", "1970-01-01T00:00:00Z"),
+            ("jd 2460000.5", "2023-02-25T00:00:00Z"),
+            ("JD2460000", "2023-02-24T12:00:00Z"),
+            ("JD 2460000.75", "2023-02-25T06:00:00Z"),
+            ("JD 2451545.0", "2000-01-01T12:00:00Z"),
+            ("JD 0", "-004713-11-24T12:00:00Z"),
+        ] {
+            assert_eq!(timestamp(input).to_string(), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn modified_julian_day() {
+        for (input, expected) in [
+            ("MJD 40587", "1970-01-01T00:00:00Z"),
+            ("mjd 60000", "2023-02-25T00:00:00Z"),
+            ("MJD 60000.5", "2023-02-25T12:00:00Z"),
+            ("MJD 0", "1858-11-17T00:00:00Z"),
+            ("MJD -1", "1858-11-16T00:00:00Z"),
+        ] {
+            assert_eq!(timestamp(input).to_string(), expected, "{input}");
+        }
+
+        for input in ["MJD", "MJD x", "JMD 60000"] {
+            let mut s = input;
+            assert!(parse(&mut s).is_err(), "{input}");
+        }
+    }
+}
@@ -0,0 +1,165 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a custom keyword item.
+//!
+//! A word registered with [`Parser::keyword`](crate::Parser::keyword) (e.g.,
+//! "lunch" or "payday") stands on its own for a time of day, a calendar date,
+//! or a calendar date computed from the base date, and may be combined with
+//! the other items (e.g., "payday 10:00").
+
+use std::collections::HashMap;
+
+use winnow::{
+    ascii::alpha1,
+    error::{ContextError, ErrMode},
+    ModalResult, Parser,
+};
+
+use crate::Keyword;
+
+use super::{
+    date,
+    primitive::{ctx_err, s},
+    time,
+};
+
+#[derive(PartialEq, Debug)]
+pub(crate) enum Word {
+    Date(date::Date),
+    Time(time::Time),
+    /// The name of a keyword whose date is computed from the base date.
+    DateWith(String),
+}
+
+/// Parse a word registered in `keywords`.
+pub(super) fn parse(input: &mut &str, keywords: &HashMap<String, Keyword>) -> ModalResult<Word> {
+    let word = s(alpha1).parse_next(input)?;
+    match keywords.get(word) {
+        Some(Keyword::Time(t)) => Ok(Word::Time(time::Time {
+            hour: t.hour() as u8,
+            minute: t.minute() as u8,
+            second: t.second() as u8,
+            nanosecond: t.subsec_nanosecond() as u32,
+            offset: None,
+        })),
+        Some(Keyword::Date(d)) => Ok(Word::Date(date::Date {
+            day: d.day() as u8,
+            month: d.month() as u8,
+            year: Some(
+                u16::try_from(d.year())
+                    .map_err(|_| ErrMode::Cut(ctx_err("keyword date is out of range")))?,
+            ),
+            two_digit_year: false,
+        })),
+        Some(Keyword::DateWith(_)) => Ok(Word::DateWith(word.to_owned())),
+        None => Err(ErrMode::Backtrack(ContextError::new())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use jiff::{civil, ToSpan};
+
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+        Keyword,
+    };
+
+    use super::{date, parse, time, Word};
+
+    #[test]
+    fn keywords() {
+        let keywords = HashMap::from([
+            ("eod".to_owned(), Keyword::Time(civil::time(17, 30, 0, 0))),
+            ("launch".to_owned(), Keyword::Date(civil::date(2025, 6, 1))),
+            ("payday".to_owned(), Keyword::date_with(Some)),
+            ("ancient".to_owned(), Keyword::Date(civil::date(-5, 1, 1))),
+        ]);
+
+        for (input, expected, rest) in [
+            (
+                "eod",
+                Word::Time(time::Time {
+                    hour: 17,
+                    minute: 30,
+                    ..Default::default()
+                }),
+                "",
+            ),
+            (
+                " launch tomorrow",
+                Word::Date(date::Date {
+                    day: 1,
+                    month: 6,
+                    year: Some(2025),
+                    two_digit_year: false,
+                }),
+                " tomorrow",
+            ),
+            ("payday+1", Word::DateWith("payday".to_owned()), "+1"),
+        ] {
+            let mut s = input;
+            assert_eq!(parse(&mut s, &keywords).unwrap(), expected, "{input}");
+            assert_eq!(s, rest, "{input}");
+        }
+
+        for input in ["eods", "tomorrow", "", "1eod", "ancient"] {
+            let mut s = input;
+            assert!(parse(&mut s, &keywords).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn keywords_resolved() {
+        let now = at("2025-06-15 10:00:00");
+        let options = Options {
+            keywords: [
+                (
+                    "eod".to_owned(),
+                    Keyword::Time(jiff::civil::time(17, 0, 0, 0)),
+                ),
+                (
+                    "launch".to_owned(),
+                    Keyword::Date(jiff::civil::date(2025, 9, 1)),
+                ),
+                (
+                    "payday".to_owned(),
+                    Keyword::date_with(|base| base.last_of_month().checked_sub(1.day()).ok()),
+                ),
+                ("never".to_owned(), Keyword::date_with(|_| None)),
+            ]
+            .into(),
+            ..Default::default()
+        };
+
+        for (input, expected) in [
+            ("eod", "2025-06-15 17:00:00"),
+            ("EOD tomorrow", "2025-06-16 17:00:00"),
+            ("launch", "2025-09-01 00:00:00"),
+            ("launch eod -1 day", "2025-08-31 17:00:00"),
+            ("payday", "2025-06-29 00:00:00"),
+            ("payday 9am", "2025-06-29 09:00:00"),
+            // The date is computed from the base date, before relative items.
+            ("payday +1 month", "2025-07-29 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        for input in ["eod 10:00", "launch payday", "2025-01-01 payday", "never"] {
+            assert!(
+                parse_at_date(now.clone(), input, &options).is_err(),
+                "{input}"
+            );
+        }
+        assert!(parse_at_date(now.clone(), "eod", &Options::default()).is_err());
+    }
+}
@@ -0,0 +1,209 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore multispace0
+
+//! Parse machine input.
+//!
+//! Machine input is the only input accepted when
+//! [`Parser::machine_input`](crate::Parser::machine_input) is enabled:
+//!
+//! - an epoch timestamp (e.g., "@1668421230" or "@1668421230.5"),
+//! - an ISO 8601 calendar date, in the extended or basic format, with a
+//!   four-digit year (e.g., "2022-11-14" or "20221114"),
+//! - an ISO 8601 date and time of day, separated by a "T" or a space, with a
+//!   "Z" or an offset (e.g., "2022-11-14T10:20:30Z" or
+//!   "2022-11-14 10:20:30.5+01:00"), which includes RFC 3339.
+//!
+//! None of these depends on the current date and time or on a locale. A date
+//! alone is the only one that depends on the timezone of the base date and
+//! time, of which it is the midnight.
+//!
+//! The input is lowercased by the caller, so that the "T" and "Z" may be
+//! given in either case, as in RFC 3339.
+
+use winnow::{
+    ascii::multispace0,
+    combinator::{alt, delimited, eof, opt},
+    ModalResult, Parser,
+};
+
+use super::{combined, date, epoch, offset::Offset, time, Item};
+
+pub(super) fn parse(input: &mut &str) -> ModalResult<Item> {
+    delimited(
+        multispace0,
+        alt((
+            epoch::parse.map(Item::Timestamp),
+            datetime.map(Item::DateTime),
+            date.map(Item::Date),
+        )),
+        (multispace0, eof),
+    )
+    .parse_next(input)
+}
+
+/// Parse an ISO 8601 date and time of day, with "z" for UTC or an offset.
+fn datetime(input: &mut &str) -> ModalResult<combined::DateTime> {
+    let (date, _, mut time, utc) = (date, alt(('t', ' ')), time, opt('z'))
+        .verify(|(_, _, time, utc)| utc.is_some() != time.offset.is_some())
+        .parse_next(input)?;
+    if utc.is_some() {
+        time.offset = Some(Offset::default());
+    }
+    Ok(combined::DateTime { date, time })
+}
+
+/// Parse an ISO 8601 time of day, without the spaces and comments allowed
+/// between its parts in the GNU grammar.
+fn time(input: &mut &str) -> ModalResult<time::Time> {
+    time::iso
+        .with_taken()
+        .verify(|(_, taken): &(time::Time, &str)| {
+            taken
+                .bytes()
+                .all(|b| b.is_ascii_digit() || b":.,+-".contains(&b))
+        })
+        .map(|(time, _)| time)
+        .parse_next(input)
+}
+
+/// Parse an ISO 8601 calendar date with a four-digit year, as "yyyy-mm-dd" or
+/// "yyyymmdd".
+fn date(input: &mut &str) -> ModalResult<date::Date> {
+    alt((date::iso1, date::iso2))
+        .with_taken()
+        .verify(|(_, taken): &(date::Date, &str)| {
+            let b = taken.as_bytes();
+            match b.len() {
+                8 => b.iter().all(u8::is_ascii_digit),
+                10 => {
+                    b[4] == b'-'
+                        && b[7] == b'-'
+                        && [&b[..4], &b[5..7], &b[8..]]
+                            .iter()
+                            .all(|part| part.iter().all(u8::is_ascii_digit))
+                }
+                _ => false,
+            }
+        })
+        .map(|(date, _)| date)
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::tz::TimeZone;
+
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+    };
+
+    use super::parse;
+
+    #[test]
+    fn accepted() {
+        for mut s in [
+            "@0",
+            "@-1",
+            "@1668421230.5",
+            "2022-11-14",
+            "20221114",
+            "2022-11-14t10:20:30z",
+            "2022-11-14 10:20:30+00:00",
+            "2022-11-14t10:20z",
+            "2022-11-14t10:20:30.123456789z",
+            "2022-11-14t10:20:30,5+01:00",
+            "2022-11-14t10:20:30-0500",
+            "  2022-11-14t10:20:30z  ",
+        ] {
+            let old_s = s.to_owned();
+            assert!(parse(&mut s).is_ok(), "Failed string: {old_s}");
+        }
+    }
+
+    #[test]
+    fn rejected() {
+        for mut s in [
+            "",
+            "now",
+            "tomorrow",
+            "+1 day",
+            "2022-11-14 +1 day",
+            "22-11-14",
+            "022-11-14",
+            "12022-11-14",
+            "2022 - 11 - 14",
+            "2022-1-14",
+            "11/14/2022",
+            "14 nov 2022",
+            "nov 14",
+            "10:20:30",
+            "2022-11-14t10:20:30",
+            "2022-11-14 10:20",
+            "monday",
+            "2022-11-14t10:20:30 utc",
+            "2022-11-14t10:20:30 est",
+            "2022-11-14t10:20:30+01:00z",
+            "2022-11-14t8pm",
+            "2022-11-14 (comment) 10:20:30",
+            "2022-11-14t10 : 20",
+            "tz=\"utc\" 2022-11-14",
+        ] {
+            let old_s = s.to_owned();
+            assert!(parse(&mut s).is_err(), "Parsed string: {old_s}");
+        }
+    }
+
+    #[test]
+    fn machine_input() {
+        let options = Options {
+            machine_input: true,
+            serial_dates: true,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("2022-11-14", "2022-11-14 00:00:00 +00:00"),
+            ("20221114", "2022-11-14 00:00:00 +00:00"),
+            ("2022-11-14T10:20:30Z", "2022-11-14 10:20:30 +00:00"),
+            ("2022-11-14t10:20:30z", "2022-11-14 10:20:30 +00:00"),
+            ("2022-11-14 10:20:30+01:00", "2022-11-14 10:20:30 +01:00"),
+            ("@1668421230", "2022-11-14 10:20:30 +00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // A date alone is midnight in the timezone of the base date.
+        let paris = now.with_time_zone(TimeZone::get("Europe/Paris").unwrap());
+        let result = parse_at_date(paris, "2022-11-14", &options).unwrap();
+        assert_eq!(
+            result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+            "2022-11-14 00:00:00 +01:00"
+        );
+
+        for input in [
+            "now",
+            "",
+            "45292.75",
+            "2022-11-14 +1 day",
+            "14 nov 2022",
+            "2022-11-14T10:20:30",
+            "2024-01-01 10:00",
+            "2022-11-14T10:20:30+01:00z",
+        ] {
+            assert!(
+                parse_at_date(now.clone(), input, &options).is_err(),
+                "{input}"
+            );
+        }
+        assert!(parse_at_date(now.clone(), "2022-02-30", &options).is_err());
+    }
+}
@@ -18,41 +18,98 @@
 //! >  - pure numbers.
 //!
 //! We put all of those in separate modules:
+//!  - [`annotation`]
+//!  - [`calendar`]
 //!  - [`combined`]
 //!  - [`date`]
 //!  - [`epoch`]
+//!  - [`era`]
+//!  - [`fiscal`]
+//!  - [`generalized_time`]
+//!  - [`holiday`]
+//!  - [`http`]
+//!  - [`imap`]
+//!  - [`iso8601`]
+//!  - [`julian`]
+//!  - [`keyword`]
+//!  - [`machine`]
+//!  - [`ntp`]
 //!  - [`offset`]
+//!  - [`period`]
 //!  - [`pure`]
+//!  - [`received`]
 //!  - [`relative`]
+//!  - [`rfc3339`]
+//!  - [`schedule`]
+//!  - [`serial`]
+//!  - [`sql`]
+//!  - [`tai`]
+//!  - [`tai64`]
 //!  - [`time`]
+//!  - [`utc_time`]
 //!  - [`timezone`]
 //!  - [`weekday`]
+//!  - [`work_log`]
 //!  - [`year`]
 
 // date and time items
+mod annotation;
+#[cfg(feature = "calendars")]
+mod calendar;
 mod combined;
 mod date;
 mod epoch;
+#[cfg(feature = "calendars")]
+mod era;
+mod fiscal;
+mod generalized_time;
+mod holiday;
+mod http;
+mod imap;
+mod iso8601;
+mod julian;
+mod keyword;
+mod machine;
+mod ntp;
 mod offset;
+mod period;
 mod pure;
+mod received;
 mod relative;
+mod rfc3339;
+mod schedule;
+mod serial;
+mod sql;
+mod tai;
+mod tai64;
 mod time;
 mod timezone;
+mod utc_time;
 mod weekday;
+mod work_log;
 mod year;
 
 // utility modules
 mod builder;
 mod ordinal;
+mod parsed;
 mod primitive;
+mod specification;
 
 pub(crate) mod error;
 
-use jiff::Zoned;
+pub mod combinators;
+
+pub use parsed::Parsed;
+pub use specification::Specification;
+
+use std::borrow::Cow;
+
+use jiff::{SignedDuration, Span, Zoned};
 use primitive::space;
 use winnow::{
-    combinator::{alt, eof, preceded, repeat_till, terminated, trace},
-    error::{AddContext, ContextError, ErrMode, StrContext, StrContextValue},
+    combinator::{alt, eof, opt, preceded, repeat_till, terminated, trace},
+    error::{AddContext, ContextError, ErrMode, FromExternalError, StrContext, StrContextValue},
     stream::Stream,
     ModalResult, Parser,
 };
@@ -60,33 +117,68 @@ use winnow::{
 use builder::DateTimeBuilder;
 use error::Error;
 
+use crate::{
+    parser::Options, DstAmbiguity, Iso8601Value, ParsedDateTime, Quirk, RepeatingInterval,
+    Schedule, TemporalValue,
+};
+
 #[derive(PartialEq, Debug)]
 enum Item {
     Timestamp(epoch::Timestamp),
     DateTime(combined::DateTime),
     Date(date::Date),
+    /// A date given to the month only (e.g., "2024-07" or "july"), as the
+    /// first day of the month.
+    Month(date::Date),
+    Holiday(holiday::Holiday),
+    Keyword(keyword::Word),
+    Fiscal(fiscal::Fiscal),
+    Period(period::Period),
     Time(time::Time),
     Weekday(weekday::Weekday),
+    NthWeekday(weekday::NthWeekday),
     Relative(relative::Relative),
+    Relatives(Vec<relative::Relative>),
     Offset(offset::Offset),
     TimeZone(jiff::tz::TimeZone),
+    Annotations(Option<annotation::ZoneAnnotation>),
     Pure(String),
 }
 
 /// Parse a date and time string and build a `Zoned` object. The parsed result
 /// is resolved against the given base date and time.
-pub(crate) fn parse_at_date<S: AsRef<str> + Clone>(base: Zoned, input: S) -> Result<Zoned, Error> {
-    match parse(&mut input.as_ref()) {
-        Ok(builder) => builder.set_base(base).build(),
+pub(crate) fn parse_at_date<S: AsRef<str> + Clone>(
+    base: Zoned,
+    input: S,
+    options: &Options,
+) -> Result<Zoned, Error> {
+    match parse(&mut input.as_ref(), options) {
+        Ok(builder) => builder.set_base(base).build(options),
         Err(e) => Err(e.into()),
     }
 }
 
+/// Return the current date and time in the default timezone: that of the `TZ`
+/// environment variable if it is honored, or the system timezone.
+pub(crate) fn now(options: &Options) -> Zoned {
+    DateTimeBuilder::new().now(options)
+}
+
+/// Parse a date and time string, to be resolved later against any number of
+/// base dates and times.
+pub(crate) fn parse_deferred<'a>(input: &str, options: &'a Options) -> Result<Parsed<'a>, Error> {
+    let builder = parse(&mut &*input, options)?;
+    Ok(Parsed::new(builder, options))
+}
+
 /// Parse a date and time string and build a `Zoned` object. The parsed result
 /// is resolved against the current local date and time.
-pub(crate) fn parse_at_local<S: AsRef<str> + Clone>(input: S) -> Result<Zoned, Error> {
-    match parse(&mut input.as_ref()) {
-        Ok(builder) => builder.build(), // the builder uses current local date and time if no base is given.
+pub(crate) fn parse_at_local<S: AsRef<str> + Clone>(
+    input: S,
+    options: &Options,
+) -> Result<Zoned, Error> {
+    match parse(&mut input.as_ref(), options) {
+        Ok(builder) => builder.build(options), // the builder uses current local date and time if no base is given.
         Err(e) => Err(e.into()),
     }
 }
@@ -100,18 +192,40 @@ pub(crate) fn parse_at_local<S: AsRef<str> + Clone>(input: S) -> Result<Zoned, E
 ///
 /// tz_rule            = "TZ=" , "\"" , ( posix_tz | iana_tz ) , "\"" ;
 ///
-/// timestamp           = "@" , float ;
+/// timestamp           = "@" , float , [ "s" | "ms" | "us" | "ns" ]
+///                     | "@" , tai64_label
+///                     | ( "jd" | "mjd" ) , float
+///                     | [ ( "gps" | "tai" ) , float ] ;
+///
+/// tai64_label         = 16 * hex_digit , [ 8 * hex_digit , [ 8 * hex_digit ] ] ;
 ///
 /// items               = item , { item } ;
-/// item                = datetime | date | time | relative | weekday | offset | pure ;
+/// item                = [ holiday ] | [ serial_date ] | [ fiscal ] | [ period ] | sql_literal | datetime | date | year_month | time | nth_weekday | relative | weekday | offset | annotations | pure ;
+///
+/// holiday             = holiday_name , [ year ] ;
+///
+/// fiscal              = "fy" , year , [ quarter ]
+///                     | quarter , "fy" , year
+///                     | ( "start" | "beginning" ) , "of" , [ "the" | "this" ] , "fiscal" , ( "year" | "quarter" ) ;
+/// quarter             = "q" , ( "1" | "2" | "3" | "4" ) ;
+///
+/// period              = ( "last" | "this" | "next" ) , ( "day" | "week" | "month" | "year" ) ;
+///
+/// serial_date         = float ;
+///
+/// sql_literal         = [ sql_type ] , "'" , ( datetime | iso_date | iso_time ) , "'" ;
+/// sql_type            = "date" | "timetz" | "timestamptz"
+///                     | ( "time" | "timestamp" ) , [ ( "with" | "without" ) , "time" , "zone" ] ;
 ///
-/// datetime            = date , [ "t" | whitespace ] , iso_time ;
+/// datetime            = date , ( "t" , ( iso_time | fractional_hour ) | whitespace , iso_time ) ;
 ///
-/// date                = iso_date | us_date | literal1_date | literal2_date ;
+/// date                = iso_date | us_date | literal1_date | literal2_date | era_date | calendar_date ;
 ///
 /// iso_date            = year , [ iso_date_delim ] , month , [ iso_date_delim ] , day ;
 /// iso_date_delim      = optional_whitespace , "-" , optional_whitespace ;
 ///
+/// year_month          = 4 * digit , "-" , 2 * digit ;
+///
 /// us_date             = month , [ us_date_delim ] , day , [ us_date_delim , year ];
 /// us_date_delim       = optional_whitespace , "/" , optional_whitespace ;
 ///
@@ -121,29 +235,49 @@ pub(crate) fn parse_at_local<S: AsRef<str> + Clone>(input: S) -> Result<Zoned, E
 /// literal2_date       = literal_month , optional_whitespace , day , [ literal2_date_delim , year ] ;
 /// literal2_date_delim = (optional_whitespace , "," , optional_whitespace) | optional_whitespace ;
 ///
+/// era_date            = era_name , ( year | "元" ) , "年" , month , "月" , day , "日"
+///                     | era_abbr , year , era_date_delim , month , era_date_delim , day ;
+/// era_name            = "明治" | "大正" | "昭和" | "平成" | "令和" ;
+/// era_abbr            = "m" | "t" | "s" | "h" | "r" ;
+/// era_date_delim      = "." | "/" | "-" ;
+///
+/// calendar_date       = day , calendar_month , year ;
+/// calendar_month      = ? a month name of the configured calendar ? ;
+///
 /// year                = dec_uint ;
 /// month               = dec_uint ;
 /// day                 = dec_uint ;
 ///
-/// literal_month       = "january" | "jan"
-///                     | "february" | "feb"
-///                     | "march" | "mar"
-///                     | "april" | "apr"
-///                     | "may"
-///                     | "june" | "jun"
-///                     | "july" | "jul"
-///                     | "august" | "aug"
-///                     | "september" | "sept" | "sep"
-///                     | "october" | "oct"
-///                     | "november" | "nov"
-///                     | "december" | "dec" ;
+/// literal_month       = "january" | "jan" [ "." ]
+///                     | "february" | "feb" [ "." ]
+///                     | "march" | "mar" [ "." ]
+///                     | "april" | "apr" [ "." ]
+///                     | "may" [ "." ]
+///                     | "june" | "jun" [ "." ]
+///                     | "july" | "jul" [ "." ]
+///                     | "august" | "aug" [ "." ]
+///                     | "september" | ( "sept" | "sep" ) [ "." ]
+///                     | "october" | "oct" [ "." ]
+///                     | "november" | "nov" [ "." ]
+///                     | "december" | "dec" [ "." ] ;
+///
+/// time                = iso_time | meridiem_time | oclock_time | "noon" | "midnight" ;
 ///
-/// time                = iso_time | meridiem_time ;
+/// iso_time            = hour24 , [ ":" , minute , [ ":" , second | fraction ] ] , [ time_offset ] ;
+/// fractional_hour     = hour24 , fraction , [ time_offset ] ;
+/// fraction            = ( "." | "," ) , digit , { digit } ;
 ///
-/// iso_time            = hour24 , [ ":" , minute , [ ":" , second ] ] , [ time_offset ] ;
+/// meridiem_time       = hour12 , [ ":" , minute , [ ":" , second ] ] , meridiem
+///                     | locale_meridiem , hour12 , [ ":" , minute , [ ":" , second ] ] ;
+/// meridiem            = "am" | "pm" | "a.m." | "p.m." | "a.m" | "p.m" | "a. m." | "p. m."
+///                     | locale_meridiem ;
+/// locale_meridiem     = ? a meridiem marker of the configured locale ? ;
 ///
-/// meridiem_time       = hour12 , [ ":" , minute , [ ":" , second ] ] , meridiem ;
-/// meridiem            = "am" | "pm" | "a.m." | "p.m." ;
+/// oclock_time         = hour_word , ( oclock , [ meridiem ] | meridiem )
+///                     | hour12 , oclock , [ meridiem ] ;
+/// oclock              = "o'clock" | "o’clock" | "oclock" ;
+/// hour_word           = "one" | "two" | "three" | "four" | "five" | "six"
+///                     | "seven" | "eight" | "nine" | "ten" | "eleven" | "twelve" ;
 ///
 /// hour24              = dec_uint ;
 /// hour12              = dec_uint ;
@@ -152,7 +286,9 @@ pub(crate) fn parse_at_local<S: AsRef<str> + Clone>(input: S) -> Result<Zoned, E
 ///
 /// time_offset         = ( "+" | "-" ) , dec_uint , [ ":" , dec_uint ] ;
 ///
-/// relative            = [ numeric_ordinal  ] , unit , [ "ago" ] | day_shift ;
+/// relative            = relative_item , { "and" , relative_item } ;
+/// relative_item       = [ numeric_ordinal  ] , unit , [ "ago" ] | day_shift | business_days
+///                     | shorthand | [ compact_duration ] | [ work_log ] ;
 ///
 /// unit                = "year" | "years"
 ///                     | "month" | "months"
@@ -161,12 +297,30 @@ pub(crate) fn parse_at_local<S: AsRef<str> + Clone>(input: S) -> Result<Zoned, E
 ///                     | "day" | "days"
 ///                     | "hour" | "hours"
 ///                     | "minute" | "minutes" | "min" | "mins"
-///                     | "second" | "seconds" | "sec" | "secs" ;
+///                     | "second" | "seconds" | "sec" | "secs"
+///                     | "yr" | "yrs" | "mo" | "mos" | "wk" | "wks" | "hr" | "hrs"
+///                     | [ "m" ] ;
 ///
 /// day_shift           = "tomorrow" | "yesterday" | "today" | "now" ;
 ///
+/// shorthand           = [ "+" | "-" ] , shorthand_part , { shorthand_part } , [ "ago" ] ;
+/// shorthand_part      = dec_uint , ( "y" | "mo" | "w" | "d" | "h" | "m" | "s" ) ;
+///
+/// compact_duration    = [ "+" | "-" ] , compact_part , { compact_part } , [ "ago" ] ;
+/// compact_part        = float , ( "ns" | "us" | "µs" | "ms" | "s" | "m" | "h" | "d" | "w" ) ;
+///
+/// work_log            = float , ( "w" | "d" | "h" | "m" ) ;
+///
+/// business_days       = [ numeric_ordinal ] , business_unit , [ "ago" ] ;
+/// business_unit       = ( "business" | "working" ) , ( "day" | "days" )
+///                     | "workday" | "workdays" | "weekday" | "weekdays" ;
+///
 /// weekday             = [ ordinal ] , day , [ "," ] ;
 ///
+/// nth_weekday         = ( ordinal | "second" ) , day , "of" , month_selector ;
+/// month_selector      = ( "the" | "this" ) , "month"
+///                     | literal_month , [ year ] ;
+///
 /// ordinal             = numeric_ordinal | text_ordinal ;
 /// numeric_ordinal     = [ "+" | "-" ] , dec_uint ;
 /// text_ordinal        = "last" | "this" | "next" | "first"
@@ -174,25 +328,85 @@ pub(crate) fn parse_at_local<S: AsRef<str> + Clone>(input: S) -> Result<Zoned, E
 ///                     | "seventh" | "eighth" | "ninth" | "tenth"
 ///                     | "eleventh" | "twelfth" ;
 ///
-/// day                 = "monday" | "mon" | "mon."
-///                     | "tuesday" | "tue" | "tue." | "tues"
-///                     | "wednesday" | "wed" | "wed." | "wednes"
-///                     | "thursday" | "thu" | "thu." | "thur" | "thurs"
-///                     | "friday" | "fri" | "fri."
-///                     | "saturday" | "sat" | "sat."
-///                     | "sunday" | "sun" | "sun." ;
+/// day                 = "monday" | "mon" [ "." ]
+///                     | "tuesday" | ( "tue" | "tues" ) [ "." ]
+///                     | "wednesday" | ( "wed" | "wednes" ) [ "." ]
+///                     | "thursday" | ( "thu" | "thur" | "thurs" ) [ "." ]
+///                     | "friday" | "fri" [ "." ]
+///                     | "saturday" | "sat" [ "." ]
+///                     | "sunday" | "sun" [ "." ] ;
 ///
 /// offset             = named_zone , [ time_offset ] ;
 ///
+/// annotations        = annotation , { annotation } ;
+/// annotation         = "[" , [ "!" ] , ( annotation_tag | annotation_zone ) , "]" ;
+/// annotation_tag     = annotation_key , "=" , annotation_value ;
+/// annotation_zone    = iana_tz | time_offset ;
+///
+/// holiday_name       = ? a name known to the configured holiday provider ? ;
+///
 /// pure               = { digit }
 ///
 /// optional_whitespace = { whitespace } ;
 /// ```
-fn parse(input: &mut &str) -> ModalResult<DateTimeBuilder> {
-    trace("parse", alt((parse_timestamp, parse_items))).parse_next(input)
+///
+/// Non-ASCII digits, whitespace and minus signs are replaced with their ASCII
+/// equivalents before any of the alternatives is parsed, so that they are
+/// accepted alike in timestamps, items and machine input.
+fn parse(input: &mut &str, options: &Options) -> ModalResult<DateTimeBuilder> {
+    check_length(input, options)
+        .map_err(|e| ErrMode::Cut(ContextError::from_external_error(input, e)))?;
+
+    let normalized = primitive::normalize(input);
+    let result = if options.machine_input {
+        parse_machine(&mut normalized.as_ref(), options)
+    } else {
+        with_budget(&mut normalized.as_ref(), options, |input| {
+            trace(
+                "parse",
+                alt((
+                    |i: &mut &str| parse_timestamp(i, options),
+                    |i: &mut &str| parse_items(i, options),
+                )),
+            )
+            .parse_next(input)
+        })
+    };
+
+    match result {
+        Ok(builder) => {
+            *input = &input[input.len()..];
+            Ok(builder)
+        }
+        // Report the span of a syntax error in the input as it was given.
+        Err(ErrMode::Cut(e)) if matches!(normalized, Cow::Owned(_)) => {
+            match e.cause().and_then(|cause| cause.downcast_ref::<Error>()) {
+                Some(Error::Parse(p)) => {
+                    let mut p = p.clone();
+                    p.span = p.span.map(|span| {
+                        primitive::original_offset(input, span.start)
+                            ..primitive::original_offset(input, span.end)
+                    });
+                    Err(ErrMode::Cut(ContextError::from_external_error(
+                        input,
+                        Error::Parse(p),
+                    )))
+                }
+                _ => Err(ErrMode::Cut(e)),
+            }
+        }
+        Err(e) => Err(e),
+    }
 }
 
-/// Parse a standalone epoch timestamp (e.g., `@1758724019`).
+/// Parse a standalone epoch timestamp (e.g., `@1758724019`), TAI64N label
+/// (e.g., `@4000000065a1c2ea075bcd15`), Julian day (e.g., `JD 2460000.5`), or
+/// GPS or TAI timestamp (e.g., `GPS 1400000000`).
+/// GPS and TAI timestamps are only recognized when a leap second table is
+/// configured. When an epoch origin is configured, epoch timestamps are counted
+/// from it, and may be given without their `@` (e.g., `1758724019`). When unit
+/// detection is enabled, the unit of an integer is detected from its magnitude,
+/// and an integer of at least 9 digits may be given without its `@`.
 ///
 /// GNU `date` specifies that a timestamp item is *complete* and *must not* be
 /// combined with any other date/time item.
@@ -202,14 +416,26 @@ fn parse(input: &mut &str) -> ModalResult<DateTimeBuilder> {
 /// - If a timezone rule (`TZ="..."`) appears at the beginning of the input, it
 ///   has no effect on the epoch value. We intentionally parse and ignore it.
 /// - Trailing input (aside from optional whitespaces) is rejected.
-fn parse_timestamp(input: &mut &str) -> ModalResult<DateTimeBuilder> {
+fn parse_timestamp(input: &mut &str, options: &Options) -> ModalResult<DateTimeBuilder> {
     // Parse and ignore an optional leading timezone rule.
-    let _ = timezone::parse(input);
+    opt(timezone::parse).parse_next(input)?;
 
     trace(
         "parse_timestamp",
         // Expect exactly one timestamp and then EOF (allowing trailing spaces).
-        terminated(epoch::parse.map(Item::Timestamp), preceded(space, eof)),
+        terminated(
+            alt((
+                tai64::parse,
+                |i: &mut &str| epoch(i, options),
+                julian::parse,
+                |i: &mut &str| match &options.leap_seconds {
+                    Some(leap_seconds) => tai::parse(i, leap_seconds),
+                    None => Err(ErrMode::Backtrack(ContextError::new())),
+                },
+            ))
+            .map(Item::Timestamp),
+            preceded(space, eof),
+        ),
     )
     .verify_map(|item: Item| match item {
         Item::Timestamp(ts) => DateTimeBuilder::new().set_timestamp(ts).ok(),
@@ -218,6 +444,47 @@ fn parse_timestamp(input: &mut &str) -> ModalResult<DateTimeBuilder> {
     .parse_next(input)
 }
 
+/// Parse machine input, the only input accepted when machine input is enabled.
+///
+/// The input is converted to lowercase, as in [`parse_items`].
+fn parse_machine(input: &mut &str, options: &Options) -> ModalResult<DateTimeBuilder> {
+    let lower = input.to_ascii_lowercase();
+    let item = match trace("parse_machine", machine::parse).parse_next(&mut lower.as_str())? {
+        Item::Timestamp(ts) => Item::Timestamp(since_origin(ts, options)),
+        item => item,
+    };
+    vec![item].try_into().map_err(|e| expect_error(input, e))
+}
+
+/// Parse an epoch timestamp, with its unit detected from its magnitude and
+/// counted from the configured origin, if so configured.
+fn epoch(input: &mut &str, options: &Options) -> ModalResult<epoch::Timestamp> {
+    let ts = alt((
+        |i: &mut &str| {
+            if options.detect_epoch_unit {
+                epoch::magnitude(i)
+            } else {
+                Err(ErrMode::Backtrack(ContextError::new()))
+            }
+        },
+        epoch::parse,
+        |i: &mut &str| match options.epoch_origin {
+            Some(_) => epoch::bare(i),
+            None => Err(ErrMode::Backtrack(ContextError::new())),
+        },
+    ))
+    .parse_next(input)?;
+    Ok(since_origin(ts, options))
+}
+
+/// Count an epoch timestamp from the configured origin, if any.
+fn since_origin(ts: epoch::Timestamp, options: &Options) -> epoch::Timestamp {
+    match options.epoch_origin {
+        Some(origin) => ts.since(origin),
+        None => ts,
+    }
+}
+
 /// Parse a sequence of date/time items, honoring an optional leading TZ rule.
 ///
 /// Notes:
@@ -227,46 +494,445 @@ fn parse_timestamp(input: &mut &str) -> ModalResult<DateTimeBuilder> {
 /// - After the optional timezone rule is parsed, we convert the input to
 ///   lowercase to allow case-insensitive parsing of the remaining items.
 /// - Trailing input (aside from optional whitespaces) is rejected.
-fn parse_items(input: &mut &str) -> ModalResult<DateTimeBuilder> {
+fn parse_items(input: &mut &str, options: &Options) -> ModalResult<DateTimeBuilder> {
+    let start = input.len();
+
     // Parse and consume an optional leading timezone rule.
-    let tz = timezone::parse(input).map(Item::TimeZone);
+    let rule = opt(timezone::rule).parse_next(input)?;
 
     // Convert input to lowercase for case-insensitive parsing.
     let lower = input.to_ascii_lowercase();
     let input = &mut lower.as_str();
 
-    let (mut items, _): (Vec<Item>, _) = trace(
+    let mut count = 0;
+    // The length of the input that remains at the start of the last item,
+    // where a syntax error is reported.
+    let mut item = input.len();
+    let result: ModalResult<(Vec<Item>, _)> = trace(
         "parse_items",
         // Parse zero or more items until EOF (allowing trailing spaces).
-        repeat_till(0.., parse_item, preceded(space, eof)),
+        repeat_till(
+            0..,
+            |i: &mut &str| {
+                item = i.len();
+                count += 1;
+                if options.max_items.is_some_and(|max| count > max) {
+                    return Err(ErrMode::Cut(ContextError::from_external_error(
+                        i,
+                        Error::TooManyItems,
+                    )));
+                }
+                parse_item(i, options)
+            },
+            preceded(space, eof),
+        ),
     )
-    .parse_next(input)?;
+    .parse_next(input);
+    let (mut items, _) =
+        result.map_err(|e| syntax_error(e, start, &lower[lower.len() - item..]))?;
 
-    if let Ok(tz) = tz {
-        items.push(tz);
+    if let Some(rule) = &rule {
+        if let Some(quirk) = timezone::rule_quirk(rule) {
+            options.quirk(quirk);
+        }
+        items.push(Item::TimeZone(timezone::rule_time_zone(rule)));
     }
 
-    items.try_into().map_err(|e| expect_error(input, e))
+    let builder: DateTimeBuilder = items.try_into().map_err(|e| expect_error(input, e))?;
+    if builder.two_digit_year() {
+        options.quirk(Quirk::TwoDigitYear);
+    }
+    Ok(builder.set_rule_abbreviation(rule.as_deref().and_then(timezone::rule_abbreviation)))
 }
 
 /// Parse an item.
-fn parse_item(input: &mut &str) -> ModalResult<Item> {
+///
+/// Items that are not part of the GNU grammar and must be enabled take
+/// precedence over the other items, and so do dates of a non-Gregorian
+/// calendar when that calendar is selected.
+fn parse_item(input: &mut &str, options: &Options) -> ModalResult<Item> {
     trace(
         "parse_item",
         alt((
-            combined::parse.map(Item::DateTime),
+            |i: &mut &str| parse_extension(i, options),
+            #[cfg(feature = "calendars")]
+            |i: &mut &str| {
+                if options.eras {
+                    era::parse.map(Item::Date).parse_next(i)
+                } else {
+                    Err(ErrMode::Backtrack(ContextError::new()))
+                }
+            },
+            #[cfg(feature = "calendars")]
+            |i: &mut &str| match options.calendar {
+                Some(c) => calendar::parse(i, c).map(Item::Date),
+                None => Err(ErrMode::Backtrack(ContextError::new())),
+            },
+            sql::parse,
+            |i: &mut &str| {
+                combined::with_offset(i, offset::time_offset(options)).map(Item::DateTime)
+            },
             date::parse.map(Item::Date),
-            time::parse.map(Item::Time),
+            date::year_month.map(Item::Month),
+            |i: &mut &str| time::parse(i, options).map(Item::Time),
+            // Must precede relative items, which would otherwise consume
+            // "second" as a unit.
+            weekday::nth_of_month.map(Item::NthWeekday),
+            relative::and_chain.map(Item::Relatives),
             relative::parse.map(Item::Relative),
+            relative::shorthand.map(Item::Relatives),
             weekday::parse.map(Item::Weekday),
-            offset::parse.map(Item::Offset),
+            |i: &mut &str| offset::parse(i, options).map(Item::Offset),
+            annotation::parse.map(Item::Annotations),
             pure::parse.map(Item::Pure),
         )),
     )
     .parse_next(input)
 }
 
+/// Parse an item that is not part of the GNU grammar.
+///
+/// Custom keywords and relative units are only recognized when they are
+/// registered, holiday names only when a holiday provider is configured, numbers are spreadsheet
+/// serial dates only when serial dates are enabled, compact durations only
+/// when they are enabled, "m" as a unit only when its meaning is configured,
+/// work-log durations only when a working time is configured, fiscal periods
+/// only when a fiscal year start month is configured, whole periods only when
+/// period starts are enabled, hours in words only in the natural-language
+/// mode, and "noon" and "midnight" only in the natural-language or strict
+/// meridiem mode.
+fn parse_extension(input: &mut &str, options: &Options) -> ModalResult<Item> {
+    alt((
+        |i: &mut &str| {
+            if options.keywords.is_empty() {
+                Err(ErrMode::Backtrack(ContextError::new()))
+            } else {
+                keyword::parse(i, &options.keywords).map(Item::Keyword)
+            }
+        },
+        |i: &mut &str| {
+            if options.relative_units.is_empty() {
+                Err(ErrMode::Backtrack(ContextError::new()))
+            } else {
+                relative::custom(i, &options.relative_units).map(Item::Relative)
+            }
+        },
+        |i: &mut &str| match &options.holidays {
+            Some(provider) => holiday::parse(i, provider.as_ref()).map(Item::Holiday),
+            None => Err(ErrMode::Backtrack(ContextError::new())),
+        },
+        |i: &mut &str| {
+            if options.serial_dates {
+                serial::parse.map(Item::DateTime).parse_next(i)
+            } else {
+                Err(ErrMode::Backtrack(ContextError::new()))
+            }
+        },
+        |i: &mut &str| {
+            if options.compact_durations {
+                relative::compact_duration.map(Item::Relative).parse_next(i)
+            } else {
+                Err(ErrMode::Backtrack(ContextError::new()))
+            }
+        },
+        |i: &mut &str| match options.m_unit {
+            Some(unit) => relative::m_unit(i, unit).map(Item::Relative),
+            None => Err(ErrMode::Backtrack(ContextError::new())),
+        },
+        |i: &mut &str| match options.work_time {
+            Some(_) => work_log::parse.map(Item::Relative).parse_next(i),
+            None => Err(ErrMode::Backtrack(ContextError::new())),
+        },
+        |i: &mut &str| match options.fiscal_year_start {
+            Some(_) => fiscal::parse(i).map(Item::Fiscal),
+            None => Err(ErrMode::Backtrack(ContextError::new())),
+        },
+        |i: &mut &str| {
+            if options.period_start {
+                period::parse.map(Item::Period).parse_next(i)
+            } else {
+                Err(ErrMode::Backtrack(ContextError::new()))
+            }
+        },
+        |i: &mut &str| {
+            if options.natural_language {
+                alt((
+                    alt((
+                        |i: &mut &str| time::oclock(i, options),
+                        time::military,
+                        time::noon_or_midnight,
+                    ))
+                    .map(Item::Time),
+                    date::month_only.map(Item::Month),
+                ))
+                .parse_next(i)
+            } else if options.strict_meridiem {
+                time::noon_or_midnight.map(Item::Time).parse_next(i)
+            } else {
+                Err(ErrMode::Backtrack(ContextError::new()))
+            }
+        },
+    ))
+    .parse_next(input)
+}
+
+/// Parse a date and time string and return the duration from the base date and
+/// time (or the current date and time, if no base is given) to the parsed
+/// result.
+pub(crate) fn parse_deadline<S: AsRef<str> + Clone>(
+    base: Option<Zoned>,
+    input: S,
+    options: &Options,
+) -> Result<SignedDuration, Error> {
+    let builder = parse(&mut input.as_ref(), options)?;
+    let base = base.unwrap_or_else(|| builder.now(options));
+    let deadline = builder.set_base(base.clone()).build(options)?;
+    Ok(deadline.timestamp().duration_since(base.timestamp()))
+}
+
+/// Parse two date and time strings against the same base date and time (or
+/// the current date and time, if no base is given), and return the calendar
+/// span from the first to the second.
+pub(crate) fn span_between<A: AsRef<str>, B: AsRef<str>>(
+    base: Option<Zoned>,
+    a: A,
+    b: B,
+    options: &Options,
+) -> Result<Span, Error> {
+    let a = parse(&mut a.as_ref(), options)?;
+    let b = parse(&mut b.as_ref(), options)?;
+    let base = base.unwrap_or_else(|| a.now(options));
+    let a = a.set_base(base.clone()).build(options)?;
+    let b = b.set_base(base).build(options)?;
+    builder::calendar_span(&a, &b).map_err(Error::resolving)
+}
+
+/// Parse an RFC 3339 date and time (e.g., "2022-11-14T10:20:30Z"), and
+/// nothing else.
+pub(crate) fn parse_rfc3339(input: &str, options: &Options) -> Result<Zoned, Error> {
+    check_length(input, options)?;
+    let (datetime, tz) = rfc3339::parse(&mut &*input)?;
+    datetime.to_zoned(tz).map_err(|_| Error::OutOfRange)
+}
+
+/// Parse an ASN.1 GeneralizedTime (e.g., "20240702143000Z"), and nothing
+/// else.
+pub(crate) fn parse_generalized_time(input: &str, options: &Options) -> Result<Zoned, Error> {
+    check_length(input, options)?;
+    let (datetime, tz) = generalized_time::parse(&mut &*input)?;
+    datetime.to_zoned(tz).map_err(|_| Error::OutOfRange)
+}
+
+/// Parse an ASN.1 UTCTime (e.g., "240702143000Z"), and nothing else.
+pub(crate) fn parse_utc_time(input: &str, options: &Options) -> Result<Zoned, Error> {
+    check_length(input, options)?;
+    let (datetime, tz) = utc_time::parse(&mut &*input)?;
+    datetime.to_zoned(tz).map_err(|_| Error::OutOfRange)
+}
+
+/// Parse an IMAP date and time (e.g., "01-Jul-2024 12:34:56 +0000"), in
+/// quotes or not, and nothing else.
+pub(crate) fn parse_imap(input: &str, options: &Options) -> Result<Zoned, Error> {
+    check_length(input, options)?;
+    let (datetime, tz) = imap::parse(&mut &*input)?;
+    datetime.to_zoned(tz).map_err(|_| Error::OutOfRange)
+}
+
+/// Parse the date of an email `Received:` header field, or the whole field
+/// value, tolerating the variants written by mail servers.
+pub(crate) fn parse_received(input: &str, options: &Options) -> Result<Zoned, Error> {
+    check_length(input, options)?;
+    let cleaned = received::clean(input);
+    let (datetime, tz) = received::parse(&mut cleaned.as_str())?;
+    datetime.to_zoned(tz).map_err(|_| Error::OutOfRange)
+}
+
+/// Parse an ISO 8601 date, time of day, date and time, duration, time
+/// interval or recurring interval, and nothing else.
+pub(crate) fn parse_iso8601(input: &str, options: &Options) -> Result<Iso8601Value, Error> {
+    check_length(input, options)?;
+    Ok(iso8601::parse(&mut &*input)?)
+}
+
+/// Parse an ISO 8601 date, time of day or date and time with optional RFC
+/// 9557 annotations, and classify it as TC39 Temporal does.
+pub(crate) fn parse_temporal(input: &str, options: &Options) -> Result<TemporalValue, Error> {
+    check_length(input, options)?;
+    iso8601::parse_temporal(&mut &*input)
+}
+
+/// Parse an ISO 8601 recurring interval, and nothing else. Dates and times
+/// without an offset are in the default timezone.
+pub(crate) fn parse_repeating_interval(
+    input: &str,
+    options: &Options,
+) -> Result<RepeatingInterval, Error> {
+    let value = parse_iso8601(input, options)?;
+    iso8601::repeating_interval(value, now(options).time_zone())
+}
+
+/// Parse the value of an HTTP `Retry-After` header field, an HTTP date or a
+/// delay in seconds, and return the date and time it stands for, in the
+/// timezone of the base date and time (or the current date and time, if no
+/// base is given).
+pub(crate) fn parse_retry_after(
+    base: Option<Zoned>,
+    input: &str,
+    options: &Options,
+) -> Result<Zoned, Error> {
+    check_length(input, options)?;
+    let value = http::parse(&mut &*input)?;
+    let base = base.unwrap_or_else(|| now(options));
+    value.resolve(&base)
+}
+
+/// Parse an NTP timestamp (e.g., "e9d7a7b8.3d4f2a10"), in the era closest to
+/// the base date and time (or the current date and time, if no base is given),
+/// and return it in the timezone of the base.
+pub(crate) fn parse_ntp(
+    base: Option<Zoned>,
+    input: &str,
+    options: &Options,
+) -> Result<Zoned, Error> {
+    check_length(input, options)?;
+    let timestamp = ntp::parse(&mut &*input)?;
+    let base = base.unwrap_or_else(|| now(options));
+    timestamp.resolve(&base)
+}
+
+/// Parse a work-log duration (e.g., "1w 2d 3h 30m") as a plain duration.
+pub(crate) fn parse_work_log(input: &str, options: &Options) -> Result<SignedDuration, Error> {
+    check_length(input, options)?;
+    let work_time = options.work_time.unwrap_or_default();
+    let lower = primitive::normalize(input).to_ascii_lowercase();
+    with_budget(&mut lower.as_str(), options, |i| {
+        work_log::duration(i, &work_time)
+    })
+    .map_err(|e| e.into())
+}
+
+/// Parse a date and time string and build a `Zoned` object, along with
+/// whether its local time was skipped or repeated by a daylight saving time
+/// transition. The parsed result is resolved against `base`, or the current
+/// local date and time if it is `None`.
+pub(crate) fn parse_with_dst<S: AsRef<str> + Clone>(
+    base: Option<Zoned>,
+    input: S,
+    options: &Options,
+) -> Result<(Zoned, DstAmbiguity), Error> {
+    let builder = parse(&mut input.as_ref(), options)?;
+    match base {
+        Some(base) => builder.set_base(base).build_with_dst(options),
+        None => builder.build_with_dst(options),
+    }
+}
+
+/// Parse a date and time string and build a `Zoned` object, along with the
+/// name of the timezone that was parsed, if any. The parsed result is resolved
+/// against `base`, or the current local date and time if it is `None`.
+pub(crate) fn parse_with_zone<S: AsRef<str> + Clone>(
+    base: Option<Zoned>,
+    input: S,
+    options: &Options,
+) -> Result<(Zoned, Option<String>), Error> {
+    let builder = parse(&mut input.as_ref(), options)?;
+    match base {
+        Some(base) => builder.set_base(base).build_with_zone(options),
+        None => builder.build_with_zone(options),
+    }
+}
+
+/// Parse a date and time string and build a `Zoned` object, along with the
+/// GNU quirks that were applied, in order. The parsed result is resolved
+/// against `base`, or the current local date and time if it is `None`.
+pub(crate) fn parse_with_quirks<S: AsRef<str> + Clone>(
+    base: Option<Zoned>,
+    input: S,
+    options: &Options,
+) -> Result<(Zoned, Vec<Quirk>), Error> {
+    let (result, quirks) = crate::quirk::collect(|| match base {
+        Some(base) => parse_at_date(base, input, options),
+        None => parse_at_local(input, options),
+    });
+    Ok((result?, quirks))
+}
+
+/// Parse a date and time string and build a [`ParsedDateTime`]. The parsed
+/// result is resolved against `base`, or the current local date and time if
+/// it is `None`.
+pub(crate) fn parse_detailed<S: AsRef<str> + Clone>(
+    base: Option<Zoned>,
+    input: S,
+    options: &Options,
+) -> Result<ParsedDateTime, Error> {
+    let builder = parse(&mut input.as_ref(), options)?;
+    match base {
+        Some(base) => builder.set_base(base).build_detailed(options),
+        None => builder.build_detailed(options),
+    }
+}
+
+/// Parse a recurring schedule, e.g., "every friday at 9am".
+pub(crate) fn parse_schedule(input: &str, options: &Options) -> Result<Schedule, Error> {
+    check_length(input, options)?;
+    let lower = primitive::normalize(input).to_ascii_lowercase();
+    with_budget(&mut lower.as_str(), options, |i| {
+        schedule::parse(i, options)
+    })
+    .map_err(|e| e.into())
+}
+
+/// Check the length of the input, in bytes, against the configured limit.
+fn check_length(input: &str, options: &Options) -> Result<(), Error> {
+    match options.max_input_length {
+        Some(max) if input.len() > max => Err(Error::InputTooLong),
+        _ => Ok(()),
+    }
+}
+
+/// Run a parser with the configured budget of steps. If the parser fails once
+/// the budget is exhausted, the cause of the error is
+/// [`Error::BudgetExceeded`].
+fn with_budget<T>(
+    input: &mut &str,
+    options: &Options,
+    parser: impl FnOnce(&mut &str) -> ModalResult<T>,
+) -> ModalResult<T> {
+    let (result, exhausted) = primitive::with_budget(options.max_steps, || parser(input));
+    match result {
+        Err(_) if exhausted => Err(ErrMode::Cut(ContextError::from_external_error(
+            input,
+            Error::BudgetExceeded,
+        ))),
+        result => result,
+    }
+}
+
+/// Give a syntax error of [`parse_items`] the span of the word at which it
+/// failed, and the words of the grammar that this word may be a misspelling
+/// of. `start` is the length of the input of [`parse_items`], and `rest` the
+/// input that remains at the start of the item that failed.
+///
+/// Errors that have a cause (e.g., an invalid date) are returned as they are.
+fn syntax_error(err: ErrMode<ContextError>, start: usize, rest: &str) -> ErrMode<ContextError> {
+    let e = match &err {
+        ErrMode::Backtrack(e) | ErrMode::Cut(e) if e.cause().is_none() => e,
+        _ => return err,
+    };
+
+    let word = rest.trim_start();
+    let word = &word[..word.find(char::is_whitespace).unwrap_or(word.len())];
+    let offset = start - rest.trim_start().len();
+    let mut p = crate::ParseError::new(error::reason(e));
+    p.span = Some(offset..offset + word.len());
+    p.suggestions = error::suggestions(word);
+    ErrMode::Cut(ContextError::from_external_error(&rest, Error::Parse(p)))
+}
+
 /// Create an error with context for unexpected input.
+///
+/// Like [`primitive::ctx_err`], this allocates, and is only called for errors
+/// that are returned to the caller.
 fn expect_error(input: &mut &str, reason: &'static str) -> ErrMode<ContextError> {
     ErrMode::Cut(ContextError::new()).add_context(
         input,
@@ -275,14 +941,31 @@ fn expect_error(input: &mut &str, reason: &'static str) -> ErrMode<ContextError>
     )
 }
 
+/// Return the civil date and time `input` in UTC, as the base date and time
+/// of a test.
+#[cfg(test)]
+fn at(input: &str) -> Zoned {
+    input
+        .parse::<jiff::civil::DateTime>()
+        .unwrap()
+        .to_zoned(jiff::tz::TimeZone::UTC)
+        .unwrap()
+}
+
 #[cfg(test)]
 mod tests {
-    use jiff::{civil::DateTime, tz::TimeZone, ToSpan, Zoned};
+    use jiff::{tz::TimeZone, ToSpan, Zoned};
+
+    use crate::{Quirk, RelativeUnit};
 
     use super::*;
 
+    fn parse(input: &mut &str) -> ModalResult<DateTimeBuilder> {
+        super::parse(input, &Options::default())
+    }
+
     fn at_date(builder: DateTimeBuilder, base: Zoned) -> Zoned {
-        builder.set_base(base).build().unwrap()
+        builder.set_base(base).build(&Options::default()).unwrap()
     }
 
     fn at_utc(builder: DateTimeBuilder) -> Zoned {
@@ -364,10 +1047,21 @@ mod tests {
     fn empty() {
         let result = parse(&mut "");
         assert!(result.is_ok());
+
+        // The empty string is the beginning of today.
+        let now = "2025-06-15T10:20:30+00:00[UTC]".parse::<Zoned>().unwrap();
+        let result = at_date(parse(&mut "  ").unwrap(), now);
+        assert_eq!(
+            result,
+            "2025-06-15T00:00:00+00:00[UTC]".parse::<Zoned>().unwrap()
+        );
     }
 
     #[test]
     fn invalid() {
+        let result = parse(&mut r#"TZ="Asia/Tokyo 10:00"#);
+        assert!(result.unwrap_err().to_string().contains("closing quote"));
+
         let result = parse(&mut "2025-05-19 2024-05-20 06:14:49");
         assert!(result.is_err());
         assert!(result
@@ -452,11 +1146,7 @@ mod tests {
     #[test]
     fn relative_weekday() {
         // Jan 1 2025 is a Wed
-        let now = "2025-01-01 00:00:00"
-            .parse::<DateTime>()
-            .unwrap()
-            .to_zoned(TimeZone::UTC)
-            .unwrap();
+        let now = at("2025-01-01 00:00:00");
 
         assert_eq!(
             at_date(parse(&mut "last wed").unwrap(), now.clone()),
@@ -497,6 +1187,853 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unicode_digits() {
+        for (input, expected) in [
+            ("٢٠٢٤-٠٥-٠١ ١٠:٣٠", "2024-05-01 10:30"),
+            ("२०२४-०५-०१", "2024-05-01 00:00"),
+            ("２０２４-０５-０１ １０:３０", "2024-05-01 10:30"),
+        ] {
+            assert_eq!(test_eq_fmt("%Y-%m-%d %H:%M", input), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn unicode_digits_in_timestamps_and_machine_input() {
+        assert_eq!(test_eq_fmt("%s", "@١٧٠٠٠٠٠٠٠٠"), "1700000000");
+        assert_eq!(test_eq_fmt("%s", "@\u{2212}１"), "-1");
+
+        let options = Options {
+            machine_input: true,
+            ..Default::default()
+        };
+        for (input, expected) in [
+            ("@١٧٠٠٠٠٠٠٠٠", "1700000000"),
+            ("２０２２-１１-１４T10:20:30Z", "1668421230"),
+        ] {
+            let result = super::parse(&mut &*input, &options).unwrap();
+            assert_eq!(
+                at_utc(result).strftime("%s").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn unicode_spaces_and_minus() {
+        for (input, expected) in [
+            ("2024-05-01\u{00A0}10:30", "2024-05-01 10:30 +00:00"),
+            ("2024-05-01\u{2007}10:30", "2024-05-01 10:30 +00:00"),
+            ("2024-05-01 \u{2212}2 days", "2024-04-29 00:00 +00:00"),
+            ("2024-05-01 10:30 \u{2212}05:00", "2024-05-01 10:30 -05:00"),
+            ("2024-05-01 10:30 utc\u{2212}5", "2024-05-01 10:30 -05:00"),
+        ] {
+            assert_eq!(
+                test_eq_fmt("%Y-%m-%d %H:%M %:z", input),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn natural_language() {
+        let options = Options {
+            natural_language: true,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("5 o'clock", "2025-06-15 05:00:00"),
+            ("tomorrow five o'clock pm", "2025-06-16 17:00:00"),
+            ("2025-07-01 nine pm", "2025-07-01 21:00:00"),
+            ("july", "2025-07-01 00:00:00"),
+            ("Dec", "2025-12-01 00:00:00"),
+            ("sept. 10:00", "2025-09-01 10:00:00"),
+            ("may +1 week", "2025-05-08 00:00:00"),
+            ("july 4", "2025-07-04 00:00:00"),
+            ("4 july", "2025-07-04 00:00:00"),
+            ("july 4, 2024", "2024-07-04 00:00:00"),
+            ("0930 hours", "2025-06-15 09:30:00"),
+            ("2025-07-01 1700 hrs", "2025-07-01 17:00:00"),
+            ("0930 hours ago", "2025-05-07 16:00:00"),
+            ("2 hours", "2025-06-15 12:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // The natural-language mode is opt-in.
+        assert!(parse_at_date(now.clone(), "5 o'clock", &Options::default()).is_err());
+        assert!(parse_at_date(now.clone(), "july", &Options::default()).is_err());
+        let result = parse_at_date(now.clone(), "0930 hours", &Options::default()).unwrap();
+        assert_eq!(result.strftime("%F %T").to_string(), "2025-07-24 04:00:00");
+        // "1700 hrs" is not 1700 hours, whether or not the military phrasing
+        // is enabled, and takes precedence over the other extensions.
+        assert!(parse_at_date(now.clone(), "1700 hrs", &Options::default()).is_err());
+        let options = Options {
+            natural_language: true,
+            compact_durations: true,
+            m_unit: Some(crate::MUnit::Minute),
+            ..Default::default()
+        };
+        let result = parse_at_date(now, "1700 hrs", &options).unwrap();
+        assert_eq!(result.strftime("%F %T").to_string(), "2025-06-15 17:00:00");
+    }
+
+    #[test]
+    fn zone_resolution() {
+        use crate::ZoneResolution;
+
+        let options = |zone_resolution| Options {
+            zone_resolution,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+        let fmt = |z: Zoned| z.strftime("%Y-%m-%d %H:%M:%S %:z").to_string();
+
+        for (resolution, input, expected) in [
+            (
+                ZoneResolution::FixedOffset,
+                "2025-07-01 10:00 est",
+                "2025-07-01 10:00:00 -05:00",
+            ),
+            (
+                ZoneResolution::Verify,
+                "2025-07-01 10:00 edt",
+                "2025-07-01 10:00:00 -04:00",
+            ),
+            (
+                ZoneResolution::Verify,
+                "2025-01-01 10:00 est",
+                "2025-01-01 10:00:00 -05:00",
+            ),
+            (
+                ZoneResolution::Verify,
+                "2025-07-01 10:00 jst",
+                "2025-07-01 10:00:00 +09:00",
+            ),
+            (
+                ZoneResolution::Compute,
+                "2025-07-01 10:00 est",
+                "2025-07-01 10:00:00 -04:00",
+            ),
+            (
+                ZoneResolution::Compute,
+                "2025-01-01 10:00 cest",
+                "2025-01-01 10:00:00 +01:00",
+            ),
+            (
+                ZoneResolution::Compute,
+                "2025-07-01 10:00 utc",
+                "2025-07-01 10:00:00 +00:00",
+            ),
+            (
+                ZoneResolution::Compute,
+                "2025-07-01 10:00 est+1",
+                "2025-07-01 10:00:00 -04:00",
+            ),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options(resolution)).unwrap();
+            assert_eq!(fmt(result), expected, "{input}");
+        }
+
+        let result = parse_at_date(
+            now.clone(),
+            "2025-07-01 10:00 est",
+            &options(ZoneResolution::Compute),
+        );
+        assert_eq!(
+            result.unwrap().time_zone().iana_name(),
+            Some("America/New_York")
+        );
+
+        for input in [
+            "2025-07-01 10:00 est",
+            "2025-01-01 10:00 cest",
+            "2025-03-09 02:30 est",
+        ] {
+            assert!(
+                parse_at_date(now.clone(), input, &options(ZoneResolution::Verify)).is_err(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn dst_resolution() {
+        use crate::DstResolution;
+
+        let options = |dst_resolution| Options {
+            dst_resolution,
+            ..Default::default()
+        };
+        let now = "2025-06-15T10:00:00-04:00[America/New_York]"
+            .parse::<Zoned>()
+            .unwrap();
+        let fmt = |z: Zoned| z.strftime("%Y-%m-%d %H:%M:%S %:z").to_string();
+
+        for (resolution, input, expected) in [
+            // 02:30 does not exist on 2025-03-09.
+            (
+                DstResolution::Shift,
+                "2025-03-09 02:30",
+                "2025-03-09 03:30:00 -04:00",
+            ),
+            (
+                DstResolution::Earliest,
+                "2025-03-09 02:30",
+                "2025-03-09 01:30:00 -05:00",
+            ),
+            (
+                DstResolution::Latest,
+                "2025-03-09 02:30",
+                "2025-03-09 03:30:00 -04:00",
+            ),
+            // 01:30 exists twice on 2025-11-02.
+            (
+                DstResolution::Shift,
+                "2025-11-02 01:30",
+                "2025-11-02 01:30:00 -04:00",
+            ),
+            (
+                DstResolution::Earliest,
+                "2025-11-02 01:30",
+                "2025-11-02 01:30:00 -04:00",
+            ),
+            (
+                DstResolution::Latest,
+                "2025-11-02 01:30",
+                "2025-11-02 01:30:00 -05:00",
+            ),
+            (
+                DstResolution::Latest,
+                "2025-11-01 01:30 +1 day",
+                "2025-11-02 01:30:00 -05:00",
+            ),
+            (
+                DstResolution::Error,
+                "2025-11-02 03:30",
+                "2025-11-02 03:30:00 -05:00",
+            ),
+            // A fixed offset is never ambiguous.
+            (
+                DstResolution::Error,
+                "2025-11-02 01:30 -05:00",
+                "2025-11-02 01:30:00 -05:00",
+            ),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options(resolution)).unwrap();
+            assert_eq!(fmt(result), expected, "{resolution:?} {input}");
+        }
+
+        for input in [
+            "2025-03-09 02:30",
+            "2025-11-02 01:30",
+            "2025-11-01 01:30 tomorrow",
+            "2025-03-08 02:30 +1 day",
+        ] {
+            assert!(
+                parse_at_date(now.clone(), input, &options(DstResolution::Error)).is_err(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn dst_ambiguity() {
+        use crate::{DstAmbiguity, DstResolution};
+
+        let now = "2025-06-15T10:00:00-04:00[America/New_York]"
+            .parse::<Zoned>()
+            .unwrap();
+        let fmt = |z: &Zoned| z.strftime("%Y-%m-%d %H:%M:%S %:z").to_string();
+        let parse = |input, dst_resolution| {
+            let options = Options {
+                dst_resolution,
+                ..Default::default()
+            };
+            let (dt, dst) = parse_with_dst(Some(now.clone()), input, &options).unwrap();
+            let dst = match dst {
+                DstAmbiguity::None => None,
+                DstAmbiguity::Skipped { alternative } => Some(("skipped", fmt(&alternative))),
+                DstAmbiguity::Repeated { alternative } => Some(("repeated", fmt(&alternative))),
+            };
+            (fmt(&dt), dst)
+        };
+
+        for (input, resolution, expected, ambiguity) in [
+            (
+                "2025-07-01 10:00",
+                DstResolution::Shift,
+                "2025-07-01 10:00:00 -04:00",
+                None,
+            ),
+            (
+                "2025-03-09 02:30",
+                DstResolution::Shift,
+                "2025-03-09 03:30:00 -04:00",
+                Some(("skipped", "2025-03-09 01:30:00 -05:00")),
+            ),
+            (
+                "2025-03-09 02:30",
+                DstResolution::Earliest,
+                "2025-03-09 01:30:00 -05:00",
+                Some(("skipped", "2025-03-09 03:30:00 -04:00")),
+            ),
+            (
+                "2025-11-02 01:30",
+                DstResolution::Shift,
+                "2025-11-02 01:30:00 -04:00",
+                Some(("repeated", "2025-11-02 01:30:00 -05:00")),
+            ),
+            (
+                "2025-11-02 01:30",
+                DstResolution::Latest,
+                "2025-11-02 01:30:00 -05:00",
+                Some(("repeated", "2025-11-02 01:30:00 -04:00")),
+            ),
+            (
+                "2025-11-01 01:30 +1 day",
+                DstResolution::Shift,
+                "2025-11-02 01:30:00 -04:00",
+                Some(("repeated", "2025-11-02 01:30:00 -05:00")),
+            ),
+            // Hours move the alternative along with the result.
+            (
+                "2025-11-02 01:30 +2 hours",
+                DstResolution::Shift,
+                "2025-11-02 02:30:00 -05:00",
+                Some(("repeated", "2025-11-02 03:30:00 -05:00")),
+            ),
+            // A later date replaces an ambiguous one.
+            (
+                "2025-11-01 01:30 +2 days",
+                DstResolution::Shift,
+                "2025-11-03 01:30:00 -05:00",
+                None,
+            ),
+            (
+                "2025-11-02 01:30 -05:00",
+                DstResolution::Shift,
+                "2025-11-02 01:30:00 -05:00",
+                None,
+            ),
+        ] {
+            let ambiguity = ambiguity.map(|(kind, alt)| (kind, alt.to_string()));
+            assert_eq!(
+                parse(input, resolution),
+                (expected.to_string(), ambiguity),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn zone_name() {
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("2025-01-01 10:00", None),
+            ("2025-01-01 10:00 EST", Some("EST")),
+            ("2025-01-01 10:00 utc", Some("UTC")),
+            ("2025-01-01 10:00 +05:00", None),
+            // A corrected timezone is a numeric offset.
+            ("2025-01-01 10:00 utc+1", None),
+            (r#"TZ="Asia/Tokyo" 2025-01-01 10:00"#, Some("JST")),
+            (r#"TZ="America/New_York" 2025-07-01"#, Some("EDT")),
+            (r#"TZ="EST5EDT,M3.2.0,M11.1.0" 2025-01-01"#, Some("EST")),
+            (r#"TZ="UTC+5" 2025-01-01 10:00"#, Some("UTC")),
+            (r#"TZ=":<+0530>-5:30""#, Some("+0530")),
+            // The timezone item takes precedence over the rule.
+            (r#"TZ="Asia/Tokyo" 2025-01-01 10:00 cet"#, Some("CET")),
+            (r#"TZ="Asia/Tokyo" 2025-01-01 10:00+01:00"#, None),
+            (r#"TZ="Asia/Tokyo" @1700000000"#, None),
+        ] {
+            let (_, name) = parse_with_zone(Some(now.clone()), input, &Options::default()).unwrap();
+            assert_eq!(name.as_deref(), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn rfc2822_zones() {
+        let options = Options {
+            rfc2822_zones: true,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, unknown) in [
+            ("Mon, 14 Nov 2022 10:00:00 UT", false),
+            ("Mon, 14 Nov 2022 10:00:00 GMT", false),
+            ("Mon, 14 Nov 2022 10:00:00 Z", false),
+            ("Mon, 14 Nov 2022 10:00:00 +0000", false),
+            ("Mon, 14 Nov 2022 10:00:00 -0000", true),
+            ("Mon, 14 Nov 2022 10:00:00 A", true),
+            ("Mon, 14 Nov 2022 10:00:00 N", true),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+                "2022-11-14 10:00:00 +00:00",
+                "{input}"
+            );
+            assert_eq!(result.time_zone().is_unknown(), unknown, "{input}");
+        }
+
+        // Otherwise, "-0000" is UTC and military zones have their own offset.
+        for (input, expected) in [
+            (
+                "Mon, 14 Nov 2022 10:00:00 -0000",
+                "2022-11-14 10:00:00 +00:00",
+            ),
+            ("Mon, 14 Nov 2022 10:00:00 A", "2022-11-14 10:00:00 +01:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+                expected,
+                "{input}"
+            );
+            assert!(!result.time_zone().is_unknown(), "{input}");
+        }
+    }
+
+    #[test]
+    fn comments() {
+        let now = at("2025-06-15 10:00:00");
+
+        for input in [
+            "Mon, 14 Nov 2022 10:00:00 -0500 (EST)",
+            "Mon(day), 14 Nov(ember) 2022 10:00:00(EST)-0500",
+            "(sent) Mon, (the) 14 Nov 2022 (at) 10:00:00 -0500",
+            "Mon, 14 Nov 2022 10:00:00 -0500 (Eastern (US) Standard Time)",
+            "Mon, 14 Nov 2022 10:00:00 -0500 (a \\) b)",
+            "Mon, 14 Nov 2022 10:00:00 -0500 (C:\\)",
+            "Mon, 14 Nov 2022\r\n (folded) 10:00:00 -0500",
+            "Mon, 14 Nov 2022 10:(c)00:00 -0500",
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result
+                    .with_time_zone(TimeZone::UTC)
+                    .strftime("%Y-%m-%d %H:%M:%S")
+                    .to_string(),
+                "2022-11-14 15:00:00",
+                "{input}"
+            );
+        }
+
+        for input in [
+            "Mon, 14 Nov 2022 10:00:00 -0500 (EST",
+            "Mon, 14 Nov 2022 10:00:00 -0500 EST)",
+        ] {
+            assert!(
+                parse_at_date(now.clone(), input, &Options::default()).is_err(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn tai64n() {
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("@4000000065a1c2ea075bcd15", "2024-01-12 22:53:20.123456789"),
+            ("@4000000065a1c2ea", "2024-01-12 22:53:20.0"),
+            (
+                "TZ=\"Europe/Paris\" @4000000065a1c2ea075bcd15",
+                "2024-01-12 22:53:20.123456789",
+            ),
+            // A plain epoch timestamp is not a label.
+            ("@4000000000000000ns", "1970-02-16 07:06:40.0"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result
+                    .with_time_zone(TimeZone::UTC)
+                    .strftime("%Y-%m-%d %H:%M:%S.%f")
+                    .to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        for input in [
+            "@4000000065a1c2ea075bcd15 +1 day",
+            "@4000000065a1c2ea075bcd1",
+            "@4000000065a1c2ea075bcd15 message",
+        ] {
+            assert!(
+                parse_at_date(now.clone(), input, &Options::default()).is_err(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn infer_recent_year() {
+        let options = Options {
+            infer_recent_year: true,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("Mar  3 12:00", "2025-03-03 12:00:00"),
+            ("Mar  3  2023", "2023-03-03 00:00:00"),
+            ("Dec 24 12:00", "2024-12-24 12:00:00"),
+            ("Jun 15 09:59", "2025-06-15 09:59:00"),
+            ("Jun 15 10:01", "2024-06-15 10:01:00"),
+            ("Jun 15", "2025-06-15 00:00:00"),
+            ("Feb 29 12:00", "2024-02-29 12:00:00"),
+            ("Jun 15 10:30 +0100", "2025-06-15 09:30:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result
+                    .with_time_zone(TimeZone::UTC)
+                    .strftime("%Y-%m-%d %H:%M:%S")
+                    .to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // Otherwise, the year of the base date is used.
+        let result = parse_at_date(now, "Dec 24 12:00", &Options::default()).unwrap();
+        assert_eq!(result.strftime("%Y-%m-%d").to_string(), "2025-12-24");
+    }
+
+    #[test]
+    fn limits() {
+        let now = Zoned::now();
+        let options = Options {
+            max_input_length: Some(20),
+            max_items: Some(3),
+            ..Default::default()
+        };
+
+        for input in ["", "06/15 10:00 +1 day", "   tomorrow   ", "@1700000000"] {
+            assert!(
+                parse_at_date(now.clone(), input, &options).is_ok(),
+                "{input}"
+            );
+        }
+
+        for input in ["2025-06-15 10:00:00 +1 day", "@1700000000          "] {
+            assert!(
+                matches!(
+                    parse_at_date(now.clone(), input, &options),
+                    Err(Error::InputTooLong)
+                ),
+                "{input}"
+            );
+        }
+        assert!(matches!(
+            parse_schedule("every day at 10:00 and 11:00", &options),
+            Err(Error::InputTooLong)
+        ));
+
+        // The input is rejected at the fourth item, even if it is invalid.
+        for input in ["1h1m 1h1m 1h1m 1h1m", "1h1m 1h1m 1h1m ???"] {
+            assert!(
+                matches!(
+                    parse_at_date(now.clone(), input, &options),
+                    Err(Error::TooManyItems)
+                ),
+                "{input}"
+            );
+        }
+
+        let options = Options {
+            max_steps: Some(1_000),
+            ..Default::default()
+        };
+        assert!(parse_at_date(now.clone(), "2025-06-15 10:00 +1 day", &options).is_ok());
+        let input = format!("{}{}", "(".repeat(500), ")".repeat(500));
+        assert!(matches!(
+            parse_at_date(now.clone(), input, &options),
+            Err(Error::BudgetExceeded)
+        ));
+        assert!(matches!(
+            parse_schedule(&format!("every day {}", "(x) ".repeat(500)), &options),
+            Err(Error::BudgetExceeded)
+        ));
+    }
+
+    #[test]
+    fn negative_ago() {
+        let now = at("2025-06-15 10:00:00");
+
+        // As in GNU, "ago" negates the whole item, including a negative count.
+        for (input, expected) in [
+            ("-2 days ago", "2025-06-17 10:00:00.0"),
+            ("- 2 days ago", "2025-06-17 10:00:00.0"),
+            ("+2 days ago", "2025-06-13 10:00:00.0"),
+            ("-1 week ago", "2025-06-22 10:00:00.0"),
+            ("-3 hours ago", "2025-06-15 13:00:00.0"),
+            ("last day ago", "2025-06-16 10:00:00.0"),
+            ("-2 days ago -1 day", "2025-06-16 10:00:00.0"),
+            ("-1.5 seconds ago", "2025-06-15 10:00:01.5"),
+            ("1.5 seconds ago", "2025-06-15 09:59:58.5"),
+            ("-1.5 seconds", "2025-06-15 09:59:58.5"),
+            ("-2 workdays ago", "2025-06-17 10:00:00.0"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S%.1f").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        assert!(parse_at_date(now, "-2 days ago ago", &Options::default()).is_err());
+    }
+
+    #[test]
+    fn strict_meridiem() {
+        let options = Options {
+            strict_meridiem: true,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("noon", "2025-06-15 12:00:00"),
+            ("tomorrow midnight", "2025-06-16 00:00:00"),
+            ("12:30pm", "2025-06-15 12:30:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        for input in ["12am", "tomorrow 12 pm", "12:00 a.m."] {
+            assert!(
+                parse_at_date(now.clone(), input, &options).is_err(),
+                "{input}"
+            );
+            assert!(parse_at_date(now.clone(), input, &Options::default()).is_ok());
+        }
+    }
+
+    #[test]
+    fn weekday_with_date() {
+        let now = at("2025-06-15 10:00:00");
+        let strict = Options {
+            strict_weekday: true,
+            ..Default::default()
+        };
+
+        // As in GNU date, a weekday given with a date is ignored.
+        for (input, expected) in [
+            ("wednesday 2024-07-17", "2024-07-17 00:00:00"),
+            ("tuesday 2024-07-17", "2024-07-17 00:00:00"),
+            ("next tuesday 2024-07-17 10:00", "2024-07-17 10:00:00"),
+            ("Tue, 17 Jul 2024 10:00:00 +0000", "2024-07-17 10:00:00"),
+            ("fri jul 17", "2025-07-17 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // A weekday alone moves to that day.
+        for (input, expected) in [
+            ("tuesday", "2025-06-17 00:00:00"),
+            ("next sunday 10:00", "2025-06-22 10:00:00"),
+            ("2 days ago friday", "2025-06-18 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // A weekday is ignored with a date of another kind as well.
+        let options = |strict_weekday| Options {
+            fiscal_year_start: Some(10),
+            holidays: Some(Box::new(crate::Holidays::builtin())),
+            strict_weekday,
+            ..Default::default()
+        };
+        for (input, expected) in [
+            ("friday first monday of march 2025", "2025-03-03 00:00:00"),
+            ("friday christmas 2025", "2025-12-25 00:00:00"),
+            ("monday fy2025 q2", "2025-01-01 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options(false)).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+            assert!(
+                parse_at_date(now.clone(), input, &options(true)).is_err(),
+                "{input}"
+            );
+        }
+        assert!(parse_at_date(now.clone(), "thursday christmas 2025", &options(true)).is_ok());
+
+        for input in [
+            "wednesday 2024-07-17",
+            "thu jul 17",
+            "2024-07-17 +1 day wed",
+        ] {
+            assert!(
+                parse_at_date(now.clone(), input, &strict).is_ok(),
+                "{input}"
+            );
+        }
+        for input in ["tuesday 2024-07-17", "Tue, 17 Jul 2024 10:00:00 +0000"] {
+            assert!(
+                parse_at_date(now.clone(), input, &strict).is_err(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn weekday_with_week() {
+        // A Thursday.
+        let now = at("2026-10-15 10:00:00");
+
+        // The weekday is resolved first, and the week is added to it, so that
+        // "monday week" is the monday after next, as in GNU date.
+        for (input, expected) in [
+            ("monday week", "2026-10-26 00:00:00"),
+            ("week monday", "2026-10-26 00:00:00"),
+            ("next week monday", "2026-10-26 00:00:00"),
+            ("monday next week", "2026-10-26 00:00:00"),
+            ("next monday week", "2026-10-26 00:00:00"),
+            ("thursday week", "2026-10-22 00:00:00"),
+            ("monday 2 weeks", "2026-11-02 00:00:00"),
+            ("monday fortnight", "2026-11-02 00:00:00"),
+            ("last week monday", "2026-10-12 00:00:00"),
+            ("monday last week", "2026-10-12 00:00:00"),
+            ("monday week ago", "2026-10-12 00:00:00"),
+            ("monday week 9am", "2026-10-26 09:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn ordinal_words() {
+        // A Thursday.
+        let now = at("2026-10-15 10:00:00");
+
+        for (input, expected) in [
+            ("third wednesday", "2026-11-04 00:00:00"),
+            ("ninth fri", "2026-12-11 00:00:00"),
+            ("this thursday", "2026-10-15 00:00:00"),
+            ("fifth day", "2026-10-20 10:00:00"),
+            ("eighth day", "2026-10-23 10:00:00"),
+            ("twelfth hour", "2026-10-15 22:00:00"),
+            ("last day", "2026-10-14 10:00:00"),
+            ("first month", "2026-11-15 10:00:00"),
+            ("eleventh week ago", "2026-07-30 10:00:00"),
+            ("twelfth year", "2038-10-15 10:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        assert!(parse_at_date(now, "eight day", &Options::default()).is_err());
+    }
+
+    #[test]
+    fn locale() {
+        let options = Options {
+            locale: Some(crate::Locale::Japanese),
+            ..Default::default()
+        };
+        let now = Zoned::now().with_time_zone(TimeZone::UTC);
+
+        let result = parse_at_date(now.clone(), "2024-05-01 午後3:30", &options).unwrap();
+        assert_eq!(
+            result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+            "2024-05-01 15:30:00"
+        );
+
+        // Locales are opt-in.
+        assert!(parse_at_date(now, "2024-05-01 午後3:30", &Options::default()).is_err());
+    }
+
+    #[test]
+    fn business_days() {
+        let fmt = |input: &str, options: &Options| {
+            // Wednesday
+            let now = at("2025-12-24 10:00:00");
+            parse_at_date(now, input, options)
+                .unwrap()
+                .strftime("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        };
+
+        let weekends = Options::default();
+        for (input, expected) in [
+            ("0 business days", "2025-12-24 10:00:00"),
+            ("+1 business day", "2025-12-25 10:00:00"),
+            ("3 business days", "2025-12-29 10:00:00"),
+            ("2 workdays ago", "2025-12-22 10:00:00"),
+            ("-3 working days", "2025-12-19 10:00:00"),
+            ("2025-12-27 next business day", "2025-12-29 00:00:00"),
+            ("2025-12-27 business day ago", "2025-12-26 00:00:00"),
+        ] {
+            assert_eq!(fmt(input, &weekends), expected, "{input}");
+        }
+
+        let holidays = Options {
+            business_calendar: Some(Box::new(crate::Holidays::builtin())),
+            ..Default::default()
+        };
+        for (input, expected) in [
+            ("+1 business day", "2025-12-26 10:00:00"),
+            ("3 business days", "2025-12-30 10:00:00"),
+            ("6 business days", "2026-01-06 10:00:00"),
+            ("2025-12-26 business day ago", "2025-12-23 00:00:00"),
+            // Weekdays skip weekends, but not holidays.
+            ("+1 weekday", "2025-12-25 10:00:00"),
+            ("3 weekdays", "2025-12-29 10:00:00"),
+        ] {
+            assert_eq!(fmt(input, &holidays), expected, "{input}");
+        }
+        for (input, expected) in [
+            ("+5 weekdays", "2025-12-31 10:00:00"),
+            ("weekday ago", "2025-12-23 10:00:00"),
+            ("2025-12-27 next weekday", "2025-12-29 00:00:00"),
+            ("2025-12-27 -1 weekday", "2025-12-26 00:00:00"),
+        ] {
+            assert_eq!(fmt(input, &weekends), expected, "{input}");
+        }
+    }
+
     #[test]
     fn relative_date_time() {
         let now = Zoned::now().with_time_zone(TimeZone::UTC);
@@ -558,7 +2095,7 @@ mod tests {
 
     #[test]
     fn timezone_rule() {
-        let parse_build = |mut s| parse(&mut s).unwrap().build().unwrap();
+        let parse_build = |mut s| parse(&mut s).unwrap().build(&Options::default()).unwrap();
 
         for (input, expected) in [
             (
@@ -572,5 +2109,494 @@ mod tests {
         ] {
             assert_eq!(parse_build(input), expected, "{input}");
         }
+
+        // The timezone rule applies to the base date, and an empty remainder
+        // is the beginning of today in that timezone.
+        let now = "2025-06-15T20:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+        for (input, expected) in [
+            (r#"TZ="""#, "2025-06-15T00:00:00+00:00[UTC]"),
+            (
+                r#"TZ="Asia/Tokyo""#,
+                "2025-06-16T00:00:00+09:00[Asia/Tokyo]",
+            ),
+            (
+                r#" TZ="Asia/Tokyo"  "#,
+                "2025-06-16T00:00:00+09:00[Asia/Tokyo]",
+            ),
+            (
+                r#"TZ="Asia/Tokyo" now"#,
+                "2025-06-16T05:00:00+09:00[Asia/Tokyo]",
+            ),
+            (
+                r#"TZ="Asia/Tokyo" +1 hour"#,
+                "2025-06-16T06:00:00+09:00[Asia/Tokyo]",
+            ),
+            (
+                r#"TZ="Asia/Tokyo" 2025-01-02"#,
+                "2025-01-02T00:00:00+09:00[Asia/Tokyo]",
+            ),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(result, expected.parse::<Zoned>().unwrap(), "{input}");
+        }
+    }
+
+    #[test]
+    fn relative_units() {
+        let now = at("2025-06-15 10:00:00");
+        let sprint = |dt: &Zoned, n: i32| dt.checked_add((2 * i64::from(n)).weeks()).ok();
+        let quarter = |dt: &Zoned, n: i32| dt.checked_add((3 * i64::from(n)).months()).ok();
+        let units: Vec<(String, Box<dyn RelativeUnit>)> = vec![
+            ("sprint".to_owned(), Box::new(sprint)),
+            ("quarter".to_owned(), Box::new(quarter)),
+            ("never".to_owned(), Box::new(|_: &Zoned, _: i32| None)),
+        ];
+        let options = Options {
+            relative_units: units,
+            ..Default::default()
+        };
+
+        for (input, expected) in [
+            ("next sprint", "2025-06-29 10:00:00"),
+            ("2 Sprints ago", "2025-05-18 10:00:00"),
+            ("2025-01-01 +1 sprint 9am", "2025-01-15 09:00:00"),
+            ("quarter", "2025-09-15 10:00:00"),
+            ("1 quarter ago", "2025-03-15 10:00:00"),
+            // Relative items are applied in order.
+            ("2025-03-31 -1 day 1 quarter", "2025-06-30 00:00:00"),
+            ("2025-03-31 1 quarter -1 day", "2025-06-29 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        assert!(parse_at_date(now.clone(), "never", &options).is_err());
+        assert!(parse_at_date(now.clone(), "2 sprints", &Options::default()).is_err());
+    }
+
+    #[test]
+    fn resolution_hook() {
+        use std::sync::{Arc, Mutex};
+
+        use crate::ParseDateTimeError;
+
+        let now = at("2025-06-15 10:00:00");
+
+        let latest = now.checked_add(7.days()).unwrap();
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let options = Options {
+            resolution_hook: Some(Box::new({
+                let log = log.clone();
+                move |spec| {
+                    log.lock().unwrap().push(format!("{spec:?}"));
+                    if spec.precision().offset {
+                        return Err(ParseDateTimeError::Rejected);
+                    }
+                    // Resolve times of day against noon, and clamp the results
+                    // to the next seven days.
+                    let base = spec.base().with().hour(12).build().unwrap();
+                    Ok(spec.with_base(base).resolve()?.min(latest.clone()))
+                }
+            })),
+            ..Default::default()
+        };
+
+        for (input, expected) in [
+            ("+1 hour", "2025-06-15 13:00:00"),
+            ("tomorrow", "2025-06-16 12:00:00"),
+            ("next month", "2025-06-22 10:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+        assert_eq!(log.lock().unwrap().len(), 3);
+        assert!(log.lock().unwrap()[0].starts_with("Specification(DateTimeBuilder"));
+
+        assert!(matches!(
+            parse_at_date(now.clone(), "10:00 +02:00", &options),
+            Err(Error::Rejected)
+        ));
+        assert!(matches!(
+            parse_at_date(now.clone(), "9999-12-31 +1 year", &options),
+            Err(Error::OutOfRange)
+        ));
+        // The hook is not called for inputs that cannot be parsed.
+        assert!(parse_at_date(now.clone(), "foo", &options).is_err());
+        assert_eq!(log.lock().unwrap().len(), 5);
+
+        // The base passed to the hook is in the timezone of the TZ rule.
+        let options = Options {
+            resolution_hook: Some(Box::new(|spec| {
+                assert_eq!(spec.base().time_zone().iana_name(), Some("Asia/Tokyo"));
+                spec.resolve()
+            })),
+            ..Default::default()
+        };
+        let result = parse_at_date(now.clone(), r#"TZ="Asia/Tokyo" 10:00"#, &options).unwrap();
+        assert_eq!(
+            result.strftime("%F %T %:z").to_string(),
+            "2025-06-15 10:00:00 +09:00"
+        );
+    }
+
+    #[test]
+    fn quirks() {
+        use std::sync::{Arc, Mutex};
+
+        let now = at("2025-01-31 10:00:00");
+        let quirks = Arc::new(Mutex::new(Vec::new()));
+        let options = Options {
+            quirk_callback: Some(Box::new({
+                let quirks = quirks.clone();
+                move |quirk| quirks.lock().unwrap().push(quirk)
+            })),
+            ..Default::default()
+        };
+
+        for (input, expected, expected_quirks) in [
+            (
+                "+1 month",
+                "2025-03-03 10:00:00 +00:00",
+                vec![Quirk::MonthOverflow],
+            ),
+            (
+                "+1 month -1 month",
+                "2025-01-31 10:00:00 +00:00",
+                vec![Quirk::MonthOverflow; 2],
+            ),
+            ("2025-06-15 +1 month", "2025-07-15 00:00:00 +00:00", vec![]),
+            (
+                "12:34:56 m+24",
+                "2025-01-30 23:34:56 +23:00",
+                vec![Quirk::OffsetClamped],
+            ),
+            (
+                r#"TZ="UTC+30" 10:00"#,
+                "2025-01-30 10:00:00 -24:00",
+                vec![Quirk::OffsetClamped],
+            ),
+            (
+                r#"TZ="Unknown/Zone" 10:00"#,
+                "2025-01-31 10:00:00 +00:00",
+                vec![Quirk::UnknownTimeZone],
+            ),
+            (
+                r#"TZ="Asia/Tokyo" 10:00"#,
+                "2025-01-31 10:00:00 +09:00",
+                vec![],
+            ),
+            (
+                "98-01-01",
+                "1998-01-01 00:00:00 +00:00",
+                vec![Quirk::TwoDigitYear],
+            ),
+            (
+                "12/25/24 10:00",
+                "2024-12-25 10:00:00 +00:00",
+                vec![Quirk::TwoDigitYear],
+            ),
+            (
+                "jan 5 10:00 98",
+                "1998-01-05 10:00:00 +00:00",
+                vec![Quirk::TwoDigitYear],
+            ),
+            ("1998-01-01", "1998-01-01 00:00:00 +00:00", vec![]),
+            ("098-01-01", "0098-01-01 00:00:00 +00:00", vec![]),
+        ] {
+            quirks.lock().unwrap().clear();
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+                expected,
+                "{input}"
+            );
+            assert_eq!(*quirks.lock().unwrap(), expected_quirks, "{input}");
+        }
+    }
+
+    #[test]
+    fn parse_with_quirks() {
+        let now = at("2025-01-31 10:00:00");
+        let options = Options::default();
+
+        let (result, quirks) =
+            super::parse_with_quirks(Some(now.clone()), "98-01-31 +1 month", &options).unwrap();
+        assert_eq!(result.strftime("%F").to_string(), "1998-03-03");
+        assert_eq!(quirks, [Quirk::TwoDigitYear, Quirk::MonthOverflow]);
+
+        let (_, quirks) =
+            super::parse_with_quirks(Some(now.clone()), "2025-06-15", &options).unwrap();
+        assert_eq!(quirks, []);
+
+        // Quirks are collected per call, and not kept after an error.
+        assert!(super::parse_with_quirks(Some(now.clone()), "98-02-30", &options).is_err());
+        let (_, quirks) = super::parse_with_quirks(Some(now), "+1 day", &options).unwrap();
+        assert_eq!(quirks, []);
+    }
+
+    #[test]
+    fn month_arithmetic() {
+        let options = Options {
+            month_arithmetic: crate::MonthArithmetic::ClampToMonthEnd,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("2024-01-31 +1 month", "2024-02-29"),
+            ("2025-01-31 +1 month", "2025-02-28"),
+            ("2025-03-31 -1 month", "2025-02-28"),
+            ("2025-01-31 +3 months", "2025-04-30"),
+            ("2025-01-31 +1 month +1 month", "2025-03-28"),
+            ("2025-01-15 +1 month", "2025-02-15"),
+            ("2025-01-31 +1 month 2 days", "2025-03-02"),
+            ("2024-02-29 +1 year", "2025-02-28"),
+            ("2024-02-29 -4 years", "2020-02-29"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(result.strftime("%F").to_string(), expected, "{input}");
+        }
+
+        // Months overshoot by default, and years clamp February 29th.
+        for (input, expected) in [
+            ("2024-01-31 +1 month", "2024-03-02"),
+            ("2024-02-29 +1 year", "2025-02-28"),
+            ("2024-02-29 -1 year", "2023-02-28"),
+            ("2024-02-29 +4 years", "2028-02-29"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(result.strftime("%F").to_string(), expected, "{input}");
+        }
+
+        let options = Options {
+            month_arithmetic: crate::MonthArithmetic::Overflow,
+            ..Default::default()
+        };
+        for (input, expected) in [
+            ("2024-01-31 +1 month", "2024-03-02"),
+            ("2025-01-31 +1 month", "2025-03-03"),
+            ("2022-11-14 -1 month", "2022-10-14"),
+            ("2022-03-31 -1 month", "2022-03-03"),
+            ("2024-02-29 +1 year", "2025-03-01"),
+            ("2024-02-29 -1 year", "2023-03-01"),
+            ("2024-02-29 +4 years", "2028-02-29"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(result.strftime("%F").to_string(), expected, "{input}");
+        }
+
+        let options = Options {
+            month_arithmetic: crate::MonthArithmetic::Error,
+            ..Default::default()
+        };
+        for input in [
+            "2025-01-31 +1 month",
+            "2025-03-31 -1 month",
+            "2024-02-29 +1 year",
+        ] {
+            assert!(
+                matches!(
+                    parse_at_date(now.clone(), input, &options),
+                    Err(Error::Resolve(_))
+                ),
+                "{input}"
+            );
+        }
+        for (input, expected) in [
+            ("2025-01-15 +1 month", "2025-02-15"),
+            ("2025-01-31 +2 months", "2025-03-31"),
+            ("2024-02-29 +4 years", "2028-02-29"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(result.strftime("%F").to_string(), expected, "{input}");
+        }
+
+        // February 29th without a year, in a common year.
+        let clamp = Options {
+            month_arithmetic: crate::MonthArithmetic::ClampToMonthEnd,
+            ..Default::default()
+        };
+        let result = parse_at_date(now.clone(), "feb 29", &clamp).unwrap();
+        assert_eq!(result.strftime("%F").to_string(), "2025-02-28");
+        let result = parse_at_date(now.clone(), "feb 29 +1 day", &clamp).unwrap();
+        assert_eq!(result.strftime("%F").to_string(), "2025-03-01");
+        assert!(parse_at_date(now.clone(), "feb 30", &clamp).is_err());
+        for options in [&options, &Options::default()] {
+            match parse_at_date(now.clone(), "feb 29", options) {
+                Err(Error::Resolve(reason)) => assert!(reason.contains("February 29th")),
+                result => panic!("unexpected result: {result:?}"),
+            }
+        }
+        let leap_year = now.with().year(2024).build().unwrap();
+        let result = parse_at_date(leap_year, "feb 29", &options).unwrap();
+        assert_eq!(result.strftime("%F").to_string(), "2024-02-29");
+    }
+
+    #[test]
+    fn out_of_range() {
+        let now = at("2025-06-15 10:00:00");
+
+        for input in [
+            "@99999999999999",
+            "@-99999999999999",
+            "@99999999999999999999",
+            "@-99999999999999999999.5",
+            "@1000000000000000000000000000000000000000000",
+            "9999-12-31 +1 day",
+            "-20000 years",
+            "9999-12-01 +1 month",
+            "+2147483647 months",
+            "+100000000 hours",
+            "9999-12-01 +30 business days",
+        ] {
+            assert!(
+                matches!(
+                    parse_at_date(now.clone(), input, &Options::default()),
+                    Err(Error::OutOfRange)
+                ),
+                "{input}"
+            );
+        }
+
+        let options = Options {
+            saturate: true,
+            ..Default::default()
+        };
+        for (input, expected) in [
+            ("@99999999999999", "9999-12-30 22:00:00.999999999"),
+            ("@-99999999999999", "-9999-01-02 01:59:59.000000000"),
+            ("@99999999999999999999", "9999-12-30 22:00:00.999999999"),
+            ("@-99999999999999999999ms", "-9999-01-02 01:59:59.000000000"),
+            ("9999-12-01 +1 month", "9999-12-30 22:00:00.999999999"),
+            ("-20000 years", "-9999-01-02 01:59:59.000000000"),
+            (
+                "9999-12-01 +1 month -1 day",
+                "9999-12-29 22:00:00.999999999",
+            ),
+            ("@1700000000", "2023-11-14 22:13:20.000000000"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S.%N").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn truncate() {
+        let now = at("2025-06-15 10:20:30.5");
+        let options = Options {
+            truncate: true,
+            ..Default::default()
+        };
+
+        for (input, expected) in [
+            ("10am", "2025-06-15 10:00:00.000"),
+            ("10:45:15.25", "2025-06-15 10:45:15.250"),
+            ("+2 hours", "2025-06-15 12:00:00.000"),
+            ("+5 minutes", "2025-06-15 10:25:00.000"),
+            ("+1 second", "2025-06-15 10:20:31.000"),
+            ("tomorrow", "2025-06-16 00:00:00.000"),
+            ("+1 month", "2025-07-01 00:00:00.000"),
+            ("next year", "2026-01-01 00:00:00.000"),
+            ("2025-07-04 +3 hours", "2025-07-04 03:00:00.000"),
+            ("2024-07", "2024-07-01 00:00:00.000"),
+            ("now", "2025-06-15 10:20:30.500"),
+            ("", "2025-06-15 00:00:00.000"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S%.3f").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        let options = Options {
+            truncate: true,
+            period_start: true,
+            ..Default::default()
+        };
+        let result = parse_at_date(now.clone(), "next week", &options).unwrap();
+        assert_eq!(result.strftime("%F %T").to_string(), "2025-06-16 00:00:00");
+    }
+
+    #[test]
+    fn round() {
+        use jiff::{RoundMode, Unit, ZonedRound};
+
+        let now = at("2025-06-15 10:20:30.5");
+        let round = |unit, increment, mode| Options {
+            round: Some(
+                ZonedRound::new()
+                    .smallest(unit)
+                    .increment(increment)
+                    .mode(mode),
+            ),
+            ..Default::default()
+        };
+
+        for (options, input, expected) in [
+            (
+                round(Unit::Minute, 1, RoundMode::HalfExpand),
+                "now",
+                "2025-06-15 10:21:00",
+            ),
+            (
+                round(Unit::Minute, 15, RoundMode::HalfExpand),
+                "+1 hour",
+                "2025-06-15 11:15:00",
+            ),
+            (
+                round(Unit::Hour, 1, RoundMode::Floor),
+                "+1 hour",
+                "2025-06-15 11:00:00",
+            ),
+            (
+                round(Unit::Hour, 1, RoundMode::Ceil),
+                "now",
+                "2025-06-15 11:00:00",
+            ),
+            (
+                round(Unit::Day, 1, RoundMode::HalfExpand),
+                "18:00",
+                "2025-06-16 00:00:00",
+            ),
+            (
+                round(Unit::Day, 1, RoundMode::Floor),
+                "18:00",
+                "2025-06-15 00:00:00",
+            ),
+            (
+                round(Unit::Second, 1, RoundMode::Trunc),
+                "10:20:30.75",
+                "2025-06-15 10:20:30",
+            ),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S%.f").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // Truncation applies first.
+        let options = Options {
+            truncate: true,
+            ..round(Unit::Hour, 1, RoundMode::Ceil)
+        };
+        let result = parse_at_date(now.clone(), "+1 minute", &options).unwrap();
+        assert_eq!(result.strftime("%T").to_string(), "11:00:00");
     }
 }
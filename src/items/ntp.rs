@@ -0,0 +1,171 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore ntpq
+
+//! Parse an NTP timestamp.
+//!
+//! An NTP timestamp (RFC 5905, section 6) is a 32-bit count of seconds since
+//! 1900-01-01 00:00:00 UTC and a 32-bit fraction of a second, in units of
+//! 2^-32 seconds. It is written in one of two forms:
+//!
+//! - in hexadecimal, as `ntpq` prints it, with 8 digits for the seconds and 8
+//!   for the fraction, separated by a dot (e.g., `e9d7a7b8.3d4f2a10`), or
+//! - in decimal, as a number of seconds, with an optional decimal fraction
+//!   (e.g., `3922159544.25`), less than 2^32.
+//!
+//! A number of 8 digits, a dot and 8 digits is taken to be hexadecimal.
+//!
+//! The seconds wrap around every 2^32 seconds (about 136 years), the first
+//! time on 2036-02-07 06:28:16 UTC, which starts era 1. As RFC 5905
+//! recommends, the era is the one that puts the timestamp closest to the base
+//! date and time, so that a timestamp is resolved correctly within 68 years of
+//! the base.
+
+use jiff::{Timestamp, Zoned};
+use winnow::{
+    ascii::{digit1, multispace0},
+    combinator::{alt, delimited, eof, opt, preceded},
+    stream::AsChar,
+    token::take_while,
+    ModalResult, Parser,
+};
+
+use super::error::Error;
+
+/// The number of seconds from 1900-01-01 to 1970-01-01.
+const UNIX_EPOCH: i64 = 2_208_988_800;
+
+/// The number of seconds in an era.
+const ERA: i64 = 1 << 32;
+
+/// An NTP timestamp, whose era is not known.
+#[derive(Debug, PartialEq)]
+pub(super) struct NtpTimestamp {
+    seconds: u32,
+    nanoseconds: u32,
+}
+
+impl NtpTimestamp {
+    /// Resolve the timestamp in the era that puts it closest to the base date
+    /// and time, in the timezone of the base.
+    pub(super) fn resolve(self, base: &Zoned) -> Result<Zoned, Error> {
+        let base_seconds = base.timestamp().as_second() + UNIX_EPOCH;
+        let era = (base_seconds - i64::from(self.seconds) + ERA / 2).div_euclid(ERA);
+        let seconds = era * ERA + i64::from(self.seconds) - UNIX_EPOCH;
+        Timestamp::new(seconds, self.nanoseconds as i32)
+            .map(|ts| ts.to_zoned(base.time_zone().clone()))
+            .map_err(|_| Error::OutOfRange)
+    }
+}
+
+/// Parse an NTP timestamp, in hexadecimal or decimal.
+pub(super) fn parse(input: &mut &str) -> ModalResult<NtpTimestamp> {
+    delimited(multispace0, alt((hexadecimal, decimal)), (multispace0, eof)).parse_next(input)
+}
+
+/// Parse an NTP timestamp in hexadecimal, e.g., `e9d7a7b8.3d4f2a10`.
+fn hexadecimal(input: &mut &str) -> ModalResult<NtpTimestamp> {
+    (
+        take_while(8, AsChar::is_hex_digit),
+        '.',
+        take_while(8, AsChar::is_hex_digit),
+    )
+        .verify_map(|(seconds, _, fraction): (&str, _, &str)| {
+            let seconds = u32::from_str_radix(seconds, 16).ok()?;
+            let fraction = u64::from(u32::from_str_radix(fraction, 16).ok()?);
+            Some(NtpTimestamp {
+                seconds,
+                // The fraction is in units of 2^-32 seconds.
+                nanoseconds: ((fraction * 1_000_000_000) >> 32) as u32,
+            })
+        })
+        .parse_next(input)
+}
+
+/// Parse an NTP timestamp in decimal, e.g., `3922159544.25`.
+fn decimal(input: &mut &str) -> ModalResult<NtpTimestamp> {
+    (digit1, opt(preceded(alt(('.', ',')), digit1)))
+        .verify_map(|(seconds, fraction): (&str, Option<&str>)| {
+            let seconds = seconds.parse().ok()?;
+            let fraction = fraction.unwrap_or("");
+            let fraction = &fraction[..fraction.len().min(9)];
+            Some(NtpTimestamp {
+                seconds,
+                nanoseconds: format!("{fraction:0<9}").parse().ok()?,
+            })
+        })
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::Zoned;
+
+    use super::parse;
+
+    fn resolve(input: &str, base: &str) -> String {
+        let base: Zoned = base.parse().unwrap();
+        let ts = parse(&mut &*input).unwrap();
+        ts.resolve(&base).unwrap().timestamp().to_string()
+    }
+
+    #[test]
+    fn eras() {
+        let now = "2024-01-12T00:00:00+00:00[UTC]";
+        for (input, base, expected) in [
+            ("e9d7a7b8.3d4f2a10", now, "2024-04-27T16:35:04.239489201Z"),
+            ("E9D7A7B8.80000000", now, "2024-04-27T16:35:04.5Z"),
+            ("3922159544", now, "2024-04-15T08:45:44Z"),
+            ("3922159544.25", now, "2024-04-15T08:45:44.25Z"),
+            ("3922159544,25", now, "2024-04-15T08:45:44.25Z"),
+            (
+                "0",
+                "1900-01-01T00:00:00+00:00[UTC]",
+                "1900-01-01T00:00:00Z",
+            ),
+            ("83aa7e80.00000000", now, "1970-01-01T00:00:00Z"),
+            // Era 1 starts on 2036-02-07 06:28:16 UTC.
+            ("00000000.00000000", now, "2036-02-07T06:28:16Z"),
+            ("00000010.00000000", now, "2036-02-07T06:28:32Z"),
+            ("ffffffff.00000000", now, "2036-02-07T06:28:15Z"),
+            // Within 68 years of the base, in either direction.
+            (
+                "00000000.00000000",
+                "1960-01-01T00:00:00+00:00[UTC]",
+                "1900-01-01T00:00:00Z",
+            ),
+            (
+                "e9d7a7b8.00000000",
+                "2080-01-01T00:00:00+00:00[UTC]",
+                "2024-04-27T16:35:04Z",
+            ),
+            (
+                "10000000.00000000",
+                "2080-01-01T00:00:00+00:00[UTC]",
+                "2044-08-10T03:52:32Z",
+            ),
+        ] {
+            assert_eq!(resolve(input, base), expected, "{input} at {base}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "",
+            "e9d7a7b8",
+            "e9d7a7b8.3d4f2a1",
+            "e9d7a7b8.3d4f2a100",
+            "e9d7a7b83d4f2a10",
+            "0xe9d7a7b8.3d4f2a10",
+            "4294967296",
+            "-1",
+            "3922159544.",
+            "@3922159544",
+            "2024-04-16",
+        ] {
+            assert!(parse(&mut &*input).is_err(), "{input}");
+        }
+    }
+}
@@ -35,6 +35,8 @@ use winnow::{
     ModalResult, Parser,
 };
 
+use crate::{abbreviation::AMBIGUOUS, parser::Options, AbbreviationPolicy, ZoneResolution};
+
 use super::{
     primitive::{colon, ctx_err, dec_uint, dec_uint_str, plus_or_minus, s},
     relative,
@@ -44,12 +46,16 @@ use super::{
 ///
 /// This struct is used to represent a time zone offset in hours and minutes,
 /// with a boolean indicating whether the offset is negative (i.e., west of
-/// UTC).
+/// UTC). An offset given by a timezone abbreviation may carry the IANA zone
+/// the abbreviation stands for, and the abbreviation itself.
 #[derive(PartialEq, Debug, Clone, Default)]
 pub(super) struct Offset {
     negative: bool,
     hours: u8,
     minutes: u8,
+    pub(super) zone: Option<&'static str>,
+    /// The abbreviation, in upper case, unless a correction was appended.
+    pub(super) name: Option<String>,
 }
 
 impl Offset {
@@ -82,9 +88,17 @@ impl Offset {
             negative,
             hours,
             minutes,
+            zone: None,
+            name: None,
         }
     }
 
+    /// Whether this is the "-0000" offset, which RFC 2822 uses for a time in
+    /// UTC that carries no information about the local timezone.
+    pub(super) fn is_unknown_local(&self) -> bool {
+        self.negative && self.hours == 0 && self.minutes == 0
+    }
+
     /// Normalize the offset so that the hour field is within the accepted range.
     ///
     /// - If the hour field is less than 24, or exactly 24 with a zero minute,
@@ -99,14 +113,7 @@ impl Offset {
         }
 
         let hour_adjustment = (self.hours as i8 - 23) * if self.negative { 1 } else { -1 };
-        (
-            Offset {
-                negative: self.negative,
-                hours: 23,
-                minutes: self.minutes,
-            },
-            hour_adjustment,
-        )
+        (Offset { hours: 23, ..self }, hour_adjustment)
     }
 }
 
@@ -125,6 +132,8 @@ impl TryFrom<(bool, u8, u8)> for Offset {
             negative,
             hours,
             minutes,
+            zone: None,
+            name: None,
         })
     }
 }
@@ -137,6 +146,7 @@ impl TryFrom<&Offset> for jiff::tz::TimeZone {
             negative,
             hours,
             minutes,
+            ..
         }: &Offset,
     ) -> Result<Self, Self::Error> {
         let secs = (*hours as i32) * 3600 + (*minutes as i32) * 60;
@@ -147,6 +157,20 @@ impl TryFrom<&Offset> for jiff::tz::TimeZone {
     }
 }
 
+impl From<jiff::tz::Offset> for Offset {
+    fn from(offset: jiff::tz::Offset) -> Self {
+        let secs = offset.seconds();
+        let minutes = secs.unsigned_abs() / 60;
+        Offset {
+            negative: secs < 0,
+            hours: (minutes / 60) as u8,
+            minutes: (minutes % 60) as u8,
+            zone: None,
+            name: None,
+        }
+    }
+}
+
 impl Display for Offset {
     fn fmt(&self, fmt: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
         write!(
@@ -159,8 +183,22 @@ impl Display for Offset {
     }
 }
 
-pub(super) fn parse(input: &mut &str) -> ModalResult<Offset> {
-    timezone_name_offset.parse_next(input)
+/// Parse a timezone by name, recording the name unless a numeric offset is
+/// appended to it.
+pub(super) fn parse(input: &mut &str, options: &Options) -> ModalResult<Offset> {
+    (|i: &mut &str| timezone_name_offset(i, options))
+        .with_taken()
+        .map(|(offset, taken)| {
+            let taken = taken.trim();
+            Offset {
+                name: taken
+                    .bytes()
+                    .all(|b| b.is_ascii_alphabetic())
+                    .then(|| taken.to_ascii_uppercase()),
+                ..offset
+            }
+        })
+        .parse_next(input)
 }
 
 /// Parse a timezone starting with `+` or `-`.
@@ -169,21 +207,45 @@ pub(super) fn timezone_offset(input: &mut &str) -> ModalResult<Offset> {
     // timezone offset "+8" and a relative time "years", or just a relative time
     // "+8 years". GNU date parses them the second way, so we do the same here.
     //
-    // Return early if the input can be parsed as a relative time.
-    if peek(relative::parse).parse_next(input).is_ok() {
+    // Return early if the input can be parsed as a relative time. Strings like
+    // "+1d2h" cannot be a valid offset followed by a timezone, so they are
+    // left to be parsed as a relative shorthand.
+    if peek(relative::parse).parse_next(input).is_ok()
+        || peek(relative::shorthand).parse_next(input).is_ok()
+    {
         return Err(ErrMode::Backtrack(ContextError::new()));
     }
 
     alt((timezone_offset_colon, timezone_offset_colonless)).parse_next(input)
 }
 
+/// Return the parser of the offset of a time of day. When compact durations
+/// are enabled, a compact duration after a time of day (e.g., "12:00 -1m") is
+/// not parsed as an offset followed by a military timezone, but left to be
+/// parsed as a duration.
+pub(super) fn time_offset(options: &Options) -> fn(&mut &str) -> ModalResult<Offset> {
+    if options.compact_durations {
+        |input| {
+            if peek(relative::compact_duration).parse_next(input).is_ok() {
+                return Err(ErrMode::Backtrack(ContextError::new()));
+            }
+            timezone_offset(input)
+        }
+    } else {
+        timezone_offset
+    }
+}
+
 /// Parse a timezone by name, with an optional numeric offset appended.
-fn timezone_name_offset(input: &mut &str) -> ModalResult<Offset> {
-    /// I'm assuming there are no timezone abbreviations with more
-    /// than 6 charactres
-    const MAX_TZ_SIZE: usize = 6;
-    let nextword = s(take_while(1..=MAX_TZ_SIZE, AsChar::is_alpha)).parse_next(input)?;
-    let tz = timezone_name_to_offset(nextword)?;
+///
+/// The abbreviations configured in `options` take precedence over the built-in
+/// ones.
+fn timezone_name_offset(input: &mut &str, options: &Options) -> ModalResult<Offset> {
+    let nextword = s(take_while(1.., AsChar::is_alpha)).parse_next(input)?;
+    let tz = match options.timezone_abbreviations.get(nextword) {
+        Some(offset) => Offset::from(*offset),
+        None => resolve_timezone_name(nextword, options)?,
+    };
 
     // Strings like "UTC +8 years" are ambiguous, they can either be parsed as
     // "UTC+8" and "years", or "UTC" and "+8 years". GNU date parses them the
@@ -256,22 +318,102 @@ fn timezone_offset_colonless(input: &mut &str) -> ModalResult<Offset> {
         })
 }
 
+/// Resolve a timezone name, which may be ambiguous, according to the
+/// abbreviation policy. Unless the zone resolution is
+/// [`ZoneResolution::FixedOffset`], the offset of a built-in abbreviation
+/// carries the IANA zone the abbreviation stands for.
+fn resolve_timezone_name(input: &str, options: &Options) -> ModalResult<Offset> {
+    // RFC 822 defined the signs of the military zones backwards, so RFC 2822
+    // considers them equivalent to "-0000". "Z" is UT in either case.
+    if options.rfc2822_zones && input.len() == 1 && input != "z" {
+        timezone_name_to_offset(input)?;
+        return Ok(Offset {
+            negative: true,
+            ..Default::default()
+        });
+    }
+
+    let regions = AMBIGUOUS
+        .iter()
+        .find_map(|(name, regions)| (*name == input).then_some(*regions));
+    let builtin = || {
+        timezone_name_to_offset(input).map(|offset| Offset {
+            zone: match options.zone_resolution {
+                ZoneResolution::FixedOffset => None,
+                _ => ZONES
+                    .iter()
+                    .find_map(|(name, zone)| (*name == input).then_some(*zone)),
+            },
+            ..offset
+        })
+    };
+
+    match (options.abbreviation_policy, regions) {
+        (AbbreviationPolicy::ErrorOnAmbiguous, Some(_)) => {
+            Err(ErrMode::Cut(ctx_err("timezone abbreviation is ambiguous")))
+        }
+        (AbbreviationPolicy::PreferRegion(region), Some(regions)) => {
+            match regions.iter().find(|(r, _)| *r == region) {
+                Some((_, offset_str)) => timezone_offset(&mut &**offset_str),
+                None => builtin(),
+            }
+        }
+        _ => builtin(),
+    }
+}
+
+/// The IANA zones of the built-in abbreviations of timezones that observe
+/// daylight saving time.
+const ZONES: &[(&str, &str)] = &[
+    ("acdt", "Australia/Adelaide"),
+    ("acst", "Australia/Adelaide"),
+    ("aedt", "Australia/Sydney"),
+    ("aest", "Australia/Sydney"),
+    ("akdt", "America/Anchorage"),
+    ("akst", "America/Anchorage"),
+    ("cdt", "America/Chicago"),
+    ("cest", "Europe/Paris"),
+    ("cet", "Europe/Paris"),
+    ("clst", "America/Santiago"),
+    ("clt", "America/Santiago"),
+    ("cst", "America/Chicago"),
+    ("edt", "America/New_York"),
+    ("eest", "Europe/Athens"),
+    ("eet", "Europe/Athens"),
+    ("est", "America/New_York"),
+    ("mdt", "America/Denver"),
+    ("mst", "America/Denver"),
+    ("ndt", "America/St_Johns"),
+    ("nst", "America/St_Johns"),
+    ("nzdt", "Pacific/Auckland"),
+    ("nzst", "Pacific/Auckland"),
+    ("pdt", "America/Los_Angeles"),
+    ("pst", "America/Los_Angeles"),
+    ("west", "Europe/Lisbon"),
+    ("wet", "Europe/Lisbon"),
+];
+
 /// Named timezone list.
 ///
 /// The full list of timezones can be extracted from
 /// https://www.timeanddate.com/time/zones/. GNU date only supports a subset of
-/// these. We support the same subset as GNU date.
+/// these. We support that subset, plus some abbreviations in common use in
+/// Asia, Australia and South America.
 fn timezone_name_to_offset(input: &str) -> ModalResult<Offset> {
     let mut offset_str = match input {
         "z" => Ok("+0"),
         "y" => Ok("-12"),
         "x" => Ok("-11"),
+        "wita" => Ok("+8"),
+        "wit" => Ok("+9"),
+        "wib" => Ok("+7"),
         "wet" => Ok("+0"),
         "west" => Ok("+1"),
         "wat" => Ok("+1"),
         "w" => Ok("-10"),
         "v" => Ok("-9"),
         "utc" => Ok("+0"),
+        "ut" => Ok("+0"),
         "u" => Ok("-8"),
         "t" => Ok("-7"),
         "sst" => Ok("-11"),
@@ -281,6 +423,9 @@ fn timezone_name_to_offset(input: &str) -> ModalResult<Offset> {
         "r" => Ok("-5"),
         "q" => Ok("-4"),
         "pst" => Ok("-8"),
+        "pkt" => Ok("+5"),
+        "pht" => Ok("+8"),
+        "pet" => Ok("-5"),
         "pdt" => Ok("-7"),
         "p" => Ok("-3"),
         "o" => Ok("-2"),
@@ -289,17 +434,21 @@ fn timezone_name_to_offset(input: &str) -> ModalResult<Offset> {
         "nst" => Ok("-3:30"),
         "ndt" => Ok("-2:30"),
         "n" => Ok("-1"),
+        "myt" => Ok("+8"),
         "mst" => Ok("-7"),
         "msk" => Ok("+3"),
         "msd" => Ok("+4"),
         "mdt" => Ok("-6"),
         "m" => Ok("+12"),
         "l" => Ok("+11"),
+        "kst" => Ok("+9"),
         "k" => Ok("+10"),
         "jst" => Ok("+9"),
         "ist" => Ok("+5:30"),
+        "ict" => Ok("+7"),
         "i" => Ok("+9"),
         "hst" => Ok("-10"),
+        "hkt" => Ok("+8"),
         "h" => Ok("+8"),
         "gst" => Ok("+4"),
         "gmt" => Ok("+0"),
@@ -313,8 +462,10 @@ fn timezone_name_to_offset(input: &str) -> ModalResult<Offset> {
         "e" => Ok("+5"),
         "d" => Ok("+4"),
         "cst" => Ok("-6"),
+        "cot" => Ok("-5"),
         "clt" => Ok("-4"),
         "clst" => Ok("-3"),
+        "chst" => Ok("+10"),
         "cet" => Ok("+1"),
         "cest" => Ok("+2"),
         "cdt" => Ok("-5"),
@@ -324,11 +475,16 @@ fn timezone_name_to_offset(input: &str) -> ModalResult<Offset> {
         "brt" => Ok("-3"),
         "brst" => Ok("-2"),
         "b" => Ok("+2"),
+        "awst" => Ok("+8"),
         "ast" => Ok("-3"),
         "art" => Ok("-3"),
         "akst" => Ok("-9"),
         "akdt" => Ok("-8"),
+        "aest" => Ok("+10"),
+        "aedt" => Ok("+11"),
         "adt" => Ok("+4"),
+        "acst" => Ok("+9:30"),
+        "acdt" => Ok("+10:30"),
         "a" => Ok("+1"),
         _ => Err(ErrMode::Backtrack(ContextError::new())),
     }?;
@@ -345,6 +501,8 @@ mod tests {
             negative,
             hours,
             minutes,
+            zone: None,
+            name: None,
         }
     }
 
@@ -415,6 +573,7 @@ mod tests {
         for (input, expected) in [
             ("utc", off(false, 0, 0)),  // UTC
             ("gmt", off(false, 0, 0)),  // UTC
+            ("ut", off(false, 0, 0)),   // UTC
             ("z", off(false, 0, 0)),    // UTC
             ("west", off(false, 1, 0)), // positive offset
             ("cst", off(true, 6, 0)),   // negative offset
@@ -423,7 +582,11 @@ mod tests {
             ("z123", off(false, 0, 0)), // space separator can be ignored if immediately followed by digits (GNU date behavior)
         ] {
             let mut s = input;
-            assert_eq!(timezone_name_offset(&mut s).unwrap(), expected, "{input}");
+            assert_eq!(
+                timezone_name_offset(&mut s, &Options::default()).unwrap(),
+                expected,
+                "{input}"
+            );
         }
 
         for input in [
@@ -431,7 +594,10 @@ mod tests {
             "utcabc", // invalid: non-existent timezone
         ] {
             let mut s = input;
-            assert!(timezone_name_offset(&mut s).is_err(), "{input}");
+            assert!(
+                timezone_name_offset(&mut s, &Options::default()).is_err(),
+                "{input}"
+            );
         }
     }
 
@@ -450,7 +616,11 @@ mod tests {
             ("y-24", off(true, 36, 0)),          // maximum possible negative offset (y=-12)
         ] {
             let mut s = input;
-            assert_eq!(timezone_name_offset(&mut s).unwrap(), expected, "{input}");
+            assert_eq!(
+                timezone_name_offset(&mut s, &Options::default()).unwrap(),
+                expected,
+                "{input}"
+            );
         }
 
         for input in [
@@ -460,9 +630,131 @@ mod tests {
         ] {
             let mut s = input;
             assert!(
-                timezone_name_offset(&mut s).is_err() || !s.is_empty(),
+                timezone_name_offset(&mut s, &Options::default()).is_err() || !s.is_empty(),
                 "{input}"
             );
         }
     }
+
+    #[test]
+    fn timezone_name_configured() {
+        let mut options = Options::default();
+        for (name, secs) in [("cst", 8 * 3600), ("ist", 3600), ("aest", 10 * 3600)] {
+            options.timezone_abbreviations.insert(
+                name.to_owned(),
+                jiff::tz::Offset::from_seconds(secs).unwrap(),
+            );
+        }
+
+        for (input, expected) in [
+            ("cst", off(false, 8, 0)),       // overridden
+            ("ist+0:30", off(false, 1, 30)), // overridden, with offset
+            ("aest", off(false, 10, 0)),     // added
+            ("est", off(true, 5, 0)),        // built-in
+        ] {
+            let mut s = input;
+            assert_eq!(
+                timezone_name_offset(&mut s, &options).unwrap(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn timezone_name_extended() {
+        for (input, expected) in [
+            ("aest", off(false, 10, 0)),
+            ("acst", off(false, 9, 30)),
+            ("acdt", off(false, 10, 30)),
+            ("awst", off(false, 8, 0)),
+            ("hkt", off(false, 8, 0)),
+            ("kst", off(false, 9, 0)),
+            ("wib", off(false, 7, 0)),
+        ] {
+            let mut s = input;
+            assert_eq!(
+                timezone_name_offset(&mut s, &Options::default()).unwrap(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn timezone_name_policy() {
+        use crate::Region;
+
+        let options = |abbreviation_policy| Options {
+            abbreviation_policy,
+            ..Default::default()
+        };
+
+        for (policy, input, expected) in [
+            (AbbreviationPolicy::FirstMatch, "cst", off(true, 6, 0)),
+            (AbbreviationPolicy::FirstMatch, "ist", off(false, 5, 30)),
+            (
+                AbbreviationPolicy::ErrorOnAmbiguous,
+                "utc",
+                off(false, 0, 0),
+            ),
+            (
+                AbbreviationPolicy::ErrorOnAmbiguous,
+                "cet",
+                off(false, 1, 0),
+            ),
+            (
+                AbbreviationPolicy::PreferRegion(Region::Asia),
+                "cst",
+                off(false, 8, 0),
+            ),
+            (
+                AbbreviationPolicy::PreferRegion(Region::Europe),
+                "ist",
+                off(false, 1, 0),
+            ),
+            (
+                AbbreviationPolicy::PreferRegion(Region::Europe),
+                "bst+1",
+                off(false, 2, 0),
+            ),
+            (
+                AbbreviationPolicy::PreferRegion(Region::Australia),
+                "est",
+                off(false, 10, 0),
+            ),
+            // No interpretation in the region: fall back to the built-in one.
+            (
+                AbbreviationPolicy::PreferRegion(Region::Africa),
+                "cst",
+                off(true, 6, 0),
+            ),
+        ] {
+            let mut s = input;
+            assert_eq!(
+                timezone_name_offset(&mut s, &options(policy)).unwrap(),
+                expected,
+                "{input}"
+            );
+        }
+
+        for input in ["cst", "ist", "bst", "est"] {
+            let mut s = input;
+            assert!(
+                timezone_name_offset(&mut s, &options(AbbreviationPolicy::ErrorOnAmbiguous))
+                    .is_err(),
+                "{input}"
+            );
+        }
+
+        // Configured abbreviations are not subject to the policy.
+        let mut options = options(AbbreviationPolicy::ErrorOnAmbiguous);
+        options
+            .timezone_abbreviations
+            .insert("cst".to_owned(), jiff::tz::Offset::from_hours(8).unwrap());
+        assert_eq!(
+            timezone_name_offset(&mut "cst", &options).unwrap(),
+            off(false, 8, 0)
+        );
+    }
 }
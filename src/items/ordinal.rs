@@ -23,6 +23,8 @@ fn number_ordinal(input: &mut &str) -> ModalResult<i32> {
         .parse_next(input)
 }
 
+/// Parse an ordinal word. As in GNU `date`, "second" is not an ordinal, as it
+/// is a unit of time.
 fn text_ordinal(input: &mut &str) -> ModalResult<i32> {
     s(alpha1)
         .verify_map(|s: &str| {
@@ -35,7 +37,7 @@ fn text_ordinal(input: &mut &str) -> ModalResult<i32> {
                 "fifth" => 5,
                 "sixth" => 6,
                 "seventh" => 7,
-                "eight" => 8,
+                "eighth" => 8,
                 "ninth" => 9,
                 "tenth" => 10,
                 "eleventh" => 11,
@@ -45,3 +47,36 @@ fn text_ordinal(input: &mut &str) -> ModalResult<i32> {
         })
         .parse_next(input)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ordinal;
+
+    #[test]
+    fn text_ordinals() {
+        for (input, expected) in [
+            ("last", -1),
+            ("this", 0),
+            ("next", 1),
+            ("first", 1),
+            ("third", 3),
+            ("fourth", 4),
+            ("fifth", 5),
+            ("sixth", 6),
+            ("seventh", 7),
+            ("eighth", 8),
+            ("ninth", 9),
+            ("tenth", 10),
+            ("eleventh", 11),
+            ("twelfth", 12),
+        ] {
+            let mut s = input;
+            assert_eq!(ordinal(&mut s).unwrap(), expected, "{input}");
+        }
+
+        for input in ["second", "eight", "thirteenth"] {
+            let mut s = input;
+            assert!(ordinal(&mut s).is_err(), "{input}");
+        }
+    }
+}
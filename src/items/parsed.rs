@@ -0,0 +1,424 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! A parsed date and time string, resolved later against any number of base
+//! dates and times.
+//!
+//! A [`Parsed`] is returned by [`parse`](crate::parse) and
+//! [`Parser::parse`](crate::Parser::parse), so that applications evaluating
+//! the same expression repeatedly (e.g., on each tick of a scheduler) parse it
+//! only once.
+
+use std::{fmt, sync::Arc};
+
+use jiff::Zoned;
+
+use crate::{parser::Options, ParseDateTimeError, Precision};
+
+use super::{builder::DateTimeBuilder, error::Error};
+
+/// A parsed date and time string (e.g., "next friday 17:00"), not yet
+/// resolved against a base date and time.
+///
+/// Cloning a `Parsed` is inexpensive, as the parsed items are shared.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+///
+/// let parsed = parse_datetime::parse("next friday 17:00").unwrap();
+///
+/// let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+/// let dt = parsed.resolve_at(base).unwrap();
+/// assert_eq!(dt.strftime("%F %T").to_string(), "2025-06-20 17:00:00");
+///
+/// let base = "2025-06-21T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+/// let dt = parsed.resolve_at(base).unwrap();
+/// assert_eq!(dt.strftime("%F %T").to_string(), "2025-06-27 17:00:00");
+/// ```
+#[derive(Clone)]
+pub struct Parsed<'a> {
+    builder: Arc<DateTimeBuilder>,
+    options: &'a Options,
+}
+
+impl<'a> Parsed<'a> {
+    pub(super) fn new(builder: DateTimeBuilder, options: &'a Options) -> Self {
+        Self {
+            builder: Arc::new(builder),
+            options,
+        }
+    }
+
+    /// Resolve the parsed string against `base`, as
+    /// [`parse_datetime_at_date`](crate::parse_datetime_at_date) does.
+    ///
+    /// Resolution may still fail (e.g., "feb 29" in a year that is not a leap
+    /// year), with [`ParseDateTimeError::Resolve`] or
+    /// [`ParseDateTimeError::OutOfRange`].
+    pub fn resolve_at(&self, base: Zoned) -> Result<Zoned, ParseDateTimeError> {
+        let builder = DateTimeBuilder::clone(&self.builder);
+        Ok(builder.set_base(base).build(self.options)?)
+    }
+
+    /// Resolve the parsed string with `template` as its base date and time,
+    /// taking from it the fields that the input does not specify, including
+    /// the time of day: unlike [`resolve_at`](Self::resolve_at), a date alone
+    /// (e.g., "2025-03-01") keeps the time of day of `template` instead of
+    /// being at midnight.
+    ///
+    /// This is what `touch -d` needs to change only some fields of the
+    /// timestamp of a file (e.g., "12:00" keeps its date, and "2025-03-01"
+    /// its time of day).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::Zoned;
+    ///
+    /// let mtime = "2024-11-05T08:15:30+00:00[UTC]".parse::<Zoned>().unwrap();
+    ///
+    /// let parsed = parse_datetime::parse("2025-03-01").unwrap();
+    /// let dt = parsed.resolve_with_defaults(mtime.clone()).unwrap();
+    /// assert_eq!(dt.strftime("%F %T").to_string(), "2025-03-01 08:15:30");
+    ///
+    /// let parsed = parse_datetime::parse("12:00").unwrap();
+    /// let dt = parsed.resolve_with_defaults(mtime).unwrap();
+    /// assert_eq!(dt.strftime("%F %T").to_string(), "2024-11-05 12:00:00");
+    /// ```
+    pub fn resolve_with_defaults(&self, template: Zoned) -> Result<Zoned, ParseDateTimeError> {
+        let builder = DateTimeBuilder::clone(&self.builder);
+        Ok(builder.set_base(template).keep_time().build(self.options)?)
+    }
+
+    /// The parts of the date and time that were given by the input.
+    pub fn precision(&self) -> Precision {
+        self.builder.precision()
+    }
+
+    /// Fill a chrono [`Parsed`](chrono::format::Parsed) with the fields given
+    /// by the input, for validation or resolution by chrono (e.g., with
+    /// `to_datetime`), as if chrono had parsed them.
+    ///
+    /// The date, the time of day, the offset, a day of the week given with a
+    /// date, and a timestamp are filled in. An input with items that depend on
+    /// the base date and time (e.g., "tomorrow", "+1 hour", "friday" alone or
+    /// a holiday), or with a `TZ="..."` rule, cannot be represented and is a
+    /// [`ParseDateTimeError::Resolve`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let parsed = parse_datetime::parse("2025-06-15 10:30 EST").unwrap();
+    /// let parsed = parsed.to_chrono_parsed().unwrap();
+    /// assert_eq!(
+    ///     (parsed.year(), parsed.month(), parsed.day()),
+    ///     (Some(2025), Some(6), Some(15))
+    /// );
+    /// assert_eq!(parsed.offset(), Some(-5 * 3600));
+    ///
+    /// let dt = parsed.to_datetime().unwrap();
+    /// assert_eq!(dt.timestamp(), 1750001400);
+    ///
+    /// assert!(parse_datetime::parse("tomorrow")
+    ///     .unwrap()
+    ///     .to_chrono_parsed()
+    ///     .is_err());
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn to_chrono_parsed(&self) -> Result<chrono::format::Parsed, ParseDateTimeError> {
+        self.builder
+            .to_chrono_parsed()
+            .map_err(|e| e.resolving().into())
+    }
+
+    /// Overlay `other` on this value, as defaults overridden by later input
+    /// (e.g., a time of day from a configuration file, and a date given on
+    /// the command line).
+    ///
+    /// An item of `other` replaces the item of the same kind in `self`: a
+    /// calendar date (including a weekday within a month, a holiday or a
+    /// period) replaces a calendar date, a time of day a time of day, and so
+    /// on. The relative items of both are applied, those of `other` last. A
+    /// timestamp replaces, and is replaced by, all the other items.
+    ///
+    /// The result uses the options of `self`. If `other` was parsed by
+    /// another [`Parser`](crate::Parser), its custom keywords and relative
+    /// units are looked up by name in the options of `self`, and a
+    /// [`ParseDateTimeError::Resolve`] error is returned if one of its units
+    /// is not registered there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use jiff::Zoned;
+    ///
+    /// let config = parse_datetime::parse("09:00").unwrap();
+    /// let input = parse_datetime::parse("tomorrow").unwrap();
+    ///
+    /// let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+    /// let dt = config.merge(&input).unwrap().resolve_at(base).unwrap();
+    /// assert_eq!(dt.strftime("%F %T").to_string(), "2025-06-16 09:00:00");
+    /// ```
+    pub fn merge(&self, other: &Parsed<'a>) -> Result<Parsed<'a>, ParseDateTimeError> {
+        let mut other_builder = DateTimeBuilder::clone(&other.builder);
+        if !std::ptr::eq(self.options, other.options) {
+            other_builder = other_builder
+                .map_custom_units(|unit| {
+                    let (name, _) = other.options.relative_units.get(unit)?;
+                    self.options
+                        .relative_units
+                        .iter()
+                        .position(|(n, _)| n == name)
+                })
+                .ok_or_else(|| Error::Resolve("relative unit is not registered".into()))?;
+        }
+        let builder = DateTimeBuilder::clone(&self.builder);
+        Ok(Self::new(builder.merge(other_builder), self.options))
+    }
+}
+
+impl fmt::Debug for Parsed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Parsed").field(&self.builder).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::Zoned;
+
+    use crate::{parser::Options, ParseDateTimeError, Parser};
+
+    use super::super::parse_deferred;
+
+    #[test]
+    fn resolve_at() {
+        let options = Options::default();
+        let parsed = parse_deferred("next friday 17:00", &options).unwrap();
+        let copy = parsed.clone();
+
+        for (base, expected) in [
+            (
+                "2025-06-15T10:00:00+00:00[UTC]",
+                "2025-06-20 17:00:00 +00:00",
+            ),
+            (
+                "2025-06-20T18:00:00+00:00[UTC]",
+                "2025-06-27 17:00:00 +00:00",
+            ),
+            (
+                "2025-06-15T10:00:00+09:00[Asia/Tokyo]",
+                "2025-06-20 17:00:00 +09:00",
+            ),
+        ] {
+            let base = base.parse::<Zoned>().unwrap();
+            for parsed in [&parsed, &copy] {
+                let result = parsed.resolve_at(base.clone()).unwrap();
+                assert_eq!(
+                    result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+                    expected,
+                    "{base}"
+                );
+            }
+        }
+        assert!(parsed.precision().time);
+        assert!(!parsed.precision().offset);
+
+        let parsed = parse_deferred("feb 29", &options).unwrap();
+        let base = "2024-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+        assert!(parsed.resolve_at(base).is_ok());
+        let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+        assert!(matches!(
+            parsed.resolve_at(base),
+            Err(ParseDateTimeError::Resolve(_))
+        ));
+
+        assert!(parse_deferred("next fooday", &options).is_err());
+    }
+
+    #[test]
+    fn merge() {
+        let options = Options::default();
+        let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+        let parse = |input| parse_deferred(input, &options).unwrap();
+
+        for (defaults, input, expected) in [
+            ("09:00", "tomorrow", "2025-06-16 09:00:00 +00:00"),
+            ("09:00", "tomorrow 17:30", "2025-06-16 17:30:00 +00:00"),
+            (
+                "2025-01-01 09:00",
+                "2025-03-01",
+                "2025-03-01 09:00:00 +00:00",
+            ),
+            (
+                "2025-01-01 09:00",
+                "first monday of march",
+                "2025-03-03 09:00:00 +00:00",
+            ),
+            (
+                "last friday of june",
+                "2025-03-01",
+                "2025-03-01 00:00:00 +00:00",
+            ),
+            (
+                "2025-01-01 +1 day",
+                "+2 hours",
+                "2025-01-02 02:00:00 +00:00",
+            ),
+            ("09:00 +02:00", "10:00", "2025-06-15 10:00:00 +02:00"),
+            ("09:00+02:00", "utc", "2025-06-15 09:00:00 +00:00"),
+            ("monday", "09:00", "2025-06-16 09:00:00 +00:00"),
+            ("monday", "friday", "2025-06-20 00:00:00 +00:00"),
+            ("@0", "", "1970-01-01 00:00:00 +00:00"),
+            ("@0", "2025-03-01", "2025-03-01 00:00:00 +00:00"),
+            ("2025-03-01", "@0", "1970-01-01 00:00:00 +00:00"),
+            (
+                r#"TZ="Asia/Tokyo" 09:00"#,
+                "tomorrow",
+                "2025-06-16 09:00:00 +09:00",
+            ),
+        ] {
+            let result = parse(defaults)
+                .merge(&parse(input))
+                .unwrap()
+                .resolve_at(base.clone())
+                .unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+                expected,
+                "{defaults} | {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn resolve_with_defaults() {
+        let options = Options::default();
+        let template = "2024-11-05T08:15:30.5+00:00[UTC]".parse::<Zoned>().unwrap();
+
+        for (input, expected) in [
+            ("12:00", "2024-11-05 12:00:00.000"),
+            ("2025-03-01", "2025-03-01 08:15:30.500"),
+            ("2025-03-01 12:00", "2025-03-01 12:00:00.000"),
+            ("mar 1", "2024-03-01 08:15:30.500"),
+            ("friday", "2024-11-08 08:15:30.500"),
+            ("+1 day", "2024-11-06 08:15:30.500"),
+            ("", "2024-11-05 08:15:30.500"),
+            ("@0", "1970-01-01 00:00:00.000"),
+        ] {
+            let parsed = parse_deferred(input, &options).unwrap();
+            let result = parsed.resolve_with_defaults(template.clone()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S%.3f").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn merge_other_parser() {
+        let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+        let fortnight = |dt: &Zoned, n: i32| dt.checked_add(jiff::Span::new().weeks(2 * n)).ok();
+        let config = Parser::new();
+        let input = Parser::new()
+            .relative_unit("sprint", fortnight)
+            .relative_unit("fortnight", fortnight);
+
+        let result = config
+            .parse("09:00")
+            .unwrap()
+            .merge(&Parser::new().parse("tomorrow").unwrap())
+            .unwrap()
+            .resolve_at(base.clone())
+            .unwrap();
+        assert_eq!(result.strftime("%F %T").to_string(), "2025-06-16 09:00:00");
+
+        let config = Parser::new().relative_unit("fortnight", fortnight);
+        let result = config
+            .parse("09:00")
+            .unwrap()
+            .merge(&input.parse("next fortnight").unwrap())
+            .unwrap()
+            .resolve_at(base)
+            .unwrap();
+        assert_eq!(result.strftime("%F %T").to_string(), "2025-06-29 09:00:00");
+
+        assert!(matches!(
+            config
+                .parse("09:00")
+                .unwrap()
+                .merge(&input.parse("next sprint").unwrap()),
+            Err(ParseDateTimeError::Resolve(_))
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn to_chrono_parsed() {
+        let options = Options::default();
+        let parse = |input| {
+            parse_deferred(input, &options)
+                .unwrap()
+                .to_chrono_parsed()
+                .unwrap()
+        };
+
+        let parsed = parse("2024-02-29 23:59:58.123456789 +05:30");
+        assert_eq!(
+            (parsed.year(), parsed.month(), parsed.day()),
+            (Some(2024), Some(2), Some(29))
+        );
+        assert_eq!(
+            (parsed.hour_div_12(), parsed.hour_mod_12(), parsed.minute()),
+            (Some(1), Some(11), Some(59))
+        );
+        assert_eq!(
+            (parsed.second(), parsed.nanosecond(), parsed.offset()),
+            (Some(58), Some(123456789), Some(19800))
+        );
+
+        let parsed = parse("feb 14");
+        assert_eq!(
+            (parsed.year(), parsed.month(), parsed.day()),
+            (None, Some(2), Some(14))
+        );
+        assert_eq!(parsed.hour_div_12(), None);
+
+        let parsed = parse("10:30 EST");
+        assert_eq!(parsed.offset(), Some(-5 * 3600));
+        let parsed = parse("10:30");
+        assert_eq!(parsed.offset(), None);
+
+        let parsed = parse("friday 2025-06-13");
+        assert_eq!(parsed.weekday(), Some(chrono::Weekday::Fri));
+        assert!(parsed.to_naive_date().is_ok());
+        let parsed = parse("saturday 2025-06-13");
+        assert!(parsed.to_naive_date().is_err());
+
+        let parsed = parse("@1700000000.5");
+        assert_eq!(
+            (parsed.timestamp(), parsed.nanosecond(), parsed.offset()),
+            (Some(1700000000), Some(500000000), Some(0))
+        );
+
+        for input in [
+            "tomorrow",
+            "2025-06-15 +1 day",
+            "friday",
+            "first monday of march",
+            "next month",
+            r#"TZ="Europe/Paris" 2025-06-15"#,
+        ] {
+            assert!(
+                matches!(
+                    parse_deferred(input, &options).unwrap().to_chrono_parsed(),
+                    Err(ParseDateTimeError::Resolve(_))
+                ),
+                "{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a whole-period item.
+//!
+//! With [`Parser::period_start`](crate::Parser::period_start), ‘last’, ‘this’
+//! or ‘next’ followed by ‘day’, ‘week’, ‘month’ or ‘year’ (e.g., ‘next month’
+//! or ‘last week’) resolves to the first day of that period, where GNU `date`
+//! shifts the date by the period. Weeks start on Monday.
+
+use jiff::{civil, Span};
+use winnow::{
+    ascii::alpha1,
+    combinator::{alt, not},
+    ModalResult, Parser,
+};
+
+use super::primitive::s;
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub(crate) enum Unit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl From<Unit> for jiff::Unit {
+    fn from(unit: Unit) -> Self {
+        match unit {
+            Unit::Day => jiff::Unit::Day,
+            Unit::Week => jiff::Unit::Week,
+            Unit::Month => jiff::Unit::Month,
+            Unit::Year => jiff::Unit::Year,
+        }
+    }
+}
+
+/// A period, counted from the one containing the base date.
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub(crate) struct Period {
+    pub(crate) offset: i32,
+    pub(crate) unit: Unit,
+}
+
+impl Period {
+    /// Resolve the period to its first day.
+    pub(super) fn resolve(&self, base: civil::Date) -> Result<civil::Date, &'static str> {
+        let start = match self.unit {
+            Unit::Day => Ok(base),
+            Unit::Week => {
+                base.checked_sub(Span::new().days(base.weekday().to_monday_zero_offset()))
+            }
+            Unit::Month => Ok(base.first_of_month()),
+            Unit::Year => Ok(base.first_of_year()),
+        };
+        let span = match self.unit {
+            Unit::Day => Span::new().try_days(self.offset),
+            Unit::Week => Span::new().try_weeks(self.offset),
+            Unit::Month => Span::new().try_months(self.offset),
+            Unit::Year => Span::new().try_years(self.offset),
+        };
+
+        start
+            .and_then(|start| start.checked_add(span?))
+            .map_err(|_| "period is out of range")
+    }
+}
+
+/// Parse a whole-period item, e.g., "next month".
+pub(super) fn parse(input: &mut &str) -> ModalResult<Period> {
+    (
+        alt((s("last").value(-1), s("this").value(0), s("next").value(1))),
+        s(alpha1).verify_map(|unit: &str| match unit {
+            "day" => Some(Unit::Day),
+            "week" => Some(Unit::Week),
+            "month" => Some(Unit::Month),
+            "year" => Some(Unit::Year),
+            _ => None,
+        }),
+        // "next month ago" is a relative item.
+        not(s("ago")),
+    )
+        .map(|(offset, unit, _)| Period { offset, unit })
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::civil::date;
+
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+    };
+
+    use super::{parse, Period, Unit};
+
+    #[test]
+    fn period() {
+        for (input, offset, unit) in [
+            ("next month", 1, Unit::Month),
+            ("last week", -1, Unit::Week),
+            ("this year", 0, Unit::Year),
+            ("next  day", 1, Unit::Day),
+        ] {
+            let mut s = input;
+            assert_eq!(parse(&mut s).unwrap(), Period { offset, unit }, "{input}");
+        }
+
+        for input in [
+            "next months",
+            "2 months",
+            "next hour",
+            "next fortnight",
+            "last week ago",
+        ] {
+            let mut s = input;
+            assert!(parse(&mut s).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn resolve() {
+        // A Thursday.
+        let base = date(2026, 10, 15);
+        for (offset, unit, expected) in [
+            (1, Unit::Day, date(2026, 10, 16)),
+            (0, Unit::Week, date(2026, 10, 12)),
+            (-1, Unit::Week, date(2026, 10, 5)),
+            (1, Unit::Week, date(2026, 10, 19)),
+            (1, Unit::Month, date(2026, 11, 1)),
+            (-1, Unit::Month, date(2026, 9, 1)),
+            (3, Unit::Month, date(2027, 1, 1)),
+            (-1, Unit::Year, date(2025, 1, 1)),
+        ] {
+            let period = Period { offset, unit };
+            assert_eq!(period.resolve(base).unwrap(), expected, "{period:?}");
+        }
+
+        let period = Period {
+            offset: 1,
+            unit: Unit::Year,
+        };
+        assert!(period.resolve(date(9999, 6, 1)).is_err());
+    }
+
+    #[test]
+    fn period_start() {
+        let options = Options {
+            period_start: true,
+            ..Default::default()
+        };
+        // A Thursday.
+        let now = at("2026-10-15 10:00:00");
+
+        for (input, expected) in [
+            ("next month", "2026-11-01 00:00:00"),
+            ("last month", "2026-09-01 00:00:00"),
+            ("this month", "2026-10-01 00:00:00"),
+            ("last week", "2026-10-05 00:00:00"),
+            ("next year", "2027-01-01 00:00:00"),
+            ("next day", "2026-10-16 00:00:00"),
+            ("next month 9am", "2026-11-01 09:00:00"),
+            // A weekday moves within the week.
+            ("next week friday", "2026-10-23 00:00:00"),
+            ("monday next week", "2026-10-19 00:00:00"),
+            // Other relative items are applied to the start of the period.
+            ("next month -1 day", "2026-10-31 00:00:00"),
+            // Shifts that are not a whole period are unchanged.
+            ("2 weeks", "2026-10-29 10:00:00"),
+            ("next week ago", "2026-10-08 10:00:00"),
+            ("next hour", "2026-10-15 11:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        assert!(parse_at_date(now.clone(), "2026-01-01 next month", &options).is_err());
+        let result = parse_at_date(now, "next month", &Options::default()).unwrap();
+        assert_eq!(
+            result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+            "2026-11-15 10:00:00"
+        );
+    }
+}
@@ -3,15 +3,16 @@
 
 //! Primitive combinators.
 
-use std::str::FromStr;
+use std::{borrow::Cow, cell::Cell, str::FromStr};
 
+use jiff::tz::Offset;
 use winnow::{
     ascii::{digit1, multispace0, Uint},
-    combinator::{alt, delimited, not, opt, peek, preceded, repeat, separated},
-    error::{ContextError, ParserError, StrContext, StrContextValue},
+    combinator::{alt, cut_err, not, opt, peek, preceded, repeat, separated, terminated},
+    error::{ContextError, ErrMode, ParserError, StrContext, StrContextValue},
     stream::AsChar,
-    token::{none_of, one_of, take_while},
-    Parser,
+    token::{one_of, take, take_while},
+    ModalResult, Parser,
 };
 
 /// Allow spaces and comments before a parser
@@ -26,6 +27,36 @@ where
     preceded(space, p)
 }
 
+thread_local! {
+    /// The number of steps left to the parser running on this thread, if it
+    /// has a budget.
+    static STEPS: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Run `f` with a budget of `steps`, or without a budget if it is `None`, and
+/// return its result along with whether the budget is exhausted.
+///
+/// A step is spent on each attempt to parse a token and on each byte of space
+/// skipped before it, so that retrying many alternatives on the same input is
+/// bounded. Once the budget is exhausted, every token fails to parse.
+pub(super) fn with_budget<T>(steps: Option<usize>, f: impl FnOnce() -> T) -> (T, bool) {
+    let outer = STEPS.with(|cell| cell.replace(steps));
+    let result = f();
+    let left = STEPS.with(|cell| cell.replace(outer));
+    (result, left == Some(0))
+}
+
+/// Spend `steps` of the budget, and return whether it was sufficient.
+fn spend(steps: usize) -> bool {
+    STEPS.with(|cell| match cell.get() {
+        None => true,
+        Some(left) => {
+            cell.set(Some(left.saturating_sub(steps)));
+            left >= steps
+        }
+    })
+}
+
 /// Parse the space in-between tokens
 ///
 /// You probably want to use the [`s`] combinator instead.
@@ -33,7 +64,15 @@ pub(super) fn space<'a, E>(input: &mut &'a str) -> winnow::Result<(), E>
 where
     E: ParserError<&'a str>,
 {
-    separated(0.., multispace0, alt((comment, ignored_hyphen_or_plus))).parse_next(input)
+    let len = input.len();
+    separated::<_, _, (), _, _, _, _>(0.., multispace0, alt((comment, ignored_hyphen_or_plus)))
+        .parse_next(input)?;
+
+    if spend(1 + len - input.len()) {
+        Ok(())
+    } else {
+        Err(E::from_input(input))
+    }
 }
 
 /// A hyphen or plus is ignored when it is not followed by a digit
@@ -62,19 +101,51 @@ where
 /// Parse a comment
 ///
 /// A comment is given between parentheses, which must be balanced. Any other
-/// tokens can be within the comment.
-fn comment<'a, E>(input: &mut &'a str) -> winnow::Result<(), E>
+/// tokens can be within the comment. As in RFC 822 and its successors, a
+/// backslash quotes the next character (e.g., "(a \) b)"), unless the comment
+/// is only balanced without quoting (e.g., "(C:\)"), as GNU `date` has it.
+pub(super) fn comment<'a, E>(input: &mut &'a str) -> winnow::Result<(), E>
 where
     E: ParserError<&'a str>,
 {
-    delimited(
-        '(',
-        repeat(0.., alt((none_of(['(', ')']).void(), comment))),
-        ')',
-    )
+    alt((
+        |i: &mut &'a str| balanced(i, true),
+        |i: &mut &'a str| balanced(i, false),
+    ))
     .parse_next(input)
 }
 
+/// Parse balanced parentheses and their content, with backslashes quoting the
+/// next character if `quoted_pairs` is set.
+///
+/// The content is scanned rather than parsed recursively, so that deeply
+/// nested comments do not exhaust the stack.
+fn balanced<'a, E>(input: &mut &'a str, quoted_pairs: bool) -> winnow::Result<(), E>
+where
+    E: ParserError<&'a str>,
+{
+    let mut depth = 0usize;
+    let mut chars = input.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    *input = &input[i + 1..];
+                    return Ok(());
+                }
+            }
+            _ if depth == 0 => break,
+            '\\' if quoted_pairs => {
+                chars.next();
+            }
+            _ => {}
+        }
+    }
+    Err(E::from_input(input))
+}
+
 /// Parse a signed decimal integer.
 ///
 /// Rationale for not using `winnow::ascii::dec_int`: When upgrading winnow from
@@ -136,9 +207,314 @@ where
     s(alt(('+', '-'))).parse_next(input)
 }
 
+/// The zero digits of the Unicode decimal digit ranges of common scripts. Each
+/// range holds the digits 0 to 9 in order.
+const UNICODE_ZEROS: [char; 18] = [
+    '\u{0660}', // Arabic-Indic
+    '\u{06F0}', // Extended Arabic-Indic
+    '\u{0966}', // Devanagari
+    '\u{09E6}', // Bengali
+    '\u{0A66}', // Gurmukhi
+    '\u{0AE6}', // Gujarati
+    '\u{0B66}', // Oriya
+    '\u{0BE6}', // Tamil
+    '\u{0C66}', // Telugu
+    '\u{0CE6}', // Kannada
+    '\u{0D66}', // Malayalam
+    '\u{0E50}', // Thai
+    '\u{0ED0}', // Lao
+    '\u{0F20}', // Tibetan
+    '\u{1040}', // Myanmar
+    '\u{17E0}', // Khmer
+    '\u{1810}', // Mongolian
+    '\u{FF10}', // Full-width
+];
+
+/// Replace the non-ASCII characters of `input` that have an ASCII equivalent
+/// in the grammar:
+///
+/// - decimal digits (e.g., Arabic-Indic, Devanagari or full-width digits) with
+///   ASCII digits,
+/// - whitespace (e.g., no-break spaces) with a space,
+/// - the minus sign (U+2212) with a hyphen-minus.
+pub(super) fn normalize(input: &str) -> Cow<'_, str> {
+    if input.is_ascii() {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(input.chars().map(ascii).collect())
+}
+
+/// Return the offset in `input` of the byte at `offset` in the normalized
+/// input.
+pub(super) fn original_offset(input: &str, offset: usize) -> usize {
+    let mut normalized = 0;
+    for (i, c) in input.char_indices() {
+        if normalized >= offset {
+            return i;
+        }
+        normalized += ascii(c).len_utf8();
+    }
+    input.len()
+}
+
+/// Return the ASCII equivalent of `c` in the grammar, or `c` itself if it
+/// has none.
+fn ascii(c: char) -> char {
+    if c.is_ascii() {
+        return c;
+    } else if c == '\u{2212}' {
+        return '-';
+    } else if c.is_whitespace() {
+        return ' ';
+    }
+
+    UNICODE_ZEROS
+        .iter()
+        .find_map(|&zero| {
+            let d = (c as u32).checked_sub(zero as u32)?;
+            (d < 10).then(|| char::from(b'0' + d as u8))
+        })
+        .unwrap_or(c)
+}
+
+/// Parse a double-quoted string, in which quotes and backslashes are escaped
+/// by a backslash (e.g., `"a\"b"` is `a"b`), and return its unescaped
+/// content.
+///
+/// Once the opening quote is parsed, a backslash followed by another character
+/// or a missing closing quote is an error.
+pub(super) fn escaped_string(input: &mut &str) -> ModalResult<String> {
+    let content = repeat(
+        0..,
+        alt((
+            take_while(1.., |c| c != '"' && c != '\\'),
+            preceded(
+                '\\',
+                cut_err(alt(("\\", "\"")).context(StrContext::Expected(
+                    StrContextValue::Description("escaped quote or backslash"),
+                ))),
+            ),
+        )),
+    )
+    .fold(String::new, |mut acc, part: &str| {
+        acc.push_str(part);
+        acc
+    });
+
+    preceded(
+        '"',
+        cut_err(terminated(
+            content,
+            '"'.context(StrContext::Expected(StrContextValue::Description(
+                "closing quote",
+            ))),
+        )),
+    )
+    .parse_next(input)
+}
+
+/// Parse exactly `n` decimal digits.
+pub(super) fn digits<'a>(n: usize) -> impl Parser<&'a str, u16, ErrMode<ContextError>> {
+    take(n)
+        .verify(|s: &str| s.bytes().all(|b| b.is_ascii_digit()))
+        .try_map(str::parse)
+}
+
+/// The value of a fraction, given by its digits, of a unit of `unit`
+/// nanoseconds. Any precision beyond a nanosecond is dropped.
+pub(super) fn fraction_of(digits: &str, unit: i128) -> i128 {
+    // Digits beyond the 18th cannot add a nanosecond to an hour.
+    let digits = &digits[..digits.len().min(18)];
+    let value: i128 = digits.parse().unwrap_or(0);
+    value * unit / 10_i128.pow(digits.len() as u32)
+}
+
+/// Build the offset from UTC given by a sign, '+' or '-', and a number of
+/// hours and minutes.
+pub(super) fn fixed_offset(sign: char, hours: u16, minutes: u16) -> Result<Offset, jiff::Error> {
+    let seconds = i32::from(hours) * 3600 + i32::from(minutes) * 60;
+    Offset::from_seconds(if sign == '-' { -seconds } else { seconds })
+}
+
+/// The abbreviated names of the months, as the RFCs of email and HTTP give
+/// them.
+pub(super) const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The abbreviated names of the days of the week, from Monday.
+pub(super) const DAY_NAMES: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+/// Parse a name of `names`, and return its position, counted from 1.
+///
+/// If `caseless` is set, the name is matched regardless of case and may be
+/// given in full (e.g., "october" for "Oct"); otherwise it must be given
+/// exactly.
+pub(super) fn name<'a>(
+    names: &'static [&'static str],
+    caseless: bool,
+) -> impl Parser<&'a str, u16, ErrMode<ContextError>> {
+    move |input: &mut &'a str| {
+        let word = if caseless {
+            let len = input
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(input.len());
+            &input[..len]
+        } else {
+            *input
+        };
+        for (i, name) in names.iter().enumerate() {
+            let matched = if caseless {
+                word.len() >= name.len() && word[..name.len()].eq_ignore_ascii_case(name)
+            } else {
+                word.starts_with(name)
+            };
+            if matched {
+                *input = &input[if caseless { word.len() } else { name.len() }..];
+                return Ok(i as u16 + 1);
+            }
+        }
+        Err(ErrMode::Backtrack(ContextError::new()))
+    }
+}
+
 /// Create a context error with a reason.
+///
+/// The reason is stored in an allocated context, so this should only be
+/// called lazily (e.g., in `map_err` or `ok_or_else`) for errors that are
+/// returned to the caller. A backtrack, which may be discarded by `alt` or
+/// `opt`, should carry an empty `ContextError` instead.
 pub(super) fn ctx_err(reason: &'static str) -> ContextError {
     let mut err = ContextError::new();
     err.push(StrContext::Expected(StrContextValue::Description(reason)));
     err
 }
+
+#[cfg(test)]
+mod tests {
+    use winnow::{error::ErrMode, Parser};
+
+    use super::{escaped_string, normalize, original_offset, s, with_budget};
+
+    #[test]
+    fn comments() {
+        let parse = |input: &str| {
+            let mut input = input;
+            let result: winnow::Result<_> = s("x").parse_next(&mut input);
+            result.is_ok()
+        };
+
+        for input in [
+            "(comment) x",
+            "(a (nested) comment)x",
+            "(a) (b)\t(c) x",
+            "(a \\) b) x",
+            "(a \\( b) x",
+            "(C:\\) x",
+            "(a \\\\) x",
+        ] {
+            assert!(parse(input), "{input}");
+        }
+        for input in ["(unclosed x", "(a (b) x", ") x", "(a \\) (b x"] {
+            assert!(!parse(input), "{input}");
+        }
+
+        let nested = format!("{}{} x", "(".repeat(100_000), ")".repeat(100_000));
+        assert!(parse(&nested));
+    }
+
+    #[test]
+    fn budget() {
+        let parse = |input: &str| {
+            let mut input = input;
+            let result: winnow::Result<_> = s("x").parse_next(&mut input);
+            result.is_ok()
+        };
+
+        assert_eq!(with_budget(None, || parse("  (comment) x")), (true, false));
+        assert_eq!(
+            with_budget(Some(100), || parse("  (comment) x")),
+            (true, false)
+        );
+        assert_eq!(
+            with_budget(Some(5), || parse("  (comment) x")),
+            (false, true)
+        );
+        assert_eq!(with_budget(Some(1), || parse("x")), (true, true));
+
+        // The budget is only set for the duration of the call.
+        assert_eq!(with_budget(Some(0), || ()), ((), true));
+        assert!(parse("  (comment) x"));
+    }
+
+    #[test]
+    fn escaped_strings() {
+        for (input, expected, rest) in [
+            (r#""""#, "", ""),
+            (r#""abc" def"#, "abc", " def"),
+            (r#""a\"b""#, "a\"b", ""),
+            (r#""a\\b""#, "a\\b", ""),
+            (r#""\\\"""#, "\\\"", ""),
+        ] {
+            let mut s = input;
+            assert_eq!(escaped_string(&mut s).unwrap(), expected, "{input}");
+            assert_eq!(s, rest, "{input}");
+        }
+
+        for (input, reason) in [
+            (r#""abc"#, "closing quote"),
+            (r#"""#, "closing quote"),
+            (r#""abc\""#, "closing quote"),
+            (r#""a\/b""#, "escaped quote or backslash"),
+        ] {
+            let mut s = input;
+            let err = escaped_string(&mut s).unwrap_err();
+            assert!(matches!(err, ErrMode::Cut(_)), "{input}");
+            assert!(err.to_string().contains(reason), "{input}: {err}");
+        }
+
+        // A string must start with a quote.
+        let mut s = "abc";
+        assert!(matches!(escaped_string(&mut s), Err(ErrMode::Backtrack(_))));
+    }
+
+    #[test]
+    fn unicode_digits() {
+        for (input, expected) in [
+            ("2024-05-01", "2024-05-01"),
+            ("٢٠٢٤-٠٥-٠١", "2024-05-01"),
+            ("۱۴۰۳/۰۱/۱۵", "1403/01/15"),
+            ("२०२४-०५-०१", "2024-05-01"),
+            ("２０２４年５月１日", "2024年5月1日"),
+            ("๑๐:๓๐", "10:30"),
+        ] {
+            assert_eq!(normalize(input), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn original_offsets() {
+        let input = "٢٠٢٤-٠٥-٠١ foo";
+        let normalized = normalize(input);
+        let offset = normalized.find("foo").unwrap();
+        assert_eq!(offset, 11);
+        assert_eq!(&input[original_offset(input, offset)..], "foo");
+        assert_eq!(original_offset(input, normalized.len()), input.len());
+        assert_eq!(original_offset("abc", 2), 2);
+    }
+
+    #[test]
+    fn unicode_spaces_and_minus() {
+        for (input, expected) in [
+            ("10:00\u{00A0}pm", "10:00 pm"),
+            ("10:00\u{202F}pm", "10:00 pm"),
+            ("2024-05-01\u{3000}10:00", "2024-05-01 10:00"),
+            ("\u{2212}3 days", "-3 days"),
+            ("10:00 utc\u{2212}05:00", "10:00 utc-05:00"),
+            ("令和6年", "令和6年"),
+        ] {
+            assert_eq!(normalize(input), expected, "{input}");
+        }
+    }
+}
@@ -0,0 +1,288 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+// spell-checker:ignore multispace0
+
+//! Parse the date of an email `Received:` header field.
+//!
+//! RFC 5322 (section 3.6.7) ends a `Received:` field with a semicolon and a
+//! date and time:
+//!
+//! ```abnf
+//! date-time   = [ day-of-week "," ] date time [CFWS]
+//! date        = day month year
+//! time        = time-of-day zone
+//! time-of-day = hour ":" minute [ ":" second ]
+//! zone        = (FWS ( "+" / "-" ) 4DIGIT) / obs-zone
+//! ```
+//!
+//! Mail servers have long written this date in many variants, which are all
+//! accepted:
+//!
+//! - The text up to the last semicolon is ignored, so that the whole field
+//!   value may be given.
+//! - Comments, which may be nested and are closed by the end of the input,
+//!   are ignored (e.g., "-0400 (EDT)").
+//! - Any amount of whitespace, including folded lines, separates the parts,
+//!   and the comma after the day of the week is optional.
+//! - The names of the days and months are case-insensitive and may be given
+//!   in full, and the month may precede the day, with the year after the time
+//!   (e.g., "Tue Oct  3 10:20:30 2023", as `asctime` writes it).
+//! - The seconds may be missing, and may have a fraction.
+//! - A two-digit year is in 2000 to 2049 or 1950 to 1999, and a three-digit
+//!   year is counted from 1900, as RFC 5322 (section 4.3) specifies.
+//! - The zone is a numeric offset, with or without a colon, or an obsolete
+//!   zone name ("UT", "GMT", "EST", "EDT", "CST", "CDT", "MST", "MDT", "PST"
+//!   or "PDT"). Other names, including military zones, are considered
+//!   equivalent to "-0000" (i.e., UTC), as RFC 5322 (section 4.3) specifies,
+//!   and so is a missing zone. A name after an offset is ignored.
+
+use jiff::{
+    civil,
+    tz::{Offset, TimeZone},
+};
+use winnow::{
+    ascii::{multispace0, multispace1},
+    combinator::{alt, eof, opt, preceded, terminated},
+    error::{ContextError, ErrMode, FromExternalError},
+    stream::AsChar,
+    token::{one_of, take_while},
+    ModalResult, Parser,
+};
+
+use super::{
+    error::Error,
+    primitive::{comment, fixed_offset, name, DAY_NAMES, MONTH_NAMES},
+};
+
+/// The obsolete zone names of RFC 822 that stand for an offset, in hours.
+const ZONES: [(&str, i8); 10] = [
+    ("ut", 0),
+    ("gmt", 0),
+    ("est", -5),
+    ("edt", -4),
+    ("cst", -6),
+    ("cdt", -5),
+    ("mst", -7),
+    ("mdt", -6),
+    ("pst", -8),
+    ("pdt", -7),
+];
+
+/// Remove the text up to the last semicolon and the comments of a
+/// `Received:` field, replacing each comment with a space.
+pub(super) fn clean(input: &str) -> String {
+    let mut rest = input.rsplit(';').next().unwrap_or(input);
+    let mut cleaned = String::with_capacity(rest.len());
+    while let Some(i) = rest.find('(') {
+        cleaned.push_str(&rest[..i]);
+        cleaned.push(' ');
+        rest = &rest[i..];
+        if comment::<ContextError>(&mut rest).is_err() {
+            // A comment left open is closed by the end of the field.
+            rest = "";
+        }
+    }
+    cleaned.push_str(rest);
+    cleaned
+}
+
+/// Parse the date of a `Received:` field, cleaned by [`clean`], as its civil
+/// date and time and the timezone of its offset.
+pub(super) fn parse(input: &mut &str) -> ModalResult<(civil::DateTime, TimeZone)> {
+    (
+        multispace0,
+        opt((name(&DAY_NAMES, true), multispace0, opt(','), multispace0)),
+    )
+        .void()
+        .parse_next(input)?;
+    let (year, month, day, time, tz) = alt((
+        (
+            terminated(number(1, 2), multispace0),
+            terminated(name(&MONTH_NAMES, true), multispace0),
+            terminated(number(2, 4), multispace1),
+            time,
+            zone,
+        )
+            .map(|(day, month, year, time, tz)| (year, month, day, time, tz)),
+        (
+            terminated(name(&MONTH_NAMES, true), multispace0),
+            terminated(number(1, 2), multispace1),
+            time,
+            zone,
+            preceded(multispace0, number(4, 4)),
+        )
+            .map(|(month, day, time, tz, year)| (year, month, day, time, tz)),
+    ))
+    .parse_next(input)?;
+    (multispace0, eof).parse_next(input)?;
+
+    let year = match year {
+        0..=49 => year + 2000,
+        50..=999 => year + 1900,
+        _ => year,
+    };
+    let date = civil::Date::new(year as i16, month as i8, day as i8).map_err(|_| {
+        ErrMode::Cut(ContextError::from_external_error(
+            input,
+            Error::Resolve("date is not valid".to_owned()),
+        ))
+    })?;
+    Ok((date.to_datetime(time), tz))
+}
+
+/// Parse a time of day, with optional seconds and fraction of a second.
+fn time(input: &mut &str) -> ModalResult<civil::Time> {
+    let (hour, _, minute, second) = (
+        number(1, 2).verify(|h| *h < 24),
+        ':',
+        number(2, 2).verify(|m| *m < 60),
+        opt(preceded(
+            ':',
+            (
+                number(2, 2).verify(|s| *s < 60),
+                opt(preceded(
+                    one_of(['.', ',']),
+                    take_while(1.., AsChar::is_dec_digit),
+                )),
+            ),
+        )),
+    )
+        .parse_next(input)?;
+    let (second, fraction) = second.unwrap_or((0, None));
+    let fraction = fraction.unwrap_or("");
+    let fraction = &fraction[..fraction.len().min(9)];
+    let nanosecond = format!("{fraction:0<9}").parse().unwrap_or(0);
+    Ok(civil::time(
+        hour as i8,
+        minute as i8,
+        second as i8,
+        nanosecond,
+    ))
+}
+
+/// Parse a zone, numeric or obsolete, or no zone at all. A numeric zone may be
+/// followed by a name of up to five letters.
+fn zone(input: &mut &str) -> ModalResult<TimeZone> {
+    let numeric = (
+        one_of(['+', '-']),
+        number(2, 2).verify(|h| *h < 24),
+        opt(':'),
+        number(2, 2).verify(|m| *m < 60),
+    )
+        .try_map(|(sign, hours, _, minutes)| {
+            fixed_offset(sign, hours, minutes).map(TimeZone::fixed)
+        });
+    let named = take_while(1..=5, AsChar::is_alpha).map(|name: &str| {
+        ZONES
+            .iter()
+            .find_map(|(zone, hours)| {
+                zone.eq_ignore_ascii_case(name)
+                    .then(|| TimeZone::fixed(Offset::constant(*hours)))
+            })
+            .unwrap_or(TimeZone::UTC)
+    });
+    alt((
+        terminated(
+            preceded(multispace0, numeric),
+            opt(preceded(multispace1, take_while(1..=5, AsChar::is_alpha))),
+        ),
+        preceded(multispace1, named),
+        multispace0.value(TimeZone::UTC),
+    ))
+    .parse_next(input)
+}
+
+/// Parse from `min` to `max` decimal digits.
+fn number<'a>(min: usize, max: usize) -> impl Parser<&'a str, u16, ErrMode<ContextError>> {
+    take_while(min..=max, AsChar::is_dec_digit).try_map(str::parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clean, parse};
+
+    fn received(input: &str) -> Option<String> {
+        let (datetime, tz) = parse(&mut clean(input).as_str()).ok()?;
+        let zoned = datetime.to_zoned(tz).ok()?;
+        Some(
+            zoned
+                .timestamp()
+                .display_with_offset(zoned.offset())
+                .to_string(),
+        )
+    }
+
+    #[test]
+    fn valid() {
+        for (input, expected) in [
+            (
+                "Tue, 3 Oct 2023 10:20:30 -0400",
+                "2023-10-03T10:20:30-04:00",
+            ),
+            (
+                "from mx.example.com by mail.example.org; Tue, 3 Oct 2023 10:20:30 -0400 (EDT)",
+                "2023-10-03T10:20:30-04:00",
+            ),
+            (
+                "Tue,  3   Oct 2023\r\n\t10:20 -0400",
+                "2023-10-03T10:20:00-04:00",
+            ),
+            (
+                "Tue 03 Oct 2023 10:20:30 +0200",
+                "2023-10-03T10:20:30+02:00",
+            ),
+            ("3 Oct 2023 10:20:30 +02:00", "2023-10-03T10:20:30+02:00"),
+            ("3 Oct 2023 10:20:30 -2359", "2023-10-03T10:20:30-23:59"),
+            (
+                "tuesday, 3 october 2023 10:20:30 EDT",
+                "2023-10-03T10:20:30-04:00",
+            ),
+            ("3 Oct 23 10:20:30 PST", "2023-10-03T10:20:30-08:00"),
+            ("3 Oct 99 10:20:30 GMT", "1999-10-03T10:20:30+00:00"),
+            ("3 Oct 123 10:20:30 UT", "2023-10-03T10:20:30+00:00"),
+            ("3 Oct 2023 10:20:30 A", "2023-10-03T10:20:30+00:00"),
+            ("3 Oct 2023 10:20:30 CEST", "2023-10-03T10:20:30+00:00"),
+            ("3 Oct 2023 10:20:30", "2023-10-03T10:20:30+00:00"),
+            ("3 Oct 2023 10:20:30 +0000 GMT", "2023-10-03T10:20:30+00:00"),
+            (
+                "3 Oct 2023 10:20:30.25 -0000",
+                "2023-10-03T10:20:30.25+00:00",
+            ),
+            ("Tue Oct  3 10:20:30 2023", "2023-10-03T10:20:30+00:00"),
+            ("Tue Oct 3 10:20:30 EDT 2023", "2023-10-03T10:20:30-04:00"),
+            (
+                "Tue, 3 Oct 2023 10:20:30 -0400 (EDT (nested \\) comment)) ",
+                "2023-10-03T10:20:30-04:00",
+            ),
+            (
+                "Tue, 3 Oct 2023 10:20:30 -0400 (unclosed",
+                "2023-10-03T10:20:30-04:00",
+            ),
+            (
+                "(comment) Tue, 3 Oct (comment) 2023 10:20:30 -0400",
+                "2023-10-03T10:20:30-04:00",
+            ),
+        ] {
+            assert_eq!(received(input).as_deref(), Some(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "",
+            "from mx.example.com by mail.example.org;",
+            "Tue, 3 Oct 2023",
+            "Tue, 3 Foo 2023 10:20:30 -0400",
+            "Tue, 3 Oct 2023 25:20:30 -0400",
+            "Tue, 3 Oct 2023 10:20:30 -04",
+            "Tue, 3 Oct 2023 10:20:30 -0400 tomorrow",
+            "Tue, 31 Nov 2023 10:20:30 -0400",
+            "2023-10-03T10:20:30Z",
+            "Tue, 3 Oct 2023 10:20:30 -0400; Wed, 4 Oct 2023",
+        ] {
+            assert_eq!(received(input), None, "{input}");
+        }
+    }
+}
@@ -30,14 +30,47 @@
 //! > items, like in ‘12:00 today’. The string ‘this’ also has the meaning of a
 //! > zero-valued time displacement, but is preferred in date strings like
 //! > ‘this thursday’.
+//!
+//! In addition to the GNU units, the abbreviations ‘yr’, ‘mo’, ‘wk’ and ‘hr’
+//! (with an optional ‘s’ suffix) are accepted for years, months, weeks and
+//! hours. ‘hr’ and ‘hrs’ need a number, other than one of four digits, as
+//! ‘1700 hrs’ is a time of day when natural language is enabled. If
+//! configured, ‘m’ is accepted after a number for either minutes or months;
+//! otherwise, it is the military timezone M.
+//!
+//! A number of business days may be given as
+//! ‘business day’, ‘working day’ or ‘workday’ (e.g., ‘+3 business days’ or
+//! ‘2 workdays ago’). Business days skip weekends and, if a holiday calendar
+//! is configured, holidays. A number of weekdays (e.g., ‘+5 weekdays’) only
+//! skips weekends, whether or not a holiday calendar is configured.
+//!
+//! When compact durations are enabled, a duration may also be given in the
+//! notation of Go's `time.Duration` and Prometheus, i.e., a sequence of
+//! numbers with a unit and without spaces (e.g., ‘5m’, ‘2h45m’ or ‘1.5h’). The
+//! units are ‘ns’, ‘us’ (or ‘µs’), ‘ms’, ‘s’, ‘m’, ‘h’, ‘d’ (24 hours) and
+//! ‘w’ (7 days).
+//!
+//! Otherwise, several relative items may be combined into a single token, as
+//! in `journalctl` (e.g., ‘+1d2h30m’ or ‘1w2d ago’). Each number is an integer
+//! followed by one of the units ‘y’, ‘mo’, ‘w’, ‘d’, ‘h’, ‘m’ and ‘s’, and
+//! stands for a separate relative item. A token needs at least two
+//! components, as ‘5m’ is a time in the military timezone M, and ‘+1h’ an
+//! offset followed by the military timezone H.
 
 use winnow::{
     ascii::alpha1,
-    combinator::{alt, opt},
+    combinator::{alt, not, opt, peek, repeat, separated},
+    token::one_of,
     ModalResult, Parser,
 };
 
-use super::{epoch::sec_and_nsec, ordinal::ordinal, primitive::s};
+use crate::{MUnit, RelativeUnit};
+
+use super::{
+    epoch::sec_and_nsec,
+    ordinal::ordinal,
+    primitive::{dec_uint, s},
+};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub(crate) enum Relative {
@@ -47,6 +80,64 @@ pub(crate) enum Relative {
     Hours(i32),
     Minutes(i32),
     Seconds(i64, u32),
+    BusinessDays(i32),
+    Weekdays(i32),
+    /// A number of custom units, given by their index among the registered
+    /// units.
+    Custom(usize, i32),
+}
+
+impl Relative {
+    /// The unit of the relative item, if it moves the date (e.g., "now" does
+    /// not). Custom units have none.
+    pub(super) fn unit(&self) -> Option<jiff::Unit> {
+        match *self {
+            Relative::Years(n) if n != 0 => Some(jiff::Unit::Year),
+            Relative::Months(n) if n != 0 => Some(jiff::Unit::Month),
+            Relative::Days(n) | Relative::BusinessDays(n) | Relative::Weekdays(n) if n != 0 => {
+                Some(jiff::Unit::Day)
+            }
+            Relative::Hours(n) if n != 0 => Some(jiff::Unit::Hour),
+            Relative::Minutes(n) if n != 0 => Some(jiff::Unit::Minute),
+            Relative::Seconds(_, nanos) if nanos != 0 => Some(jiff::Unit::Nanosecond),
+            Relative::Seconds(n, _) if n != 0 => Some(jiff::Unit::Second),
+            _ => None,
+        }
+    }
+
+    /// Whether the relative item moves backward in time.
+    pub(super) fn is_negative(&self) -> bool {
+        match *self {
+            Relative::Years(n)
+            | Relative::Months(n)
+            | Relative::Days(n)
+            | Relative::Hours(n)
+            | Relative::Minutes(n)
+            | Relative::BusinessDays(n)
+            | Relative::Weekdays(n)
+            | Relative::Custom(_, n) => n < 0,
+            Relative::Seconds(n, _) => n < 0,
+        }
+    }
+
+    /// Return the relative item in the opposite direction, or `None` on
+    /// overflow.
+    fn negate(self) -> Option<Self> {
+        Some(match self {
+            Relative::Years(n) => Relative::Years(n.checked_neg()?),
+            Relative::Months(n) => Relative::Months(n.checked_neg()?),
+            Relative::Days(n) => Relative::Days(n.checked_neg()?),
+            Relative::Hours(n) => Relative::Hours(n.checked_neg()?),
+            Relative::Minutes(n) => Relative::Minutes(n.checked_neg()?),
+            Relative::Seconds(n, 0) => Relative::Seconds(n.checked_neg()?, 0),
+            Relative::Seconds(n, nanos) => {
+                Relative::Seconds(n.checked_neg()?.checked_sub(1)?, 1_000_000_000 - nanos)
+            }
+            Relative::BusinessDays(n) => Relative::BusinessDays(n.checked_neg()?),
+            Relative::Weekdays(n) => Relative::Weekdays(n.checked_neg()?),
+            Relative::Custom(unit, n) => Relative::Custom(unit, n.checked_neg()?),
+        })
+    }
 }
 
 impl TryFrom<Relative> for jiff::Span {
@@ -59,9 +150,21 @@ impl TryFrom<Relative> for jiff::Span {
             Relative::Days(days) => jiff::Span::new().try_days(days),
             Relative::Hours(hours) => jiff::Span::new().try_hours(hours),
             Relative::Minutes(minutes) => jiff::Span::new().try_minutes(minutes),
+            // All the units of a span have the same sign, so that a negative
+            // number of seconds takes a negative fraction, e.g., -2 seconds
+            // and 500,000,000 nanoseconds is -1 second and -500,000,000
+            // nanoseconds.
+            Relative::Seconds(seconds, nanoseconds) if seconds < 0 && nanoseconds != 0 => {
+                jiff::Span::new()
+                    .try_seconds(seconds + 1)
+                    .and_then(|span| span.try_nanoseconds(i64::from(nanoseconds) - 1_000_000_000))
+            }
             Relative::Seconds(seconds, nanoseconds) => jiff::Span::new()
                 .try_seconds(seconds)
                 .and_then(|span| span.try_nanoseconds(nanoseconds)),
+            Relative::BusinessDays(_) => return Err("business days cannot be converted to a span"),
+            Relative::Weekdays(_) => return Err("weekdays cannot be converted to a span"),
+            Relative::Custom(..) => return Err("custom units cannot be converted to a span"),
         }
         .map_err(|_| "relative value is invalid")
     }
@@ -75,15 +178,42 @@ pub(super) fn parse(input: &mut &str) -> ModalResult<Relative> {
         s("today").value(Relative::Days(0)),
         s("now").value(Relative::Days(0)),
         seconds,
+        business_days,
         displacement,
     ))
     .parse_next(input)
 }
 
-fn seconds(input: &mut &str) -> ModalResult<Relative> {
+fn business_days(input: &mut &str) -> ModalResult<Relative> {
+    let day = s(alpha1).verify(|s: &str| matches!(s, "day" | "days"));
+    (
+        opt(ordinal),
+        alt((
+            (alt((s("business"), s("working"))), day).value(false),
+            s(alpha1)
+                .verify(|s: &str| matches!(s, "workday" | "workdays"))
+                .value(false),
+            s(alpha1)
+                .verify(|s: &str| matches!(s, "weekday" | "weekdays"))
+                .value(true),
+        )),
+        ago,
+    )
+        .map(|(n, weekdays, ago): (Option<i32>, bool, bool)| {
+            let n = n.unwrap_or(1) * if ago { -1 } else { 1 };
+            if weekdays {
+                Relative::Weekdays(n)
+            } else {
+                Relative::BusinessDays(n)
+            }
+        })
+        .parse_next(input)
+}
+
+pub(super) fn seconds(input: &mut &str) -> ModalResult<Relative> {
     (
         opt(alt((s('+').value(1), s('-').value(-1)))),
-        sec_and_nsec,
+        s(sec_and_nsec),
         s(alpha1).verify(|s: &str| matches!(s, "seconds" | "second" | "sec" | "secs")),
         ago,
     )
@@ -101,21 +231,177 @@ fn seconds(input: &mut &str) -> ModalResult<Relative> {
         .parse_next(input)
 }
 
-fn displacement(input: &mut &str) -> ModalResult<Relative> {
+/// Parse a compact duration, e.g., `2h45m` or `-1.5h`.
+pub(super) fn compact_duration(input: &mut &str) -> ModalResult<Relative> {
+    const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+    let component = (
+        sec_and_nsec,
+        alt((
+            "ns".value(1),
+            alt(("us", "µs", "μs")).value(1_000),
+            "ms".value(1_000_000),
+            "s".value(NANOS_PER_SEC),
+            "m".value(60 * NANOS_PER_SEC),
+            "h".value(3_600 * NANOS_PER_SEC),
+            "d".value(86_400 * NANOS_PER_SEC),
+            "w".value(604_800 * NANOS_PER_SEC),
+        )),
+    )
+        .map(|((whole, fraction), unit): ((u64, u32), i128)| {
+            // The fraction is given in billionths of the unit.
+            i128::from(whole) * unit + i128::from(fraction) * unit / NANOS_PER_SEC
+        });
+
+    (
+        s(opt(one_of(['+', '-']))),
+        repeat(1.., component).fold(
+            || Some(0),
+            |total: Option<i128>, nanos| total?.checked_add(nanos),
+        ),
+        // The unit must not be the prefix of a word, e.g., "5 min".
+        peek(not(one_of(|c: char| c.is_alphabetic()))),
+        ago,
+    )
+        .verify_map(|(sign, nanos, _, ago)| {
+            let nanos = nanos?;
+            let negative = (sign == Some('-')) != ago;
+            let nanos = if negative { -nanos } else { nanos };
+            Some(Relative::Seconds(
+                i64::try_from(nanos.div_euclid(NANOS_PER_SEC)).ok()?,
+                nanos.rem_euclid(NANOS_PER_SEC) as u32,
+            ))
+        })
+        .parse_next(input)
+}
+
+/// Parse a combined relative shorthand, e.g., `+1d2h30m` or `1w2d ago`, into
+/// one relative item per component.
+pub(super) fn shorthand(input: &mut &str) -> ModalResult<Vec<Relative>> {
+    let component = (dec_uint, alt(("y", "mo", "w", "d", "h", "m", "s"))).verify_map(
+        |(n, unit): (u32, &str)| {
+            let n = i32::try_from(n).ok()?;
+            Some(match unit {
+                "y" => Relative::Years(n),
+                "mo" => Relative::Months(n),
+                "w" => Relative::Days(n.checked_mul(7)?),
+                "d" => Relative::Days(n),
+                "h" => Relative::Hours(n),
+                "m" => Relative::Minutes(n),
+                _ => Relative::Seconds(n.into(), 0),
+            })
+        },
+    );
+
+    (
+        s(opt(one_of(['+', '-']))),
+        repeat(1.., component),
+        // The unit must not be the prefix of a word, e.g., "2h 5 min".
+        peek(not(one_of(|c: char| c.is_alphanumeric()))),
+        ago,
+    )
+        .verify_map(|(sign, items, _, ago): (_, Vec<Relative>, _, _)| {
+            if items.len() < 2 {
+                return None;
+            }
+            if (sign == Some('-')) == ago {
+                return Some(items);
+            }
+            items.into_iter().map(Relative::negate).collect()
+        })
+        .parse_next(input)
+}
+
+pub(super) fn displacement(input: &mut &str) -> ModalResult<Relative> {
+    (opt(ordinal).with_taken(), s(alpha1), ago)
+        .verify_map(
+            |((n, number), unit, ago): ((Option<i32>, &str), &str, bool)| {
+                // A four-digit number followed by "hrs" is a time of day in
+                // operational logs (e.g., "1700 hrs"), and neither 1700 hours
+                // nor the time 17:00 followed by an hour.
+                if matches!(unit, "hr" | "hrs") && !ago && (n.is_none() || is_military_time(number))
+                {
+                    return None;
+                }
+                let multiplier = n.unwrap_or(1) * if ago { -1 } else { 1 };
+                Some(match unit.strip_suffix('s').unwrap_or(unit) {
+                    "year" | "yr" => Relative::Years(multiplier),
+                    "month" | "mo" => Relative::Months(multiplier),
+                    "fortnight" => Relative::Days(multiplier.checked_mul(14)?),
+                    "week" | "wk" => Relative::Days(multiplier.checked_mul(7)?),
+                    "day" => Relative::Days(multiplier),
+                    "hour" | "hr" => Relative::Hours(multiplier),
+                    "minute" | "min" => Relative::Minutes(multiplier),
+                    "second" | "sec" => Relative::Seconds(multiplier as i64, 0),
+                    _ => return None,
+                })
+            },
+        )
+        .parse_next(input)
+}
+
+/// Whether `number` is four digits without a sign, as the time of day of
+/// military phrasing (e.g., "1700 hrs").
+fn is_military_time(number: &str) -> bool {
+    let number = number.trim();
+    number.len() == 4 && number.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parse relative items joined by "and", e.g., `1 hour and 30 minutes ago`.
+///
+/// A trailing "ago" applies to the whole chain, unlike a sequence of relative
+/// items without "and" (e.g., `1 hour 30 minutes ago` is 30 minutes from now).
+pub(super) fn and_chain(input: &mut &str) -> ModalResult<Vec<Relative>> {
+    // Check for "and" before collecting the items, so that a relative item
+    // without "and" does not allocate.
+    peek((parse, s("and"))).parse_next(input)?;
+
+    separated(2.., parse.with_taken(), s("and"))
+        .verify_map(|items: Vec<(Relative, &str)>| {
+            let in_past = items.last()?.1.trim_end().ends_with("ago");
+            items
+                .into_iter()
+                .map(|(item, taken)| {
+                    if in_past && !taken.trim_end().ends_with("ago") {
+                        item.negate()
+                    } else {
+                        Some(item)
+                    }
+                })
+                .collect()
+        })
+        .parse_next(input)
+}
+
+/// Parse a number of minutes or months with the unit "m", e.g., "5 m".
+pub(super) fn m_unit(input: &mut &str, unit: MUnit) -> ModalResult<Relative> {
+    (ordinal, s(alpha1).verify(|s: &str| s == "m"), ago)
+        .map(|(n, _, ago)| {
+            let n = n * if ago { -1 } else { 1 };
+            match unit {
+                MUnit::Minute => Relative::Minutes(n),
+                MUnit::Month => Relative::Months(n),
+            }
+        })
+        .parse_next(input)
+}
+
+/// Parse a number of custom units, e.g., "2 sprints" or "last sprint", and
+/// return their index among `units`. An "s" suffix on the unit is accepted and
+/// ignored.
+pub(super) fn custom(
+    input: &mut &str,
+    units: &[(String, Box<dyn RelativeUnit>)],
+) -> ModalResult<Relative> {
     (opt(ordinal), s(alpha1), ago)
         .verify_map(|(n, unit, ago): (Option<i32>, &str, bool)| {
-            let multiplier = n.unwrap_or(1) * if ago { -1 } else { 1 };
-            Some(match unit.strip_suffix('s').unwrap_or(unit) {
-                "year" => Relative::Years(multiplier),
-                "month" => Relative::Months(multiplier),
-                "fortnight" => Relative::Days(multiplier.checked_mul(14)?),
-                "week" => Relative::Days(multiplier.checked_mul(7)?),
-                "day" => Relative::Days(multiplier),
-                "hour" => Relative::Hours(multiplier),
-                "minute" | "min" => Relative::Minutes(multiplier),
-                "second" | "sec" => Relative::Seconds(multiplier as i64, 0),
-                _ => return None,
-            })
+            let index = units.iter().position(|(name, _)| {
+                unit == name || unit.strip_suffix('s') == Some(name.as_str())
+            })?;
+            Some(Relative::Custom(
+                index,
+                n.unwrap_or(1) * if ago { -1 } else { 1 },
+            ))
         })
         .parse_next(input)
 }
@@ -126,7 +412,159 @@ fn ago(input: &mut &str) -> ModalResult<bool> {
 
 #[cfg(test)]
 mod tests {
-    use super::{parse, Relative};
+    use jiff::Zoned;
+
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+        MUnit, RelativeUnit,
+    };
+
+    use super::{and_chain, compact_duration, custom, m_unit, parse, shorthand, Relative};
+
+    #[test]
+    fn compact_durations() {
+        for (input, expected) in [
+            ("5m", Relative::Seconds(300, 0)),
+            ("2h45m", Relative::Seconds(9_900, 0)),
+            ("1h30m10s", Relative::Seconds(5_410, 0)),
+            ("1.5h", Relative::Seconds(5_400, 0)),
+            ("300ms", Relative::Seconds(0, 300_000_000)),
+            ("1s500ms", Relative::Seconds(1, 500_000_000)),
+            ("10us", Relative::Seconds(0, 10_000)),
+            ("10µs", Relative::Seconds(0, 10_000)),
+            ("7ns", Relative::Seconds(0, 7)),
+            ("1d12h", Relative::Seconds(129_600, 0)),
+            ("2w", Relative::Seconds(1_209_600, 0)),
+            ("-1.5s", Relative::Seconds(-2, 500_000_000)),
+            ("+90m", Relative::Seconds(5_400, 0)),
+            ("5m ago", Relative::Seconds(-300, 0)),
+            ("-5m ago", Relative::Seconds(300, 0)),
+        ] {
+            let mut s = input;
+            assert_eq!(compact_duration(&mut s).unwrap(), expected, "{input}");
+            assert_eq!(s, "", "{input}");
+        }
+
+        for input in ["5", "5 m", "5min", "h", "1.5", "5x"] {
+            let mut s = input;
+            assert!(compact_duration(&mut s).is_err(), "{input}");
+        }
+
+        // A total beyond the range of nanoseconds fails to parse.
+        let input = "18446744073709551615w".repeat(20_000);
+        assert!(compact_duration(&mut input.as_str()).is_err());
+    }
+
+    #[test]
+    fn shorthands() {
+        for (input, expected) in [
+            (
+                "+1d2h30m",
+                vec![Relative::Days(1), Relative::Hours(2), Relative::Minutes(30)],
+            ),
+            ("2h30m", vec![Relative::Hours(2), Relative::Minutes(30)]),
+            ("1y6m", vec![Relative::Years(1), Relative::Minutes(6)]),
+            ("1y6mo", vec![Relative::Years(1), Relative::Months(6)]),
+            ("-1mo2d", vec![Relative::Months(-1), Relative::Days(-2)]),
+            ("1w2d ago", vec![Relative::Days(-7), Relative::Days(-2)]),
+            ("-1h5m ago", vec![Relative::Hours(1), Relative::Minutes(5)]),
+        ] {
+            let mut s = input;
+            assert_eq!(shorthand(&mut s).unwrap(), expected, "{input}");
+            assert_eq!(s, "", "{input}");
+        }
+
+        // A single component is an offset followed by a military timezone
+        // (e.g., "+1h") or a time in a military timezone (e.g., "5m").
+        for input in [
+            "5m", "1d", "+1d", "-2w", "+45s", "+1", "+1x", "+1min", "2h30", "1.5h2m", "+ 1d",
+        ] {
+            let mut s = input;
+            assert!(shorthand(&mut s).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn and_chains() {
+        for (input, expected) in [
+            (
+                "2 days and 4 hours",
+                vec![Relative::Days(2), Relative::Hours(4)],
+            ),
+            (
+                "1 hour and 30 minutes ago",
+                vec![Relative::Hours(-1), Relative::Minutes(-30)],
+            ),
+            (
+                "1 day and 1.5 seconds ago",
+                vec![Relative::Days(-1), Relative::Seconds(-2, 500_000_000)],
+            ),
+            (
+                "2 days ago and 3 hours",
+                vec![Relative::Days(-2), Relative::Hours(3)],
+            ),
+            (
+                "week and 2 days and 1 hour",
+                vec![Relative::Days(7), Relative::Days(2), Relative::Hours(1)],
+            ),
+        ] {
+            let mut s = input;
+            assert_eq!(and_chain(&mut s).unwrap(), expected, "{input}");
+            assert_eq!(s, "", "{input}");
+        }
+
+        for input in ["2 days", "2 days and", "and 2 days", "2 days and monday"] {
+            let mut s = input;
+            assert!(and_chain(&mut s).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn m_units() {
+        for (input, unit, expected) in [
+            ("5 m", MUnit::Minute, Relative::Minutes(5)),
+            ("5m", MUnit::Minute, Relative::Minutes(5)),
+            ("-5 m", MUnit::Minute, Relative::Minutes(-5)),
+            ("5 m ago", MUnit::Month, Relative::Months(-5)),
+            ("next m", MUnit::Month, Relative::Months(1)),
+        ] {
+            let mut s = input;
+            assert_eq!(m_unit(&mut s, unit).unwrap(), expected, "{input}");
+            assert_eq!(s, "", "{input}");
+        }
+
+        for input in ["m", "5 min", "5 mo", "5 ms"] {
+            let mut s = input;
+            assert!(m_unit(&mut s, MUnit::Minute).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn custom_units() {
+        let unit = |dt: &Zoned, _: i32| Some(dt.clone());
+        let units: Vec<(String, Box<dyn RelativeUnit>)> = vec![
+            ("sprint".to_owned(), Box::new(unit)),
+            ("payperiod".to_owned(), Box::new(unit)),
+        ];
+
+        for (input, expected) in [
+            ("sprint", Relative::Custom(0, 1)),
+            ("2 sprints", Relative::Custom(0, 2)),
+            ("next sprint", Relative::Custom(0, 1)),
+            ("-1 payperiod", Relative::Custom(1, -1)),
+            ("3 payperiods ago", Relative::Custom(1, -3)),
+        ] {
+            let mut s = input;
+            assert_eq!(custom(&mut s, &units).unwrap(), expected, "{input}");
+            assert_eq!(s, "", "{input}");
+        }
+
+        for input in ["2 days", "2 sprintss", "sprin", ""] {
+            let mut s = input;
+            assert!(custom(&mut s, &units).is_err(), "{input}");
+        }
+    }
 
     #[test]
     fn all() {
@@ -143,6 +581,7 @@ mod tests {
             ("+3.5 seconds", Relative::Seconds(3, 500_000_000)),
             ("3.5 seconds ago", Relative::Seconds(-4, 500_000_000)),
             ("-3.5 seconds ago", Relative::Seconds(3, 500_000_000)),
+            (" 3.5 seconds", Relative::Seconds(3, 500_000_000)),
             // Minutes
             ("minute", Relative::Minutes(1)),
             ("minutes", Relative::Minutes(1)),
@@ -152,6 +591,7 @@ mod tests {
             ("-10 minutes", Relative::Minutes(-10)),
             ("10 minutes ago", Relative::Minutes(-10)),
             ("-10 minutes ago", Relative::Minutes(10)),
+            ("5 mins", Relative::Minutes(5)),
             // Hours
             ("hour", Relative::Hours(1)),
             ("hours", Relative::Hours(1)),
@@ -160,6 +600,12 @@ mod tests {
             ("-10 hours", Relative::Hours(-10)),
             ("10 hours ago", Relative::Hours(-10)),
             ("-10 hours ago", Relative::Hours(10)),
+            ("2 hrs", Relative::Hours(2)),
+            ("2hrs ago", Relative::Hours(-2)),
+            ("+1700 hrs", Relative::Hours(1700)),
+            ("1700 hrs ago", Relative::Hours(-1700)),
+            ("170 hrs", Relative::Hours(170)),
+            ("0930 hours", Relative::Hours(930)),
             // Days
             ("day", Relative::Days(1)),
             ("days", Relative::Days(1)),
@@ -176,11 +622,29 @@ mod tests {
             ("week", Relative::Days(7)),
             ("weeks", Relative::Days(7)),
             ("2 weeks ago", Relative::Days(-14)),
+            ("wk", Relative::Days(7)),
+            ("3 wks", Relative::Days(21)),
+            // Business days
+            ("business day", Relative::BusinessDays(1)),
+            ("+3 business days", Relative::BusinessDays(3)),
+            ("3 working days ago", Relative::BusinessDays(-3)),
+            ("workday", Relative::BusinessDays(1)),
+            ("-2 workdays", Relative::BusinessDays(-2)),
+            ("2 workdays ago", Relative::BusinessDays(-2)),
+            ("next business day", Relative::BusinessDays(1)),
+            // Weekdays
+            ("weekday", Relative::Weekdays(1)),
+            ("+5 weekdays", Relative::Weekdays(5)),
+            ("2 weekdays ago", Relative::Weekdays(-2)),
             // Other
             ("year", Relative::Years(1)),
             ("years", Relative::Years(1)),
             ("month", Relative::Months(1)),
             ("months", Relative::Months(1)),
+            ("yr", Relative::Years(1)),
+            ("2 yrs ago", Relative::Years(-2)),
+            ("mo", Relative::Months(1)),
+            ("6 mos", Relative::Months(6)),
             // Special
             ("yesterday", Relative::Days(-1)),
             ("tomorrow", Relative::Days(1)),
@@ -198,5 +662,176 @@ mod tests {
             let mut t = s;
             assert_eq!(parse(&mut t).ok(), Some(rel), "Failed string: {s}")
         }
+
+        // A four-digit number of "hrs" is a time of day (e.g., "1700 hrs").
+        for s in ["1700 hrs", "0930 hr", "hrs", "hr"] {
+            let mut t = s;
+            assert!(parse(&mut t).is_err(), "Parsed string: {s}");
+        }
+    }
+
+    #[test]
+    fn fused_units() {
+        // As in GNU date, the space between the number and the unit may be
+        // omitted, with or without a sign.
+        for (s, rel) in [
+            ("2days", Relative::Days(2)),
+            ("+3hours", Relative::Hours(3)),
+            ("-3hours", Relative::Hours(-3)),
+            ("1week ago", Relative::Days(-7)),
+            ("-2weeks", Relative::Days(-14)),
+            ("2fortnights", Relative::Days(28)),
+            ("3min", Relative::Minutes(3)),
+            ("+1year", Relative::Years(1)),
+            ("6months ago", Relative::Months(-6)),
+            ("10sec", Relative::Seconds(10, 0)),
+            ("1.5seconds", Relative::Seconds(1, 500_000_000)),
+            ("-1.5seconds", Relative::Seconds(-2, 500_000_000)),
+            ("+ 2days", Relative::Days(2)),
+        ] {
+            let mut t = s;
+            assert_eq!(parse(&mut t).ok(), Some(rel), "Failed string: {s}");
+            assert_eq!(t, "", "Failed string: {s}");
+        }
+    }
+
+    #[test]
+    fn compact_durations_resolved() {
+        let options = Options {
+            compact_durations: true,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("5m", "2025-06-15 10:05:00"),
+            ("2h45m ago", "2025-06-15 07:15:00"),
+            ("-1.5h", "2025-06-15 08:30:00"),
+            ("2025-01-01 12:00 +1h30m10s", "2025-01-01 13:30:10"),
+            ("+5 min", "2025-06-15 10:05:00"),
+            ("12:00 -1m", "2025-06-15 11:59:00"),
+            ("2025-01-01 12:00 -1m", "2025-01-01 11:59:00"),
+            ("12:00 +05:00", "2025-06-15 12:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // Compact durations are opt-in; "5m" is 05:00 in the military zone M.
+        let result = parse_at_date(now, "5m", &Options::default()).unwrap();
+        assert_eq!(
+            result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+            "2025-06-15 05:00:00 +12:00"
+        );
+    }
+
+    #[test]
+    fn and_connective() {
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("1 hour and 30 minutes ago", "2025-06-15 08:30:00"),
+            ("2 days and 4 hours", "2025-06-17 14:00:00"),
+            ("2025-01-01 12:00 +1 day and 2 hours", "2025-01-02 14:00:00"),
+            // Without "and", "ago" only applies to the last item, as in GNU.
+            ("1 hour 30 minutes ago", "2025-06-15 10:30:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        assert!(parse_at_date(now, "2 days and", &Options::default()).is_err());
+    }
+
+    #[test]
+    fn unit_aliases() {
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("2 hrs ago", "2025-06-15 08:00:00"),
+            ("+1 wk", "2025-06-22 10:00:00"),
+            ("1 mo", "2025-07-15 10:00:00"),
+            ("1 yr ago", "2024-06-15 10:00:00"),
+            ("90 secs", "2025-06-15 10:01:30"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // "m" is the military timezone M, unless its meaning is configured.
+        let result = parse_at_date(now.clone(), "5 m", &Options::default()).unwrap();
+        assert_eq!(
+            result.strftime("%Y-%m-%d %H:%M:%S %:z").to_string(),
+            "2025-06-15 05:00:00 +12:00"
+        );
+        for (input, unit, expected) in [
+            ("5 m", MUnit::Minute, "2025-06-15 10:05:00"),
+            ("1 m", MUnit::Month, "2025-07-15 10:00:00"),
+        ] {
+            let options = Options {
+                m_unit: Some(unit),
+                ..Default::default()
+            };
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn relative_shorthand() {
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("+1d2h30m", "2025-06-16 12:30:00"),
+            ("2h30m", "2025-06-15 12:30:00"),
+            ("1w2d ago", "2025-06-06 10:00:00"),
+            ("-1y2d", "2024-06-13 10:00:00"),
+            ("2025-01-31 +1y1d", "2026-02-01 00:00:00"),
+            ("2025-01-01 12:00 +1h5m", "2025-01-01 13:05:00"),
+            ("+1d1h 3 hours", "2025-06-16 14:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        // A single component is an offset followed by a military timezone, as
+        // in GNU `date`, and two offsets are an error.
+        for input in ["+1h", "-5m", "+1m", "-1d", "12:00 -1m", "10:00 +1h"] {
+            assert!(
+                parse_at_date(now.clone(), input, &Options::default()).is_err(),
+                "{input}"
+            );
+        }
+
+        // With compact durations, "w" and "d" are exact durations.
+        let options = Options {
+            compact_durations: true,
+            ..Default::default()
+        };
+        let result = parse_at_date(now, "+1d2h", &options).unwrap();
+        assert_eq!(
+            result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+            "2025-06-16 12:00:00"
+        );
     }
 }
@@ -0,0 +1,179 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse an RFC 3339 date and time.
+//!
+//! RFC 3339 (section 5.6) specifies:
+//!
+//! ```abnf
+//! date-fullyear   = 4DIGIT
+//! date-month      = 2DIGIT  ; 01-12
+//! date-mday       = 2DIGIT  ; 01-28, 01-29, 01-30, 01-31 based on
+//!                           ; month/year
+//! time-hour       = 2DIGIT  ; 00-23
+//! time-minute     = 2DIGIT  ; 00-59
+//! time-second     = 2DIGIT  ; 00-58, 00-59, 00-60 based on leap second
+//!                           ; rules
+//! time-secfrac    = "." 1*DIGIT
+//! time-numoffset  = ("+" / "-") time-hour ":" time-minute
+//! time-offset     = "Z" / time-numoffset
+//!
+//! partial-time    = time-hour ":" time-minute ":" time-second
+//!                   [time-secfrac]
+//! full-date       = date-fullyear "-" date-month "-" date-mday
+//! full-time       = partial-time time-offset
+//!
+//! date-time       = full-date "T" full-time
+//! ```
+//!
+//! The "T" and "Z" may be lowercase, and the "T" may be replaced by a space,
+//! as the RFC allows. Nothing else is accepted: no surrounding whitespace, no
+//! missing seconds or offset, and no comma before the fraction. Digits of the
+//! fraction beyond nanoseconds are discarded.
+//!
+//! A leap second (second 60) is rejected, as it cannot be represented.
+
+use jiff::{civil, tz::TimeZone};
+use winnow::{
+    combinator::{alt, eof, opt, preceded, terminated},
+    error::{ContextError, ErrMode, FromExternalError},
+    stream::AsChar,
+    token::{one_of, take_while},
+    ModalResult, Parser,
+};
+
+use super::{
+    error::Error,
+    primitive::{digits, fixed_offset},
+};
+
+/// Parse an RFC 3339 date and time, as its civil date and time and the
+/// timezone of its offset ("Z" is UTC).
+pub(super) fn parse(input: &mut &str) -> ModalResult<(civil::DateTime, TimeZone)> {
+    let (year, _, month, _, day) = (
+        digits(4),
+        '-',
+        digits(2).verify(|m| (1..=12).contains(m)),
+        '-',
+        digits(2).verify(|d| (1..=31).contains(d)),
+    )
+        .parse_next(input)?;
+    let (_, hour, _, minute, _, second, nanosecond, tz) = terminated(
+        (
+            one_of(['T', 't', ' ']),
+            digits(2).verify(|h| *h < 24),
+            ':',
+            digits(2).verify(|m| *m < 60),
+            ':',
+            digits(2).verify(|s| *s < 60),
+            opt(preceded('.', take_while(1.., AsChar::is_dec_digit))).map(nanoseconds),
+            offset,
+        ),
+        eof,
+    )
+    .parse_next(input)?;
+
+    let date = civil::Date::new(year as i16, month as i8, day as i8).map_err(|_| {
+        ErrMode::Cut(ContextError::from_external_error(
+            input,
+            Error::Resolve("date is not valid".to_owned()),
+        ))
+    })?;
+    let time = civil::time(hour as i8, minute as i8, second as i8, nanosecond);
+    Ok((date.to_datetime(time), tz))
+}
+
+/// Convert the digits of a fraction of a second to nanoseconds.
+fn nanoseconds(digits: Option<&str>) -> i32 {
+    let digits = digits.unwrap_or("");
+    let digits = &digits[..digits.len().min(9)];
+    format!("{digits:0<9}").parse().unwrap_or(0)
+}
+
+/// Parse the offset of a date and time.
+fn offset(input: &mut &str) -> ModalResult<TimeZone> {
+    alt((
+        one_of(['Z', 'z']).value(TimeZone::UTC),
+        (
+            one_of(['+', '-']),
+            digits(2).verify(|h| *h < 24),
+            ':',
+            digits(2).verify(|m| *m < 60),
+        )
+            .try_map(|(sign, hours, _, minutes)| {
+                fixed_offset(sign, hours, minutes).map(TimeZone::fixed)
+            }),
+    ))
+    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::tz::TimeZone;
+
+    use super::parse;
+
+    #[test]
+    fn valid() {
+        for (input, expected) in [
+            ("1985-04-12T23:20:50.52Z", "1985-04-12T23:20:50.52+00:00"),
+            ("1996-12-19T16:39:57-08:00", "1996-12-19T16:39:57-08:00"),
+            (
+                "1937-01-01T12:00:27.87+00:20",
+                "1937-01-01T12:00:27.87+00:20",
+            ),
+            ("1990-12-31t15:59:59z", "1990-12-31T15:59:59+00:00"),
+            ("1990-12-31 15:59:59-00:00", "1990-12-31T15:59:59+00:00"),
+            ("1990-12-31T15:59:59+23:59", "1990-12-31T15:59:59+23:59"),
+            (
+                "2022-11-14T10:20:30.1234567891Z",
+                "2022-11-14T10:20:30.123456789+00:00",
+            ),
+        ] {
+            let (dt, tz) = parse(&mut &*input).unwrap();
+            let zoned = dt.to_zoned(tz).unwrap();
+            assert_eq!(
+                zoned
+                    .timestamp()
+                    .display_with_offset(zoned.offset())
+                    .to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        let (_, tz) = parse(&mut "2022-11-14T10:20:30Z").unwrap();
+        assert_eq!(tz, TimeZone::UTC);
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "",
+            "2022-11-14",
+            "2022-11-14T10:20Z",
+            "2022-11-14T10:20:30",
+            "2022-11-14T10:20:30 Z",
+            "2022-11-14T10:20:30+0100",
+            "2022-11-14T10:20:30+01",
+            "2022-11-14T10:20:30,5Z",
+            "2022-11-14T10:20:30.Z",
+            "2022-11-14t10:20:30 utc",
+            "2022-11-14T10:20:60Z",
+            "2022-11-14T24:00:00Z",
+            "2022-13-14T10:20:30Z",
+            "2022-11-32T10:20:30Z",
+            "22-11-14T10:20:30Z",
+            "12022-11-14T10:20:30Z",
+            "2022-1-14T10:20:30Z",
+            " 2022-11-14T10:20:30Z",
+            "2022-11-14T10:20:30Z ",
+            "2022-11-14  10:20:30Z",
+            "@1668421230",
+        ] {
+            assert!(parse(&mut &*input).is_err(), "{input}");
+        }
+
+        assert!(parse(&mut "2023-02-29T10:20:30Z").is_err());
+    }
+}
@@ -0,0 +1,187 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a recurring schedule.
+//!
+//! A schedule is ‘every’ followed by either days of the week, separated by
+//! commas or ‘and’, and an optional time of day (e.g., ‘every monday and
+//! thursday at 9:30am’), or by a relative item without ‘ago’ (e.g., ‘every 2
+//! hours’ or ‘every month’).
+//!
+//! A list of at least two days of the week is a weekly schedule without
+//! ‘every’, e.g., ‘mon,wed,fri at 9am’.
+
+use winnow::{
+    ascii::alpha1,
+    combinator::{alt, eof, opt, preceded, separated, terminated},
+    ModalResult, Parser,
+};
+
+use crate::{parser::Options, Schedule};
+
+use super::{
+    primitive::{s, space},
+    relative::{self, Relative},
+    time, weekday,
+};
+
+/// Parse a whole schedule.
+pub(super) fn parse(input: &mut &str, options: &Options) -> ModalResult<Schedule> {
+    let every = s(alpha1).verify(|s: &str| s == "every");
+    terminated(
+        alt((
+            preceded(every, alt((|i: &mut &str| weekly(i, 1, options), interval))),
+            |i: &mut &str| weekly(i, 2, options),
+        )),
+        preceded(space, eof),
+    )
+    .parse_next(input)
+}
+
+/// Parse days of the week with an optional time of day, e.g., `monday, wednesday
+/// and friday at 9am`, with at least `min_days` days.
+fn weekly(input: &mut &str, min_days: usize, options: &Options) -> ModalResult<Schedule> {
+    let separator = alt(((s(","), opt(s("and"))).void(), s("and").void()));
+    (
+        separated(min_days.., weekday::day, separator),
+        opt(preceded(
+            opt(s("at")),
+            // Occurrences are in the timezone of the start of the schedule.
+            s(|i: &mut &str| time::parse(i, options)).verify(|t| t.offset.is_none()),
+        )),
+    )
+        .verify_map(|(days, time): (Vec<weekday::Day>, Option<time::Time>)| {
+            let mut unique = Vec::with_capacity(days.len());
+            for day in days {
+                if !unique.contains(&day) {
+                    unique.push(day);
+                }
+            }
+            Some(Schedule::Weekly {
+                days: unique.into_iter().map(Into::into).collect(),
+                time: time.map(TryInto::try_into).transpose().ok()?,
+            })
+        })
+        .parse_next(input)
+}
+
+/// Parse an interval, e.g., `2 hours`, `fortnight` or `90 seconds`.
+fn interval(input: &mut &str) -> ModalResult<Schedule> {
+    alt((relative::seconds, relative::displacement))
+        .verify_map(|relative| {
+            let positive = match relative {
+                Relative::Years(n)
+                | Relative::Months(n)
+                | Relative::Days(n)
+                | Relative::Hours(n)
+                | Relative::Minutes(n) => n > 0,
+                Relative::Seconds(sec, nsec) => sec > 0 || (sec == 0 && nsec > 0),
+                Relative::BusinessDays(_) | Relative::Weekdays(_) | Relative::Custom(..) => false,
+            };
+            positive
+                .then(|| relative.try_into().ok().map(Schedule::Interval))
+                .flatten()
+        })
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::{
+        civil::{time, Weekday},
+        ToSpan,
+    };
+
+    use crate::{parser::Options, Schedule};
+
+    use super::parse;
+
+    fn weekly(input: &str) -> (Vec<Weekday>, Option<jiff::civil::Time>) {
+        let mut s = input;
+        match parse(&mut s, &Options::default()).unwrap() {
+            Schedule::Weekly { days, time } => (days, time),
+            other => panic!("{input}: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn weekly_schedules() {
+        use Weekday::*;
+
+        for (input, days, at) in [
+            ("every friday", vec![Friday], None),
+            ("every fri at 9am", vec![Friday], Some(time(9, 0, 0, 0))),
+            ("every friday 17:30", vec![Friday], Some(time(17, 30, 0, 0))),
+            (
+                "every monday and thursday at 9:30pm",
+                vec![Monday, Thursday],
+                Some(time(21, 30, 0, 0)),
+            ),
+            (
+                "every mon, wed, and fri",
+                vec![Monday, Wednesday, Friday],
+                None,
+            ),
+            ("every tue,thu ", vec![Tuesday, Thursday], None),
+            // Repeated days are listed once.
+            ("every fri and fri, mon", vec![Friday, Monday], None),
+            // A list of days does not require "every".
+            ("mon,wed,fri", vec![Monday, Wednesday, Friday], None),
+            (
+                "tue, thu at 18:00",
+                vec![Tuesday, Thursday],
+                Some(time(18, 0, 0, 0)),
+            ),
+        ] {
+            assert_eq!(weekly(input), (days, at), "{input}");
+        }
+    }
+
+    #[test]
+    fn intervals() {
+        for (input, expected) in [
+            ("every 2 hours", 2.hours()),
+            ("every hour", 1.hours()),
+            ("every 15 minutes", 15.minutes()),
+            ("every 90 seconds", 90.seconds()),
+            ("every second", 1.seconds()),
+            ("every day", 1.days()),
+            ("every 2 weeks", 14.days()),
+            ("every fortnight", 14.days()),
+            ("every month", 1.months()),
+            ("every 3 years", 3.years()),
+        ] {
+            let mut s = input;
+            match parse(&mut s, &Options::default()).unwrap() {
+                Schedule::Interval(span) => {
+                    assert_eq!(span.fieldwise(), expected.fieldwise(), "{input}")
+                }
+                other => panic!("{input}: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "",
+            "every",
+            "everyday",
+            "friday",
+            "friday at 9am",
+            "mon,wed,",
+            "every mon,,wed",
+            "every 0 days",
+            "every -2 hours",
+            "every 2 hours ago",
+            "every 2 business days",
+            "every friday at 9am +02:00",
+            "every friday at 25:00",
+            "every friday 2025-06-20",
+            "every 2 hours at 9am",
+        ] {
+            let mut s = input;
+            assert!(parse(&mut s, &Options::default()).is_err(), "{input}");
+        }
+    }
+}
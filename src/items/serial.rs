@@ -0,0 +1,147 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a spreadsheet serial date.
+//!
+//! Excel (and Lotus 1-2-3 before it) stores a date and time as a number of
+//! days, e.g., `45292.75` is 2024-01-01 18:00:00. The integer part counts days
+//! from 1900-01-01, which is day 1, and the fractional part is the time of day.
+//!
+//! Lotus 1-2-3 wrongly treated 1900 as a leap year, and Excel kept the bug for
+//! compatibility: day 60 is the nonexistent 1900-02-29, and every later day is
+//! off by one from a plain count.
+//!
+//! Serial dates are read with
+//! [`Parser::serial_dates`](crate::Parser::serial_dates), in which case a
+//! number is no longer a year or a time of day.
+
+use jiff::{civil, ToSpan};
+use winnow::{
+    ascii::multispace1,
+    combinator::{alt, eof, peek},
+    error::ErrMode,
+    ModalResult, Parser,
+};
+
+use super::{
+    combined::DateTime,
+    date::Date,
+    epoch::sec_and_nsec,
+    primitive::{ctx_err, s},
+    time::Time,
+};
+
+/// The number of milliseconds in a day.
+const MILLIS_PER_DAY: u64 = 86_400_000;
+
+/// Parse a serial date, e.g., `45292` or `45292.75`.
+///
+/// The time of day is rounded to the nearest millisecond.
+pub(super) fn parse(input: &mut &str) -> ModalResult<DateTime> {
+    let (whole, fraction) = s(sec_and_nsec).parse_next(input)?;
+    // The number must be followed by a space or end of input.
+    peek(alt((multispace1, eof))).parse_next(input)?;
+
+    let days = match whole {
+        60 => return Err(ErrMode::Cut(ctx_err("serial date 60 is not a valid date"))),
+        // Day 0 is 1900-01-00, i.e., 1899-12-31.
+        0..=59 => whole + 1,
+        _ => whole,
+    };
+
+    // The fraction is given in billionths of a day.
+    let millis = days
+        .checked_mul(MILLIS_PER_DAY)
+        .and_then(|m| m.checked_add((u64::from(fraction) * 86_400 + 500_000) / 1_000_000))
+        .ok_or_else(|| ErrMode::Cut(ctx_err("serial date is out of range")))?;
+    let (days, millis) = (millis / MILLIS_PER_DAY, millis % MILLIS_PER_DAY);
+
+    let date = i64::try_from(days)
+        .ok()
+        .and_then(|days| civil::date(1899, 12, 30).checked_add(days.days()).ok())
+        .ok_or_else(|| ErrMode::Cut(ctx_err("serial date is out of range")))?;
+
+    Ok(DateTime {
+        date: Date {
+            day: date.day() as u8,
+            month: date.month() as u8,
+            year: Some(date.year() as u16),
+            two_digit_year: false,
+        },
+        time: Time {
+            hour: (millis / 3_600_000) as u8,
+            minute: (millis / 60_000 % 60) as u8,
+            second: (millis / 1000 % 60) as u8,
+            nanosecond: (millis % 1000) as u32 * 1_000_000,
+            offset: None,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+    };
+
+    use super::parse;
+
+    fn serial(input: &str) -> String {
+        let mut s = input;
+        let dt = parse(&mut s).unwrap();
+        let date: jiff::civil::Date = dt.date.try_into().unwrap();
+        let time: jiff::civil::Time = dt.time.try_into().unwrap();
+        date.to_datetime(time).to_string()
+    }
+
+    #[test]
+    fn serial_dates() {
+        for (input, expected) in [
+            ("1", "1900-01-01T00:00:00"),
+            ("0", "1899-12-31T00:00:00"),
+            ("59", "1900-02-28T00:00:00"),
+            ("61", "1900-03-01T00:00:00"),
+            ("45292", "2024-01-01T00:00:00"),
+            ("45292.75", "2024-01-01T18:00:00"),
+            ("45292,5", "2024-01-01T12:00:00"),
+            ("45292.333333333", "2024-01-01T08:00:00"),
+            ("45292.00001", "2024-01-01T00:00:00.864"),
+            ("45292.9999999999", "2024-01-02T00:00:00"),
+            ("2958465", "9999-12-31T00:00:00"),
+        ] {
+            assert_eq!(serial(input), expected, "{input}");
+        }
+
+        for input in ["60", "2958466", "45292x", "-1"] {
+            let mut s = input;
+            assert!(parse(&mut s).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn serial_dates_resolved() {
+        let options = Options {
+            serial_dates: true,
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("45292.75", "2024-01-01 18:00:00"),
+            ("45292 +1 day", "2024-01-02 00:00:00"),
+            ("45292.5 UTC+2", "2024-01-01 12:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        assert!(parse_at_date(now.clone(), "60", &options).is_err());
+        // Serial dates are opt-in.
+        assert!(parse_at_date(now, "45292.75", &Options::default()).is_err());
+    }
+}
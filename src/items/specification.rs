@@ -0,0 +1,102 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! A parsed specification, before it is resolved against its base date and
+//! time.
+//!
+//! A [`Specification`] is passed to the resolution hook set with
+//! [`Parser::resolution_hook`](crate::Parser::resolution_hook), which decides
+//! whether and how it is resolved.
+
+use std::{cell::Cell, fmt};
+
+use jiff::Zoned;
+
+use crate::{parser::Options, DstAmbiguity, ParseDateTimeError, Precision};
+
+use super::builder::DateTimeBuilder;
+
+/// A parsed date and time specification (e.g., "next friday 10:00"), with the
+/// base date and time it is resolved against.
+///
+/// Its `Debug` representation lists the parsed items, for logging.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::{ToSpan, Zoned};
+/// use parse_datetime::{ParseDateTimeError, Parser};
+///
+/// let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+/// let latest = base.checked_add(30.days()).unwrap();
+///
+/// // Reject specifications without a date, and clamp the results to the next
+/// // 30 days.
+/// let parser = Parser::new().resolution_hook(move |spec| {
+///     if !spec.precision().date {
+///         return Err(ParseDateTimeError::Rejected);
+///     }
+///     Ok(spec.resolve()?.min(latest.clone()))
+/// });
+///
+/// let dt = parser.parse_datetime_at_date(base.clone(), "2025-12-25").unwrap();
+/// assert_eq!(dt.strftime("%F").to_string(), "2025-07-15");
+/// assert_eq!(
+///     parser.parse_datetime_at_date(base, "tomorrow"),
+///     Err(ParseDateTimeError::Rejected),
+/// );
+/// ```
+pub struct Specification<'a> {
+    builder: DateTimeBuilder,
+    options: &'a Options,
+    dst: &'a Cell<DstAmbiguity>,
+}
+
+impl<'a> Specification<'a> {
+    pub(super) fn new(
+        builder: DateTimeBuilder,
+        options: &'a Options,
+        dst: &'a Cell<DstAmbiguity>,
+    ) -> Self {
+        Self {
+            builder,
+            options,
+            dst,
+        }
+    }
+
+    /// The base date and time, in the timezone of the `TZ="..."` rule if
+    /// there is one.
+    pub fn base(&self) -> &Zoned {
+        self.builder
+            .base()
+            .expect("the base of a specification is set")
+    }
+
+    /// Resolve the specification against `base` instead.
+    pub fn with_base(self, base: Zoned) -> Self {
+        Self {
+            builder: self.builder.set_base(base),
+            ..self
+        }
+    }
+
+    /// The parts of the date and time that were given by the input.
+    pub fn precision(&self) -> Precision {
+        self.builder.precision()
+    }
+
+    /// Resolve the specification against its base date and time, as the
+    /// parser does without a resolution hook.
+    pub fn resolve(self) -> Result<Zoned, ParseDateTimeError> {
+        let (dt, dst) = self.builder.resolve(self.options)?;
+        self.dst.set(dst);
+        Ok(dt)
+    }
+}
+
+impl fmt::Debug for Specification<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Specification").field(&self.builder).finish()
+    }
+}
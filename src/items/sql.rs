@@ -0,0 +1,188 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse an SQL literal item.
+//!
+//! SQL writes a date and time value as a typed literal, a keyword followed by
+//! a string in single quotes (e.g., `DATE '2024-01-02'` or
+//! `TIMESTAMP '2024-01-02 10:00:00+02'`), and database dumps and logs print it
+//! with or without the keyword and quotes. The keyword is one of:
+//!
+//! - `DATE`, for a date (e.g., "2024-01-02"),
+//! - `TIME` or `TIMETZ`, for a time of day (e.g., "10:00:00" or
+//!   "10:00:00+02"),
+//! - `TIMESTAMP` or `TIMESTAMPTZ`, for a date and time of day (e.g.,
+//!   "2024-01-02 10:00:00.5+02") or a date, which is its midnight.
+//!
+//! `TIME` and `TIMESTAMP` may be followed by `WITH TIME ZONE` or
+//! `WITHOUT TIME ZONE`. The offset in the string is honored in either case.
+//!
+//! A string in quotes without a keyword is any of these values, and a value
+//! without the keyword and quotes is parsed by the other items.
+
+use winnow::{
+    combinator::{alt, delimited, opt, preceded},
+    ModalResult, Parser,
+};
+
+use super::{
+    combined, date,
+    primitive::{s, space},
+    time, Item,
+};
+
+/// The type of an SQL literal.
+#[derive(Clone, Copy)]
+enum Type {
+    Date,
+    Time,
+    Timestamp,
+}
+
+/// Parse an SQL literal, with or without its keyword.
+pub(super) fn parse(input: &mut &str) -> ModalResult<Item> {
+    let ty = opt(keyword).parse_next(input)?;
+    delimited(s('\''), move |i: &mut &str| value(i, ty), (space, '\'')).parse_next(input)
+}
+
+/// Parse the keyword of an SQL literal.
+fn keyword(input: &mut &str) -> ModalResult<Type> {
+    s(alt((
+        "date".value(Type::Date),
+        "timestamptz".value(Type::Timestamp),
+        "timetz".value(Type::Time),
+        ("timestamp", time_zone).value(Type::Timestamp),
+        ("time", time_zone).value(Type::Time),
+    )))
+    .parse_next(input)
+}
+
+/// Parse the optional `WITH TIME ZONE` or `WITHOUT TIME ZONE` of a keyword.
+fn time_zone(input: &mut &str) -> ModalResult<()> {
+    opt(preceded(
+        s(alt(("without", "with"))),
+        (s("time"), s("zone")),
+    ))
+    .void()
+    .parse_next(input)
+}
+
+/// Parse the value of an SQL literal of the given type, or of any type.
+fn value(input: &mut &str, ty: Option<Type>) -> ModalResult<Item> {
+    let mut date = s(date::iso1).map(Item::Date);
+    let mut time = s(time::iso).map(Item::Time);
+    let datetime = s(combined::parse).map(Item::DateTime);
+    match ty {
+        Some(Type::Date) => date.parse_next(input),
+        Some(Type::Time) => time.parse_next(input),
+        Some(Type::Timestamp) => alt((datetime, date)).parse_next(input),
+        None => alt((datetime, date, time)).parse_next(input),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::tz::TimeZone;
+
+    use crate::{
+        items::{at, parse_at_date, Item},
+        parser::Options,
+    };
+
+    use super::parse;
+
+    #[test]
+    fn typed() {
+        for input in [
+            "date '2024-01-02'",
+            "date'2024-01-02'",
+            "timestamp '2024-01-02'",
+            "'2024-01-02'",
+            "' 2024-01-02 '",
+        ] {
+            assert!(matches!(parse(&mut &*input), Ok(Item::Date(_))), "{input}");
+        }
+        for input in [
+            "time '10:00:00'",
+            "timetz '10:00:00+02'",
+            "time with time zone '10:00:00-08'",
+            "time without time zone '10:00'",
+            "'10:00:00.5'",
+        ] {
+            assert!(matches!(parse(&mut &*input), Ok(Item::Time(_))), "{input}");
+        }
+        for input in [
+            "timestamp '2024-01-02 10:00:00+02'",
+            "timestamptz '2024-01-02 10:00:00.123456+05:30'",
+            "timestamp with time zone '2024-01-02 10:00:00+02'",
+            "timestamp without time zone '2024-01-02t10:00:00'",
+            "'2024-01-02 10:00:00'",
+        ] {
+            assert!(
+                matches!(parse(&mut &*input), Ok(Item::DateTime(_))),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "date '10:00:00'",
+            "date '2024-01-02 10:00:00'",
+            "time '2024-01-02'",
+            "timestamp '10:00:00'",
+            "date 2024-01-02",
+            "date '2024-01-02",
+            "'tomorrow'",
+            "interval '1 day'",
+            "timestamp with '2024-01-02'",
+        ] {
+            let mut s = input;
+            assert!(parse(&mut s).is_err() || !s.is_empty(), "{input}");
+        }
+    }
+
+    #[test]
+    fn sql_literals() {
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("DATE '2024-01-02'", "2024-01-02 00:00:00"),
+            ("TIMESTAMP '2024-01-02 10:00:00+02'", "2024-01-02 08:00:00"),
+            (
+                "TIMESTAMP WITH TIME ZONE '2024-01-02 10:00:00.5-05:30'",
+                "2024-01-02 15:30:00",
+            ),
+            ("timestamptz '2024-01-02'", "2024-01-02 00:00:00"),
+            ("TIME '10:20:30'", "2025-06-15 10:20:30"),
+            ("TIME WITH TIME ZONE '10:20+02'", "2025-06-15 08:20:00"),
+            ("'2024-01-02 10:00:00+02'", "2024-01-02 08:00:00"),
+            ("2024-01-02 10:00:00+02", "2024-01-02 08:00:00"),
+            ("DATE '2024-01-02' +1 day", "2024-01-03 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result
+                    .with_time_zone(TimeZone::UTC)
+                    .strftime("%Y-%m-%d %H:%M:%S")
+                    .to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        for input in [
+            "DATE '10:20:30'",
+            "TIME '2024-01-02'",
+            "DATE '2024-01-02",
+            "DATE '2024-02-30'",
+            "INTERVAL '1 day'",
+        ] {
+            assert!(
+                parse_at_date(now.clone(), input, &Options::default()).is_err(),
+                "{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,125 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a GPS or TAI timestamp item.
+//!
+//! A GPS timestamp is a count of seconds since the GPS epoch, 1980-01-06
+//! 00:00:00 UTC, e.g., `GPS 1400000000`. A TAI timestamp is a count of seconds
+//! since 1970-01-01 00:00:00 on the TAI time scale (as with the `CLOCK_TAI`
+//! clock of Linux), e.g., `TAI 1700000037`. Neither time scale has leap
+//! seconds, so both are converted to UTC with a table of leap seconds.
+//!
+//! Like a timestamp, a GPS or TAI timestamp specifies a complete point in time
+//! and cannot be combined with any other date item. Both are read once the
+//! table is set with [`Parser::leap_seconds`](crate::Parser::leap_seconds).
+
+use winnow::{ascii::Caseless, combinator::alt, ModalResult, Parser};
+
+use crate::LeapSeconds;
+
+use super::{
+    epoch::{sec_and_nsec, Timestamp, NANOS_PER_SEC},
+    primitive::s,
+};
+
+/// The GPS epoch in seconds since the Unix epoch on the TAI time scale. GPS
+/// time is 19 seconds behind TAI.
+const GPS_EPOCH: i128 = 315_964_800 + 19;
+
+/// Parse a timestamp in the form of `GPS 1400000000` or `TAI 1700000037.5`.
+pub(super) fn parse(input: &mut &str, leap_seconds: &LeapSeconds) -> ModalResult<Timestamp> {
+    (
+        s(alt((
+            Caseless("gps").value(GPS_EPOCH),
+            Caseless("tai").value(0),
+        ))),
+        s(sec_and_nsec),
+    )
+        .verify_map(|(epoch, (sec, nsec))| {
+            let tai = (epoch + i128::from(sec)) * NANOS_PER_SEC + i128::from(nsec);
+            Some(Timestamp::from_nanos(leap_seconds.tai_to_utc(tai)))
+        })
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+        LeapSeconds,
+    };
+
+    use super::parse;
+
+    fn timestamp(input: &str, leap_seconds: &LeapSeconds) -> String {
+        let mut s = input;
+        let ts: jiff::Timestamp = parse(&mut s, leap_seconds).unwrap().try_into().unwrap();
+        ts.to_string()
+    }
+
+    #[test]
+    fn gps() {
+        let table = LeapSeconds::builtin();
+        for (input, expected) in [
+            ("GPS 0", "1980-01-06T00:00:00Z"),
+            ("gps 1400000000", "2024-05-17T16:53:02Z"),
+            ("GPS 1400000000.25", "2024-05-17T16:53:02.25Z"),
+            // 1999-01-01, before and after a leap second.
+            ("GPS 599184013", "1999-01-01T00:00:00Z"),
+            ("GPS 599184012", "1999-01-01T00:00:00Z"),
+            ("GPS 599184011", "1998-12-31T23:59:59Z"),
+        ] {
+            assert_eq!(timestamp(input, &table), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn tai() {
+        for (input, leap_seconds, expected) in [
+            (
+                "TAI 1700000037",
+                LeapSeconds::builtin(),
+                "2023-11-14T22:13:20Z",
+            ),
+            (
+                "tai 63072010",
+                LeapSeconds::builtin(),
+                "1972-01-01T00:00:00Z",
+            ),
+            ("TAI 1700000037", LeapSeconds::new(), "2023-11-14T22:13:57Z"),
+        ] {
+            assert_eq!(timestamp(input, &leap_seconds), expected, "{input}");
+        }
+
+        for input in ["TAI", "TAI -1", "UTC 1700000000"] {
+            let mut s = input;
+            assert!(parse(&mut s, &LeapSeconds::builtin()).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn gps_and_tai() {
+        let options = Options {
+            leap_seconds: Some(crate::LeapSeconds::builtin()),
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("GPS 1400000000", "2024-05-17 16:53:02"),
+            ("TAI 1700000037", "2023-11-14 22:13:20"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        assert!(parse_at_date(now.clone(), "GPS 1400000000 +1 day", &options).is_err());
+        // GPS and TAI timestamps are opt-in.
+        assert!(parse_at_date(now, "GPS 1400000000", &Options::default()).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a TAI64, TAI64N or TAI64NA label item.
+//!
+//! daemontools and runit prefix log lines with a TAI64N label, an "@" and 24
+//! hexadecimal digits (e.g., `@4000000065a1c2ea075bcd15`): 16 digits for the
+//! TAI64 label of the second, 2^62 plus the number of seconds since
+//! 1970-01-01 00:00:00 TAI, and 8 for the nanoseconds. A TAI64 label has the 16
+//! digits only, and a TAI64NA label 8 more for the attoseconds, which are
+//! discarded.
+//!
+//! Like `tai64nlocal`, the label is converted to UTC by taking TAI to be 10
+//! seconds ahead of UTC, as the programs writing it do, rather than with a
+//! table of leap seconds.
+//!
+//! A label is told apart from an epoch timestamp by its length and its first
+//! digit, which is 4 to 7 as the label is at least 2^62. Like a timestamp, it
+//! specifies a complete point in time and cannot be combined with any other
+//! date item.
+
+use winnow::{
+    ascii::multispace1,
+    combinator::{alt, eof, peek},
+    stream::AsChar,
+    token::take_while,
+    ModalResult, Parser,
+};
+
+use super::{
+    epoch::{Timestamp, NANOS_PER_SEC},
+    primitive::s,
+};
+
+/// The TAI64 label of 1970-01-01 00:00:00 UTC.
+const UNIX_EPOCH: i128 = (1 << 62) + 10;
+
+/// Parse a label in the form of `@4000000065a1c2ea` (TAI64),
+/// `@4000000065a1c2ea075bcd15` (TAI64N) or
+/// `@4000000065a1c2ea075bcd1500000000` (TAI64NA).
+pub(super) fn parse(input: &mut &str) -> ModalResult<Timestamp> {
+    (
+        s("@"),
+        take_while(16..=32, AsChar::is_hex_digit),
+        // The label must end here, so that an epoch timestamp with a unit is
+        // not taken for a label (e.g., "@4000000000000000ns").
+        peek(alt((multispace1, eof))),
+    )
+        .verify_map(|(_, label, _): (_, &str, _)| {
+            if !matches!(label.len(), 16 | 24 | 32) || !matches!(label.as_bytes()[0], b'4'..=b'7') {
+                return None;
+            }
+            let seconds = i128::from_str_radix(&label[..16], 16).ok()?;
+            let nanos = match label.get(16..24) {
+                Some(nanos) => i128::from_str_radix(nanos, 16).ok()?,
+                None => 0,
+            };
+            if nanos >= NANOS_PER_SEC {
+                return None;
+            }
+            Some(Timestamp::from_nanos(
+                (seconds - UNIX_EPOCH) * NANOS_PER_SEC + nanos,
+            ))
+        })
+        .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    fn timestamp(input: &str) -> Option<String> {
+        let mut s = input;
+        let ts: jiff::Timestamp = parse(&mut s).ok()?.try_into().ok()?;
+        Some(ts.to_string())
+    }
+
+    #[test]
+    fn labels() {
+        for (input, expected) in [
+            ("@400000000000000a", "1970-01-01T00:00:00Z"),
+            ("@4000000065a1c2ea", "2024-01-12T22:53:20Z"),
+            (
+                "@4000000065a1c2ea075bcd15",
+                "2024-01-12T22:53:20.123456789Z",
+            ),
+            (
+                "@4000000065A1C2EA075BCD15",
+                "2024-01-12T22:53:20.123456789Z",
+            ),
+            (
+                "@4000000065a1c2ea075bcd1512345678",
+                "2024-01-12T22:53:20.123456789Z",
+            ),
+            ("@4000000000000000", "1969-12-31T23:59:50Z"),
+            (
+                "  @4000000065a1c2ea075bcd15  ",
+                "2024-01-12T22:53:20.123456789Z",
+            ),
+        ] {
+            assert_eq!(timestamp(input).as_deref(), Some(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "@1705100000",
+            "@4000000065a1c2e",
+            "@4000000065a1c2ea075bcd",
+            "@4000000065a1c2ea075bcd151",
+            "@8000000065a1c2ea",
+            "@3fffffffffffffff",
+            "@4000000065a1c2ea3b9aca00",
+            "@4000000000000000ns",
+            "@4000000065a1c2ea.5",
+            "4000000065a1c2ea075bcd15",
+        ] {
+            assert_eq!(timestamp(input), None, "{input}");
+        }
+    }
+}
@@ -38,15 +38,22 @@
 //! > Either ‘am’/‘pm’ or a time zone correction may be specified, but not both.
 
 use winnow::{
-    combinator::{alt, opt, preceded},
-    error::ErrMode,
+    ascii::{alpha1, digit1},
+    combinator::{alt, not, opt, preceded, terminated},
+    error::{ContextError, ErrMode, FromExternalError},
+    stream::AsChar,
+    token::{one_of, take_while},
     ModalResult, Parser,
 };
 
+use crate::{parser::Options, Component, InvalidComponent};
+
 use super::{
     epoch::sec_and_nsec,
-    offset::{timezone_offset, Offset},
-    primitive::{colon, ctx_err, dec_uint, s},
+    epoch::NANOS_PER_SEC,
+    error::Error,
+    offset::{self, timezone_offset, Offset},
+    primitive::{colon, ctx_err, dec_uint, fraction_of, s, space},
 };
 
 #[derive(PartialEq, Clone, Debug, Default)]
@@ -78,69 +85,142 @@ enum Meridiem {
     Pm,
 }
 
-pub(crate) fn parse(input: &mut &str) -> ModalResult<Time> {
-    alt((am_pm_time, iso)).parse_next(input)
+/// Parse a time item. The meridiem markers of the configured locale are
+/// accepted in addition to the English ones.
+pub(crate) fn parse(input: &mut &str, options: &Options) -> ModalResult<Time> {
+    let offset = offset::time_offset(options);
+    alt((
+        |i: &mut &str| am_pm_time(i, options),
+        move |i: &mut &str| iso_with(i, offset),
+    ))
+    .parse_next(input)
 }
 
 /// Parse an ISO 8601 time string
 ///
 /// Also used by the [`combined`](super::combined) module
 pub(super) fn iso(input: &mut &str) -> ModalResult<Time> {
+    iso_with(input, timezone_offset)
+}
+
+/// Parse an ISO 8601 time string, with its offset parsed by `offset`.
+pub(super) fn iso_with(
+    input: &mut &str,
+    offset: fn(&mut &str) -> ModalResult<Offset>,
+) -> ModalResult<Time> {
     alt((
-        (hour24, timezone_offset).map(|(hour, offset)| Time {
+        (hour24, offset).map(|(hour, offset)| Time {
             hour,
             minute: 0,
             second: 0,
             nanosecond: 0,
             offset: Some(offset),
         }),
-        (
-            hour24,
-            colon,
-            minute,
-            opt(preceded(colon, second)),
-            opt(timezone_offset),
-        )
-            .map(|(hour, _, minute, sec_nsec, offset)| Time {
-                hour,
-                minute,
-                second: sec_nsec.map_or(0, |(s, _)| s),
-                nanosecond: sec_nsec.map_or(0, |(_, ns)| ns),
-                offset,
-            }),
+        |i: &mut &str| hour_minute_second(i, offset),
     ))
     .parse_next(input)
 }
 
+/// Parse `[hour]:[minute]`, optionally followed by `:[second]` or by a
+/// fraction of the minute after a comma or a period (e.g., "10:30.5" is
+/// 10:30:30), and an offset.
+///
+/// A component out of range (e.g., the hour of "25:00") is well formed, and so
+/// fails without backtracking with an invalid time error, naming the
+/// component, rather than a syntax error.
+fn hour_minute_second(
+    input: &mut &str,
+    offset: fn(&mut &str) -> ModalResult<Offset>,
+) -> ModalResult<Time> {
+    let (hour, _, minute) = (s(dec_uint), colon, s(dec_uint)).parse_next(input)?;
+    let sec_nsec = match opt(preceded(colon, s(sec_and_nsec))).parse_next(input)? {
+        Some(sec_nsec) => Some(sec_nsec),
+        // ISO 8601 fractional minutes, which GNU `date` does not support.
+        None => opt(preceded(one_of(['.', ',']), digit1))
+            .parse_next(input)?
+            .map(|fraction| {
+                let nanos = fraction_of(fraction, 60 * NANOS_PER_SEC);
+                (
+                    (nanos / NANOS_PER_SEC) as u64,
+                    (nanos % NANOS_PER_SEC) as u32,
+                )
+            }),
+    };
+    let offset = opt(offset).parse_next(input)?;
+
+    let (second, nanosecond) = sec_nsec.unwrap_or((0, 0));
+    Ok(Time {
+        hour: in_range(Component::Hour, hour, 24, "hour must be between 0 and 23")?,
+        minute: in_range(
+            Component::Minute,
+            minute,
+            60,
+            "minute must be between 0 and 59",
+        )?,
+        second: in_range(
+            Component::Second,
+            second,
+            60,
+            "second must be between 0 and 59",
+        )?,
+        nanosecond,
+        offset,
+    })
+}
+
+/// Parse an hour with a fraction after a comma or a period (e.g., "10.5" is
+/// 10:30), and an optional offset, as ISO 8601 allows.
+///
+/// GNU `date` does not support fractional hours, and a number with a fraction
+/// on its own is ambiguous, so this is only used after the "T" of a date and
+/// time by [`combined`](super::combined).
+pub(super) fn fractional_hour(input: &mut &str) -> ModalResult<Time> {
+    let (hour, _, fraction, offset) =
+        (hour24, one_of(['.', ',']), digit1, opt(timezone_offset)).parse_next(input)?;
+
+    let nanos = fraction_of(fraction, 3600 * NANOS_PER_SEC);
+    Ok(Time {
+        hour,
+        minute: (nanos / (60 * NANOS_PER_SEC)) as u8,
+        second: (nanos / NANOS_PER_SEC % 60) as u8,
+        nanosecond: (nanos % NANOS_PER_SEC) as u32,
+        offset,
+    })
+}
+
+/// Check that a component of a time is in `0..max`.
+fn in_range(component: Component, value: u64, max: u64, reason: &'static str) -> ModalResult<u8> {
+    if value < max {
+        Ok(value as u8)
+    } else {
+        Err(ErrMode::Cut(ContextError::from_external_error(
+            &"",
+            Error::InvalidTime(InvalidComponent::new(component, value, reason)),
+        )))
+    }
+}
+
 /// Parse a time ending with AM or PM
 ///
-/// The hours are restricted to 12 or lower in this format
-fn am_pm_time(input: &mut &str) -> ModalResult<Time> {
-    let (h, m, sec_nsec, meridiem) = (
+/// The hours are restricted to 12 or lower in this format. The meridiem
+/// markers of a locale may also precede the time (e.g., "午後3:30").
+fn am_pm_time(input: &mut &str, options: &Options) -> ModalResult<Time> {
+    let locale = options.locale;
+    let prefix = opt(|i: &mut &str| localized_meridiem(i, locale)).parse_next(input)?;
+    let (h, m, sec_nsec) = (
         hour12,
         opt(preceded(colon, minute)),
         opt(preceded(colon, second)),
-        alt((
-            s("am").value(Meridiem::Am),
-            s("a.m.").value(Meridiem::Am),
-            s("pm").value(Meridiem::Pm),
-            s("p.m.").value(Meridiem::Pm),
-        )),
     )
         .parse_next(input)?;
+    let meridiem = match prefix {
+        Some(meridiem) => meridiem,
+        None => alt((meridiem, |i: &mut &str| localized_meridiem(i, locale))).parse_next(input)?,
+    };
 
-    if h == 0 {
-        return Err(ErrMode::Cut(ctx_err(
-            "hour must be greater than 0 when meridiem is specified",
-        )));
-    }
-
-    let mut h = h % 12;
-    if let Meridiem::Pm = meridiem {
-        h += 12;
-    }
+    let exact_hour = m.unwrap_or(0) == 0 && sec_nsec.map_or(true, |(s, ns)| s == 0 && ns == 0);
     Ok(Time {
-        hour: h,
+        hour: hour_from_meridiem(h, meridiem, exact_hour && options.strict_meridiem)?,
         minute: m.unwrap_or(0),
         second: sec_nsec.map_or(0, |(s, _)| s),
         nanosecond: sec_nsec.map_or(0, |(_, ns)| ns),
@@ -148,6 +228,146 @@ fn am_pm_time(input: &mut &str) -> ModalResult<Time> {
     })
 }
 
+/// Parse an hour phrased in words, e.g., "5 o'clock", "five o'clock pm" or
+/// "five pm".
+///
+/// Without a meridiem marker, the hour is taken as is (e.g., "5 o'clock" is
+/// 05:00 and "twelve o'clock" is noon).
+pub(super) fn oclock(input: &mut &str, options: &Options) -> ModalResult<Time> {
+    let (h, meridiem) = alt((
+        (
+            hour_word,
+            alt((preceded(s(oclock_word), opt(meridiem)), meridiem.map(Some))),
+        ),
+        (hour12, preceded(s(oclock_word), opt(meridiem))),
+    ))
+    .parse_next(input)?;
+
+    let hour = match meridiem {
+        Some(meridiem) => hour_from_meridiem(h, meridiem, options.strict_meridiem)?,
+        None => h,
+    };
+    Ok(Time {
+        hour,
+        ..Default::default()
+    })
+}
+
+/// Parse a four-digit time followed by "hours" or "hrs", as in operational
+/// logs (e.g., "0930 hours" or "1700 hrs").
+///
+/// GNU `date` parses "0930 hours" as a relative item (930 hours from now), and
+/// so does the parser unless this phrasing is enabled, while "1700 hrs" is
+/// rejected. "ago" still makes a relative item of them (e.g., "0930 hours
+/// ago").
+pub(super) fn military(input: &mut &str) -> ModalResult<Time> {
+    terminated(
+        s(take_while(4, AsChar::is_dec_digit)),
+        (s(alt(("hours", "hrs"))), not(s("ago"))),
+    )
+    .verify_map(|digits: &str| {
+        let (hour, minute) = (digits[..2].parse().ok()?, digits[2..].parse().ok()?);
+        (hour < 24 && minute < 60).then_some(Time {
+            hour,
+            minute,
+            ..Default::default()
+        })
+    })
+    .parse_next(input)
+}
+
+/// Parse "noon" or "midnight".
+pub(super) fn noon_or_midnight(input: &mut &str) -> ModalResult<Time> {
+    s(alpha1)
+        .verify_map(|s: &str| match s {
+            "noon" => Some(12),
+            "midnight" => Some(0),
+            _ => None,
+        })
+        .map(|hour| Time {
+            hour,
+            ..Default::default()
+        })
+        .parse_next(input)
+}
+
+/// Convert an hour in `1..=12` followed by a meridiem marker to an hour in
+/// `0..24`.
+///
+/// In strict mode, "12am" and "12pm" are rejected: "midnight" and "noon" (or
+/// the 24-hour notation) must be used instead.
+fn hour_from_meridiem(hour: u8, meridiem: Meridiem, strict: bool) -> ModalResult<u8> {
+    if hour == 0 {
+        return Err(ErrMode::Cut(ctx_err(
+            "hour must be greater than 0 when meridiem is specified",
+        )));
+    } else if hour == 12 && strict {
+        return Err(ErrMode::Cut(ctx_err(
+            "12am and 12pm are ambiguous, use midnight or noon instead",
+        )));
+    }
+
+    Ok(match meridiem {
+        Meridiem::Am => hour % 12,
+        Meridiem::Pm => hour % 12 + 12,
+    })
+}
+
+fn oclock_word<'a>(input: &mut &'a str) -> ModalResult<&'a str> {
+    alt(("o'clock", "o\u{2019}clock", "oclock")).parse_next(input)
+}
+
+/// Parse an hour in `1..=12` spelled out in English.
+fn hour_word(input: &mut &str) -> ModalResult<u8> {
+    s(alpha1)
+        .verify_map(|s: &str| {
+            Some(match s {
+                "one" => 1,
+                "two" => 2,
+                "three" => 3,
+                "four" => 4,
+                "five" => 5,
+                "six" => 6,
+                "seven" => 7,
+                "eight" => 8,
+                "nine" => 9,
+                "ten" => 10,
+                "eleven" => 11,
+                "twelve" => 12,
+                _ => return None,
+            })
+        })
+        .parse_next(input)
+}
+
+/// Parse an English meridiem marker: "am", "a.m.", "a.m" or "a. m." (and
+/// likewise for "pm").
+fn meridiem(input: &mut &str) -> ModalResult<Meridiem> {
+    s(alt((
+        alt(("a. m.", "a.m.", "a.m", "am")).value(Meridiem::Am),
+        alt(("p. m.", "p.m.", "p.m", "pm")).value(Meridiem::Pm),
+    )))
+    .parse_next(input)
+}
+
+/// Parse a meridiem marker of `locale`.
+fn localized_meridiem(input: &mut &str, locale: Option<crate::Locale>) -> ModalResult<Meridiem> {
+    let Some(locale) = locale else {
+        return Err(ErrMode::Backtrack(ContextError::new()));
+    };
+
+    space.parse_next(input)?;
+    let (am, pm) = locale.meridiem_markers();
+    for (markers, meridiem) in [(am, Meridiem::Am), (pm, Meridiem::Pm)] {
+        if let Some(marker) = markers.iter().find(|m| input.starts_with(*m)) {
+            *input = &input[marker.len()..];
+            return Ok(meridiem);
+        }
+    }
+
+    Err(ErrMode::Backtrack(ContextError::new()))
+}
+
 /// Parse a number of hours in `0..24`.
 pub(super) fn hour24(input: &mut &str) -> ModalResult<u8> {
     s(dec_uint).verify(|x| *x < 24).parse_next(input)
@@ -175,6 +395,12 @@ fn second(input: &mut &str) -> ModalResult<(u8, u32)> {
 mod tests {
     use super::*;
 
+    use crate::Locale;
+
+    fn parse(input: &mut &str) -> ModalResult<Time> {
+        super::parse(input, &Options::default())
+    }
+
     #[test]
     fn simple() {
         let reference = Time {
@@ -202,6 +428,8 @@ mod tests {
             "8:   02     pm",
             "8:02p.m.",
             "8:   02     p.m.",
+            "8:02 p.m",
+            "8:02 p. m.",
         ] {
             let old_s = s.to_owned();
             assert_eq!(
@@ -212,6 +440,99 @@ mod tests {
         }
     }
 
+    #[test]
+    fn localized_meridiem() {
+        for (locale, input, hour, rest) in [
+            (Locale::Japanese, "午後3:30", 15, ""),
+            (Locale::Japanese, "午前 11:30 tomorrow", 11, " tomorrow"),
+            (Locale::Chinese, "下午3:30", 15, ""),
+            (Locale::Korean, "오후 12:30", 12, ""),
+            (Locale::Korean, "3:30 오전", 3, ""),
+            (Locale::Greek, "3:30 μ.μ.", 15, ""),
+        ] {
+            let mut s = input;
+            let options = Options {
+                locale: Some(locale),
+                ..Default::default()
+            };
+            let time = super::parse(&mut s, &options).unwrap();
+            assert_eq!((time.hour, time.minute), (hour, 30), "{input}");
+            assert_eq!(s, rest, "{input}");
+        }
+
+        // Localized markers are only accepted when the locale is configured.
+        assert!(parse(&mut "午後3:30").is_err());
+        let mut s = "3:30 午後";
+        assert_eq!(parse(&mut s).unwrap().hour, 3);
+        assert_eq!(s, " 午後");
+    }
+
+    #[test]
+    fn oclock() {
+        for (input, hour) in [
+            ("5 o'clock", 5),
+            ("5 oclock", 5),
+            ("five o\u{2019}clock", 5),
+            ("five o'clock pm", 17),
+            ("12 o'clock", 12),
+            ("twelve o'clock am", 0),
+            ("eleven   o'clock   p.m.", 23),
+            ("nine pm", 21),
+        ] {
+            let mut s = input;
+            assert_eq!(
+                super::oclock(&mut s, &Options::default()).unwrap(),
+                Time {
+                    hour,
+                    ..Default::default()
+                },
+                "{input}"
+            );
+            assert!(s.is_empty(), "{input}");
+        }
+
+        for input in ["five", "13 o'clock", "0 o'clock pm", "5 pm"] {
+            let mut s = input;
+            assert!(
+                super::oclock(&mut s, &Options::default()).is_err(),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn strict_meridiem() {
+        let options = Options {
+            strict_meridiem: true,
+            ..Default::default()
+        };
+
+        for input in ["12am", "12 pm", "12:00 a.m.", "12:00:00pm"] {
+            let mut s = input;
+            assert!(parse(&mut s).is_ok(), "{input}");
+            let mut s = input;
+            assert!(super::parse(&mut s, &options).is_err(), "{input}");
+        }
+        assert!(super::oclock(&mut "twelve o'clock pm", &options).is_err());
+
+        for (input, hour) in [("12:30am", 0), ("12:30 pm", 12), ("11pm", 23), ("1am", 1)] {
+            let mut s = input;
+            assert_eq!(
+                super::parse(&mut s, &options).unwrap().hour,
+                hour,
+                "{input}"
+            );
+        }
+        assert_eq!(super::oclock(&mut "12 o'clock", &options).unwrap().hour, 12);
+    }
+
+    #[test]
+    fn noon_or_midnight() {
+        assert_eq!(super::noon_or_midnight(&mut "noon").unwrap().hour, 12);
+        assert_eq!(super::noon_or_midnight(&mut "midnight").unwrap().hour, 0);
+        assert!(super::noon_or_midnight(&mut "noonday").is_err());
+    }
+
     #[test]
     fn invalid() {
         assert!(parse(&mut "00:00am").is_err());
@@ -283,6 +604,47 @@ mod tests {
         }
     }
 
+    #[test]
+    fn fractional_minutes() {
+        let reference = Time {
+            hour: 10,
+            minute: 30,
+            second: 45,
+            nanosecond: 0,
+            offset: None,
+        };
+
+        for mut s in ["10:30.75", "10:30,75", "10:30.750"] {
+            let old_s = s.to_owned();
+            assert_eq!(
+                parse(&mut s).ok(),
+                Some(reference.clone()),
+                "Format string: {old_s}"
+            );
+        }
+
+        let mut s = "10:00.0000001";
+        assert_eq!(parse(&mut s).unwrap().nanosecond, 6000);
+    }
+
+    #[test]
+    fn fractional_hours() {
+        for (mut s, (minute, second, nanosecond)) in [
+            ("10.5", (30, 0, 0)),
+            ("10,25", (15, 0, 0)),
+            ("10.0001", (0, 0, 360_000_000)),
+        ] {
+            let time = super::fractional_hour(&mut s).unwrap();
+            assert_eq!(
+                (time.hour, time.minute, time.second, time.nanosecond),
+                (10, minute, second, nanosecond)
+            );
+        }
+
+        assert!(super::fractional_hour(&mut "24.5").is_err());
+        assert!(super::fractional_hour(&mut "10").is_err());
+    }
+
     #[test]
     fn noon() {
         let reference = Time {
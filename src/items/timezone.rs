@@ -14,24 +14,84 @@
 //! > the date, and any quotes or backslashes within RULE must be escaped by a
 //! > backslash.
 
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock, PoisonError},
+};
+
 use jiff::tz::{Offset, TimeZone};
 use winnow::{
-    combinator::{alt, delimited, opt, preceded, repeat},
+    ascii::multispace0,
+    combinator::{alt, delimited, eof, opt, preceded, terminated},
     stream::AsChar,
-    token::{one_of, take_while},
+    token::{rest, take_while},
     ModalResult, Parser,
 };
 
-use super::primitive::{dec_uint, plus_or_minus};
+use crate::Quirk;
 
+use super::primitive::{dec_uint, escaped_string, plus_or_minus};
+
+/// Parse a timezone rule. Quotes and backslashes within the rule must be
+/// escaped by a backslash; an unterminated rule is an error.
 pub(super) fn parse(input: &mut &str) -> ModalResult<TimeZone> {
-    delimited("TZ=\"", preceded(opt(':'), alt((posix, iana))), '"').parse_next(input)
+    let tz = rule.parse_next(input)?;
+    Ok(rule_time_zone(&tz))
 }
 
-/// Parse a posix (proleptic) timezone string (e.g., "UTC7", "JST-9").
+/// Parse a timezone rule, and return its unescaped text.
+pub(super) fn rule(input: &mut &str) -> ModalResult<String> {
+    preceded((multispace0, "TZ="), escaped_string).parse_next(input)
+}
+
+/// Return the GNU quirk that the timezone of a rule relies on, if any: an
+/// offset that is clamped (e.g., "UTC+30"), or an unknown timezone for which
+/// UTC is used.
+pub(super) fn rule_quirk(rule: &str) -> Option<Quirk> {
+    let rule = rule.strip_prefix(':').unwrap_or(rule);
+    if let Ok((_, h, m, s)) = preceded(posix_std, posix_offset_parts).parse(rule) {
+        return (h > 24 || m > 59 || s > 59).then_some(Quirk::OffsetClamped);
+    }
+
+    let known = rule.is_empty() || cached_posix(rule).is_some() || TimeZone::get(rule).is_ok();
+    (!known).then_some(Quirk::UnknownTimeZone)
+}
+
+/// Return the abbreviation of a rule with a fixed offset (e.g., "UTC" for
+/// "UTC+5"), which the fixed offset timezone of the rule does not carry.
+pub(super) fn rule_abbreviation(rule: &str) -> Option<String> {
+    let mut rule = rule.strip_prefix(':').unwrap_or(rule);
+    terminated(posix_std, (posix_offset, eof))
+        .parse_next(&mut rule)
+        .ok()
+        .map(str::to_string)
+}
+
+/// Return the timezone of the `TZ` environment variable, interpreted as a
+/// timezone rule, or the system timezone if it is not set.
+pub(super) fn from_env() -> TimeZone {
+    match std::env::var("TZ") {
+        Ok(rule) => rule_time_zone(&rule),
+        Err(_) => TimeZone::system(),
+    }
+}
+
+/// Return the timezone of the unescaped text of a timezone rule, which is a
+/// posix timezone string or an IANA timezone name, optionally preceded by a
+/// colon.
+pub(super) fn rule_time_zone(rule: &str) -> TimeZone {
+    let mut rule = rule.strip_prefix(':').unwrap_or(rule);
+    alt((posix, iana))
+        .parse_next(&mut rule)
+        .unwrap_or(TimeZone::UTC)
+}
+
+/// Parse a posix (proleptic) timezone string (e.g., "UTC7", "JST-9",
+/// "<+0530>-5:30" or "EST5EDT,M3.2.0,M11.1.0").
 ///
-/// TODO: This implementation is incomplete. It currently only parses the
-/// `STDOFFSET` part of the format.
+/// The `STDOFFSET` part is parsed here, with the clamping of GNU `date`. A
+/// string with daylight saving time rules is parsed by jiff. Compiled rules
+/// are cached, keyed by the text of the rule.
 ///
 /// From the GNU docs:
 ///
@@ -40,7 +100,9 @@ pub(super) fn parse(input: &mut &str) -> ModalResult<TimeZone> {
 /// >   STDOFFSET[DST[OFFSET][,START[/TIME],END[/TIME]]]
 /// >
 /// > The STD string specifies the time zone abbreviation, which must be at
-/// > least three bytes long. ...
+/// > least three bytes long. If it contains only ASCII alphabetic
+/// > characters, it may be unquoted; otherwise, it must be enclosed in
+/// > angle brackets ('<' and '>'). ...
 /// >
 /// > The OFFSET specifies the time value you must add to the local time to
 /// > get a UTC value.  It has syntax like:
@@ -53,9 +115,66 @@ pub(super) fn parse(input: &mut &str) -> ModalResult<TimeZone> {
 /// > hour HH must be between 0 and 24 and may be a single digit, and the
 /// > minutes MM and seconds SS, if present, must be between 0 and 59.
 fn posix(input: &mut &str) -> ModalResult<TimeZone> {
-    (take_while(3.., AsChar::is_alpha), posix_offset)
-        .verify_map(|(_, offset)| Offset::from_seconds(offset).ok().map(|o| o.to_time_zone()))
-        .parse_next(input)
+    rest.verify_map(cached_posix).parse_next(input)
+}
+
+/// Compile a posix timezone string, or return it from the cache of compiled
+/// rules. Batches of dates tend to repeat the same rule, which then need not
+/// be parsed again for each date.
+fn cached_posix(rule: &str) -> Option<TimeZone> {
+    // The cache is cleared when full, so that it does not grow without bound
+    // on arbitrary input.
+    const CAPACITY: usize = 64;
+    static CACHE: OnceLock<Mutex<HashMap<String, Option<TimeZone>>>> = OnceLock::new();
+
+    let cache = CACHE.get_or_init(Default::default);
+    if let Some(tz) = cache
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(rule)
+    {
+        return tz.clone();
+    }
+
+    let tz = compile_posix(rule);
+    let mut cache = cache.lock().unwrap_or_else(PoisonError::into_inner);
+    if cache.len() >= CAPACITY {
+        cache.clear();
+    }
+    cache.insert(rule.to_string(), tz.clone());
+    tz
+}
+
+/// Compile a posix timezone string.
+fn compile_posix(rule: &str) -> Option<TimeZone> {
+    if let Ok(offset) = preceded(posix_std, posix_offset).parse(rule) {
+        return Offset::from_seconds(offset).ok().map(|o| o.to_time_zone());
+    }
+
+    // Without transition rules, a name such as "EST5EDT" is an IANA name.
+    if rule.contains(',') {
+        TimeZone::posix(rule).ok()
+    } else {
+        None
+    }
+}
+
+/// Parse the `STD` part of a posix timezone string, which is either at least
+/// three letters (e.g., "JST") or, in the quoted form used by tzdata for
+/// numeric abbreviations, at least three ASCII alphanumeric characters, '+' or
+/// '-' between angle brackets (e.g., "<+0530>").
+fn posix_std<'a>(input: &mut &'a str) -> ModalResult<&'a str> {
+    alt((
+        take_while(3.., AsChar::is_alpha),
+        delimited(
+            '<',
+            take_while(3.., |c: char| {
+                c.is_ascii_alphanumeric() || c == '+' || c == '-'
+            }),
+            '>',
+        ),
+    ))
+    .parse_next(input)
 }
 
 /// Parse an IANA (geographical) timezone string (e.g., "Europe/Paris"). If the
@@ -82,30 +201,13 @@ fn posix(input: &mut &str) -> ModalResult<TimeZone> {
 /// > administrator, who also sets ‘/etc/localtime’ to point to the data file
 /// > for the local time zone ruleset.
 fn iana(input: &mut &str) -> ModalResult<TimeZone> {
-    repeat(
-        0..,
-        alt((
-            preceded('\\', one_of(['\\', '"'])).map(|c: char| c.to_string()),
-            take_while(1, |c| c != '"' && c != '\\').map(str::to_string),
-        )),
-    )
-    .map(|parts: Vec<String>| parts.concat())
-    .map(|s| TimeZone::get(&s).unwrap_or(TimeZone::UTC))
-    .parse_next(input)
+    rest.map(|s| TimeZone::get(s).unwrap_or(TimeZone::UTC))
+        .parse_next(input)
 }
 
 fn posix_offset(input: &mut &str) -> ModalResult<i32> {
-    let uint = dec_uint::<u32, _>;
-
-    (
-        opt(plus_or_minus),
-        alt((
-            (uint, preceded(':', uint), preceded(':', uint)).map(|(h, m, s)| (h, m, s)),
-            (uint, preceded(':', uint)).map(|(h, m)| (h, m, 0)),
-            uint.map(|h| (h, 0, 0)),
-        )),
-    )
-        .map(|(sign, (h, m, s))| {
+    posix_offset_parts
+        .map(|(sign, h, m, s)| {
             // The sign is opposite from the usual convention:
             // - Positive offsets are west of UTC.
             // - Negative offsets are east of UTC.
@@ -123,8 +225,27 @@ fn posix_offset(input: &mut &str) -> ModalResult<i32> {
         .parse_next(input)
 }
 
+/// Parse the sign, hours, minutes and seconds of a posix offset, before they
+/// are clamped.
+fn posix_offset_parts(input: &mut &str) -> ModalResult<(Option<char>, u32, u32, u32)> {
+    let uint = dec_uint::<u32, _>;
+
+    (
+        opt(plus_or_minus),
+        alt((
+            (uint, preceded(':', uint), preceded(':', uint)).map(|(h, m, s)| (h, m, s)),
+            (uint, preceded(':', uint)).map(|(h, m)| (h, m, 0)),
+            uint.map(|h| (h, 0, 0)),
+        )),
+    )
+        .map(|(sign, (h, m, s))| (sign, h, m, s))
+        .parse_next(input)
+}
+
 #[cfg(test)]
 mod tests {
+    use winnow::error::ErrMode;
+
     use super::*;
 
     #[test]
@@ -135,6 +256,7 @@ mod tests {
             (r#"TZ=":""#, "UTC"),
             (r#"TZ="  ""#, "UTC"),
             (r#"TZ=":  ""#, "UTC"),
+            (r#"  TZ="""#, "UTC"),
         ] {
             let mut s = input;
             assert_eq!(
@@ -188,6 +310,30 @@ mod tests {
             );
         }
 
+        // escaped
+        for (input, expected) in [
+            (r#"TZ="America\/New_York""#, None),
+            (r#"TZ="Asia/Tokyo\\""#, Some("UTC")),
+            (r#"TZ="\"Asia/Tokyo\"""#, Some("UTC")),
+        ] {
+            let mut s = input;
+            assert_eq!(
+                parse(&mut s)
+                    .ok()
+                    .map(|tz| tz.iana_name().map(str::to_owned)),
+                expected.map(|name| Some(name.to_owned())),
+                "{input}"
+            );
+        }
+
+        // unterminated
+        for input in [r#"TZ=""#, r#"TZ="Asia/Tokyo"#, r#"TZ="Asia/Tokyo\""#] {
+            let mut s = input;
+            let err = parse(&mut s).unwrap_err();
+            assert!(matches!(err, ErrMode::Cut(_)), "{input}");
+            assert!(err.to_string().contains("closing quote"), "{input}");
+        }
+
         // invalid
         for input in [
             r#"UTC"#,      // missing "TZ="
@@ -199,6 +345,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn rule_time_zones() {
+        for (rule, expected) in [
+            ("", 0),
+            (":", 0),
+            ("UTC+5", -5 * 3600),
+            ("<+0530>-5:30", 5 * 3600 + 30 * 60),
+            ("Asia/Tokyo", 9 * 3600),
+            (":Asia/Tokyo", 9 * 3600),
+            ("Unknown/Timezone", 0),
+        ] {
+            let ts = jiff::Timestamp::UNIX_EPOCH;
+            assert_eq!(
+                rule_time_zone(rule).to_offset(ts).seconds(),
+                expected,
+                "{rule}"
+            );
+        }
+    }
+
+    #[test]
+    fn rule_quirks() {
+        for (rule, expected) in [
+            ("", None),
+            ("UTC+5", None),
+            ("Asia/Tokyo", None),
+            (":Asia/Tokyo", None),
+            ("EST5EDT,M3.2.0,M11.1.0", None),
+            ("UTC+30", Some(Quirk::OffsetClamped)),
+            ("UTC-5:75", Some(Quirk::OffsetClamped)),
+            ("Unknown/Timezone", Some(Quirk::UnknownTimeZone)),
+            ("UTC5EDT,foo", Some(Quirk::UnknownTimeZone)),
+        ] {
+            assert_eq!(rule_quirk(rule), expected, "{rule}");
+        }
+    }
+
     #[test]
     fn parse_iana() {
         for (input, expected) in [
@@ -265,16 +448,56 @@ mod tests {
             assert_eq!(to_seconds(input), expected, "{input}");
         }
 
+        // quoted
+        for (input, expected) in [
+            ("<+05>-5", 5 * 3600),
+            ("<+0530>-5:30", 5 * 3600 + 30 * 60),
+            ("<-03>3", -3 * 3600),
+            ("<UTC>0", 0),
+        ] {
+            assert_eq!(to_seconds(input), expected, "{input}");
+        }
+
         // invalid
         for input in [
-            "AB",  // too short
-            "A1C", // not just letters
+            "<+5>-5",           // quoted, too short
+            "<+05-5",           // quoted, unterminated
+            "<+0 5>-5",         // quoted, not alphanumeric
+            "AB",               // too short
+            "A1C",              // not just letters
+            "ABC",              // no offset
+            "EST5EDT",          // no transition rules
+            "EST5EDT,M3.2.0",   // incomplete transition rules
+            "America/New_York", // not a posix string
         ] {
             let mut s = input;
             assert!(posix(&mut s).is_err(), "{input}");
         }
     }
 
+    #[test]
+    fn parse_posix_rules() {
+        // Quoted names are accepted in the DST part as well.
+        let mut s = "<+0330>-3:30<+0430>,J79/24,J263/24";
+        let tz = posix(&mut s).unwrap();
+        let ts: jiff::Timestamp = "2025-07-01T12:00:00Z".parse().unwrap();
+        assert_eq!(tz.to_offset(ts).seconds(), 4 * 3600 + 30 * 60);
+
+        let rule = "EST5EDT,M3.2.0,M11.1.0";
+        let mut s = rule;
+        let tz = posix(&mut s).unwrap();
+        for (ts, expected) in [
+            ("2025-01-15T12:00:00Z", -5 * 3600),
+            ("2025-07-01T12:00:00Z", -4 * 3600),
+        ] {
+            let ts: jiff::Timestamp = ts.parse().unwrap();
+            assert_eq!(tz.to_offset(ts).seconds(), expected, "{ts}");
+        }
+
+        // The compiled rule is cached, and returned again.
+        assert_eq!(cached_posix(rule), Some(tz));
+    }
+
     #[test]
     fn parse_posix_offset() {
         // hour
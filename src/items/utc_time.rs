@@ -0,0 +1,131 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse an ASN.1 UTCTime, as X.509 certificates store their validity.
+//!
+//! X.680 specifies a UTCTime as a two-digit year, month, day, hour and
+//! minute, optional seconds, and "Z" or an offset of hours and minutes:
+//!
+//! ```abnf
+//! UTCTime = year month day hour minute [ second ] ( "Z" / differential )
+//! year    = 2DIGIT ; 1950 to 2049
+//! differential = ( "+" / "-" ) hour minute
+//! ```
+//!
+//! The year is in 1950 to 2049, as RFC 5280 (section 4.1.2.5.1) specifies:
+//! a year of 50 or more is in the 20th century, and a lower year in the 21st.
+//! X.509 certificates always give the seconds and "Z", which are optional here.
+//!
+//! A leap second (second 60) is rejected, as it cannot be represented.
+
+use jiff::{civil, tz::TimeZone};
+use winnow::{
+    combinator::{alt, eof, opt, terminated},
+    error::{ContextError, ErrMode, FromExternalError},
+    token::one_of,
+    ModalResult, Parser,
+};
+
+use super::{
+    error::Error,
+    primitive::{digits, fixed_offset},
+};
+
+/// Parse a UTCTime, as its civil date and time and the timezone of its offset
+/// ("Z" is UTC).
+pub(super) fn parse(input: &mut &str) -> ModalResult<(civil::DateTime, TimeZone)> {
+    let (year, month, day, hour, minute, second, tz) = terminated(
+        (
+            digits(2),
+            digits(2).verify(|m| (1..=12).contains(m)),
+            digits(2).verify(|d| (1..=31).contains(d)),
+            digits(2).verify(|h| *h < 24),
+            digits(2).verify(|m| *m < 60),
+            opt(digits(2).verify(|s| *s < 60)),
+            zone,
+        ),
+        eof,
+    )
+    .parse_next(input)?;
+
+    let year = if year >= 50 { 1900 + year } else { 2000 + year };
+    let date = civil::Date::new(year as i16, month as i8, day as i8).map_err(|_| {
+        ErrMode::Cut(ContextError::from_external_error(
+            input,
+            Error::Resolve("date is not valid".to_owned()),
+        ))
+    })?;
+    let time = civil::time(hour as i8, minute as i8, second.unwrap_or(0) as i8, 0);
+    Ok((date.to_datetime(time), tz))
+}
+
+/// Parse the timezone, "Z" or an offset of hours and minutes.
+fn zone(input: &mut &str) -> ModalResult<TimeZone> {
+    alt((
+        'Z'.value(TimeZone::UTC),
+        (
+            one_of(['+', '-']),
+            digits(2).verify(|h| *h < 24),
+            digits(2).verify(|m| *m < 60),
+        )
+            .try_map(|(sign, hours, minutes)| {
+                fixed_offset(sign, hours, minutes).map(TimeZone::fixed)
+            }),
+    ))
+    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse;
+
+    fn utc_time(input: &str) -> Option<String> {
+        let (datetime, tz) = parse(&mut &*input).ok()?;
+        let zoned = datetime.to_zoned(tz).ok()?;
+        Some(
+            zoned
+                .timestamp()
+                .display_with_offset(zoned.offset())
+                .to_string(),
+        )
+    }
+
+    #[test]
+    fn valid() {
+        for (input, expected) in [
+            ("240702143000Z", "2024-07-02T14:30:00+00:00"),
+            ("2407021430Z", "2024-07-02T14:30:00+00:00"),
+            ("240702143000+0200", "2024-07-02T14:30:00+02:00"),
+            ("2407021430-2359", "2024-07-02T14:30:00-23:59"),
+            ("491231235959Z", "2049-12-31T23:59:59+00:00"),
+            ("500101000000Z", "1950-01-01T00:00:00+00:00"),
+            ("000229000000Z", "2000-02-29T00:00:00+00:00"),
+        ] {
+            assert_eq!(utc_time(input).as_deref(), Some(expected), "{input}");
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        for input in [
+            "",
+            "240702143000",
+            "240702143000z",
+            "24070214Z",
+            "24070214300Z",
+            "240702143000.5Z",
+            "240702143000+02",
+            "240702143000+02:00",
+            "20240702143000Z",
+            "240702143060Z",
+            "240702146000Z",
+            "240702240000Z",
+            "241302143000Z",
+            "230229143000Z",
+            " 240702143000Z",
+            "240702143000Z ",
+        ] {
+            assert_eq!(utc_time(input), None, "{input}");
+        }
+    }
+}
@@ -20,14 +20,26 @@
 //! > before or after the day that day by itself would represent.
 //! >
 //! > A comma following a day of the week item is ignored.
+//!
+//! In addition to the GNU forms, a day of the week may select an occurrence
+//! within a month, e.g., ‘first monday of march’, ‘second tuesday of march
+//! 2025’, or ‘last friday of the month’. Such an item specifies a calendar
+//! date rather than a displacement.
 
+use jiff::{civil, Span};
 use winnow::{
-    ascii::alpha1,
-    combinator::{opt, terminated},
+    ascii::{alpha1, multispace1},
+    combinator::{alt, eof, opt, terminated},
+    error::ErrMode,
     seq, ModalResult, Parser,
 };
 
-use super::{ordinal::ordinal, primitive::s};
+use super::{
+    date::literal_month,
+    ordinal::ordinal,
+    primitive::{ctx_err, s},
+    year::{year_from_str, year_str},
+};
 
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 pub(crate) enum Day {
@@ -40,12 +52,54 @@ pub(crate) enum Day {
     Sunday,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub(crate) struct Weekday {
     pub(crate) offset: i32,
     pub(crate) day: Day,
 }
 
+/// The n-th occurrence of a day of the week within a month. A negative `nth`
+/// counts from the end of the month (`-1` is the last occurrence). If `month`
+/// or `year` is omitted, it is taken from the base date.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub(crate) struct NthWeekday {
+    pub(crate) nth: i32,
+    pub(crate) day: Day,
+    pub(crate) month: Option<u8>,
+    pub(crate) year: Option<u16>,
+    /// The year was given with two digits, and taken as a year of the 20th or
+    /// 21st century.
+    pub(crate) two_digit_year: bool,
+}
+
+impl NthWeekday {
+    /// Resolve the occurrence to a calendar date, using `base` for the month
+    /// and year when they are not given explicitly.
+    pub(super) fn resolve(&self, base: civil::Date) -> Result<civil::Date, &'static str> {
+        let year = self.year.map_or(base.year(), |y| y as i16);
+        let month = self.month.map_or(base.month(), |m| m as i8);
+        let first = civil::Date::new(year, month, 1).map_err(|_| "date is not valid")?;
+        let target = self.day.into();
+
+        let (start, delta) = if self.nth > 0 {
+            let delta = days_until(first.weekday(), target) + (self.nth - 1) * 7;
+            (first, delta)
+        } else {
+            let last = first.last_of_month();
+            let delta = -days_until(target, last.weekday()) + (self.nth + 1) * 7;
+            (last, delta)
+        };
+
+        let date = Span::new()
+            .try_days(delta)
+            .ok()
+            .and_then(|span| start.checked_add(span).ok())
+            .filter(|date| date.month() == month)
+            .ok_or("weekday does not occur that many times in the month")?;
+        Ok(date)
+    }
+}
+
 impl From<Day> for jiff::civil::Weekday {
     fn from(value: Day) -> Self {
         match value {
@@ -60,6 +114,21 @@ impl From<Day> for jiff::civil::Weekday {
     }
 }
 
+#[cfg(feature = "chrono")]
+impl From<Day> for chrono::Weekday {
+    fn from(value: Day) -> Self {
+        match value {
+            Day::Monday => chrono::Weekday::Mon,
+            Day::Tuesday => chrono::Weekday::Tue,
+            Day::Wednesday => chrono::Weekday::Wed,
+            Day::Thursday => chrono::Weekday::Thu,
+            Day::Friday => chrono::Weekday::Fri,
+            Day::Saturday => chrono::Weekday::Sat,
+            Day::Sunday => chrono::Weekday::Sun,
+        }
+    }
+}
+
 /// Parse a weekday item.
 pub(super) fn parse(input: &mut &str) -> ModalResult<Weekday> {
     seq!(Weekday {
@@ -69,26 +138,77 @@ pub(super) fn parse(input: &mut &str) -> ModalResult<Weekday> {
     .parse_next(input)
 }
 
-fn day(input: &mut &str) -> ModalResult<Day> {
-    s(alpha1)
-        .verify_map(|s: &str| {
-            Some(match s {
-                "monday" | "mon" | "mon." => Day::Monday,
-                "tuesday" | "tue" | "tue." | "tues" => Day::Tuesday,
-                "wednesday" | "wed" | "wed." | "wednes" => Day::Wednesday,
-                "thursday" | "thu" | "thu." | "thur" | "thurs" => Day::Thursday,
-                "friday" | "fri" | "fri." => Day::Friday,
-                "saturday" | "sat" | "sat." => Day::Saturday,
-                "sunday" | "sun" | "sun." => Day::Sunday,
+/// Parse an ordinal weekday within a month, e.g., "first monday of march",
+/// "second tuesday of march 2025", or "last friday of the month".
+pub(super) fn nth_of_month(input: &mut &str) -> ModalResult<NthWeekday> {
+    let (nth, day, _, (month, year)) = (
+        // "second" is not a GNU ordinal (it is a unit), but it is unambiguous
+        // in this position.
+        alt((s("second").value(2), ordinal)).verify(|n| *n != 0),
+        day,
+        s("of"),
+        alt((
+            (alt((s("the"), s("this"))), s("month")).value((None, None)),
+            (
+                literal_month,
+                // The year must be followed by a space or end of input.
+                opt(terminated(year_str, alt((multispace1, eof)))),
+            )
+                .map(|(month, year)| (Some(month), year)),
+        )),
+    )
+        .parse_next(input)?;
+
+    let year = year
+        .map(year_from_str)
+        .transpose()
+        .map_err(|e| ErrMode::Cut(ctx_err(e)))?;
+
+    Ok(NthWeekday {
+        nth,
+        day,
+        month,
+        year: year.map(|year| year.value),
+        two_digit_year: year.is_some_and(|year| year.expanded),
+    })
+}
+
+/// Return the number of days (in `0..7`) from `from` forward to the next
+/// `to`, which is zero when both are the same day.
+pub(super) fn days_until(from: civil::Weekday, to: civil::Weekday) -> i32 {
+    (to.since(civil::Weekday::Monday) as i32 - from.since(civil::Weekday::Monday) as i32)
+        .rem_euclid(7)
+}
+
+pub(super) fn day(input: &mut &str) -> ModalResult<Day> {
+    s((alpha1, opt('.')))
+        .verify_map(|(s, period): (&str, Option<char>)| {
+            let day = match s {
+                "monday" | "mon" => Day::Monday,
+                "tuesday" | "tue" | "tues" => Day::Tuesday,
+                "wednesday" | "wed" | "wednes" => Day::Wednesday,
+                "thursday" | "thu" | "thur" | "thurs" => Day::Thursday,
+                "friday" | "fri" => Day::Friday,
+                "saturday" | "sat" => Day::Saturday,
+                "sunday" | "sun" => Day::Sunday,
                 _ => return None,
-            })
+            };
+            // Only abbreviations may be followed by a period.
+            (period.is_none() || !s.ends_with("day")).then_some(day)
         })
         .parse_next(input)
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{parse, Day, Weekday};
+    use jiff::civil::date;
+
+    use crate::{
+        items::{at, parse_at_date},
+        parser::Options,
+    };
+
+    use super::{nth_of_month, parse, Day, NthWeekday, Weekday};
 
     #[test]
     fn this_monday() {
@@ -141,6 +261,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn abbreviations() {
+        for (mut s, day) in [
+            ("mon.", Day::Monday),
+            ("tues.", Day::Tuesday),
+            ("wednes.", Day::Wednesday),
+            ("thur", Day::Thursday),
+            ("thurs.", Day::Thursday),
+            ("sat.,", Day::Saturday),
+        ] {
+            let input = s;
+            assert_eq!(
+                parse(&mut s).unwrap(),
+                Weekday { offset: 0, day },
+                "{input}"
+            );
+            assert!(s.is_empty(), "{input}");
+        }
+
+        // Only abbreviations may be followed by a period.
+        let mut s = "monday.";
+        assert!(parse(&mut s).is_err());
+    }
+
     #[test]
     fn optional_comma() {
         for mut s in ["monday,", "mon,", "mon.,", "mon. ,"] {
@@ -153,4 +297,112 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn nth_weekday_of_month() {
+        for (input, expected) in [
+            (
+                "first monday of march",
+                (1, Day::Monday, Some(3), None, false),
+            ),
+            (
+                "second tuesday of mar 2025",
+                (2, Day::Tuesday, Some(3), Some(2025), false),
+            ),
+            (
+                "third wed of the month",
+                (3, Day::Wednesday, None, None, false),
+            ),
+            (
+                "last friday of this month",
+                (-1, Day::Friday, None, None, false),
+            ),
+            (
+                "last fri of december 24",
+                (-1, Day::Friday, Some(12), Some(2024), true),
+            ),
+        ] {
+            let mut s = input;
+            let (nth, day, month, year, two_digit_year) = expected;
+            assert_eq!(
+                nth_of_month(&mut s).unwrap(),
+                NthWeekday {
+                    nth,
+                    day,
+                    month,
+                    year,
+                    two_digit_year,
+                },
+                "{input}"
+            );
+        }
+
+        for input in ["this monday of march", "monday of march", "first monday"] {
+            let mut s = input;
+            assert!(nth_of_month(&mut s).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn resolve_nth_weekday() {
+        // March 2025 starts on a Saturday and ends on a Monday.
+        let base = date(2025, 3, 15);
+        for (nth, day, expected) in [
+            (1, Day::Saturday, date(2025, 3, 1)),
+            (1, Day::Monday, date(2025, 3, 3)),
+            (2, Day::Friday, date(2025, 3, 14)),
+            (5, Day::Monday, date(2025, 3, 31)),
+            (-1, Day::Monday, date(2025, 3, 31)),
+            (-1, Day::Sunday, date(2025, 3, 30)),
+            (-2, Day::Saturday, date(2025, 3, 22)),
+        ] {
+            let nth_weekday = NthWeekday {
+                nth,
+                day,
+                month: None,
+                year: None,
+                two_digit_year: false,
+            };
+            assert_eq!(nth_weekday.resolve(base), Ok(expected), "{nth} {day:?}");
+        }
+
+        let nth_weekday = NthWeekday {
+            nth: 5,
+            day: Day::Tuesday,
+            month: None,
+            year: None,
+            two_digit_year: false,
+        };
+        assert!(nth_weekday.resolve(base).is_err());
+    }
+
+    #[test]
+    fn nth_weekday_of_month_resolved() {
+        let now = at("2025-01-15 10:00:00");
+
+        for (input, expected) in [
+            ("first monday of march", "2025-03-03 00:00:00"),
+            ("second tuesday of march 2024", "2024-03-12 00:00:00"),
+            ("last friday of the month", "2025-01-31 00:00:00"),
+            ("first sunday of this month 17:30", "2025-01-05 17:30:00"),
+            ("last friday of march +1 day", "2025-03-29 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &Options::default()).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        for input in [
+            "first monday of march 2025-03-01",
+            "fifth monday of february 2025",
+        ] {
+            assert!(
+                parse_at_date(now.clone(), input, &Options::default()).is_err(),
+                "{input}"
+            );
+        }
+    }
 }
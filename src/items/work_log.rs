@@ -0,0 +1,201 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parse a work-log duration.
+//!
+//! Work-log tools (e.g., Jira or Tempo) write durations as numbers with the
+//! units ‘w’ (weeks), ‘d’ (days), ‘h’ (hours) and ‘m’ (minutes), separated by
+//! spaces, e.g., ‘1w 2d 3h 30m’.
+//!
+//! Each component is a relative item, so in a date string, weeks and days are
+//! calendar weeks and days. As a plain duration, they are converted to hours
+//! with a configurable working time (by default, a day of 8 hours and a week
+//! of 5 days). Both are enabled by setting that working time with
+//! [`Parser::work_time`](crate::Parser::work_time).
+
+use jiff::SignedDuration;
+use winnow::{
+    combinator::{alt, eof, not, peek, preceded, repeat, terminated},
+    token::one_of,
+    ModalResult, Parser,
+};
+
+use crate::WorkTime;
+
+use super::{
+    epoch::sec_and_nsec,
+    primitive::{s, space},
+    relative::Relative,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Unit {
+    Week,
+    Day,
+    Hour,
+    Minute,
+}
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// Parse a component of a work-log duration, e.g., `3h` or `1.5d`.
+fn component(input: &mut &str) -> ModalResult<(u64, u32, Unit)> {
+    (
+        s(sec_and_nsec),
+        alt((
+            'w'.value(Unit::Week),
+            'd'.value(Unit::Day),
+            'h'.value(Unit::Hour),
+            'm'.value(Unit::Minute),
+        )),
+        // The unit must not be the prefix of a word, e.g., "3 hours".
+        peek(not(one_of(|c: char| c.is_alphabetic()))),
+    )
+        .map(|((whole, fraction), unit, _)| (whole, fraction, unit))
+        .parse_next(input)
+}
+
+/// Parse a component of a work-log duration as a relative item. Weeks and days
+/// are calendar weeks and days; fractional weeks and days are converted to
+/// seconds.
+pub(super) fn parse(input: &mut &str) -> ModalResult<Relative> {
+    component
+        .verify_map(|(whole, fraction, unit)| {
+            let whole = i32::try_from(whole).ok()?;
+            let (days, seconds) = match unit {
+                Unit::Week => (7, 604_800),
+                Unit::Day => (1, 86_400),
+                Unit::Hour if fraction == 0 => return Some(Relative::Hours(whole)),
+                Unit::Minute if fraction == 0 => return Some(Relative::Minutes(whole)),
+                Unit::Hour => (0, 3_600),
+                Unit::Minute => (0, 60),
+            };
+            if fraction == 0 {
+                return Some(Relative::Days(whole.checked_mul(days)?));
+            }
+
+            let nanos = (i128::from(whole) * NANOS_PER_SEC + i128::from(fraction)) * seconds;
+            Some(Relative::Seconds(
+                i64::try_from(nanos / NANOS_PER_SEC).ok()?,
+                (nanos % NANOS_PER_SEC) as u32,
+            ))
+        })
+        .parse_next(input)
+}
+
+/// Parse a whole work-log duration as a plain duration, converting weeks and
+/// days to hours with `work_time`.
+pub(super) fn duration(input: &mut &str, work_time: &WorkTime) -> ModalResult<SignedDuration> {
+    let day = work_time.day.as_nanos();
+    let week = day * i128::from(work_time.days_per_week);
+
+    terminated(
+        repeat(1.., component).fold(
+            || 0,
+            move |total: i128, (whole, fraction, unit)| {
+                let unit = match unit {
+                    Unit::Week => week,
+                    Unit::Day => day,
+                    Unit::Hour => 3_600 * NANOS_PER_SEC,
+                    Unit::Minute => 60 * NANOS_PER_SEC,
+                };
+                // The fraction is given in billionths of the unit.
+                total + i128::from(whole) * unit + i128::from(fraction) * unit / NANOS_PER_SEC
+            },
+        ),
+        preceded(space, eof),
+    )
+    .verify_map(|nanos: i128| {
+        Some(SignedDuration::new(
+            i64::try_from(nanos / NANOS_PER_SEC).ok()?,
+            (nanos % NANOS_PER_SEC) as i32,
+        ))
+    })
+    .parse_next(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::SignedDuration;
+
+    use crate::{
+        items::{at, parse_at_date, parse_work_log},
+        parser::Options,
+        WorkTime,
+    };
+
+    use super::{duration, parse, Relative};
+
+    #[test]
+    fn relative() {
+        for (input, expected) in [
+            ("1w", Relative::Days(7)),
+            ("2d", Relative::Days(2)),
+            ("3h", Relative::Hours(3)),
+            ("30m", Relative::Minutes(30)),
+            ("1.5d", Relative::Seconds(129_600, 0)),
+            ("0.5h", Relative::Seconds(1_800, 0)),
+        ] {
+            let mut s = input;
+            assert_eq!(parse(&mut s).unwrap(), expected, "{input}");
+        }
+
+        for input in ["3 h", "3hours", "3x", "h"] {
+            let mut s = input;
+            assert!(parse(&mut s).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn plain_duration() {
+        let hm = |h: i64, m: i64| SignedDuration::from_mins(h * 60 + m);
+        for (input, work_time, expected) in [
+            ("1w 2d 3h 30m", WorkTime::default(), hm(59, 30)),
+            ("3h 30m", WorkTime::default(), hm(3, 30)),
+            ("1.5d", WorkTime::default(), hm(12, 0)),
+            ("2d1h", WorkTime::default(), hm(17, 0)),
+            (
+                "1w 1d",
+                WorkTime::new(SignedDuration::from_mins(450), 4),
+                hm(37, 30),
+            ),
+        ] {
+            let mut s = input;
+            assert_eq!(duration(&mut s, &work_time).unwrap(), expected, "{input}");
+        }
+
+        for input in ["", "1w 2x", "1w tomorrow", "-1h"] {
+            let mut s = input;
+            assert!(duration(&mut s, &WorkTime::default()).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn work_log() {
+        let options = Options {
+            work_time: Some(crate::WorkTime::default()),
+            ..Default::default()
+        };
+        let now = at("2025-06-15 10:00:00");
+
+        for (input, expected) in [
+            ("1w 2d 3h 30m", "2025-06-24 13:30:00"),
+            ("2025-01-01 1w 2d", "2025-01-10 00:00:00"),
+            ("2025-01-01 12:00 1.5d", "2025-01-03 00:00:00"),
+        ] {
+            let result = parse_at_date(now.clone(), input, &options).unwrap();
+            assert_eq!(
+                result.strftime("%Y-%m-%d %H:%M:%S").to_string(),
+                expected,
+                "{input}"
+            );
+        }
+
+        assert_eq!(
+            parse_work_log("1W 2D 3H 30M", &options).unwrap(),
+            SignedDuration::from_mins(59 * 60 + 30)
+        );
+        // Work-log durations are opt-in in date strings.
+        assert!(parse_at_date(now, "1w 2d", &Options::default()).is_err());
+    }
+}
@@ -14,8 +14,21 @@ use winnow::{stream::AsChar, token::take_while, ModalResult, Parser};
 
 use super::primitive::s;
 
+/// A parsed year.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct Year {
+    pub(super) value: u16,
+    /// The year was given with two digits, and taken as a year of the 20th or
+    /// 21st century.
+    ///
+    /// Years are parsed before it is known whether the item they are part of
+    /// is kept, so it is up to the caller to report
+    /// [`Quirk::TwoDigitYear`](crate::Quirk::TwoDigitYear) once it is.
+    pub(super) expanded: bool,
+}
+
 // TODO: Leverage `TryFrom` trait.
-pub(super) fn year_from_str(year_str: &str) -> Result<u16, &'static str> {
+pub(super) fn year_from_str(year_str: &str) -> Result<Year, &'static str> {
     let mut year = year_str
         .parse::<u16>()
         .map_err(|_| "year must be a valid u16 number")?;
@@ -26,7 +39,8 @@ pub(super) fn year_from_str(year_str: &str) -> Result<u16, &'static str> {
     // GNU quirk: this only applies to two-digit years. For example,
     // "98-01-01" will be parsed as "1998-01-01", whereas "098-01-01" will be
     // parsed as "0098-01-01".
-    if year_str.len() == 2 {
+    let expanded = year_str.len() == 2;
+    if expanded {
         if year <= 68 {
             year += 2000
         } else {
@@ -43,7 +57,10 @@ pub(super) fn year_from_str(year_str: &str) -> Result<u16, &'static str> {
         return Err("year must be no greater than 9999");
     }
 
-    Ok(year)
+    Ok(Year {
+        value: year,
+        expanded,
+    })
 }
 
 pub(super) fn year_str<'a>(input: &mut &'a str) -> ModalResult<&'a str> {
@@ -57,16 +74,18 @@ mod tests {
     #[test]
     fn test_year() {
         // 2-characters are converted to 19XX/20XX
-        assert_eq!(year_from_str("10").unwrap(), 2010u16);
-        assert_eq!(year_from_str("68").unwrap(), 2068u16);
-        assert_eq!(year_from_str("69").unwrap(), 1969u16);
-        assert_eq!(year_from_str("99").unwrap(), 1999u16);
+        assert_eq!(year_from_str("10").unwrap().value, 2010u16);
+        assert_eq!(year_from_str("68").unwrap().value, 2068u16);
+        assert_eq!(year_from_str("69").unwrap().value, 1969u16);
+        assert_eq!(year_from_str("99").unwrap().value, 1999u16);
+        assert!(year_from_str("99").unwrap().expanded);
 
         // 3,4-characters are converted verbatim
-        assert_eq!(year_from_str("468").unwrap(), 468u16);
-        assert_eq!(year_from_str("469").unwrap(), 469u16);
-        assert_eq!(year_from_str("1568").unwrap(), 1568u16);
-        assert_eq!(year_from_str("1569").unwrap(), 1569u16);
+        assert_eq!(year_from_str("468").unwrap().value, 468u16);
+        assert_eq!(year_from_str("469").unwrap().value, 469u16);
+        assert_eq!(year_from_str("1568").unwrap().value, 1568u16);
+        assert_eq!(year_from_str("1569").unwrap().value, 1569u16);
+        assert!(!year_from_str("099").unwrap().expanded);
 
         // years greater than 9999 are not accepted
         assert!(year_from_str("10000").is_err());
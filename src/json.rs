@@ -0,0 +1,124 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Serialization of errors, with the `serde` feature.
+//!
+//! An error serializes to a map of its code, the category of its code, its
+//! English message, the span of the input at fault and the words of the
+//! grammar that the input may be a misspelling of, e.g.:
+//!
+//! ```json
+//! {"code":"parse","category":"syntax","message":"Invalid input string: cannot be parsed as a relative time","span":{"start":11,"end":18},"suggestions":["tomorrow"]}
+//! ```
+//!
+//! Only syntax errors have a span and suggestions; the span of other errors is
+//! `null`, and their suggestions are empty.
+
+use std::ops::Range;
+
+use serde::{Serialize, Serializer};
+
+use crate::{ErrorCategory, ErrorCode, ParseDateTimeError};
+
+impl Serialize for ErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Serialize for ErrorCategory {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl Serialize for ParseDateTimeError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ErrorObject::new(self).serialize(serializer)
+    }
+}
+
+impl ParseDateTimeError {
+    /// Returns the error as a JSON object, as it serializes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parse_datetime::Parser;
+    ///
+    /// let err = Parser::new()
+    ///     .max_input_length(4)
+    ///     .parse_datetime("today")
+    ///     .unwrap_err();
+    /// assert_eq!(
+    ///     err.to_json(),
+    ///     r#"{"code":"input_too_long","category":"limit","message":"Invalid input string: too long","span":null,"suggestions":[]}"#
+    /// );
+    /// ```
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("errors serialize to JSON")
+    }
+}
+
+/// The fields of an error as it serializes.
+#[derive(Serialize)]
+#[serde(rename = "ParseDateTimeError")]
+struct ErrorObject<'a> {
+    code: ErrorCode,
+    category: ErrorCategory,
+    message: &'static str,
+    /// The byte range of the input at fault.
+    span: Option<Range<usize>>,
+    /// Words of the grammar close to the input at fault.
+    suggestions: &'a [String],
+}
+
+impl<'a> ErrorObject<'a> {
+    fn new(err: &'a ParseDateTimeError) -> Self {
+        let code = err.code();
+        let (span, suggestions) = match err {
+            ParseDateTimeError::Parse(e) => (e.span(), e.suggestions()),
+            _ => (None, &[][..]),
+        };
+        Self {
+            code,
+            category: code.category(),
+            message: code.message(),
+            span,
+            suggestions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse_datetime;
+
+    #[test]
+    fn to_json() {
+        let err = parse_datetime("2025-02-30").unwrap_err();
+        assert_eq!(
+            err.to_json(),
+            concat!(
+                r#"{"code":"invalid_date","category":"resolution","#,
+                r#""message":"Invalid input string: date is not valid","#,
+                r#""span":null,"suggestions":[]}"#
+            )
+        );
+
+        let err = parse_datetime("2025-06-15 tomorow").unwrap_err();
+        assert_eq!(
+            err.to_json(),
+            concat!(
+                r#"{"code":"parse","category":"syntax","#,
+                r#""message":"Invalid input string: cannot be parsed as a relative time","#,
+                r#""span":{"start":11,"end":18},"suggestions":["tomorrow"]}"#
+            )
+        );
+
+        let err = parse_datetime("foo \"bar\"").unwrap_err();
+        assert!(err
+            .to_json()
+            .ends_with(r#""span":{"start":0,"end":3},"suggestions":[]}"#));
+    }
+}
@@ -0,0 +1,45 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Custom keywords.
+//!
+//! Applications may extend the vocabulary of the grammar with their own words
+//! (e.g., "eod" for the end of the working day), registered on a
+//! [`Parser`](crate::Parser) with [`Parser::keyword`](crate::Parser::keyword).
+
+use jiff::civil::{Date, Time};
+
+/// What a custom keyword stands for.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::{civil::{date, time}, Zoned};
+/// use parse_datetime::{Keyword, Parser};
+///
+/// let parser = Parser::new()
+///     .keyword("eod", Keyword::Time(time(17, 0, 0, 0)))
+///     // The 25th of the month of the base date.
+///     .keyword("payday", Keyword::date_with(|base| base.with().day(25).build().ok()));
+///
+/// let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+/// let dt = parser.parse_datetime_at_date(base, "payday eod").unwrap();
+/// assert_eq!(dt.strftime("%F %T").to_string(), "2025-06-25 17:00:00");
+/// ```
+#[non_exhaustive]
+pub enum Keyword {
+    /// A time of day, like a time of day item (e.g., "17:00").
+    Time(Time),
+    /// A calendar date, like a calendar date item (e.g., "2025-06-25").
+    Date(Date),
+    /// A calendar date computed from the date of the base date and time. The
+    /// keyword is invalid when the function returns `None`.
+    DateWith(Box<dyn Fn(Date) -> Option<Date> + Send + Sync>),
+}
+
+impl Keyword {
+    /// A calendar date computed from the date of the base date and time.
+    pub fn date_with(f: impl Fn(Date) -> Option<Date> + Send + Sync + 'static) -> Self {
+        Keyword::DateWith(Box::new(f))
+    }
+}
@@ -0,0 +1,146 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Leap seconds, for the conversion of GPS and TAI timestamps to UTC.
+//!
+//! [`Parser::leap_seconds`](crate::Parser::leap_seconds) enables timestamps
+//! counted on the GPS and TAI time scales (e.g., "GPS 1400000000" or "TAI
+//! 1700000037"), which have no leap seconds, and converts them to UTC with a
+//! [`LeapSeconds`] table of the offsets from UTC since 1972.
+
+use jiff::{civil, tz::TimeZone, Timestamp};
+
+/// The difference between TAI and UTC, in seconds, from the first day of the
+/// given year and month.
+const BUILTIN: [(i16, i8, i32); 28] = [
+    (1972, 1, 10),
+    (1972, 7, 11),
+    (1973, 1, 12),
+    (1974, 1, 13),
+    (1975, 1, 14),
+    (1976, 1, 15),
+    (1977, 1, 16),
+    (1978, 1, 17),
+    (1979, 1, 18),
+    (1980, 1, 19),
+    (1981, 7, 20),
+    (1982, 7, 21),
+    (1983, 7, 22),
+    (1985, 7, 23),
+    (1988, 1, 24),
+    (1990, 1, 25),
+    (1991, 1, 26),
+    (1992, 7, 27),
+    (1993, 7, 28),
+    (1994, 7, 29),
+    (1996, 1, 30),
+    (1997, 7, 31),
+    (1999, 1, 32),
+    (2006, 1, 33),
+    (2009, 1, 34),
+    (2012, 7, 35),
+    (2015, 7, 36),
+    (2017, 1, 37),
+];
+
+/// A table of the difference between TAI and UTC over time.
+///
+/// Before the first entry of the table, the difference of the first entry
+/// applies. An empty table makes TAI and UTC equal.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Timestamp;
+/// use parse_datetime::LeapSeconds;
+///
+/// // A hypothetical leap second at the end of 2030.
+/// let at: Timestamp = "2031-01-01T00:00:00Z".parse().unwrap();
+/// let leap_seconds = LeapSeconds::builtin().register(at, 38);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct LeapSeconds {
+    /// The UTC instants (in seconds since the Unix epoch) from which each
+    /// difference applies, in ascending order.
+    entries: Vec<(i64, i32)>,
+}
+
+impl LeapSeconds {
+    /// Create an empty table.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a table with the leap seconds announced by the IERS up to
+    /// 2017-01-01, when the difference between TAI and UTC became 37 seconds.
+    pub fn builtin() -> Self {
+        BUILTIN
+            .iter()
+            .fold(Self::new(), |table, &(year, month, difference)| {
+                let at = civil::date(year, month, 1)
+                    .to_zoned(TimeZone::UTC)
+                    .unwrap()
+                    .timestamp();
+                table.register(at, difference)
+            })
+    }
+
+    /// Register that TAI is ahead of UTC by `difference` seconds from the
+    /// instant `at`, replacing any entry for the same instant.
+    pub fn register(mut self, at: Timestamp, difference: i32) -> Self {
+        let at = at.as_second();
+        match self.entries.binary_search_by_key(&at, |&(t, _)| t) {
+            Ok(i) => self.entries[i].1 = difference,
+            Err(i) => self.entries.insert(i, (at, difference)),
+        }
+        self
+    }
+
+    /// Convert a number of nanoseconds since the Unix epoch on the TAI time
+    /// scale to UTC. A time within a leap second maps to the first instant
+    /// after it.
+    pub(crate) fn tai_to_utc(&self, tai: i128) -> i128 {
+        const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+        // The entries are compared on the TAI time scale.
+        let difference = self
+            .entries
+            .iter()
+            .rev()
+            .find(|&&(at, difference)| {
+                (i128::from(at) + i128::from(difference)) * NANOS_PER_SEC <= tai
+            })
+            .or(self.entries.first())
+            .map_or(0, |&(_, difference)| difference);
+
+        tai - i128::from(difference) * NANOS_PER_SEC
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LeapSeconds;
+
+    const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+    #[test]
+    fn tai_to_utc() {
+        let table = LeapSeconds::builtin();
+        // 2017-01-01T00:00:00Z is 1483228800.
+        for (tai, utc) in [
+            (1483228800 + 37, 1483228800),
+            (1483228800 + 36, 1483228800), // the leap second
+            (1483228800 + 35, 1483228800 - 1),
+            (1483228800 + 35 - 3600, 1483228800 - 1 - 3600),
+            (0, -10),
+        ] {
+            assert_eq!(
+                table.tai_to_utc(tai * NANOS_PER_SEC),
+                utc * NANOS_PER_SEC,
+                "{tai}"
+            );
+        }
+
+        assert_eq!(LeapSeconds::new().tai_to_utc(42), 42);
+    }
+}
@@ -10,107 +10,1089 @@
 //!
 use std::error::Error;
 use std::fmt::{self, Display};
-
-use jiff::Zoned;
-
+use std::ops::Range;
+
+use jiff::{tz::TimeZone, SignedDuration, Span, Zoned};
+
+mod abbreviation;
+#[cfg(feature = "calendars")]
+mod calendar;
+#[cfg(feature = "compat-tests")]
+pub mod compat;
+mod dst;
+mod holiday;
+mod iso8601;
 mod items;
+#[cfg(feature = "serde")]
+mod json;
+mod keyword;
+mod leap_seconds;
+mod lines;
+mod locale;
+mod parsed;
+mod parser;
+mod quirk;
+mod relative_unit;
+mod schedule;
+mod temporal;
+mod work_time;
+
+pub use abbreviation::{AbbreviationPolicy, Region, ZoneResolution};
+#[cfg(feature = "calendars")]
+pub use calendar::Calendar;
+pub use dst::{DstAmbiguity, DstResolution};
+pub use holiday::{HolidayCalendar, HolidayProvider, Holidays};
+pub use iso8601::Iso8601Value;
+pub use items::{combinators, Parsed, Specification};
+pub use keyword::Keyword;
+pub use leap_seconds::LeapSeconds;
+pub use lines::{LineError, Lines};
+pub use locale::Locale;
+pub use parsed::{ParsedDateTime, Precision};
+pub use parser::{MUnit, MonthArithmetic, Parser};
+pub use quirk::Quirk;
+pub use relative_unit::RelativeUnit;
+pub use schedule::{Occurrences, RepeatingInterval, Schedule};
+pub use temporal::TemporalValue;
+pub use work_time::WorkTime;
+
+use parser::Options;
 
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ParseDateTimeError {
-    InvalidInput,
+    /// The input is not a valid date and time string (e.g., "foo bar").
+    Parse(ParseError),
+    /// The input is well formed, but does not resolve to a date and time
+    /// (e.g., "2025-02-30", or a local time skipped by a daylight saving time
+    /// transition that the parser is set to reject).
+    Resolve(ResolveError),
+    /// The input is well formed, but a component of its date is out of range
+    /// (e.g., the month of "2025-13-01", or the day of "2025-02-30").
+    InvalidDate(InvalidComponent),
+    /// The input is well formed, but a component of its time is out of range
+    /// (e.g., the hour of "25:00").
+    InvalidTime(InvalidComponent),
+    /// The input is longer than the limit set with
+    /// [`Parser::max_input_length`].
+    InputTooLong,
+    /// The input has more items than the limit set with
+    /// [`Parser::max_items`].
+    TooManyItems,
+    /// Parsing the input takes more steps than the budget set with
+    /// [`Parser::max_steps`].
+    BudgetExceeded,
+    /// The specification is rejected by the hook set with
+    /// [`Parser::resolution_hook`].
+    Rejected,
+    /// The date and time is beyond the range supported by `jiff` (years
+    /// -9999 to 9999), and the parser is not set to
+    /// [saturate](Parser::saturating).
+    OutOfRange,
 }
 
 impl Display for ParseDateTimeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code().message())
+    }
+}
+
+impl Error for ParseDateTimeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseDateTimeError::Parse(e) => Some(e),
+            ParseDateTimeError::Resolve(e) => Some(e),
+            ParseDateTimeError::InvalidDate(e) | ParseDateTimeError::InvalidTime(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl ParseDateTimeError {
+    /// Returns the stable, machine-readable code of the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parse_datetime::{parse_datetime, ErrorCategory, ErrorCode};
+    ///
+    /// let err = parse_datetime("2025-02-30").unwrap_err();
+    /// assert_eq!(err.code(), ErrorCode::InvalidDate);
+    /// assert_eq!(err.code().as_str(), "invalid_date");
+    /// assert_eq!(err.code().category(), ErrorCategory::Resolution);
+    /// ```
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ParseDateTimeError::Parse(_) => ErrorCode::Parse,
+            ParseDateTimeError::Resolve(_) => ErrorCode::Resolve,
+            ParseDateTimeError::InvalidDate(_) => ErrorCode::InvalidDate,
+            ParseDateTimeError::InvalidTime(_) => ErrorCode::InvalidTime,
+            ParseDateTimeError::InputTooLong => ErrorCode::InputTooLong,
+            ParseDateTimeError::TooManyItems => ErrorCode::TooManyItems,
+            ParseDateTimeError::BudgetExceeded => ErrorCode::BudgetExceeded,
+            ParseDateTimeError::Rejected => ErrorCode::Rejected,
+            ParseDateTimeError::OutOfRange => ErrorCode::OutOfRange,
+        }
+    }
+
+    /// Returns the reason of a syntax, resolution or invalid date or time
+    /// error (e.g., "day is not valid for the given month"), in English, or
+    /// `None` for other errors.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            ParseDateTimeError::Parse(e) => Some(&e.reason),
+            ParseDateTimeError::Resolve(e) => Some(&e.reason),
+            ParseDateTimeError::InvalidDate(e) | ParseDateTimeError::InvalidTime(e) => {
+                Some(e.reason)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the message of the error, translated by `translate`, or the
+    /// English message that the error displays if `translate` returns `None`
+    /// for its code.
+    ///
+    /// The message depends only on the [code](Self::code) of the error, so a
+    /// translation is a message for each code; the [reason](Self::reason) can
+    /// be added to it as data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parse_datetime::{parse_datetime, ErrorCode};
+    ///
+    /// let french = |code| match code {
+    ///     ErrorCode::Parse => Some("Date invalide".to_string()),
+    ///     _ => None,
+    /// };
+    /// let err = parse_datetime("foobar").unwrap_err();
+    /// assert_eq!(err.localized(french), "Date invalide");
+    /// let err = parse_datetime("2025-02-30").unwrap_err();
+    /// assert_eq!(err.localized(french), err.to_string());
+    /// ```
+    pub fn localized(&self, translate: impl Fn(ErrorCode) -> Option<String>) -> String {
+        translate(self.code()).unwrap_or_else(|| self.code().message().to_owned())
+    }
+}
+
+/// The stable, machine-readable code of a [`ParseDateTimeError`], one for
+/// each of its variants.
+///
+/// All syntax errors share [`ErrorCode::Parse`]; they differ only in their
+/// [reason](ParseDateTimeError::reason), which is not stable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// The input is not a valid date and time string.
+    Parse,
+    /// The input is well formed, but does not resolve to a date and time.
+    Resolve,
+    /// A component of the date is out of range.
+    InvalidDate,
+    /// A component of the time is out of range.
+    InvalidTime,
+    /// The input is longer than the maximum input length.
+    InputTooLong,
+    /// The input has more items than the maximum number of items.
+    TooManyItems,
+    /// Parsing the input takes more steps than the budget.
+    BudgetExceeded,
+    /// The specification is rejected by the resolution hook.
+    Rejected,
+    /// The date and time is beyond the supported range.
+    OutOfRange,
+}
+
+impl ErrorCode {
+    /// Returns the code as a string in snake case (e.g., "input_too_long"),
+    /// which does not change between versions.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::Parse => "parse",
+            ErrorCode::Resolve => "resolve",
+            ErrorCode::InvalidDate => "invalid_date",
+            ErrorCode::InvalidTime => "invalid_time",
+            ErrorCode::InputTooLong => "input_too_long",
+            ErrorCode::TooManyItems => "too_many_items",
+            ErrorCode::BudgetExceeded => "budget_exceeded",
+            ErrorCode::Rejected => "rejected",
+            ErrorCode::OutOfRange => "out_of_range",
+        }
+    }
+
+    /// Returns the English message of the errors with the code, as they
+    /// display it.
+    pub fn message(self) -> &'static str {
+        match self {
+            ErrorCode::Parse => "Invalid input string: cannot be parsed as a relative time",
+            ErrorCode::Resolve => "Invalid input string: cannot be resolved to a date and time",
+            ErrorCode::InvalidDate => "Invalid input string: date is not valid",
+            ErrorCode::InvalidTime => "Invalid input string: time is not valid",
+            ErrorCode::InputTooLong => "Invalid input string: too long",
+            ErrorCode::TooManyItems => "Invalid input string: too many items",
+            ErrorCode::BudgetExceeded => "Invalid input string: too many steps to parse",
+            ErrorCode::Rejected => "Invalid input string: rejected",
+            ErrorCode::OutOfRange => "Invalid input string: out of range",
+        }
+    }
+
+    /// Returns the category of the code.
+    pub fn category(self) -> ErrorCategory {
         match self {
-            ParseDateTimeError::InvalidInput => {
-                write!(
-                    f,
-                    "Invalid input string: cannot be parsed as a relative time"
-                )
+            ErrorCode::Parse => ErrorCategory::Syntax,
+            ErrorCode::Resolve
+            | ErrorCode::InvalidDate
+            | ErrorCode::InvalidTime
+            | ErrorCode::Rejected
+            | ErrorCode::OutOfRange => ErrorCategory::Resolution,
+            ErrorCode::InputTooLong | ErrorCode::TooManyItems | ErrorCode::BudgetExceeded => {
+                ErrorCategory::Limit
             }
         }
     }
 }
 
-impl Error for ParseDateTimeError {}
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The category of an [`ErrorCode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCategory {
+    /// The input is not well formed.
+    Syntax,
+    /// The input is well formed, but its date and time cannot be produced.
+    Resolution,
+    /// The input exceeds a limit set on a [`Parser`].
+    Limit,
+}
+
+impl ErrorCategory {
+    /// Returns the category as a string in snake case (e.g., "resolution").
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErrorCategory::Syntax => "syntax",
+            ErrorCategory::Resolution => "resolution",
+            ErrorCategory::Limit => "limit",
+        }
+    }
+}
+
+/// A syntax error: the input is not a valid date and time string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    reason: String,
+    span: Option<Range<usize>>,
+    suggestions: Vec<String>,
+}
+
+impl ParseError {
+    pub(crate) fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+            span: None,
+            suggestions: Vec::new(),
+        }
+    }
+
+    /// Returns the byte range of the input at which parsing failed: the word
+    /// that starts the item that could not be parsed, or `None` if the error
+    /// is not caused by a single item (e.g., a time given twice).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parse_datetime::{parse_datetime, ParseDateTimeError};
+    ///
+    /// let input = "2025-06-15 tomorow";
+    /// let Err(ParseDateTimeError::Parse(e)) = parse_datetime(input) else {
+    ///     panic!();
+    /// };
+    /// assert_eq!(&input[e.span().unwrap()], "tomorow");
+    /// assert_eq!(e.suggestions(), ["tomorrow"]);
+    /// ```
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.clone()
+    }
+
+    /// Returns the words of the grammar that are close to the word at the
+    /// [span](Self::span), closest first, if it looks like a misspelling of
+    /// them.
+    pub fn suggestions(&self) -> &[String] {
+        &self.suggestions
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl Error for ParseError {}
+
+/// A resolution error: the input is well formed, but does not resolve to a
+/// date and time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolveError {
+    reason: String,
+}
+
+impl ResolveError {
+    pub(crate) fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.reason)
+    }
+}
+
+impl Error for ResolveError {}
+
+/// A component of a date or time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Component {
+    /// The month, in `1..=12`.
+    Month,
+    /// The day of the month, in `1..=31`, and no more than the number of days
+    /// in the month.
+    Day,
+    /// The hour, in `0..24`.
+    Hour,
+    /// The minute, in `0..60`.
+    Minute,
+    /// The second, in `0..60`.
+    Second,
+}
+
+/// An invalid date or time error: a component of a well-formed date or time
+/// is out of range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidComponent {
+    component: Component,
+    value: u64,
+    reason: &'static str,
+}
+
+impl InvalidComponent {
+    pub(crate) fn new(component: Component, value: u64, reason: &'static str) -> Self {
+        Self {
+            component,
+            value,
+            reason,
+        }
+    }
+
+    /// Returns the component that is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parse_datetime::{parse_datetime, Component, ParseDateTimeError};
+    ///
+    /// let Err(ParseDateTimeError::InvalidDate(e)) = parse_datetime("2025-04-31") else {
+    ///     panic!();
+    /// };
+    /// assert_eq!(e.component(), Component::Day);
+    /// assert_eq!(e.value(), 31);
+    /// ```
+    pub fn component(&self) -> Component {
+        self.component
+    }
+
+    /// Returns the value of the component, as it was written.
+    pub fn value(&self) -> u64 {
+        self.value
+    }
+}
+
+impl Display for InvalidComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.reason, self.value)
+    }
+}
+
+impl Error for InvalidComponent {}
 
 impl From<items::error::Error> for ParseDateTimeError {
-    fn from(_: items::error::Error) -> Self {
-        ParseDateTimeError::InvalidInput
+    fn from(err: items::error::Error) -> Self {
+        match err {
+            items::error::Error::InputTooLong => ParseDateTimeError::InputTooLong,
+            items::error::Error::TooManyItems => ParseDateTimeError::TooManyItems,
+            items::error::Error::BudgetExceeded => ParseDateTimeError::BudgetExceeded,
+            items::error::Error::Rejected => ParseDateTimeError::Rejected,
+            items::error::Error::OutOfRange => ParseDateTimeError::OutOfRange,
+            items::error::Error::Parse(e) => ParseDateTimeError::Parse(e),
+            items::error::Error::Resolve(reason) => {
+                ParseDateTimeError::Resolve(ResolveError::new(reason))
+            }
+            items::error::Error::InvalidDate(e) => ParseDateTimeError::InvalidDate(e),
+            items::error::Error::InvalidTime(e) => ParseDateTimeError::InvalidTime(e),
+        }
     }
 }
 
-/// Parses a time string and returns a `Zoned` object representing the absolute
-/// time of the string.
+/// Formats a parsed date and time with `format`, as `strftime` does, with the
+/// timezone as it was written: `%Z` is the name of the timezone that was
+/// parsed (e.g., "CEST"), or the abbreviation of the timezone of a `TZ="..."`
+/// rule, rather than the offset that it stands for, and `%z` is the offset of
+/// that name at the date and time.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use parse_datetime::{format_parsed, Parser};
+///
+/// let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+/// let parsed = Parser::new()
+///     .parse_datetime_at_date_detailed(base, "2025-07-01 10:30 CEST")
+///     .unwrap();
+/// assert_eq!(
+///     format_parsed(&parsed, "%F %T %Z (%:z)").unwrap(),
+///     "2025-07-01 10:30:00 CEST (+02:00)"
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `format` is not a valid `strftime` format, as jiff
+/// supports it.
+pub fn format_parsed(parsed: &ParsedDateTime, format: &str) -> Result<String, jiff::Error> {
+    parsed.format(format)
+}
+
+/// Parses a time string and returns a `Zoned` object representing the absolute
+/// time of the string.
+///
+/// # Arguments
+///
+/// * `input` - A string slice representing the time.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use parse_datetime::parse_datetime;
+///
+/// let time = parse_datetime("2023-06-03 12:00:01Z").unwrap();
+/// assert_eq!(time.strftime("%F %T").to_string(), "2023-06-03 12:00:01");
+/// ```
+///
+///
+/// # Returns
+///
+/// * `Ok(Zoned)` - If the input string can be parsed as a time
+/// * `Err(ParseDateTimeError)` - If the input string cannot be parsed as a
+///   relative time
+///
+/// # Errors
+///
+/// This function will return `Err(ParseDateTimeError::Parse(_))` if the input
+/// string cannot be parsed as a relative time, and
+/// `Err(ParseDateTimeError::Resolve(_))` if it does not resolve to a date and
+/// time (e.g., "2025-02-30").
+pub fn parse_datetime<S: AsRef<str> + Clone>(input: S) -> Result<Zoned, ParseDateTimeError> {
+    items::parse_at_local(input, &Options::default()).map_err(|e| e.into())
+}
+
+/// Parses a time string at a specific date and returns a `Zoned` object
+/// representing the absolute time of the string.
+///
+/// # Arguments
+///
+/// * date - The date represented in local time
+/// * `input` - A string slice representing the time.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use parse_datetime::parse_datetime_at_date;
+///
+///  let now = Zoned::now();
+///  let after = parse_datetime_at_date(now, "2024-09-13UTC +3 days").unwrap();
+///
+///  assert_eq!(
+///    "2024-09-16",
+///    after.strftime("%F").to_string()
+///  );
+/// ```
+///
+/// # Returns
+///
+/// * `Ok(Zoned)` - If the input string can be parsed as a time
+/// * `Err(ParseDateTimeError)` - If the input string cannot be parsed as a
+///   relative time
+///
+/// # Errors
+///
+/// This function will return `Err(ParseDateTimeError::Parse(_))` if the input
+/// string cannot be parsed as a relative time, and
+/// `Err(ParseDateTimeError::Resolve(_))` if it does not resolve to a date and
+/// time (e.g., "2025-02-30").
+pub fn parse_datetime_at_date<S: AsRef<str> + Clone>(
+    date: Zoned,
+    input: S,
+) -> Result<Zoned, ParseDateTimeError> {
+    items::parse_at_date(date, input, &Options::default()).map_err(|e| e.into())
+}
+
+/// Parses a time string once, and returns a [`Parsed`] value to be resolved
+/// against any number of base dates and times, e.g., on each tick of a
+/// scheduler.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use parse_datetime::parse;
+///
+/// let parsed = parse("tomorrow 9am").unwrap();
+/// let now: Zoned = "2024-09-13T10:00:00+00:00[UTC]".parse().unwrap();
+/// let dt = parsed.resolve_at(now).unwrap();
+/// assert_eq!(dt.strftime("%F %T").to_string(), "2024-09-14 09:00:00");
+/// ```
+///
+/// # Errors
+///
+/// This function will return `Err(ParseDateTimeError::Parse(_))` if the input
+/// string cannot be parsed as a relative time. Resolution errors are returned
+/// by [`Parsed::resolve_at`].
+pub fn parse<S: AsRef<str>>(input: S) -> Result<Parsed<'static>, ParseDateTimeError> {
+    items::parse_deferred(input.as_ref(), Options::default_ref()).map_err(|e| e.into())
+}
+
+/// Parses a time string as [`parse_datetime`] does, in the local timezone or
+/// the timezone given by the input (e.g., "10:00 UTC" or `TZ="Asia/Tokyo"
+/// 10:00`), and converts the result to `target`, e.g., the timezone in which
+/// it is displayed.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::tz::TimeZone;
+/// use parse_datetime::parse_in_zone_then_convert;
+///
+/// let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+/// let dt = parse_in_zone_then_convert("2024-09-13 10:00 UTC", tokyo).unwrap();
+/// assert_eq!(dt.to_string(), "2024-09-13T19:00:00+09:00[Asia/Tokyo]");
+/// ```
+///
+/// # Errors
+///
+/// This function returns the errors of [`parse_datetime`].
+pub fn parse_in_zone_then_convert<S: AsRef<str> + Clone>(
+    input: S,
+    target: TimeZone,
+) -> Result<Zoned, ParseDateTimeError> {
+    Parser::new().parse_in_zone_then_convert(input, target)
+}
+
+/// Parses an RFC 3339 date and time (e.g., "2022-11-14T10:20:30Z"), and
+/// nothing else.
+///
+/// Unlike [`parse_datetime`], this function accepts only the `date-time`
+/// production of RFC 3339: a four-digit year, two-digit fields, seconds, and
+/// an offset ("Z" or, e.g., "+01:00") are required. The "T" may be a space,
+/// and the "T" and "Z" may be lowercase. The result does not depend on the
+/// current time or the local timezone: it is in the timezone of its offset,
+/// UTC for "Z" or "-00:00".
+///
+/// # Examples
+///
+/// ```
+/// use parse_datetime::parse_rfc3339;
+///
+/// let dt = parse_rfc3339("2022-11-14T10:20:30.5+01:00").unwrap();
+/// assert_eq!(dt.to_string(), "2022-11-14T10:20:30.5+01:00[+01:00]");
+///
+/// assert!(parse_rfc3339("2022-11-14T10:20:30").is_err());
+/// assert!(parse_rfc3339("2022-11-14").is_err());
+/// ```
+///
+/// # Errors
+///
+/// This function returns [`ParseDateTimeError::Parse`] if the input is not an
+/// RFC 3339 date and time, [`ParseDateTimeError::Resolve`] if its date does
+/// not exist (e.g., "2023-02-29"), and [`ParseDateTimeError::OutOfRange`] if
+/// it is out of the supported range. A leap second (second 60) is rejected.
+pub fn parse_rfc3339<S: AsRef<str>>(input: S) -> Result<Zoned, ParseDateTimeError> {
+    Parser::new().parse_rfc3339(input)
+}
+
+/// Parses an ISO 8601 date, time of day, date and time, duration, time
+/// interval or recurring interval (e.g., "2022-W46-1", "T10:20Z",
+/// "2022-11-14T10:20:30+01:00", "P1DT12H", "2022-11-14/P1W" or
+/// "R5/2022-11-14/P1D"), and nothing else.
+///
+/// Unlike [`parse_datetime`], this function does not use the lenient grammar
+/// of GNU `date`, and does not resolve the value against the current date and
+/// time: see [`Iso8601Value`] for the values and what they are resolved to.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::civil::date;
+/// use parse_datetime::{parse_iso8601, Iso8601Value};
+///
+/// match parse_iso8601("2022-11-14T10:00/12:00").unwrap() {
+///     Iso8601Value::Interval { start, end } => {
+///         assert!(matches!(*start, Iso8601Value::DateTime { .. }));
+///         assert!(matches!(
+///             *end,
+///             Iso8601Value::DateTime { datetime, .. }
+///                 if datetime == date(2022, 11, 14).at(12, 0, 0, 0)
+///         ));
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// assert!(parse_iso8601("next friday").is_err());
+/// ```
+///
+/// # Errors
+///
+/// This function returns [`ParseDateTimeError::Parse`] if the input is not an
+/// ISO 8601 value, [`ParseDateTimeError::Resolve`] if a date does not exist
+/// (e.g., "2023-02-29" or "2021-W53"), and [`ParseDateTimeError::OutOfRange`]
+/// if a date or a duration is out of the supported range.
+pub fn parse_iso8601<S: AsRef<str>>(input: S) -> Result<Iso8601Value, ParseDateTimeError> {
+    Parser::new().parse_iso8601(input)
+}
+
+/// Parses an ISO 8601 date, time of day or date and time, followed by optional
+/// RFC 9557 annotations (e.g., "[Europe/Paris]"), and classifies it as TC39
+/// Temporal does: as a date, a time of day, a date and time, or a date and
+/// time in a timezone.
+///
+/// Unlike [`parse_datetime`], this function does not resolve a value without
+/// an offset or a timezone to an instant: see [`TemporalValue`]. The syntax is
+/// that of [`parse_iso8601`]. A date and time in a timezone (e.g.,
+/// "2024-11-03T01:30[America/New_York]") that is ambiguous or falls in a gap
+/// is resolved as Temporal's "compatible" disambiguation does.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::civil::time;
+/// use parse_datetime::{parse_temporal, TemporalValue};
+///
+/// assert_eq!(
+///     parse_temporal("10:30").unwrap(),
+///     TemporalValue::PlainTime(time(10, 30, 0, 0))
+/// );
+/// assert!(matches!(
+///     parse_temporal("2024-03-10T10:00+01:00").unwrap(),
+///     TemporalValue::ZonedDateTime(_)
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// This function returns [`ParseDateTimeError::Parse`] if the input is not an
+/// ISO 8601 date, time of day or date and time (e.g., a duration), or is a
+/// time of day with an offset or a timezone, [`ParseDateTimeError::Resolve`]
+/// if a date does not exist or an offset does not match the timezone of the
+/// time zone annotation, and [`ParseDateTimeError::OutOfRange`] if the value is
+/// out of the supported range.
+pub fn parse_temporal<S: AsRef<str>>(input: S) -> Result<TemporalValue, ParseDateTimeError> {
+    Parser::new().parse_temporal(input)
+}
+
+/// Parses an ASN.1 GeneralizedTime, as stored by LDAP directories (e.g.,
+/// "20240702143000Z" or "20240702143000.123+0200"), and nothing else.
+///
+/// The syntax is that of RFC 4517: the minutes and seconds may be omitted, a
+/// fraction (after a dot or a comma) is a fraction of the last unit given, and
+/// the timezone ("Z" or an offset of hours and optional minutes) is required.
+/// The result is in the timezone of its offset, UTC for "Z".
+///
+/// # Examples
+///
+/// ```
+/// use parse_datetime::parse_generalized_time;
+///
+/// let dt = parse_generalized_time("20240702143000.123+0200").unwrap();
+/// assert_eq!(dt.to_string(), "2024-07-02T14:30:00.123+02:00[+02:00]");
+///
+/// let dt = parse_generalized_time("2024070214.5Z").unwrap();
+/// assert_eq!(dt.to_string(), "2024-07-02T14:30:00+00:00[UTC]");
+///
+/// assert!(parse_generalized_time("20240702143000").is_err());
+/// ```
+///
+/// # Errors
+///
+/// This function returns [`ParseDateTimeError::Parse`] if the input is not a
+/// GeneralizedTime, [`ParseDateTimeError::Resolve`] if its date does not
+/// exist (e.g., "20230229000000Z"), and [`ParseDateTimeError::OutOfRange`] if
+/// it is out of the supported range. A leap second (second 60) is rejected.
+pub fn parse_generalized_time<S: AsRef<str>>(input: S) -> Result<Zoned, ParseDateTimeError> {
+    Parser::new().parse_generalized_time(input)
+}
+
+/// Parses an ASN.1 UTCTime, as stored by X.509 certificates (e.g.,
+/// "240702143000Z"), and nothing else.
+///
+/// The year has two digits and is in 1950 to 2049, as RFC 5280 specifies. The
+/// seconds may be omitted, and the timezone ("Z" or an offset of hours and
+/// minutes, e.g., "+0200") is required. The result is in the timezone of its
+/// offset, UTC for "Z".
+///
+/// # Examples
+///
+/// ```
+/// use parse_datetime::parse_utc_time;
+///
+/// let dt = parse_utc_time("240702143000Z").unwrap();
+/// assert_eq!(dt.to_string(), "2024-07-02T14:30:00+00:00[UTC]");
+///
+/// let dt = parse_utc_time("991231235959Z").unwrap();
+/// assert_eq!(dt.to_string(), "1999-12-31T23:59:59+00:00[UTC]");
+/// ```
+///
+/// # Errors
+///
+/// This function returns [`ParseDateTimeError::Parse`] if the input is not a
+/// UTCTime, and [`ParseDateTimeError::Resolve`] if its date does not exist
+/// (e.g., "230229000000Z"). A leap second (second 60) is rejected.
+pub fn parse_utc_time<S: AsRef<str>>(input: S) -> Result<Zoned, ParseDateTimeError> {
+    Parser::new().parse_utc_time(input)
+}
+
+/// Parses an IMAP date and time (RFC 3501), such as the `INTERNALDATE` of a
+/// message (e.g., "01-Jul-2024 12:34:56 +0000"), and nothing else.
+///
+/// The value may be in quotes, as in an IMAP response. The day has two digits
+/// or a leading space, the month is abbreviated, and the seconds and the
+/// numeric offset are required. The result is in the timezone of its offset.
+///
+/// # Examples
+///
+/// ```
+/// use parse_datetime::parse_imap_date;
+///
+/// let dt = parse_imap_date("\" 1-Jul-2024 12:34:56 -0700\"").unwrap();
+/// assert_eq!(dt.to_string(), "2024-07-01T12:34:56-07:00[-07:00]");
+///
+/// assert!(parse_imap_date("01-Jul-2024 12:34:56").is_err());
+/// ```
+///
+/// # Errors
+///
+/// This function returns [`ParseDateTimeError::Parse`] if the input is not an
+/// IMAP date and time, [`ParseDateTimeError::Resolve`] if its date does not
+/// exist (e.g., "29-Feb-2023"), and [`ParseDateTimeError::OutOfRange`] if it is
+/// out of the supported range.
+pub fn parse_imap_date<S: AsRef<str>>(input: S) -> Result<Zoned, ParseDateTimeError> {
+    Parser::new().parse_imap_date(input)
+}
+
+/// Parses the date of an email `Received:` header field (e.g.,
+/// "Tue, 3 Oct 2023 10:20:30 -0400 (EDT)"), or the whole field value, in
+/// which the date follows the last semicolon.
+///
+/// Unlike [`parse_datetime`], this function accepts only an RFC 5322 date and
+/// time, but tolerates the variants that mail servers write: comments,
+/// repeated whitespace and folded lines, a missing comma or seconds, names in
+/// full or in any case, the `asctime` order, two- and three-digit years, and
+/// obsolete zone names. As RFC 5322 specifies, a zone name other than "UT",
+/// "GMT" and the US zones, including the military zones, is considered
+/// equivalent to "-0000", and so is a missing zone. The result is in the
+/// timezone of its offset, UTC for "-0000".
+///
+/// # Examples
+///
+/// ```
+/// use parse_datetime::parse_received_date;
+///
+/// let dt = parse_received_date(
+///     "from mx.example.com by mail.example.org; Tue,  3 Oct 23 10:20 EDT",
+/// )
+/// .unwrap();
+/// assert_eq!(dt.to_string(), "2023-10-03T10:20:00-04:00[-04:00]");
+///
+/// let dt = parse_received_date("Tue, 3 Oct 2023 10:20:30 -0400 (EDT)").unwrap();
+/// assert_eq!(dt.to_string(), "2023-10-03T10:20:30-04:00[-04:00]");
+/// ```
+///
+/// # Errors
+///
+/// This function returns [`ParseDateTimeError::Parse`] if the input is not a
+/// date and time of these forms, [`ParseDateTimeError::Resolve`] if its date
+/// does not exist (e.g., "31 Nov 2023"), and
+/// [`ParseDateTimeError::OutOfRange`] if it is out of the supported range.
+pub fn parse_received_date<S: AsRef<str>>(input: S) -> Result<Zoned, ParseDateTimeError> {
+    Parser::new().parse_received_date(input)
+}
+
+/// Parses the value of an HTTP `Retry-After` header field, and returns the
+/// date and time it stands for, in the local timezone.
+///
+/// The value is either an HTTP date (RFC 7231, section 7.1.1.1), in the
+/// preferred format (e.g., "Sun, 06 Nov 1994 08:49:37 GMT") or one of the two
+/// obsolete formats (e.g., "Sunday, 06-Nov-94 08:49:37 GMT" or
+/// "Sun Nov  6 08:49:37 1994"), or a delay in seconds (e.g., "120") from now.
+/// Nothing else is accepted.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Timestamp;
+/// use parse_datetime::parse_retry_after;
+///
+/// let dt = parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT").unwrap();
+/// assert_eq!(dt.timestamp(), "1994-11-06T08:49:37Z".parse::<Timestamp>().unwrap());
+///
+/// let dt = parse_retry_after("120").unwrap();
+/// assert!(dt.timestamp() > Timestamp::now());
+/// ```
+///
+/// # Errors
+///
+/// This function returns [`ParseDateTimeError::Parse`] if the input is neither
+/// an HTTP date nor a delay in seconds, [`ParseDateTimeError::Resolve`] if its
+/// date does not exist (e.g., "Fri, 31 Nov 2023 00:00:00 GMT"), and
+/// [`ParseDateTimeError::OutOfRange`] if the result is out of the supported
+/// range.
+pub fn parse_retry_after<S: AsRef<str>>(input: S) -> Result<Zoned, ParseDateTimeError> {
+    Parser::new().parse_retry_after(input)
+}
+
+/// Parses the value of an HTTP `Retry-After` header field received at a
+/// specific date, and returns the date and time it stands for, in the timezone
+/// of `date`.
+///
+/// A delay in seconds is counted from `date`, and the century of the two-digit
+/// year of the obsolete RFC 850 format is the one that puts the year not more
+/// than 50 years after `date`, as RFC 7231 requires.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use parse_datetime::parse_retry_after_at_date;
+///
+/// let now: Zoned = "2024-09-13T10:00:00+02:00[Europe/Paris]".parse().unwrap();
+/// let dt = parse_retry_after_at_date(now.clone(), "120").unwrap();
+/// assert_eq!(dt.to_string(), "2024-09-13T10:02:00+02:00[Europe/Paris]");
+///
+/// let dt = parse_retry_after_at_date(now, "Friday, 13-Sep-24 09:00:00 GMT").unwrap();
+/// assert_eq!(dt.to_string(), "2024-09-13T11:00:00+02:00[Europe/Paris]");
+/// ```
+///
+/// # Errors
+///
+/// This function returns the errors of [`parse_retry_after`].
+pub fn parse_retry_after_at_date<S: AsRef<str>>(
+    date: Zoned,
+    input: S,
+) -> Result<Zoned, ParseDateTimeError> {
+    Parser::new().parse_retry_after_at_date(date, input)
+}
+
+/// Parses an NTP timestamp, a count of seconds since 1900-01-01 00:00:00 UTC,
+/// and returns it in the local timezone.
+///
+/// The timestamp is given in hexadecimal, with 8 digits for the seconds and 8
+/// for the binary fraction of a second (e.g., "e9d7a7b8.3d4f2a10", as `ntpq`
+/// prints it), or in decimal (e.g., "3923224504.25"). As the seconds wrap
+/// around every 136 years, the first time in 2036, the timestamp is taken in
+/// the era that puts it closest to now.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Timestamp;
+/// use parse_datetime::parse_ntp_timestamp;
+///
+/// let dt = parse_ntp_timestamp("83aa7e80.80000000").unwrap();
+/// assert_eq!(dt.timestamp(), "1970-01-01T00:00:00.5Z".parse::<Timestamp>().unwrap());
+/// ```
+///
+/// # Errors
+///
+/// This function returns [`ParseDateTimeError::Parse`] if the input is not an
+/// NTP timestamp, and [`ParseDateTimeError::OutOfRange`] if the result is out
+/// of the supported range.
+pub fn parse_ntp_timestamp<S: AsRef<str>>(input: S) -> Result<Zoned, ParseDateTimeError> {
+    Parser::new().parse_ntp_timestamp(input)
+}
+
+/// Parses an NTP timestamp in the era closest to a specific date, and returns
+/// it in the timezone of `date`.
+///
+/// A timestamp is resolved correctly if it is within 68 years of `date`, e.g.,
+/// a timestamp with few seconds is in 2036 or later when `date` is in this
+/// century.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use parse_datetime::parse_ntp_timestamp_at_date;
+///
+/// let now: Zoned = "2035-12-31T00:00:00+00:00[UTC]".parse().unwrap();
+/// let dt = parse_ntp_timestamp_at_date(now.clone(), "00000010.00000000").unwrap();
+/// assert_eq!(dt.to_string(), "2036-02-07T06:28:32+00:00[UTC]");
+///
+/// let dt = parse_ntp_timestamp_at_date(now, "ffffffff.00000000").unwrap();
+/// assert_eq!(dt.to_string(), "2036-02-07T06:28:15+00:00[UTC]");
+/// ```
+///
+/// # Errors
+///
+/// This function returns the errors of [`parse_ntp_timestamp`].
+pub fn parse_ntp_timestamp_at_date<S: AsRef<str>>(
+    date: Zoned,
+    input: S,
+) -> Result<Zoned, ParseDateTimeError> {
+    Parser::new().parse_ntp_timestamp_at_date(date, input)
+}
+
+/// Parses two time strings against the same base date and returns the signed
+/// duration from the time of `a` to that of `b`, e.g., how long it is from
+/// "now" until "next friday 17:00".
+///
+/// The duration is negative if `b` is before `a`.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::{SignedDuration, Zoned};
+/// use parse_datetime::diff;
+///
+/// let now: Zoned = "2024-09-13T10:00:00+00:00[UTC]".parse().unwrap();
+/// let remaining = diff("now", "next friday 17:00", now).unwrap();
+/// assert_eq!(remaining, SignedDuration::from_hours(175));
+/// ```
+///
+/// # Errors
+///
+/// This function returns the errors of [`parse_datetime_at_date`], for either
+/// string.
+pub fn diff<A: AsRef<str> + Clone, B: AsRef<str> + Clone>(
+    a: A,
+    b: B,
+    base: Zoned,
+) -> Result<SignedDuration, ParseDateTimeError> {
+    Parser::new().diff(a, b, base)
+}
+
+/// Parses two time strings against the current local time and returns the
+/// calendar span from the time of `a` to that of `b`, in years, months, days,
+/// hours, minutes and seconds (e.g., an age).
 ///
-/// # Arguments
+/// See [`span_between_at_date`] for details.
 ///
-/// * `input` - A string slice representing the time.
+/// # Errors
+///
+/// This function returns the errors of [`parse_datetime`], for either
+/// string.
+pub fn span_between<A: AsRef<str>, B: AsRef<str>>(a: A, b: B) -> Result<Span, ParseDateTimeError> {
+    Parser::new().span_between(a, b)
+}
+
+/// Parses two time strings against the same base date and returns the
+/// calendar span from the time of `a` to that of `b`, in years, months, days,
+/// hours, minutes and seconds (e.g., an age), all negative if `b` is before
+/// `a`.
+///
+/// The span is counted in the timezone of `a`, from whole years to smaller
+/// units. Months are counted as GNU `date` adds a relative month, by the
+/// number of days in the current month.
 ///
 /// # Examples
 ///
 /// ```
-/// use jiff::Zoned;
-/// use parse_datetime::parse_datetime;
+/// use jiff::{ToSpan, Zoned};
+/// use parse_datetime::span_between_at_date;
 ///
-/// let time = parse_datetime("2023-06-03 12:00:01Z").unwrap();
-/// assert_eq!(time.strftime("%F %T").to_string(), "2023-06-03 12:00:01");
+/// let now: Zoned = "2024-09-13T10:00:00+00:00[UTC]".parse().unwrap();
+/// let age = span_between_at_date(now, "1990-05-17 08:30", "now").unwrap();
+/// assert_eq!(
+///     age.fieldwise(),
+///     34.years().months(3).days(27).hours(1).minutes(30).fieldwise()
+/// );
 /// ```
 ///
+/// # Errors
+///
+/// This function returns the errors of [`parse_datetime_at_date`], for either
+/// string.
+pub fn span_between_at_date<A: AsRef<str>, B: AsRef<str>>(
+    date: Zoned,
+    a: A,
+    b: B,
+) -> Result<Span, ParseDateTimeError> {
+    Parser::new().span_between_at_date(date, a, b)
+}
+
+/// Parses a time string and returns the signed duration from now to the
+/// absolute time of the string, e.g., for a countdown or a timeout.
 ///
-/// # Returns
+/// The duration is negative if the time is in the past.
 ///
-/// * `Ok(Zoned)` - If the input string can be parsed as a time
-/// * `Err(ParseDateTimeError)` - If the input string cannot be parsed as a
-///   relative time
+/// # Examples
+///
+/// ```
+/// use jiff::SignedDuration;
+/// use parse_datetime::parse_deadline;
+///
+/// let remaining = parse_deadline("+90 minutes").unwrap();
+/// assert_eq!(remaining, SignedDuration::from_mins(90));
+/// ```
 ///
 /// # Errors
 ///
-/// This function will return `Err(ParseDateTimeError::InvalidInput)` if the
-/// input string cannot be parsed as a relative time.
-pub fn parse_datetime<S: AsRef<str> + Clone>(input: S) -> Result<Zoned, ParseDateTimeError> {
-    items::parse_at_local(input).map_err(|e| e.into())
+/// This function will return `Err(ParseDateTimeError::Parse(_))` if the input
+/// string cannot be parsed as a relative time, and
+/// `Err(ParseDateTimeError::Resolve(_))` if it does not resolve to a date and
+/// time (e.g., "2025-02-30").
+pub fn parse_deadline<S: AsRef<str> + Clone>(
+    input: S,
+) -> Result<SignedDuration, ParseDateTimeError> {
+    items::parse_deadline(None, input, &Options::default()).map_err(|e| e.into())
 }
 
-/// Parses a time string at a specific date and returns a `Zoned` object
-/// representing the absolute time of the string.
-///
-/// # Arguments
+/// Parses a time string at a specific date and returns the signed duration
+/// from that date to the absolute time of the string.
 ///
-/// * date - The date represented in local time
-/// * `input` - A string slice representing the time.
+/// The duration is negative if the time is before `date`.
 ///
 /// # Examples
 ///
 /// ```
-/// use jiff::Zoned;
-/// use parse_datetime::parse_datetime_at_date;
-///
-///  let now = Zoned::now();
-///  let after = parse_datetime_at_date(now, "2024-09-13UTC +3 days").unwrap();
+/// use jiff::{SignedDuration, Zoned};
+/// use parse_datetime::parse_deadline_at_date;
 ///
-///  assert_eq!(
-///    "2024-09-16",
-///    after.strftime("%F").to_string()
-///  );
+/// let now: Zoned = "2024-09-13T10:00:00+00:00[UTC]".parse().unwrap();
+/// let remaining = parse_deadline_at_date(now, "2024-09-13 12:30 UTC").unwrap();
+/// assert_eq!(remaining, SignedDuration::from_mins(150));
 /// ```
 ///
-/// # Returns
-///
-/// * `Ok(Zoned)` - If the input string can be parsed as a time
-/// * `Err(ParseDateTimeError)` - If the input string cannot be parsed as a
-///   relative time
-///
 /// # Errors
 ///
-/// This function will return `Err(ParseDateTimeError::InvalidInput)` if the
-/// input string cannot be parsed as a relative time.
-pub fn parse_datetime_at_date<S: AsRef<str> + Clone>(
+/// This function will return `Err(ParseDateTimeError::Parse(_))` if the input
+/// string cannot be parsed as a relative time, and
+/// `Err(ParseDateTimeError::Resolve(_))` if it does not resolve to a date and
+/// time (e.g., "2025-02-30").
+pub fn parse_deadline_at_date<S: AsRef<str> + Clone>(
     date: Zoned,
     input: S,
-) -> Result<Zoned, ParseDateTimeError> {
-    items::parse_at_date(date, input).map_err(|e| e.into())
+) -> Result<SignedDuration, ParseDateTimeError> {
+    items::parse_deadline(Some(date), input, &Options::default()).map_err(|e| e.into())
 }
 
 #[cfg(test)]
@@ -388,6 +1370,110 @@ mod tests {
                 assert_eq!(dt.timestamp(), time);
             }
         }
+
+        #[test]
+        fn test_units() {
+            let time = Timestamp::from_millisecond(1690466034123).unwrap();
+            for input in ["@1690466034.123", "@1690466034123ms", "@1690466034123000us"] {
+                assert_eq!(parse_datetime(input).unwrap().timestamp(), time, "{input}");
+            }
+
+            assert!(parse_datetime("@1690466034123ms 10:00").is_err());
+        }
+
+        #[test]
+        fn test_julian_day() {
+            let time = Timestamp::from_second(1677283200).unwrap();
+            for input in ["JD 2460000.5", "MJD 60000", "TZ=\"Europe/Paris\" MJD 60000"] {
+                assert_eq!(parse_datetime(input).unwrap().timestamp(), time, "{input}");
+            }
+
+            assert!(parse_datetime("MJD 60000 +1 day").is_err());
+        }
+    }
+
+    mod deadline {
+        use jiff::{SignedDuration, ToSpan, Zoned};
+
+        use crate::{diff, parse_deadline, parse_deadline_at_date, span_between_at_date};
+
+        #[test]
+        fn test_relative() {
+            assert_eq!(
+                parse_deadline("+2 hours").unwrap(),
+                SignedDuration::from_hours(2)
+            );
+            assert_eq!(
+                parse_deadline("3 days ago").unwrap(),
+                SignedDuration::from_hours(-72)
+            );
+            assert_eq!(parse_deadline("now").unwrap(), SignedDuration::ZERO);
+        }
+
+        #[test]
+        fn test_absolute() {
+            let now: Zoned = "2024-09-13T10:00:00+00:00[UTC]".parse().unwrap();
+            for (input, expected) in [
+                ("2024-09-13 12:30 UTC", SignedDuration::from_mins(150)),
+                ("2024-09-13 08:00 UTC", SignedDuration::from_hours(-2)),
+                ("2024-09-14 10:00 +02:00", SignedDuration::from_hours(22)),
+                ("@1726221600", SignedDuration::ZERO),
+                ("tomorrow", SignedDuration::from_hours(24)),
+            ] {
+                assert_eq!(
+                    parse_deadline_at_date(now.clone(), input).unwrap(),
+                    expected,
+                    "{input}"
+                );
+            }
+
+            assert!(parse_deadline_at_date(now, "invalid").is_err());
+        }
+
+        #[test]
+        fn test_diff() {
+            let now: Zoned = "2024-09-13T10:00:00+00:00[UTC]".parse().unwrap();
+            for (a, b, expected) in [
+                ("now", "next friday 17:00", SignedDuration::from_hours(175)),
+                ("next friday 17:00", "now", SignedDuration::from_hours(-175)),
+                ("monday", "friday", SignedDuration::from_hours(-72)),
+                ("10:00 UTC", "10:00 +02:00", SignedDuration::from_hours(-2)),
+                ("tomorrow", "tomorrow", SignedDuration::ZERO),
+            ] {
+                assert_eq!(diff(a, b, now.clone()).unwrap(), expected, "{a} | {b}");
+            }
+
+            assert!(diff("now", "invalid", now.clone()).is_err());
+            assert!(diff("invalid", "now", now).is_err());
+        }
+
+        #[test]
+        fn test_span_between() {
+            let now: Zoned = "2024-09-13T10:00:00+00:00[UTC]".parse().unwrap();
+            for (a, b, expected) in [
+                ("1990-05-17", "now", 34.years().months(3).days(27).hours(10)),
+                (
+                    "now",
+                    "1990-05-17",
+                    -34.years().months(3).days(27).hours(10),
+                ),
+                ("jan 31", "mar 2", 1.month()),
+                ("jan 31", "mar 1", 30.days()),
+                ("2024-02-28 23:00", "2024-03-01 01:00", 1.day().hours(2)),
+                ("now", "now", 0.seconds()),
+                (
+                    "10:00 UTC",
+                    "10:00:30.5 +02:00",
+                    -1.hour().minutes(59).seconds(29).nanoseconds(500_000_000),
+                ),
+            ] {
+                let span = span_between_at_date(now.clone(), a, b).unwrap();
+                assert_eq!(span.fieldwise(), expected.fieldwise(), "{a} | {b}");
+            }
+
+            assert!(span_between_at_date(now.clone(), "now", "invalid").is_err());
+            assert!(span_between_at_date(now, "invalid", "now").is_err());
+        }
     }
 
     /// Used to test example code presented in the README.
@@ -410,16 +1496,220 @@ mod tests {
     }
 
     mod invalid_test {
-        use crate::parse_datetime;
-        use crate::ParseDateTimeError;
+        use jiff::Zoned;
+
+        use crate::{parse_datetime, parse_datetime_at_date};
+        use crate::{
+            Component, DstResolution, ErrorCategory, ErrorCode, ParseDateTimeError, Parser,
+        };
 
         #[test]
         fn test_invalid_input() {
             let result = parse_datetime("foobar");
-            assert_eq!(result, Err(ParseDateTimeError::InvalidInput));
+            assert!(matches!(result, Err(ParseDateTimeError::Parse(_))));
 
             let result = parse_datetime("invalid 1");
-            assert_eq!(result, Err(ParseDateTimeError::InvalidInput));
+            assert!(matches!(result, Err(ParseDateTimeError::Parse(_))));
+        }
+
+        #[test]
+        fn test_parse_and_resolve_errors() {
+            let now = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+
+            for input in ["2025-06-15 foo", "10:30 pm pm", "2025-06-15 25"] {
+                assert!(
+                    matches!(
+                        parse_datetime_at_date(now.clone(), input),
+                        Err(ParseDateTimeError::Parse(_))
+                    ),
+                    "{input}"
+                );
+            }
+
+            for (input, component, value) in [
+                ("2025-13-01", Component::Month, 13),
+                ("2025-00-10", Component::Month, 0),
+                ("13/45/2025", Component::Month, 13),
+                ("2025-06-32", Component::Day, 32),
+                ("feb 32", Component::Day, 32),
+                ("2025-02-30", Component::Day, 30),
+                ("2025-02-29", Component::Day, 29),
+                ("feb 30", Component::Day, 30),
+                ("31 apr 2025", Component::Day, 31),
+            ] {
+                let result = parse_datetime_at_date(now.clone(), input);
+                let Err(ParseDateTimeError::InvalidDate(e)) = result else {
+                    panic!("{input}: {result:?}");
+                };
+                assert_eq!((e.component(), e.value()), (component, value), "{input}");
+            }
+
+            for (input, component, value) in [
+                ("25:00", Component::Hour, 25),
+                ("2025-06-15T24:30", Component::Hour, 24),
+                ("2025-06-15 10:61", Component::Minute, 61),
+                ("10:30:61", Component::Second, 61),
+                ("10:61pm", Component::Minute, 61),
+            ] {
+                let result = parse_datetime_at_date(now.clone(), input);
+                let Err(ParseDateTimeError::InvalidTime(e)) = result else {
+                    panic!("{input}: {result:?}");
+                };
+                assert_eq!((e.component(), e.value()), (component, value), "{input}");
+            }
+
+            assert!(
+                matches!(
+                    parse_datetime_at_date(now.clone(), "feb 29"),
+                    Err(ParseDateTimeError::Resolve(_))
+                ),
+                "feb 29"
+            );
+
+            let parser = Parser::new().dst_resolution(DstResolution::Error);
+            let result = parser
+                .parse_datetime_at_date(now.clone(), r#"TZ="America/New_York" 2025-03-09 02:30"#);
+            let Err(ParseDateTimeError::Resolve(e)) = result else {
+                panic!("{result:?}");
+            };
+            assert!(!e.to_string().is_empty());
+            assert_eq!(
+                parse_datetime_at_date(now, "9999-12-31 +1 year"),
+                Err(ParseDateTimeError::OutOfRange)
+            );
+        }
+
+        #[test]
+        fn test_error_codes() {
+            let now = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+            let parser = Parser::new()
+                .max_input_length(16)
+                .max_items(2)
+                .resolution_hook(|_| Err(ParseDateTimeError::Rejected));
+
+            for (result, code, category) in [
+                (
+                    parse_datetime("foobar"),
+                    ErrorCode::Parse,
+                    ErrorCategory::Syntax,
+                ),
+                (
+                    parse_datetime("2025-02-30"),
+                    ErrorCode::InvalidDate,
+                    ErrorCategory::Resolution,
+                ),
+                (
+                    parse_datetime("25:00"),
+                    ErrorCode::InvalidTime,
+                    ErrorCategory::Resolution,
+                ),
+                (
+                    parse_datetime_at_date(now.clone(), "feb 29"),
+                    ErrorCode::Resolve,
+                    ErrorCategory::Resolution,
+                ),
+                (
+                    parse_datetime_at_date(now.clone(), "9999-12-31 +1 year"),
+                    ErrorCode::OutOfRange,
+                    ErrorCategory::Resolution,
+                ),
+                (
+                    parser.parse_datetime("2025-06-15 10:00:00"),
+                    ErrorCode::InputTooLong,
+                    ErrorCategory::Limit,
+                ),
+                (
+                    parser.parse_datetime("1d 1h 1m"),
+                    ErrorCode::TooManyItems,
+                    ErrorCategory::Limit,
+                ),
+                (
+                    parser.parse_datetime_at_date(now, "tomorrow"),
+                    ErrorCode::Rejected,
+                    ErrorCategory::Resolution,
+                ),
+            ] {
+                let err = result.unwrap_err();
+                assert_eq!(err.code(), code, "{err:?}");
+                assert_eq!(err.code().category(), category, "{err:?}");
+            }
+
+            assert_eq!(ErrorCode::InputTooLong.to_string(), "input_too_long");
+            assert_eq!(
+                ParseDateTimeError::InputTooLong.to_string(),
+                ErrorCode::InputTooLong.message()
+            );
+            assert_eq!(ErrorCode::BudgetExceeded.category(), ErrorCategory::Limit);
+        }
+
+        #[test]
+        fn test_localized_messages() {
+            let translate = |code: ErrorCode| match code {
+                ErrorCode::InvalidDate => Some("Datum existiert nicht".to_owned()),
+                ErrorCode::InputTooLong => Some("Eingabe ist zu lang".to_owned()),
+                _ => None,
+            };
+
+            let err = parse_datetime("2025-02-30").unwrap_err();
+            assert_eq!(err.localized(translate), "Datum existiert nicht");
+            assert_eq!(err.reason(), Some("day is not valid for the given month"));
+
+            let err = parse_datetime("foobar").unwrap_err();
+            assert_eq!(err.localized(translate), err.to_string());
+            assert!(err.reason().is_some());
+
+            let err = Parser::new()
+                .max_input_length(4)
+                .parse_datetime("today")
+                .unwrap_err();
+            assert_eq!(err.localized(translate), "Eingabe ist zu lang");
+            assert_eq!(err.reason(), None);
+        }
+
+        #[test]
+        fn test_syntax_reasons() {
+            for input in ["foobar", "@abc", "10:00:00 foo", "[!u-ca=x]"] {
+                let err = parse_datetime(input).unwrap_err();
+                let reason = err.reason().unwrap();
+                assert!(!reason.is_empty(), "{input}");
+                assert!(!reason.contains("ContextError"), "{input}: {reason}");
+                assert!(!reason.contains("Parsing"), "{input}: {reason}");
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "not supported")]
+        fn test_round_unsupported() {
+            let _ = Parser::new().round(jiff::Unit::Month, 1, jiff::RoundMode::Floor);
+        }
+
+        #[test]
+        fn test_limits() {
+            let parser = Parser::new().max_input_length(16).max_items(2);
+            assert!(parser.parse_datetime("2025-06-15 10:00").is_ok());
+            assert_eq!(
+                parser.parse_datetime("2025-06-15 10:00:00"),
+                Err(ParseDateTimeError::InputTooLong)
+            );
+            assert_eq!(
+                parser.parse_datetime("1 day 1 day 1 day"),
+                Err(ParseDateTimeError::InputTooLong)
+            );
+            assert_eq!(
+                parser.parse_datetime("1d 1h 1m"),
+                Err(ParseDateTimeError::TooManyItems)
+            );
+            assert!(matches!(
+                parser.parse_datetime("foo"),
+                Err(ParseDateTimeError::Parse(_))
+            ));
+
+            let parser = Parser::new().max_steps(100);
+            assert!(parser.parse_datetime("2025-06-15 10:00").is_ok());
+            assert_eq!(
+                parser.parse_datetime("2025-06-15 10:00 (a long comment that is skipped)"),
+                Err(ParseDateTimeError::BudgetExceeded)
+            );
         }
     }
 
@@ -665,4 +1955,415 @@ mod tests {
             );
         }
     }
+
+    mod shared_parser {
+        use jiff::{civil::time, ToSpan, Zoned};
+
+        use crate::{Holidays, Keyword, Parser};
+
+        #[test]
+        fn test_threads() {
+            fn assert_send_sync<T: Send + Sync>() {}
+            assert_send_sync::<Parser>();
+
+            let parser = Parser::new()
+                .holidays(Holidays::builtin())
+                .keyword("eod", Keyword::Time(time(17, 0, 0, 0)))
+                .relative_unit("sprint", |dt: &Zoned, n: i32| {
+                    dt.checked_add((2 * i64::from(n)).weeks()).ok()
+                });
+            let base: Zoned = "2024-09-13T10:00:00+00:00[UTC]".parse().unwrap();
+
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = [
+                    ("christmas eod", "2024-12-25 17:00:00"),
+                    ("next sprint", "2024-09-27 10:00:00"),
+                    ("tomorrow eod", "2024-09-14 17:00:00"),
+                ]
+                .into_iter()
+                .map(|(input, expected)| {
+                    let (parser, base) = (&parser, base.clone());
+                    scope.spawn(move || {
+                        let dt = parser.parse_datetime_at_date(base, input).unwrap();
+                        assert_eq!(dt.strftime("%F %T").to_string(), expected, "{input}");
+                    })
+                })
+                .collect();
+                for handle in handles {
+                    handle.join().unwrap();
+                }
+            });
+        }
+    }
+
+    mod convert {
+        use jiff::tz::TimeZone;
+
+        use crate::parse_in_zone_then_convert;
+
+        #[test]
+        fn test_parse_in_zone_then_convert() {
+            let utc = TimeZone::UTC;
+            let tokyo = TimeZone::get("Asia/Tokyo").unwrap();
+
+            for (input, target, expected) in [
+                (
+                    "2024-09-13 10:00 UTC",
+                    &tokyo,
+                    "2024-09-13T19:00:00+09:00[Asia/Tokyo]",
+                ),
+                (
+                    r#"TZ="America/New_York" 2024-09-13 10:00"#,
+                    &utc,
+                    "2024-09-13T14:00:00+00:00[UTC]",
+                ),
+                (
+                    "2024-09-13 10:00 +02:00",
+                    &utc,
+                    "2024-09-13T08:00:00+00:00[UTC]",
+                ),
+                ("@0", &tokyo, "1970-01-01T09:00:00+09:00[Asia/Tokyo]"),
+            ] {
+                let dt = parse_in_zone_then_convert(input, target.clone()).unwrap();
+                assert_eq!(dt.to_string(), expected, "{input}");
+            }
+
+            assert!(parse_in_zone_then_convert("foo", utc).is_err());
+        }
+    }
+
+    mod iso8601 {
+        use crate::{parse_iso8601, Iso8601Value, ParseDateTimeError, Parser};
+
+        #[test]
+        fn test_parse_iso8601() {
+            assert!(matches!(
+                parse_iso8601("2022-11-14T10:20:30Z"),
+                Ok(Iso8601Value::DateTime { .. })
+            ));
+            assert!(matches!(
+                parse_iso8601("P1Y"),
+                Ok(Iso8601Value::Duration(_))
+            ));
+
+            for input in ["now", "2022-11-14 10:20:30", "PT1H/PT2H"] {
+                assert!(
+                    matches!(parse_iso8601(input), Err(ParseDateTimeError::Parse(_))),
+                    "{input}"
+                );
+            }
+            for input in ["2023-02-29", "2021-W53-1", "2022-366"] {
+                assert!(
+                    matches!(parse_iso8601(input), Err(ParseDateTimeError::Resolve(_))),
+                    "{input}"
+                );
+            }
+            for input in ["+10000-01-01", "P20000Y"] {
+                assert!(
+                    matches!(parse_iso8601(input), Err(ParseDateTimeError::OutOfRange)),
+                    "{input}"
+                );
+            }
+            assert!(matches!(
+                Parser::new()
+                    .max_input_length(5)
+                    .parse_iso8601("2022-11-14"),
+                Err(ParseDateTimeError::InputTooLong)
+            ));
+        }
+    }
+
+    mod temporal {
+        use crate::{parse_temporal, ParseDateTimeError, Parser, TemporalValue};
+
+        #[test]
+        fn test_parse_temporal() {
+            assert!(matches!(
+                parse_temporal("2024-03-10T10:00:00"),
+                Ok(TemporalValue::PlainDateTime(_))
+            ));
+
+            for input in [
+                "now",
+                "2024-03-10 10:00",
+                "P1D",
+                "2024-03-10/P1D",
+                "10:00Z",
+                "10:00[Europe/Paris]",
+            ] {
+                assert!(
+                    matches!(parse_temporal(input), Err(ParseDateTimeError::Parse(_))),
+                    "{input}"
+                );
+            }
+            for input in ["2023-02-29", "2024-03-10T10:00+01:00[America/New_York]"] {
+                assert!(
+                    matches!(parse_temporal(input), Err(ParseDateTimeError::Resolve(_))),
+                    "{input}"
+                );
+            }
+            assert!(matches!(
+                Parser::new()
+                    .max_input_length(5)
+                    .parse_temporal("2024-03-10"),
+                Err(ParseDateTimeError::InputTooLong)
+            ));
+        }
+    }
+
+    mod generalized_time {
+        use crate::{parse_generalized_time, ParseDateTimeError};
+
+        #[test]
+        fn test_parse_generalized_time() {
+            for (input, expected) in [
+                ("20240702143000Z", "2024-07-02T14:30:00+00:00[UTC]"),
+                ("202407021430-05", "2024-07-02T14:30:00-05:00[-05:00]"),
+            ] {
+                assert_eq!(
+                    parse_generalized_time(input).unwrap().to_string(),
+                    expected,
+                    "{input}"
+                );
+            }
+
+            for input in ["2024-07-02T14:30:00Z", "20240702143000", "now"] {
+                assert!(
+                    matches!(
+                        parse_generalized_time(input),
+                        Err(ParseDateTimeError::Parse(_))
+                    ),
+                    "{input}"
+                );
+            }
+            assert!(matches!(
+                parse_generalized_time("20240631143000Z"),
+                Err(ParseDateTimeError::Resolve(_))
+            ));
+        }
+    }
+
+    mod utc_time {
+        use crate::{parse_generalized_time, parse_utc_time, ParseDateTimeError};
+
+        #[test]
+        fn test_parse_utc_time() {
+            for (utc_time, generalized_time) in [
+                ("500101000000Z", "19500101000000Z"),
+                ("491231235959Z", "20491231235959Z"),
+                ("2407021430+0200", "202407021430+0200"),
+            ] {
+                assert_eq!(
+                    parse_utc_time(utc_time).unwrap(),
+                    parse_generalized_time(generalized_time).unwrap(),
+                    "{utc_time}"
+                );
+            }
+
+            for input in ["20240702143000Z", "240702143000", "240702143000.5Z"] {
+                assert!(
+                    matches!(parse_utc_time(input), Err(ParseDateTimeError::Parse(_))),
+                    "{input}"
+                );
+            }
+            assert!(matches!(
+                parse_utc_time("240631143000Z"),
+                Err(ParseDateTimeError::Resolve(_))
+            ));
+        }
+    }
+
+    mod imap {
+        use crate::{parse_datetime_at_date, parse_imap_date, ParseDateTimeError};
+
+        #[test]
+        fn test_parse_imap_date() {
+            let dt = parse_imap_date("\"01-Jul-2024 12:34:56 +0200\"").unwrap();
+            assert_eq!(dt.to_string(), "2024-07-01T12:34:56+02:00[+02:00]");
+
+            // The lenient grammar accepts the value without quotes.
+            let now = "2024-01-01T00:00:00+00:00[UTC]".parse().unwrap();
+            let lenient = parse_datetime_at_date(now, "01-Jul-2024 12:34:56 +0200").unwrap();
+            assert_eq!(lenient.timestamp(), dt.timestamp());
+
+            for input in ["Mon, 01 Jul 2024 12:34:56 +0200", "01-Jul-2024"] {
+                assert!(
+                    matches!(parse_imap_date(input), Err(ParseDateTimeError::Parse(_))),
+                    "{input}"
+                );
+            }
+            assert!(matches!(
+                parse_imap_date("31-Jun-2024 12:34:56 +0200"),
+                Err(ParseDateTimeError::Resolve(_))
+            ));
+        }
+    }
+
+    mod ntp {
+        use jiff::Zoned;
+
+        use crate::{parse_ntp_timestamp_at_date, ParseDateTimeError, Parser};
+
+        #[test]
+        fn test_parse_ntp_timestamp() {
+            let now: Zoned = "2024-01-12T10:00:00+01:00[Europe/Paris]".parse().unwrap();
+            for (input, expected) in [
+                (
+                    "e9d7a7b8.00000000",
+                    "2024-04-27T18:35:04+02:00[Europe/Paris]",
+                ),
+                ("3923224504.5", "2024-04-27T18:35:04.5+02:00[Europe/Paris]"),
+                (
+                    "00000000.00000000",
+                    "2036-02-07T07:28:16+01:00[Europe/Paris]",
+                ),
+            ] {
+                let dt = parse_ntp_timestamp_at_date(now.clone(), input).unwrap();
+                assert_eq!(dt.to_string(), expected, "{input}");
+            }
+
+            for input in ["@1705050000", "e9d7a7b8", "4294967296"] {
+                assert!(
+                    matches!(
+                        parse_ntp_timestamp_at_date(now.clone(), input),
+                        Err(ParseDateTimeError::Parse(_))
+                    ),
+                    "{input}"
+                );
+            }
+            assert!(matches!(
+                Parser::new()
+                    .max_input_length(8)
+                    .parse_ntp_timestamp_at_date(now, "e9d7a7b8.00000000"),
+                Err(ParseDateTimeError::InputTooLong)
+            ));
+        }
+    }
+
+    mod received {
+        use crate::{parse_received_date, ParseDateTimeError};
+
+        #[test]
+        fn test_parse_received_date() {
+            for (input, expected) in [
+                (
+                    "Tue, 3 Oct 2023 10:20:30 +0000 (GMT)",
+                    "2023-10-03T10:20:30+00:00[UTC]",
+                ),
+                (
+                    "by mail.example.org; 3 OCT 2023 10:20 -0700",
+                    "2023-10-03T10:20:00-07:00[-07:00]",
+                ),
+            ] {
+                assert_eq!(
+                    parse_received_date(input).unwrap().to_string(),
+                    expected,
+                    "{input}"
+                );
+            }
+
+            for input in ["yesterday", "2023-10-03 10:20:30", "Tue, 3 Oct 2023"] {
+                assert!(
+                    matches!(
+                        parse_received_date(input),
+                        Err(ParseDateTimeError::Parse(_))
+                    ),
+                    "{input}"
+                );
+            }
+            assert!(matches!(
+                parse_received_date("Thu, 29 Feb 2023 10:20:30 GMT"),
+                Err(ParseDateTimeError::Resolve(_))
+            ));
+        }
+    }
+
+    mod retry_after {
+        use jiff::Zoned;
+
+        use crate::{parse_retry_after_at_date, ParseDateTimeError, Parser};
+
+        #[test]
+        fn test_parse_retry_after() {
+            let now: Zoned = "2024-09-13T10:00:00+02:00[Europe/Paris]".parse().unwrap();
+            for (input, expected) in [
+                ("0", "2024-09-13T10:00:00+02:00[Europe/Paris]"),
+                ("3600", "2024-09-13T11:00:00+02:00[Europe/Paris]"),
+                (
+                    "Fri, 13 Sep 2024 09:00:00 GMT",
+                    "2024-09-13T11:00:00+02:00[Europe/Paris]",
+                ),
+                (
+                    "Fri Sep 13 09:00:00 2024",
+                    "2024-09-13T11:00:00+02:00[Europe/Paris]",
+                ),
+            ] {
+                let dt = parse_retry_after_at_date(now.clone(), input).unwrap();
+                assert_eq!(dt.to_string(), expected, "{input}");
+            }
+
+            for input in ["in 2 minutes", "2024-09-13T09:00:00Z", "-120"] {
+                assert!(
+                    matches!(
+                        parse_retry_after_at_date(now.clone(), input),
+                        Err(ParseDateTimeError::Parse(_))
+                    ),
+                    "{input}"
+                );
+            }
+            assert!(matches!(
+                parse_retry_after_at_date(now.clone(), "Thu, 29 Feb 2023 00:00:00 GMT"),
+                Err(ParseDateTimeError::Resolve(_))
+            ));
+            assert!(matches!(
+                Parser::new()
+                    .max_input_length(3)
+                    .parse_retry_after_at_date(now, "3600"),
+                Err(ParseDateTimeError::InputTooLong)
+            ));
+        }
+    }
+
+    mod rfc3339 {
+        use crate::{parse_rfc3339, ParseDateTimeError, Parser};
+
+        #[test]
+        fn test_parse_rfc3339() {
+            for (input, expected) in [
+                ("2022-11-14T10:20:30Z", "2022-11-14T10:20:30+00:00[UTC]"),
+                ("2022-11-14 10:20:30z", "2022-11-14T10:20:30+00:00[UTC]"),
+                (
+                    "2022-11-14T10:20:30.25-05:00",
+                    "2022-11-14T10:20:30.25-05:00[-05:00]",
+                ),
+            ] {
+                assert_eq!(parse_rfc3339(input).unwrap().to_string(), expected);
+            }
+
+            for input in [
+                "now",
+                "2022-11-14 10:20:30 UTC",
+                "Mon, 14 Nov 2022 10:20:30 +0000",
+            ] {
+                assert!(
+                    matches!(parse_rfc3339(input), Err(ParseDateTimeError::Parse(_))),
+                    "{input}"
+                );
+            }
+            assert!(matches!(
+                parse_rfc3339("2023-02-29T10:20:30Z"),
+                Err(ParseDateTimeError::Resolve(_))
+            ));
+            assert_eq!(
+                parse_rfc3339("9999-12-31T23:59:59Z"),
+                Err(ParseDateTimeError::OutOfRange)
+            );
+            assert_eq!(
+                Parser::new()
+                    .max_input_length(10)
+                    .parse_rfc3339("2022-11-14T10:20:30Z"),
+                Err(ParseDateTimeError::InputTooLong)
+            );
+        }
+    }
 }
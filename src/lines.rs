@@ -0,0 +1,211 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Parsing of the lines of a reader, e.g., a log file.
+//!
+//! Lines are parsed with
+//! [`Parser::parse_lines`](crate::Parser::parse_lines).
+
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io::{self, BufRead},
+};
+
+use jiff::Zoned;
+
+use crate::{items, parser::Options, ParseDateTimeError};
+
+/// An iterator over the results of parsing the lines of a reader.
+///
+/// Created by [`Parser::parse_lines`](crate::Parser::parse_lines) and
+/// [`Parser::parse_lines_at_date`](crate::Parser::parse_lines_at_date). Each
+/// line yields a result, so that results line up with the lines of the input,
+/// and the iterator ends at the end of the input, or after an error reading
+/// it.
+///
+/// All lines are resolved against the same base date and time: without one,
+/// the current date and time is taken once, when the iterator is created, so
+/// that the lines of a batch agree on "today" even if it runs past midnight
+/// or a daylight saving time transition.
+pub struct Lines<'a, R> {
+    reader: R,
+    base: Zoned,
+    options: &'a Options,
+    /// The number of the last line read, starting at 1.
+    line: usize,
+    /// The buffer that lines are read into, reused across lines.
+    buf: String,
+    done: bool,
+}
+
+impl<'a, R: BufRead> Lines<'a, R> {
+    pub(crate) fn new(reader: R, base: Option<Zoned>, options: &'a Options) -> Self {
+        Self {
+            reader,
+            base: base.unwrap_or_else(|| items::now(options)),
+            options,
+            line: 0,
+            buf: String::new(),
+            done: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<'_, R> {
+    type Item = Result<Zoned, LineError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        self.buf.clear();
+        self.line += 1;
+        let error = match self.reader.read_line(&mut self.buf) {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => {
+                let line = self.buf.strip_suffix('\n').unwrap_or(&self.buf);
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                match items::parse_at_date(self.base.clone(), line, self.options) {
+                    Ok(zoned) => return Some(Ok(zoned)),
+                    Err(e) => LineErrorKind::Parse(e.into()),
+                }
+            }
+            Err(e) => {
+                self.done = true;
+                LineErrorKind::Io(e)
+            }
+        };
+        Some(Err(LineError {
+            line: self.line,
+            kind: error,
+        }))
+    }
+}
+
+/// An error parsing a line of a reader, or reading it.
+#[derive(Debug)]
+pub struct LineError {
+    line: usize,
+    kind: LineErrorKind,
+}
+
+#[derive(Debug)]
+enum LineErrorKind {
+    Io(io::Error),
+    Parse(ParseDateTimeError),
+}
+
+impl LineError {
+    /// Returns the number of the line, starting at 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the error parsing the line, or `None` if it could not be read.
+    pub fn parse_error(&self) -> Option<&ParseDateTimeError> {
+        match &self.kind {
+            LineErrorKind::Parse(e) => Some(e),
+            LineErrorKind::Io(_) => None,
+        }
+    }
+
+    /// Returns the error reading the line, or `None` if it was read.
+    pub fn io_error(&self) -> Option<&io::Error> {
+        match &self.kind {
+            LineErrorKind::Io(e) => Some(e),
+            LineErrorKind::Parse(_) => None,
+        }
+    }
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            LineErrorKind::Io(e) => write!(f, "line {}: {e}", self.line),
+            LineErrorKind::Parse(e) => write!(f, "line {}: {e}", self.line),
+        }
+    }
+}
+
+impl Error for LineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            LineErrorKind::Io(e) => Some(e),
+            LineErrorKind::Parse(e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::Zoned;
+
+    use crate::Parser;
+
+    #[test]
+    fn lines() {
+        let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+        let input = "2025-01-01 10:00\r\n+1 day\n\nfoobar\nyesterday";
+        let parser = Parser::new();
+        let results: Vec<_> = parser
+            .parse_lines_at_date(base, input.as_bytes())
+            .map(|r| match r {
+                Ok(z) => Ok(z.strftime("%F %T").to_string()),
+                Err(e) => Err(e.line()),
+            })
+            .collect();
+        assert_eq!(
+            results,
+            [
+                Ok("2025-01-01 10:00:00".to_string()),
+                Ok("2025-06-16 10:00:00".to_string()),
+                Ok("2025-06-15 00:00:00".to_string()),
+                Err(4),
+                Ok("2025-06-14 10:00:00".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn errors() {
+        let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+        let parser = Parser::new();
+        let err = parser
+            .parse_lines_at_date(base.clone(), "today\n2025-02-30\n".as_bytes())
+            .nth(1)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(err.line(), 2);
+        assert!(err.parse_error().is_some());
+        assert!(err.io_error().is_none());
+        assert_eq!(
+            err.to_string(),
+            "line 2: Invalid input string: date is not valid"
+        );
+
+        // The iterator ends after an error reading the input, here because it
+        // is not valid UTF-8.
+        let results: Vec<_> = parser
+            .parse_lines_at_date(base, &b"today\n\xff\ntoday\n"[..])
+            .collect();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_ok());
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.line(), 2);
+        assert!(err.io_error().is_some());
+    }
+
+    #[test]
+    fn same_base() {
+        let results: Vec<_> = Parser::new()
+            .parse_lines("now\nnow\n".as_bytes())
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(results[0], results[1]);
+    }
+}
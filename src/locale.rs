@@ -0,0 +1,34 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Locale-specific words.
+//!
+//! The grammar is English. When a [`Locale`] is configured on a
+//! [`Parser`](crate::Parser), the words of that locale are accepted in
+//! addition to the English ones.
+
+/// A locale whose words are accepted in addition to the English ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Locale {
+    /// Chinese, e.g., "下午3:30".
+    Chinese,
+    /// Greek, e.g., "3:30 μ.μ.".
+    Greek,
+    /// Japanese, e.g., "午後3:30".
+    Japanese,
+    /// Korean, e.g., "오후 3:30".
+    Korean,
+}
+
+impl Locale {
+    /// The markers of the first and the second half of the day.
+    pub(crate) fn meridiem_markers(self) -> (&'static [&'static str], &'static [&'static str]) {
+        match self {
+            Locale::Chinese => (&["上午", "早上"], &["下午", "晚上"]),
+            Locale::Greek => (&["π.μ.", "Π.Μ.", "πμ", "ΠΜ"], &["μ.μ.", "Μ.Μ.", "μμ", "ΜΜ"]),
+            Locale::Japanese => (&["午前"], &["午後"]),
+            Locale::Korean => (&["오전"], &["오후"]),
+        }
+    }
+}
@@ -0,0 +1,378 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! A parsed date and time that does not depend on a date and time library.
+//!
+//! A [`ParsedDateTime`] is returned by
+//! [`Parser::parse_datetime_detailed`](crate::Parser::parse_datetime_detailed).
+//! It converts into a jiff [`Zoned`], and, with the `chrono` and `time`
+//! features, into the date and time types of those crates.
+
+use jiff::Zoned;
+
+#[cfg(feature = "chrono")]
+use crate::{ParseDateTimeError, ResolveError};
+
+/// Which parts of a date and time were given by the input, rather than taken
+/// from the base date and time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Precision {
+    /// A calendar date (e.g., "2025-06-15" or "the second tuesday of june") or
+    /// a timestamp was given.
+    pub date: bool,
+    /// A time of day or a timestamp was given.
+    pub time: bool,
+    /// A timezone item, a numeric offset, a `TZ="..."` rule or a timestamp
+    /// was given.
+    pub offset: bool,
+    /// The most specific unit given (e.g., `Hour` for "10am", `Minute` for
+    /// "10:30", `Day` for "2025-06-15" or "tomorrow", and `Month` for "+1
+    /// month"), if any. A time of day is as specific as its last non-zero
+    /// field, and relative items that do not move the date and time (e.g.,
+    /// "now") give no unit.
+    pub unit: Option<jiff::Unit>,
+}
+
+/// A parsed date and time, with its UTC offset, its IANA zone if it has one,
+/// and the parts that were given by the input.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use parse_datetime::Parser;
+///
+/// let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+/// let parsed = Parser::new()
+///     .parse_datetime_at_date_detailed(base, "2025-01-01 10:30 EST")
+///     .unwrap();
+/// assert_eq!((parsed.year(), parsed.month(), parsed.day()), (2025, 1, 1));
+/// assert_eq!(parsed.offset_seconds(), -5 * 3600);
+/// assert_eq!(parsed.zone_name(), Some("EST"));
+/// assert!(parsed.precision().time);
+///
+/// let zoned = Zoned::from(parsed);
+/// assert_eq!(zoned.strftime("%F %T %:z").to_string(), "2025-01-01 10:30:00 -05:00");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParsedDateTime {
+    zoned: Zoned,
+    zone_name: Option<String>,
+    precision: Precision,
+}
+
+impl ParsedDateTime {
+    pub(crate) fn new(zoned: Zoned, zone_name: Option<String>, precision: Precision) -> Self {
+        Self {
+            zoned,
+            zone_name,
+            precision,
+        }
+    }
+
+    /// The year, between -9999 and 9999.
+    pub fn year(&self) -> i32 {
+        self.zoned.year().into()
+    }
+
+    /// The month, between 1 and 12.
+    pub fn month(&self) -> u8 {
+        self.zoned.month() as u8
+    }
+
+    /// The day of the month, between 1 and 31.
+    pub fn day(&self) -> u8 {
+        self.zoned.day() as u8
+    }
+
+    /// The hour, between 0 and 23.
+    pub fn hour(&self) -> u8 {
+        self.zoned.hour() as u8
+    }
+
+    /// The minute, between 0 and 59.
+    pub fn minute(&self) -> u8 {
+        self.zoned.minute() as u8
+    }
+
+    /// The second, between 0 and 59.
+    pub fn second(&self) -> u8 {
+        self.zoned.second() as u8
+    }
+
+    /// The fractional part of the second, in nanoseconds.
+    pub fn nanosecond(&self) -> u32 {
+        self.zoned.subsec_nanosecond() as u32
+    }
+
+    /// The offset from UTC, in seconds east of UTC.
+    pub fn offset_seconds(&self) -> i32 {
+        self.zoned.offset().seconds()
+    }
+
+    /// The IANA identifier of the timezone (e.g., "America/New_York"), if the
+    /// date and time is in a timezone with one rather than at a fixed offset.
+    pub fn zone_id(&self) -> Option<&str> {
+        self.zoned.time_zone().iana_name()
+    }
+
+    /// The name of the timezone that was parsed, as returned by
+    /// [`Parser::parse_datetime_with_zone`](crate::Parser::parse_datetime_with_zone).
+    pub fn zone_name(&self) -> Option<&str> {
+        self.zone_name.as_deref()
+    }
+
+    /// The parts of the date and time that were given by the input.
+    pub fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Format the date and time with `format`, as `strftime` does, with `%Z`
+    /// replaced by the name of the timezone that was parsed, if any (e.g.,
+    /// "CEST" for "10:30 CEST", rather than the offset it stands for).
+    pub(crate) fn format(&self, format: &str) -> Result<String, jiff::Error> {
+        match &self.zone_name {
+            Some(name) => {
+                jiff::fmt::strtime::format(with_zone_name(format, name).as_str(), &self.zoned)
+            }
+            None => jiff::fmt::strtime::format(format, &self.zoned),
+        }
+    }
+}
+
+/// Replace the `%Z` conversions of `format` by `name`, leaving the others,
+/// including `%%`, as they are.
+fn with_zone_name(format: &str, name: &str) -> String {
+    let mut result = String::with_capacity(format.len() + name.len());
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('Z') => result.push_str(&name.replace('%', "%%")),
+            Some(c) => {
+                result.push('%');
+                result.push(c);
+            }
+            None => result.push('%'),
+        }
+    }
+    result
+}
+
+impl From<ParsedDateTime> for Zoned {
+    fn from(parsed: ParsedDateTime) -> Self {
+        parsed.zoned
+    }
+}
+
+/// The date and time at its UTC offset. The IANA zone is not kept.
+///
+/// Offsets of a day or more, which a `TZ="..."` rule may give, are out of the
+/// range of chrono.
+#[cfg(feature = "chrono")]
+impl TryFrom<ParsedDateTime> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = ParseDateTimeError;
+
+    fn try_from(parsed: ParsedDateTime) -> Result<Self, Self::Error> {
+        let ts = parsed.zoned.timestamp();
+        let offset = chrono::FixedOffset::east_opt(parsed.offset_seconds()).ok_or_else(|| {
+            ParseDateTimeError::Resolve(ResolveError::new("offset is out of the range of chrono"))
+        })?;
+        let utc = chrono::DateTime::from_timestamp(ts.as_second(), ts.subsec_nanosecond() as u32)
+            .ok_or_else(|| {
+            ParseDateTimeError::Resolve(ResolveError::new(
+                "timestamp is out of the range of chrono",
+            ))
+        })?;
+        Ok(utc.with_timezone(&offset))
+    }
+}
+
+/// The date and time at its UTC offset. The IANA zone is not kept.
+#[cfg(feature = "time")]
+impl From<ParsedDateTime> for time::OffsetDateTime {
+    fn from(parsed: ParsedDateTime) -> Self {
+        // Years between -9999 and 9999 and offsets of less than 26 hours are
+        // within the range of the time crate.
+        let offset = time::UtcOffset::from_whole_seconds(parsed.offset_seconds())
+            .expect("jiff offsets are within the range of time");
+        time::OffsetDateTime::from_unix_timestamp_nanos(parsed.zoned.timestamp().as_nanosecond())
+            .expect("jiff timestamps are within the range of time")
+            .to_offset(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::{Unit, Zoned};
+
+    use crate::Parser;
+
+    use super::{ParsedDateTime, Precision};
+
+    fn parse(input: &str) -> ParsedDateTime {
+        let base = "2025-06-15T10:00:00-04:00[America/New_York]"
+            .parse::<Zoned>()
+            .unwrap();
+        Parser::new()
+            .parse_datetime_at_date_detailed(base, input)
+            .unwrap()
+    }
+
+    #[test]
+    fn fields() {
+        let parsed = parse("2024-02-29 23:59:58.123456789 +05:30");
+        assert_eq!(
+            (
+                parsed.year(),
+                parsed.month(),
+                parsed.day(),
+                parsed.hour(),
+                parsed.minute(),
+                parsed.second(),
+                parsed.nanosecond(),
+            ),
+            (2024, 2, 29, 23, 59, 58, 123_456_789)
+        );
+        assert_eq!(parsed.offset_seconds(), 5 * 3600 + 30 * 60);
+        assert_eq!(parsed.zone_id(), None);
+        assert_eq!(parsed.zone_name(), None);
+
+        let parsed = parse("tomorrow");
+        assert_eq!((parsed.month(), parsed.day(), parsed.hour()), (6, 16, 10));
+        assert_eq!(parsed.offset_seconds(), -4 * 3600);
+        assert_eq!(parsed.zone_id(), Some("America/New_York"));
+    }
+
+    #[test]
+    fn precision() {
+        let precision = |date, time, offset, unit| Precision {
+            date,
+            time,
+            offset,
+            unit,
+        };
+        for (input, expected) in [
+            ("", precision(false, false, false, None)),
+            ("tomorrow", precision(false, false, false, Some(Unit::Day))),
+            ("now", precision(false, false, false, None)),
+            ("friday", precision(false, false, false, Some(Unit::Day))),
+            ("2025-01-01", precision(true, false, false, Some(Unit::Day))),
+            ("10:00", precision(false, true, false, Some(Unit::Hour))),
+            (
+                "10:00:30",
+                precision(false, true, false, Some(Unit::Second)),
+            ),
+            (
+                "2025-01-01 10:00",
+                precision(true, true, false, Some(Unit::Hour)),
+            ),
+            (
+                "2025-01-01 10:00 +1 minute",
+                precision(true, true, false, Some(Unit::Minute)),
+            ),
+            (
+                "+1 month",
+                precision(false, false, false, Some(Unit::Month)),
+            ),
+            ("2024-07", precision(true, false, false, Some(Unit::Month))),
+            (
+                "2024-07 10:00",
+                precision(true, true, false, Some(Unit::Hour)),
+            ),
+            (
+                "10:00+02:00",
+                precision(false, true, true, Some(Unit::Hour)),
+            ),
+            (
+                "2025-01-01 cet",
+                precision(true, false, true, Some(Unit::Day)),
+            ),
+            (
+                r#"TZ="Asia/Tokyo" 2025-01-01"#,
+                precision(true, false, true, Some(Unit::Day)),
+            ),
+            (
+                "@1700000000",
+                precision(true, true, true, Some(Unit::Nanosecond)),
+            ),
+        ] {
+            assert_eq!(parse(input).precision(), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn format() {
+        for (input, format, expected) in [
+            ("2025-07-01 10:30 CEST", "%H:%M %Z %z", "10:30 CEST +0200"),
+            ("2025-01-01 10:30 est", "%Z %:z", "EST -05:00"),
+            (
+                r#"TZ="Europe/Paris" 2025-07-01 10:30"#,
+                "%Z %z",
+                "CEST +0200",
+            ),
+            (r#"TZ="UTC-3" 2025-07-01 10:30"#, "%Z %z", "UTC +0300"),
+            ("2025-07-01 10:30 +05:30", "%z", "+0530"),
+            ("2025-07-01 10:30 CEST", "100%% %Z%%Z", "100% CEST%Z"),
+        ] {
+            assert_eq!(
+                parse(input).format(format).unwrap(),
+                expected,
+                "{input} {format}"
+            );
+        }
+
+        assert!(parse("2025-07-01 10:30 CEST").format("%Z%").is_err());
+    }
+
+    #[test]
+    fn into_zoned() {
+        let zoned = Zoned::from(parse("2025-11-02 01:30 -05:00"));
+        assert_eq!(
+            zoned.to_string(),
+            "2025-11-02T01:30:00-05:00[-05:00]".to_string()
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn into_chrono() {
+        use chrono::{Datelike, Timelike};
+
+        let dt = chrono::DateTime::<chrono::FixedOffset>::try_from(parse(
+            "2025-01-01 10:30:15.25 +05:30",
+        ))
+        .unwrap();
+        assert_eq!(
+            (dt.year(), dt.month(), dt.day(), dt.hour(), dt.minute()),
+            (2025, 1, 1, 10, 30)
+        );
+        assert_eq!((dt.second(), dt.nanosecond()), (15, 250_000_000));
+        assert_eq!(dt.offset().local_minus_utc(), 5 * 3600 + 30 * 60);
+
+        let parsed = parse(r#"TZ="UTC-24:30" 2025-01-01"#);
+        assert!(chrono::DateTime::<chrono::FixedOffset>::try_from(parsed).is_err());
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn into_time() {
+        let dt = time::OffsetDateTime::from(parse("2025-01-01 10:30:15.25 +05:30"));
+        assert_eq!(
+            (
+                dt.year(),
+                dt.month() as u8,
+                dt.day(),
+                dt.hour(),
+                dt.minute()
+            ),
+            (2025, 1, 1, 10, 30)
+        );
+        assert_eq!((dt.second(), dt.nanosecond()), (15, 250_000_000));
+        assert_eq!(dt.offset().whole_seconds(), 5 * 3600 + 30 * 60);
+    }
+}
@@ -0,0 +1,1049 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! A configurable parser.
+//!
+//! The free functions [`parse_datetime`](crate::parse_datetime) and
+//! [`parse_datetime_at_date`](crate::parse_datetime_at_date) follow GNU `date`
+//! semantics, with a few extensions and known differences listed on
+//! [`Parser`]. A [`Parser`] allows applications to opt in to further
+//! extensions on top of that grammar.
+
+use std::{collections::HashMap, io::BufRead, sync::OnceLock};
+
+use jiff::{
+    tz::{Offset, TimeZone},
+    RoundMode, SignedDuration, Span, Timestamp, Unit, Zoned, ZonedRound,
+};
+
+#[cfg(feature = "calendars")]
+use crate::Calendar;
+use crate::{
+    items, AbbreviationPolicy, DstAmbiguity, DstResolution, HolidayCalendar, HolidayProvider,
+    Iso8601Value, Keyword, LeapSeconds, Lines, Locale, ParseDateTimeError, Parsed, ParsedDateTime,
+    Quirk, RelativeUnit, RepeatingInterval, Schedule, Specification, TemporalValue, WorkTime,
+    ZoneResolution,
+};
+
+/// Options shared by the parsing and the resolution phases.
+#[derive(Default)]
+pub(crate) struct Options {
+    pub(crate) holidays: Option<Box<dyn HolidayProvider>>,
+    pub(crate) business_calendar: Option<Box<dyn HolidayCalendar>>,
+    pub(crate) fiscal_year_start: Option<i8>,
+    pub(crate) locale: Option<Locale>,
+    pub(crate) natural_language: bool,
+    pub(crate) strict_meridiem: bool,
+    pub(crate) strict_weekday: bool,
+    pub(crate) timezone_abbreviations: HashMap<String, Offset>,
+    pub(crate) keywords: HashMap<String, Keyword>,
+    pub(crate) relative_units: Vec<(String, Box<dyn RelativeUnit>)>,
+    pub(crate) resolution_hook: Option<Box<ResolutionHook>>,
+    pub(crate) quirk_callback: Option<Box<dyn Fn(Quirk) + Send + Sync>>,
+    pub(crate) abbreviation_policy: AbbreviationPolicy,
+    pub(crate) zone_resolution: ZoneResolution,
+    pub(crate) dst_resolution: DstResolution,
+    pub(crate) rfc2822_zones: bool,
+    pub(crate) tz_env: bool,
+    pub(crate) serial_dates: bool,
+    pub(crate) machine_input: bool,
+    pub(crate) leap_seconds: Option<LeapSeconds>,
+    pub(crate) epoch_origin: Option<Timestamp>,
+    pub(crate) detect_epoch_unit: bool,
+    pub(crate) infer_recent_year: bool,
+    pub(crate) compact_durations: bool,
+    pub(crate) m_unit: Option<MUnit>,
+    pub(crate) month_arithmetic: MonthArithmetic,
+    pub(crate) period_start: bool,
+    pub(crate) work_time: Option<WorkTime>,
+    pub(crate) max_input_length: Option<usize>,
+    pub(crate) max_items: Option<usize>,
+    pub(crate) max_steps: Option<usize>,
+    pub(crate) saturate: bool,
+    pub(crate) truncate: bool,
+    pub(crate) round: Option<ZonedRound>,
+    #[cfg(feature = "calendars")]
+    pub(crate) calendar: Option<Calendar>,
+    #[cfg(feature = "calendars")]
+    pub(crate) eras: bool,
+}
+
+impl Options {
+    /// The default options, for values that borrow the options they were
+    /// parsed with beyond the call (e.g., a [`Parsed`]).
+    pub(crate) fn default_ref() -> &'static Options {
+        static DEFAULT: OnceLock<Options> = OnceLock::new();
+        DEFAULT.get_or_init(Options::default)
+    }
+
+    /// Report that a GNU quirk was applied.
+    pub(crate) fn quirk(&self, quirk: Quirk) {
+        crate::quirk::record(quirk);
+        if let Some(callback) = &self.quirk_callback {
+            callback(quirk);
+        }
+    }
+}
+
+/// A function deciding how a parsed specification is resolved.
+pub(crate) type ResolutionHook =
+    dyn Fn(Specification<'_>) -> Result<Zoned, ParseDateTimeError> + Send + Sync;
+
+/// The unit of time that "m" stands for after a number (e.g., "5 m").
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MUnit {
+    /// "5 m" is five minutes.
+    Minute,
+    /// "5 m" is five months.
+    Month,
+}
+
+/// How relative months and years move a date whose day does not exist in the
+/// target month (e.g., "jan 31 +1 month" or "2024-02-29 +1 year").
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MonthArithmetic {
+    /// A relative month is the number of days in the month of the date, so
+    /// the date may overshoot into the following month (e.g., "jan 31 +1
+    /// month" is March 3rd, or 2nd in a leap year). A relative year clamps
+    /// February 29th to February 28th.
+    ///
+    /// This diverges from GNU `date`, which adds to the month of the date and
+    /// then normalizes it: "-1 month" from November 14th is October 15th
+    /// rather than 14th, "2022-03-31 -1 month" is February 28th rather than
+    /// March 3rd, and "2024-02-29 +1 year" is 2025-02-28 rather than March
+    /// 1st. See [`MonthArithmetic::Overflow`] for the arithmetic of GNU `date`.
+    #[default]
+    GnuOvershoot,
+    /// The date overflows past the end of the target month by the days it
+    /// lacks, as GNU `date` normalizes it (e.g., "jan 31 +1 month" is March
+    /// 3rd, or 2nd in a leap year, and "2024-02-29 +1 year" is 2025-03-01),
+    /// while a day that exists in the target month is kept (e.g., "-1 month"
+    /// from November 14th is October 14th).
+    Overflow,
+    /// The day is clamped to the last day of the target month (e.g., "jan 31
+    /// +1 month" is February 29th in a leap year, and 28th otherwise, and
+    /// "2024-02-29 +1 year" is 2025-02-28). February 29th given without a
+    /// year is also clamped to February 28th in a common year, which is
+    /// otherwise an error, as in GNU `date`.
+    ClampToMonthEnd,
+    /// A day that does not exist in the target month is an error. Days that
+    /// exist in every month (e.g., "jan 15 +1 month") are not affected.
+    Error,
+}
+
+/// A date and time parser with non-default options.
+///
+/// The default grammar is that of GNU `date -d`, with some extensions that GNU
+/// `date` rejects and that are always recognized:
+///
+/// - business days and weekdays (e.g., "+3 business days" or "2 weekdays"),
+/// - the units "yr", "mo", "wk" and "hr", relative items joined by "and"
+///   (e.g., "1 hour and 30 minutes ago") and combined into a single token
+///   (e.g., "+1d2h"),
+/// - ordinal weekdays within a month (e.g., "first monday of march"), a year
+///   and month (e.g., "2024-07") and SQL typed literals,
+/// - timestamps with a unit (e.g., "@1344000123ms"), Julian days and TAI64N
+///   labels, and RFC 9557 annotations.
+///
+/// The results also differ from GNU `date` for relative months (see
+/// [`MonthArithmetic::GnuOvershoot`]) and for a signed number after a time of
+/// day, which is a relative item rather than an offset when it has a unit
+/// (e.g., "10:00 +1 hour"). The known differences are listed in
+/// `src/compat/gnu.txt`.
+///
+/// A parser is configured once, and is `Send` and `Sync`: a single instance
+/// can be shared by threads (e.g., the request handlers of a web service)
+/// without being configured again for each input. Holiday providers, custom
+/// keywords and units, and hooks must therefore be `Send` and `Sync` as well.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::{civil::DateTime, tz::TimeZone};
+/// use parse_datetime::{Holidays, Parser};
+///
+/// let parser = Parser::new().holidays(Holidays::builtin());
+/// let base = "2024-06-01 12:00:00"
+///     .parse::<DateTime>()
+///     .unwrap()
+///     .to_zoned(TimeZone::UTC)
+///     .unwrap();
+///
+/// let christmas = parser.parse_datetime_at_date(base, "christmas").unwrap();
+/// assert_eq!(christmas.strftime("%F %T").to_string(), "2024-12-25 00:00:00");
+/// ```
+#[derive(Default)]
+pub struct Parser {
+    options: Options,
+}
+
+impl Parser {
+    /// Create a parser with the default options, i.e., the grammar of GNU
+    /// `date` with the extensions listed on [`Parser`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolve holiday names (e.g., "christmas") through the given provider.
+    ///
+    /// Holiday names take precedence over the other items of the grammar and
+    /// may be followed by a year (e.g., "christmas 2025"). Without a year, the
+    /// holiday is resolved in the year of the base date.
+    pub fn holidays(mut self, provider: impl HolidayProvider + 'static) -> Self {
+        self.options.holidays = Some(Box::new(provider));
+        self
+    }
+
+    /// Skip the holidays of the given calendar, in addition to weekends, when
+    /// adding or subtracting business days (e.g., "+3 business days").
+    pub fn business_calendar(mut self, calendar: impl HolidayCalendar + 'static) -> Self {
+        self.options.business_calendar = Some(Box::new(calendar));
+        self
+    }
+
+    /// Recognize fiscal periods (e.g., "fy2025 q2" or "start of fiscal year"),
+    /// with fiscal years starting on the first day of `month` (1-12).
+    ///
+    /// A fiscal year is named after the calendar year in which it ends: with
+    /// a start month of October, "fy2025" begins on 2024-10-01.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `month` is not in the range 1-12.
+    pub fn fiscal_year_start(mut self, month: i8) -> Self {
+        assert!((1..=12).contains(&month), "month must be in the range 1-12");
+        self.options.fiscal_year_start = Some(month);
+        self
+    }
+
+    /// Accept natural-language phrasings that are not part of the GNU
+    /// grammar, e.g., hours in words ("five o'clock pm"), "noon" and
+    /// "midnight", four-digit times in hours ("0930 hours" or "1700 hrs",
+    /// which GNU `date` takes as relative items), and the name of a month on
+    /// its own ("july"), which is the first day of that month in the year of
+    /// the base date.
+    pub fn natural_language(mut self, enabled: bool) -> Self {
+        self.options.natural_language = enabled;
+        self
+    }
+
+    /// Reject the ambiguous "12am" and "12pm" (including "12:00 pm" and
+    /// "twelve o'clock pm"), and accept the unambiguous "midnight" and "noon"
+    /// instead.
+    ///
+    /// Other times in the twelfth hour (e.g., "12:30pm") are still accepted.
+    pub fn strict_meridiem(mut self, enabled: bool) -> Self {
+        self.options.strict_meridiem = enabled;
+        self
+    }
+
+    /// Reject a day of the week that is not that of the date it is given with
+    /// (e.g., "tuesday 2024-07-17", a Wednesday), including a date given by
+    /// an ordinal weekday within a month, a holiday or a fiscal period.
+    ///
+    /// By default, as in GNU `date`, a day of the week given with a date is
+    /// ignored, while a day of the week alone moves to that day.
+    pub fn strict_weekday(mut self, enabled: bool) -> Self {
+        self.options.strict_weekday = enabled;
+        self
+    }
+
+    /// Interpret the timezone abbreviation `name` (e.g., "IST") as `offset`.
+    ///
+    /// Abbreviations are matched case-insensitively. A name registered here
+    /// takes precedence over the built-in table, which follows GNU `date` and
+    /// resolves ambiguous abbreviations to a single region (e.g., "CST" to US
+    /// Central Standard Time and "IST" to India Standard Time).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is empty or contains characters other than ASCII
+    /// letters.
+    pub fn timezone_abbreviation(mut self, name: &str, offset: Offset) -> Self {
+        assert!(
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic()),
+            "timezone abbreviation must consist of ASCII letters"
+        );
+        self.options
+            .timezone_abbreviations
+            .insert(name.to_ascii_lowercase(), offset);
+        self
+    }
+
+    /// Interpret the word `word` (e.g., "eod" or "payday") as `keyword`.
+    ///
+    /// Keywords are matched case-insensitively, and take precedence over the
+    /// other items of the grammar. A keyword standing for a date or a time of
+    /// day counts as a calendar date or a time of day item, so it cannot be
+    /// combined with another one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word` is empty or contains characters other than ASCII
+    /// letters.
+    pub fn keyword(mut self, word: &str, keyword: Keyword) -> Self {
+        assert!(
+            !word.is_empty() && word.chars().all(|c| c.is_ascii_alphabetic()),
+            "keyword must consist of ASCII letters"
+        );
+        self.options
+            .keywords
+            .insert(word.to_ascii_lowercase(), keyword);
+        self
+    }
+
+    /// Accept `name` (e.g., "sprint") as the unit of relative items (e.g., "2
+    /// sprints", "next sprint" or "1 sprint ago"), moving the date and time
+    /// through `unit`.
+    ///
+    /// Units are matched case-insensitively, with an optional "s" suffix, and
+    /// take precedence over the built-in units. Relative items are applied in
+    /// the order they are given.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is empty or contains characters other than ASCII
+    /// letters.
+    pub fn relative_unit(mut self, name: &str, unit: impl RelativeUnit + 'static) -> Self {
+        assert!(
+            !name.is_empty() && name.chars().all(|c| c.is_ascii_alphabetic()),
+            "relative unit must consist of ASCII letters"
+        );
+        let name = name.to_ascii_lowercase();
+        self.options.relative_units.retain(|(n, _)| *n != name);
+        self.options.relative_units.push((name, Box::new(unit)));
+        self
+    }
+
+    /// Resolve timezone abbreviations that name different timezones in
+    /// different regions (e.g., "CST") according to `policy`.
+    ///
+    /// Abbreviations registered with
+    /// [`timezone_abbreviation`](Self::timezone_abbreviation) are not subject
+    /// to the policy.
+    pub fn abbreviation_policy(mut self, policy: AbbreviationPolicy) -> Self {
+        self.options.abbreviation_policy = policy;
+        self
+    }
+
+    /// Interpret the built-in abbreviations of timezones observing daylight
+    /// saving time (e.g., "EST" and "EDT") according to `resolution`.
+    ///
+    /// Abbreviations registered with
+    /// [`timezone_abbreviation`](Self::timezone_abbreviation) or resolved
+    /// through a region with [`abbreviation_policy`](Self::abbreviation_policy)
+    /// always stand for a fixed offset.
+    pub fn zone_resolution(mut self, resolution: ZoneResolution) -> Self {
+        self.options.zone_resolution = resolution;
+        self
+    }
+
+    /// Resolve a local time that does not exist (in the gap of a transition
+    /// to daylight saving time) or exists twice (in the fold of a transition
+    /// back to standard time) according to `resolution`.
+    pub fn dst_resolution(mut self, resolution: DstResolution) -> Self {
+        self.options.dst_resolution = resolution;
+        self
+    }
+
+    /// Interpret timezones as in email dates (RFC 2822): "-0000" and the
+    /// single-letter military zones other than "Z" stand for UTC in an unknown
+    /// local timezone.
+    ///
+    /// The result is then in the [unknown](jiff::tz::TimeZone::unknown)
+    /// timezone, which has the offset of UTC.
+    pub fn rfc2822_zones(mut self, enabled: bool) -> Self {
+        self.options.rfc2822_zones = enabled;
+        self
+    }
+
+    /// Interpret the `TZ` environment variable as GNU `date` does, with the
+    /// grammar of a `TZ="..."` rule, to determine the default timezone of
+    /// inputs without a timezone rule.
+    ///
+    /// For instance, "UTC+5" is five hours west of UTC and an unknown timezone
+    /// name is UTC. The variable is read each time the current time is needed;
+    /// if it is not set, the system timezone is used.
+    pub fn tz_env(mut self, enabled: bool) -> Self {
+        self.options.tz_env = enabled;
+        self
+    }
+
+    /// Interpret a number (e.g., "45292.75") as a spreadsheet serial date: a
+    /// number of days since 1900-01-00, with the time of day as the
+    /// fractional part.
+    ///
+    /// As in Excel and Lotus 1-2-3, 1900 is treated as a leap year: day 60 is
+    /// the nonexistent 1900-02-29 and is rejected. In this mode, numbers are
+    /// no longer years or times of day (e.g., "1230").
+    pub fn serial_dates(mut self, enabled: bool) -> Self {
+        self.options.serial_dates = enabled;
+        self
+    }
+
+    /// Accept only unambiguous machine input: an epoch timestamp (e.g.,
+    /// "@1668421230"), an ISO 8601 date and time with a "Z" or an offset
+    /// (e.g., RFC 3339 "2022-11-14T10:20:30Z"), or an ISO 8601 date (e.g.,
+    /// "2022-11-14").
+    ///
+    /// Relative items, names of days, months and timezones, two-digit years,
+    /// times of day without an offset and all the extensions are rejected, so
+    /// that the result never depends on the current date and time or on a
+    /// locale. A date alone is the exception: it is midnight in the timezone
+    /// of the base date and time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parse_datetime::Parser;
+    ///
+    /// let parser = Parser::new().machine_input(true);
+    /// assert!(parser.parse_datetime("2022-11-14T10:20:30+01:00").is_ok());
+    /// assert!(parser.parse_datetime("2022-11-14T10:20:30").is_err());
+    /// assert!(parser.parse_datetime("tomorrow").is_err());
+    /// ```
+    pub fn machine_input(mut self, enabled: bool) -> Self {
+        self.options.machine_input = enabled;
+        self
+    }
+
+    /// Accept GPS and TAI timestamps (e.g., "GPS 1400000000"), converted to
+    /// UTC with the given table of leap seconds.
+    pub fn leap_seconds(mut self, leap_seconds: LeapSeconds) -> Self {
+        self.options.leap_seconds = Some(leap_seconds);
+        self
+    }
+
+    /// Count epoch timestamps from `origin` instead of from 1970-01-01 00:00:00
+    /// UTC, and accept them without their `@` (e.g., "694224000").
+    ///
+    /// This reads the timestamps of other schemes, such as the seconds since
+    /// 2001-01-01 of Cocoa's `NSDate` or the seconds since the start of a
+    /// simulation. A number alone is then a timestamp rather than a year, a
+    /// time of day or a serial date (e.g., "1230"). In machine input, the `@`
+    /// is still required.
+    pub fn epoch_origin(mut self, origin: Timestamp) -> Self {
+        self.options.epoch_origin = Some(origin);
+        self
+    }
+
+    /// Detect the unit of an integer epoch timestamp without a unit from its
+    /// magnitude, and accept an integer of at least 9 digits without its `@`
+    /// as such a timestamp.
+    ///
+    /// This reads timestamps of mixed sources, which count seconds,
+    /// milliseconds, microseconds or nanoseconds. An integer is taken to be a
+    /// number of seconds below 10^11 (up to the year 5138), of milliseconds
+    /// below 10^14 (from 1973-03-03), of microseconds below 10^17 and of
+    /// nanoseconds otherwise (e.g., "1690466034123" is 2023-07-27 13:53:54.123
+    /// UTC). An explicit unit (e.g., "@1690466034123ms") or a fraction turns
+    /// detection off for that value, and machine input is not affected.
+    pub fn detect_epoch_unit(mut self, enabled: bool) -> Self {
+        self.options.detect_epoch_unit = enabled;
+        self
+    }
+
+    /// Resolve a date without a year to its most recent occurrence not after
+    /// the base date, instead of to the year of the base date.
+    ///
+    /// This matches the timestamps printed by `ls -l`, which omits the year of
+    /// files modified in the last six months (e.g., "Mar  3 12:00") and the
+    /// time of day of older files (e.g., "Mar  3  2023"). With a base date of
+    /// 2025-01-15, "Dec 24 12:00" is 2024-12-24 12:00.
+    pub fn infer_recent_year(mut self, enabled: bool) -> Self {
+        self.options.infer_recent_year = enabled;
+        self
+    }
+
+    /// Accept durations in the compact notation of Go's `time.Duration` and
+    /// Prometheus (e.g., "5m", "2h45m" or "1.5h") as relative items.
+    ///
+    /// In this mode, a number followed by a unit letter is a duration rather
+    /// than a time and a military timezone (e.g., "5m" is five minutes from
+    /// now rather than 05:00 +12:00).
+    pub fn compact_durations(mut self, enabled: bool) -> Self {
+        self.options.compact_durations = enabled;
+        self
+    }
+
+    /// Accept "m" as a unit of time after a number (e.g., "5 m" or "+2 m
+    /// ago"), standing for minutes or months.
+    ///
+    /// By default, as in GNU `date`, "m" is the military timezone M, so "5 m"
+    /// is 05:00 +12:00. The other units are unambiguous: "min" and "mins" are
+    /// minutes, and "mo" and "mos" are months. In compact durations and in
+    /// combined relative items (e.g., "2h30m"), "m" is always minutes.
+    pub fn m_unit(mut self, unit: MUnit) -> Self {
+        self.options.m_unit = Some(unit);
+        self
+    }
+
+    /// Move a date by relative months and years according to `arithmetic`.
+    ///
+    /// By default, a relative month is the number of days in the month of the
+    /// date, so "jan 31 +1 month" overshoots to March 3rd (see
+    /// [`MonthArithmetic::GnuOvershoot`] for how this differs from GNU
+    /// `date`). With [`MonthArithmetic::Overflow`], the date is normalized
+    /// as GNU `date` does it. With [`MonthArithmetic::ClampToMonthEnd`], it is the last day of
+    /// February, as `add_months` functions of other libraries compute it, and
+    /// with [`MonthArithmetic::Error`], it is rejected.
+    pub fn month_arithmetic(mut self, arithmetic: MonthArithmetic) -> Self {
+        self.options.month_arithmetic = arithmetic;
+        self
+    }
+
+    /// Resolve "last", "this" or "next" followed by "day", "week", "month" or
+    /// "year" (e.g., "next month" or "last week") to the first day of that
+    /// period, at midnight unless a time of day is given. Weeks start on
+    /// Monday.
+    ///
+    /// By default, as in GNU `date`, such phrases shift the date by the
+    /// period, keeping the time of day (e.g., "next month" on June 15 is July
+    /// 15).
+    pub fn period_start(mut self, enabled: bool) -> Self {
+        self.options.period_start = enabled;
+        self
+    }
+
+    /// Accept work-log durations (e.g., "1w 2d 3h 30m") as relative items,
+    /// and convert them to plain durations with the given working time in
+    /// [`parse_work_log`](Self::parse_work_log).
+    ///
+    /// In a date string, weeks and days are calendar weeks and days (e.g.,
+    /// "2025-01-01 1w 2d" is 2025-01-10).
+    pub fn work_time(mut self, work_time: WorkTime) -> Self {
+        self.options.work_time = Some(work_time);
+        self
+    }
+
+    /// Reject inputs longer than `len` bytes with
+    /// [`ParseDateTimeError::InputTooLong`], before any parsing work.
+    ///
+    /// This bounds the work done on untrusted input, which is otherwise
+    /// unlimited.
+    pub fn max_input_length(mut self, len: usize) -> Self {
+        self.options.max_input_length = Some(len);
+        self
+    }
+
+    /// Reject inputs with more than `count` items (e.g., "2025-06-15", "10:00"
+    /// or "+1 day") with [`ParseDateTimeError::TooManyItems`], as soon as the
+    /// item after the last one allowed is reached.
+    pub fn max_items(mut self, count: usize) -> Self {
+        self.options.max_items = Some(count);
+        self
+    }
+
+    /// Abort parsing with [`ParseDateTimeError::BudgetExceeded`] once it has
+    /// taken `steps` steps.
+    ///
+    /// A step is spent on each attempt to parse a token, and on each byte of
+    /// whitespace or comment skipped before it. The grammar tries many
+    /// alternatives for each item, so the number of steps grows with both the
+    /// length of the input and its ambiguity: a common date string takes tens
+    /// to a few hundred steps, while some inputs (e.g., long comments) take
+    /// many more.
+    pub fn max_steps(mut self, steps: usize) -> Self {
+        self.options.max_steps = Some(steps);
+        self
+    }
+
+    /// Stop at the first or last instant supported by `jiff` (about the years
+    /// -9999 and 9999) instead of failing with
+    /// [`ParseDateTimeError::OutOfRange`] when a timestamp or a relative item
+    /// goes beyond them (e.g., "@99999999999999" or "9999-12-01 +1 month").
+    ///
+    /// GNU `date` accepts timestamps up to the year 2147485547, which `jiff`
+    /// cannot represent. Relative items after the one that saturated are
+    /// applied to the first or last instant (e.g., "9999-12-01 +1 month -1
+    /// day" is a day before the last instant).
+    pub fn saturating(mut self, enabled: bool) -> Self {
+        self.options.saturate = enabled;
+        self
+    }
+
+    /// Truncate results to the most specific unit given by the input (e.g.,
+    /// "10am" is 10:00:00.000, "+1 month" is the first day of the next month
+    /// at midnight, and "+2 hours" is at the beginning of the hour).
+    ///
+    /// The unit is reported by [`Precision::unit`](crate::Precision::unit),
+    /// for display.
+    pub fn truncate_to_precision(mut self, enabled: bool) -> Self {
+        self.options.truncate = enabled;
+        self
+    }
+
+    /// Round results to a multiple of `increment` units with `mode` (e.g., to
+    /// the nearest 15 minutes with `Unit::Minute`, 15 and
+    /// `RoundMode::HalfExpand`, or to the start of the hour with
+    /// `Unit::Hour`, 1 and `RoundMode::Floor`), after they are resolved and
+    /// truncated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `unit` is larger than a day, or if `increment` does not
+    /// divide the next larger unit evenly (e.g., 7 minutes), as with
+    /// [`ZonedRound`].
+    pub fn round(mut self, unit: Unit, increment: i64, mode: RoundMode) -> Self {
+        let round = ZonedRound::new()
+            .smallest(unit)
+            .increment(increment)
+            .mode(mode);
+        // Check the configuration once, rather than on each result.
+        assert!(
+            Zoned::default().round(round).is_ok(),
+            "rounding to {increment} {unit:?} is not supported"
+        );
+        self.options.round = Some(round);
+        self
+    }
+
+    /// Resolve parsed specifications through `hook` rather than directly.
+    ///
+    /// The hook is called between parsing and resolution. It may inspect or
+    /// log the [`Specification`], reject it with an error (e.g.,
+    /// [`ParseDateTimeError::Rejected`]), change its base date and time, and
+    /// adjust the result of [`Specification::resolve`] (e.g., clamp it to an
+    /// allowed window).
+    pub fn resolution_hook(
+        mut self,
+        hook: impl Fn(Specification<'_>) -> Result<Zoned, ParseDateTimeError> + Send + Sync + 'static,
+    ) -> Self {
+        self.options.resolution_hook = Some(Box::new(hook));
+        self
+    }
+
+    /// Call `callback` each time a lenient behavior of GNU `date` is applied
+    /// (e.g., an offset out of range is clamped), to monitor how often inputs
+    /// rely on them.
+    pub fn on_quirk(mut self, callback: impl Fn(Quirk) + Send + Sync + 'static) -> Self {
+        self.options.quirk_callback = Some(Box::new(callback));
+        self
+    }
+
+    /// Accept the words of the given locale (e.g., the meridiem markers "午前"
+    /// and "午後" in Japanese) in addition to the English ones.
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.options.locale = Some(locale);
+        self
+    }
+
+    /// Accept dates of the given calendar, written as a day, a month name and
+    /// a year (e.g., "15 Farvardin 1403").
+    #[cfg(feature = "calendars")]
+    pub fn calendar(mut self, calendar: Calendar) -> Self {
+        self.options.calendar = Some(calendar);
+        self
+    }
+
+    /// Accept Japanese era dates, in the kanji notation (e.g., "令和6年5月1日")
+    /// or the romanized one (e.g., "R6.5.1"), converted to the Gregorian
+    /// calendar. Era dates are rejected by default, as in GNU `date`.
+    #[cfg(feature = "calendars")]
+    pub fn eras(mut self, enabled: bool) -> Self {
+        self.options.eras = enabled;
+        self
+    }
+
+    /// Parse a time string and return the signed duration from now to the
+    /// absolute time of the string.
+    ///
+    /// See [`parse_deadline`](crate::parse_deadline).
+    pub fn parse_deadline<S: AsRef<str> + Clone>(
+        &self,
+        input: S,
+    ) -> Result<SignedDuration, ParseDateTimeError> {
+        items::parse_deadline(None, input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string and return the signed duration from the given
+    /// date to the absolute time of the string.
+    ///
+    /// See [`parse_deadline_at_date`](crate::parse_deadline_at_date).
+    pub fn parse_deadline_at_date<S: AsRef<str> + Clone>(
+        &self,
+        date: Zoned,
+        input: S,
+    ) -> Result<SignedDuration, ParseDateTimeError> {
+        items::parse_deadline(Some(date), input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse two time strings against the same base date and return the
+    /// signed duration from the time of `a` to that of `b`.
+    ///
+    /// See [`diff`](crate::diff).
+    pub fn diff<A: AsRef<str> + Clone, B: AsRef<str> + Clone>(
+        &self,
+        a: A,
+        b: B,
+        base: Zoned,
+    ) -> Result<SignedDuration, ParseDateTimeError> {
+        let a = self.parse_datetime_at_date(base.clone(), a)?;
+        let b = self.parse_datetime_at_date(base, b)?;
+        Ok(b.timestamp().duration_since(a.timestamp()))
+    }
+
+    /// Parse two time strings against the current local time and return the
+    /// calendar span from the time of `a` to that of `b`.
+    ///
+    /// See [`span_between`](crate::span_between).
+    pub fn span_between<A: AsRef<str>, B: AsRef<str>>(
+        &self,
+        a: A,
+        b: B,
+    ) -> Result<Span, ParseDateTimeError> {
+        items::span_between(None, a, b, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse two time strings against the given date and return the calendar
+    /// span from the time of `a` to that of `b`.
+    ///
+    /// See [`span_between_at_date`](crate::span_between_at_date).
+    pub fn span_between_at_date<A: AsRef<str>, B: AsRef<str>>(
+        &self,
+        date: Zoned,
+        a: A,
+        b: B,
+    ) -> Result<Span, ParseDateTimeError> {
+        items::span_between(Some(date), a, b, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse an RFC 3339 date and time, and nothing else.
+    ///
+    /// See [`parse_rfc3339`](crate::parse_rfc3339). Of the options, only the
+    /// maximum input length applies.
+    pub fn parse_rfc3339<S: AsRef<str>>(&self, input: S) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_rfc3339(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse an ASN.1 GeneralizedTime (e.g., "20240702143000Z"), and nothing
+    /// else.
+    ///
+    /// See [`parse_generalized_time`](crate::parse_generalized_time). Of the
+    /// options, only the maximum input length applies.
+    pub fn parse_generalized_time<S: AsRef<str>>(
+        &self,
+        input: S,
+    ) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_generalized_time(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse an ASN.1 UTCTime (e.g., "240702143000Z"), and nothing else.
+    ///
+    /// See [`parse_utc_time`](crate::parse_utc_time). Of the options, only the
+    /// maximum input length applies.
+    pub fn parse_utc_time<S: AsRef<str>>(&self, input: S) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_utc_time(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse an IMAP date and time (e.g., "01-Jul-2024 12:34:56 +0000"), and
+    /// nothing else.
+    ///
+    /// See [`parse_imap_date`](crate::parse_imap_date). Of the options, only
+    /// the maximum input length applies.
+    pub fn parse_imap_date<S: AsRef<str>>(&self, input: S) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_imap(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse the date of an email `Received:` header field, tolerating the
+    /// variants written by mail servers.
+    ///
+    /// See [`parse_received_date`](crate::parse_received_date). Of the
+    /// options, only the maximum input length applies.
+    pub fn parse_received_date<S: AsRef<str>>(
+        &self,
+        input: S,
+    ) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_received(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse an ISO 8601 date, time of day, date and time, duration, time
+    /// interval or recurring interval, and nothing else.
+    ///
+    /// See [`parse_iso8601`](crate::parse_iso8601). Of the options, only the
+    /// maximum input length applies.
+    pub fn parse_iso8601<S: AsRef<str>>(
+        &self,
+        input: S,
+    ) -> Result<Iso8601Value, ParseDateTimeError> {
+        items::parse_iso8601(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse an ISO 8601 date, time of day or date and time, with optional
+    /// RFC 9557 annotations, and classify it as TC39 Temporal does.
+    ///
+    /// See [`parse_temporal`](crate::parse_temporal). Of the options, only
+    /// the maximum input length applies.
+    pub fn parse_temporal<S: AsRef<str>>(
+        &self,
+        input: S,
+    ) -> Result<TemporalValue, ParseDateTimeError> {
+        items::parse_temporal(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse the value of an HTTP `Retry-After` header field, and return the
+    /// date and time it stands for.
+    ///
+    /// See [`parse_retry_after`](crate::parse_retry_after). Of the options,
+    /// only the maximum input length and [`tz_env`](Self::tz_env) apply.
+    pub fn parse_retry_after<S: AsRef<str>>(&self, input: S) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_retry_after(None, input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse the value of an HTTP `Retry-After` header field, and return the
+    /// date and time it stands for after `date`.
+    ///
+    /// See [`parse_retry_after_at_date`](crate::parse_retry_after_at_date). Of
+    /// the options, only the maximum input length applies.
+    pub fn parse_retry_after_at_date<S: AsRef<str>>(
+        &self,
+        date: Zoned,
+        input: S,
+    ) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_retry_after(Some(date), input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse an NTP timestamp (e.g., "e9d7a7b8.3d4f2a10"), in the era closest
+    /// to now.
+    ///
+    /// See [`parse_ntp_timestamp`](crate::parse_ntp_timestamp). Of the options,
+    /// only the maximum input length and [`tz_env`](Self::tz_env) apply.
+    pub fn parse_ntp_timestamp<S: AsRef<str>>(
+        &self,
+        input: S,
+    ) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_ntp(None, input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse an NTP timestamp (e.g., "e9d7a7b8.3d4f2a10"), in the era closest
+    /// to `date`.
+    ///
+    /// See [`parse_ntp_timestamp_at_date`](crate::parse_ntp_timestamp_at_date).
+    /// Of the options, only the maximum input length applies.
+    pub fn parse_ntp_timestamp_at_date<S: AsRef<str>>(
+        &self,
+        date: Zoned,
+        input: S,
+    ) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_ntp(Some(date), input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a work-log duration (e.g., "1w 2d 3h 30m") and return the working
+    /// time it amounts to, with weeks and days converted to hours with the
+    /// configured [`WorkTime`] (by default, a day of 8 hours and a week of 5
+    /// days).
+    pub fn parse_work_log<S: AsRef<str>>(
+        &self,
+        input: S,
+    ) -> Result<SignedDuration, ParseDateTimeError> {
+        items::parse_work_log(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string as [`parse_datetime`](Self::parse_datetime) does,
+    /// and tell whether its local time was skipped or repeated by a daylight
+    /// saving time transition, with the resolution that was not chosen.
+    pub fn parse_datetime_with_dst<S: AsRef<str> + Clone>(
+        &self,
+        input: S,
+    ) -> Result<(Zoned, DstAmbiguity), ParseDateTimeError> {
+        items::parse_with_dst(None, input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string as
+    /// [`parse_datetime_at_date`](Self::parse_datetime_at_date) does, and
+    /// tell whether its local time was skipped or repeated by a daylight
+    /// saving time transition, with the resolution that was not chosen.
+    pub fn parse_datetime_at_date_with_dst<S: AsRef<str> + Clone>(
+        &self,
+        date: Zoned,
+        input: S,
+    ) -> Result<(Zoned, DstAmbiguity), ParseDateTimeError> {
+        items::parse_with_dst(Some(date), input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string as [`parse_datetime`](Self::parse_datetime) does,
+    /// along with the name of its timezone, for formatting with `%Z`.
+    ///
+    /// The name is the abbreviation of a timezone item (e.g., "EST" for
+    /// "2025-01-01 10:00 est"), in upper case, or the abbreviation of the
+    /// timezone of a leading `TZ="..."` rule at the parsed time (e.g., "JST"
+    /// for `TZ="Asia/Tokyo"`, or "UTC" for `TZ="UTC+5"`). There is no name if
+    /// there is neither, or if the result has a numeric offset.
+    pub fn parse_datetime_with_zone<S: AsRef<str> + Clone>(
+        &self,
+        input: S,
+    ) -> Result<(Zoned, Option<String>), ParseDateTimeError> {
+        items::parse_with_zone(None, input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string as
+    /// [`parse_datetime_at_date`](Self::parse_datetime_at_date) does, along
+    /// with the name of its timezone, as
+    /// [`parse_datetime_with_zone`](Self::parse_datetime_with_zone) does.
+    pub fn parse_datetime_at_date_with_zone<S: AsRef<str> + Clone>(
+        &self,
+        date: Zoned,
+        input: S,
+    ) -> Result<(Zoned, Option<String>), ParseDateTimeError> {
+        items::parse_with_zone(Some(date), input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string as [`parse_datetime`](Self::parse_datetime) does,
+    /// along with the GNU quirks that were applied to it, in order, e.g., to
+    /// audit inputs or to reject those that rely on any.
+    ///
+    /// The quirks are also reported to the callback set with
+    /// [`on_quirk`](Self::on_quirk), if any.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parse_datetime::{Parser, Quirk};
+    ///
+    /// let (dt, quirks) = Parser::new()
+    ///     .parse_datetime_with_quirks("98-01-01 UTC")
+    ///     .unwrap();
+    /// assert_eq!(dt.year(), 1998);
+    /// assert_eq!(quirks, [Quirk::TwoDigitYear]);
+    /// ```
+    pub fn parse_datetime_with_quirks<S: AsRef<str> + Clone>(
+        &self,
+        input: S,
+    ) -> Result<(Zoned, Vec<Quirk>), ParseDateTimeError> {
+        items::parse_with_quirks(None, input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string as
+    /// [`parse_datetime_at_date`](Self::parse_datetime_at_date) does, along
+    /// with the GNU quirks that were applied to it, as
+    /// [`parse_datetime_with_quirks`](Self::parse_datetime_with_quirks) does.
+    pub fn parse_datetime_at_date_with_quirks<S: AsRef<str> + Clone>(
+        &self,
+        date: Zoned,
+        input: S,
+    ) -> Result<(Zoned, Vec<Quirk>), ParseDateTimeError> {
+        items::parse_with_quirks(Some(date), input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string as [`parse_datetime`](Self::parse_datetime) does,
+    /// and return its fields, its zone and the parts of it that were given by
+    /// the input as a [`ParsedDateTime`], which converts into the date and
+    /// time types of jiff, chrono or time.
+    pub fn parse_datetime_detailed<S: AsRef<str> + Clone>(
+        &self,
+        input: S,
+    ) -> Result<ParsedDateTime, ParseDateTimeError> {
+        items::parse_detailed(None, input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string as
+    /// [`parse_datetime_at_date`](Self::parse_datetime_at_date) does, and
+    /// return a [`ParsedDateTime`], as
+    /// [`parse_datetime_detailed`](Self::parse_datetime_detailed) does.
+    pub fn parse_datetime_at_date_detailed<S: AsRef<str> + Clone>(
+        &self,
+        date: Zoned,
+        input: S,
+    ) -> Result<ParsedDateTime, ParseDateTimeError> {
+        items::parse_detailed(Some(date), input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a recurring schedule, either days of the week with an optional
+    /// time of day (e.g., "every monday and thursday at 9am") or an interval
+    /// (e.g., "every 2 hours"). "every" may be omitted before a list of days
+    /// (e.g., "mon,wed,fri at 9am").
+    ///
+    /// See [`Schedule`] for iterating over its occurrences.
+    pub fn parse_schedule<S: AsRef<str>>(&self, input: S) -> Result<Schedule, ParseDateTimeError> {
+        items::parse_schedule(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse an ISO 8601 recurring interval (e.g., "R5/2024-01-01T00:00:00Z/P2W"
+    /// or "R/2024-01-01/P1D"), and nothing else.
+    ///
+    /// The interval is given by its start and duration, its start and end, or
+    /// its duration and end, as in [`parse_iso8601`](Self::parse_iso8601).
+    /// Dates and times without an offset are in the default timezone: that of
+    /// the `TZ` environment variable if [`tz_env`](Self::tz_env) is set, or
+    /// the system timezone. See [`RepeatingInterval`] for iterating over the
+    /// intervals.
+    pub fn parse_repeating_interval<S: AsRef<str>>(
+        &self,
+        input: S,
+    ) -> Result<RepeatingInterval, ParseDateTimeError> {
+        items::parse_repeating_interval(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse each line of `reader` as [`parse_datetime`](Self::parse_datetime)
+    /// does, e.g., the timestamps of a log file, one per line.
+    ///
+    /// The current date and time is taken once, so that all lines are
+    /// resolved against the same base. Errors carry the number of their line.
+    /// See [`Lines`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use parse_datetime::Parser;
+    ///
+    /// let input = "2025-01-01 10:00 UTC\nfoobar\n";
+    /// let results: Vec<_> = Parser::new().parse_lines(input.as_bytes()).collect();
+    /// assert_eq!(results[0].as_ref().unwrap().year(), 2025);
+    /// assert_eq!(results[1].as_ref().unwrap_err().line(), 2);
+    /// ```
+    pub fn parse_lines<R: BufRead>(&self, reader: R) -> Lines<'_, R> {
+        Lines::new(reader, None, &self.options)
+    }
+
+    /// Parse each line of `reader` as
+    /// [`parse_datetime_at_date`](Self::parse_datetime_at_date) does, against
+    /// the same `date`, as [`parse_lines`](Self::parse_lines) does.
+    pub fn parse_lines_at_date<R: BufRead>(&self, date: Zoned, reader: R) -> Lines<'_, R> {
+        Lines::new(reader, Some(date), &self.options)
+    }
+
+    /// Parse a time string once, to be resolved later against any number of
+    /// base dates and times.
+    ///
+    /// See [`parse`](crate::parse).
+    pub fn parse<S: AsRef<str>>(&self, input: S) -> Result<Parsed<'_>, ParseDateTimeError> {
+        items::parse_deferred(input.as_ref(), &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string and return a `Zoned` object representing the
+    /// absolute time of the string, relative to the current local time.
+    ///
+    /// See [`parse_datetime`](crate::parse_datetime).
+    pub fn parse_datetime<S: AsRef<str> + Clone>(
+        &self,
+        input: S,
+    ) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_at_local(input, &self.options).map_err(|e| e.into())
+    }
+
+    /// Parse a time string as [`parse_datetime`](Self::parse_datetime) does,
+    /// and convert the result to `target`.
+    ///
+    /// See [`parse_in_zone_then_convert`](crate::parse_in_zone_then_convert).
+    pub fn parse_in_zone_then_convert<S: AsRef<str> + Clone>(
+        &self,
+        input: S,
+        target: TimeZone,
+    ) -> Result<Zoned, ParseDateTimeError> {
+        Ok(self.parse_datetime(input)?.with_time_zone(target))
+    }
+
+    /// Parse a time string and return a `Zoned` object representing the
+    /// absolute time of the string, relative to the given date.
+    ///
+    /// See [`parse_datetime_at_date`](crate::parse_datetime_at_date).
+    pub fn parse_datetime_at_date<S: AsRef<str> + Clone>(
+        &self,
+        date: Zoned,
+        input: S,
+    ) -> Result<Zoned, ParseDateTimeError> {
+        items::parse_at_date(date, input, &self.options).map_err(|e| e.into())
+    }
+}
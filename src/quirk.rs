@@ -0,0 +1,113 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Lenient behaviors of GNU `date` that the parser reproduces.
+//!
+//! A callback set with [`Parser::on_quirk`](crate::Parser::on_quirk) is called
+//! each time one of them is applied, so that applications can monitor how
+//! often their inputs rely on them, and
+//! [`Parser::parse_datetime_with_quirks`](crate::Parser::parse_datetime_with_quirks)
+//! returns those applied by a single parse.
+
+use std::cell::RefCell;
+
+thread_local! {
+    /// The quirks applied by the parse in progress on this thread, if they are
+    /// being collected.
+    static COLLECTED: RefCell<Option<Vec<Quirk>>> = const { RefCell::new(None) };
+}
+
+/// Call `f` and return the quirks reported while it ran, in order.
+pub(crate) fn collect<T>(f: impl FnOnce() -> T) -> (T, Vec<Quirk>) {
+    let outer = Restore(COLLECTED.with(|collected| collected.replace(Some(Vec::new()))));
+    let result = f();
+    let quirks = COLLECTED.with(|collected| collected.take());
+    drop(outer);
+    (result, quirks.unwrap_or_default())
+}
+
+/// Restores the outer collector of a [`collect`] when dropped, so that it is
+/// restored even if the closure panics.
+struct Restore(Option<Vec<Quirk>>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        COLLECTED.with(|collected| collected.replace(self.0.take()));
+    }
+}
+
+/// Record a quirk for the [`collect`] in progress, if any.
+pub(crate) fn record(quirk: Quirk) {
+    COLLECTED.with(|collected| {
+        if let Some(quirks) = collected.borrow_mut().as_mut() {
+            quirks.push(quirk);
+        }
+    });
+}
+
+/// A lenient behavior of GNU `date`.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::{Arc, Mutex};
+///
+/// use jiff::Zoned;
+/// use parse_datetime::{Parser, Quirk};
+///
+/// let quirks = Arc::new(Mutex::new(Vec::new()));
+/// let parser = Parser::new().on_quirk({
+///     let quirks = quirks.clone();
+///     move |quirk| quirks.lock().unwrap().push(quirk)
+/// });
+///
+/// let base = "2025-01-31T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+/// let dt = parser.parse_datetime_at_date(base, "+1 month").unwrap();
+/// assert_eq!(dt.strftime("%F").to_string(), "2025-03-03");
+/// assert_eq!(*quirks.lock().unwrap(), [Quirk::MonthOverflow]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Quirk {
+    /// An offset out of range was clamped: the hours of the offset of a
+    /// `TZ="..."` rule to 24, and its minutes and seconds to 59 (e.g.,
+    /// "UTC+30"), or an offset of more than 24 hours to 23 hours, the
+    /// difference being added to the time of day.
+    OffsetClamped,
+    /// A relative month moved the date by the number of days in its month,
+    /// and so to a different day of the month (e.g., "jan 31 +1 month" is
+    /// March 3rd), or, with [`MonthArithmetic::Overflow`], a relative year
+    /// moved February 29th to March 1st.
+    ///
+    /// [`MonthArithmetic::Overflow`]: crate::MonthArithmetic::Overflow
+    MonthOverflow,
+    /// The timezone of a `TZ="..."` rule is not known, and UTC was used
+    /// instead.
+    UnknownTimeZone,
+    /// A two-digit year was taken as a year of the 20th or 21st century: 69
+    /// to 99 as 1969 to 1999, and 00 to 68 as 2000 to 2068 (e.g., "98-01-01"
+    /// is January 1st, 1998).
+    TwoDigitYear,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{collect, record, Quirk};
+
+    #[test]
+    fn collect_nested() {
+        let (_, quirks) = collect(|| {
+            record(Quirk::MonthOverflow);
+            let (_, inner) = collect(|| record(Quirk::OffsetClamped));
+            assert_eq!(inner, [Quirk::OffsetClamped]);
+            let _ = std::panic::catch_unwind(|| {
+                collect(|| {
+                    record(Quirk::UnknownTimeZone);
+                    panic!("parse failed");
+                })
+            });
+            record(Quirk::TwoDigitYear);
+        });
+        assert_eq!(quirks, [Quirk::MonthOverflow, Quirk::TwoDigitYear]);
+    }
+}
@@ -0,0 +1,44 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Custom relative units, such as "sprint" or "pay period".
+//!
+//! A unit registered with [`Parser::relative_unit`](crate::Parser::relative_unit)
+//! is used like the built-in units, with an optional number, "next", "last" or
+//! "ago" (e.g., "2 sprints", "next sprint" or "1 sprint ago"), and an "s"
+//! suffix on its name is ignored. The [`RelativeUnit`] decides how a number of
+//! units moves a date.
+
+use jiff::Zoned;
+
+/// A unit of relative items defined by the application.
+///
+/// The trait is implemented for functions taking the date and time and the
+/// number of units.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::{ToSpan, Zoned};
+/// use parse_datetime::Parser;
+///
+/// // A sprint lasts two weeks.
+/// let parser = Parser::new().relative_unit("sprint", |dt: &Zoned, n: i32| {
+///     dt.checked_add((2 * i64::from(n)).weeks()).ok()
+/// });
+///
+/// let base = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+/// let dt = parser.parse_datetime_at_date(base, "2 sprints ago").unwrap();
+/// assert_eq!(dt.strftime("%F %T").to_string(), "2025-05-18 10:00:00");
+/// ```
+pub trait RelativeUnit: Send + Sync {
+    /// Returns the date and time `count` units after `dt` (before it, if
+    /// `count` is negative), or `None` if it is out of range.
+    fn add(&self, dt: &Zoned, count: i32) -> Option<Zoned>;
+}
+
+impl<F: Fn(&Zoned, i32) -> Option<Zoned> + Send + Sync> RelativeUnit for F {
+    fn add(&self, dt: &Zoned, count: i32) -> Option<Zoned> {
+        self(dt, count)
+    }
+}
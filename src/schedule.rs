@@ -0,0 +1,347 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Recurring schedules, such as "every friday at 9am" or "every 2 hours".
+//!
+//! [`Parser::parse_schedule`](crate::Parser::parse_schedule) reads an "every"
+//! phrase, with an interval or weekdays and an optional time of day (e.g.,
+//! "every 2 hours" or "every monday and thursday at 9am"), and
+//! [`Parser::parse_repeating_interval`](crate::Parser::parse_repeating_interval)
+//! an ISO 8601 recurring interval (e.g., "R5/2024-01-01T00:00:00Z/P1D"). Both
+//! return the occurrences after a start date; neither is accepted by
+//! [`Parser::parse_datetime`](crate::Parser::parse_datetime).
+
+use jiff::{
+    civil::{Time, Weekday},
+    Span, Zoned,
+};
+
+/// A recurring schedule.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::Zoned;
+/// use parse_datetime::Parser;
+///
+/// let schedule = Parser::new().parse_schedule("every friday at 9am").unwrap();
+/// let start = "2025-06-15T10:00:00+00:00[UTC]".parse::<Zoned>().unwrap();
+/// let next: Vec<_> = schedule
+///     .occurrences(&start)
+///     .take(2)
+///     .map(|z| z.strftime("%F %T").to_string())
+///     .collect();
+/// assert_eq!(next, ["2025-06-20 09:00:00", "2025-06-27 09:00:00"]);
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Schedule {
+    /// On the given days of the week (e.g., "every monday and thursday"), at
+    /// the given time of day, or at midnight if none is given.
+    Weekly {
+        /// The days of the week, in the order in which they were written.
+        days: Vec<Weekday>,
+        /// The time of day.
+        time: Option<Time>,
+    },
+    /// At a fixed interval (e.g., "every 2 hours" or "every month").
+    Interval(Span),
+}
+
+impl Schedule {
+    /// Return an iterator over the occurrences of the schedule after `start`,
+    /// in the timezone of `start`.
+    ///
+    /// The occurrences of an interval are `start` plus a multiple of the
+    /// interval, so that "every month" from January 31 falls on the last day of
+    /// the shorter months without drifting.
+    pub fn occurrences(&self, start: &Zoned) -> Occurrences {
+        Occurrences {
+            schedule: self.clone(),
+            start: start.clone(),
+            n: 0,
+            remaining: None,
+        }
+    }
+}
+
+/// An ISO 8601 recurring time interval (e.g., "R5/2024-01-01T00:00:00Z/P2W").
+///
+/// # Examples
+///
+/// ```
+/// use parse_datetime::Parser;
+///
+/// let interval = Parser::new()
+///     .parse_repeating_interval("R3/2024-01-01T00:00:00Z/P2W")
+///     .unwrap();
+/// let starts: Vec<_> = interval
+///     .occurrences()
+///     .map(|z| z.strftime("%F").to_string())
+///     .collect();
+/// assert_eq!(starts, ["2024-01-01", "2024-01-15", "2024-01-29"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RepeatingInterval {
+    /// The start of the first interval, or the end of the last one if the
+    /// intervals are given by their duration and end.
+    anchor: Zoned,
+    span: Span,
+    repetitions: Option<u32>,
+    from_end: bool,
+}
+
+impl RepeatingInterval {
+    pub(crate) fn new(anchor: Zoned, span: Span, repetitions: Option<u32>, from_end: bool) -> Self {
+        Self {
+            anchor,
+            span,
+            repetitions,
+            from_end,
+        }
+    }
+
+    /// Returns the duration of each interval, which is positive.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// Returns the number of repetitions, or `None` if they are unbounded.
+    pub fn repetitions(&self) -> Option<u32> {
+        self.repetitions
+    }
+
+    /// Return an iterator over the starts of the intervals.
+    ///
+    /// The starts are the first start plus a multiple of the duration, as
+    /// for [`Schedule::Interval`]. If the intervals are given by their
+    /// duration and the end of the last one (e.g., "R3/P1D/2024-01-10"), the
+    /// starts go back in time from the last one.
+    pub fn occurrences(&self) -> Occurrences {
+        let (span, n) = if self.from_end {
+            (self.span.negate(), 0)
+        } else {
+            // The first occurrence is the start itself.
+            (self.span, -1)
+        };
+        Occurrences {
+            schedule: Schedule::Interval(span),
+            start: self.anchor.clone(),
+            n,
+            remaining: self.repetitions,
+        }
+    }
+}
+
+/// An iterator over the occurrences of a [`Schedule`].
+///
+/// Created by [`Schedule::occurrences`] and
+/// [`RepeatingInterval::occurrences`]. The iterator ends after the
+/// repetitions of a recurring interval, or when an occurrence would be out of
+/// the range supported by [`Zoned`].
+#[derive(Clone, Debug)]
+pub struct Occurrences {
+    schedule: Schedule,
+    start: Zoned,
+    /// The number of occurrences returned for an interval, or the number of
+    /// days after `start` examined for a weekly schedule.
+    n: i64,
+    /// The number of occurrences left, if bounded.
+    remaining: Option<u32>,
+}
+
+impl Iterator for Occurrences {
+    type Item = Zoned;
+
+    fn next(&mut self) -> Option<Zoned> {
+        if let Some(remaining) = &mut self.remaining {
+            *remaining = remaining.checked_sub(1)?;
+        }
+        match &self.schedule {
+            Schedule::Weekly { days, time } => {
+                // An occurrence falls within the next 8 days, including the day
+                // of the start, whose occurrence may already be past.
+                let tz = self.start.time_zone();
+                let time = time.unwrap_or(Time::midnight());
+                for _ in 0..8 {
+                    let date = self
+                        .start
+                        .date()
+                        .checked_add(Span::new().try_days(self.n).ok()?)
+                        .ok()?;
+                    self.n += 1;
+                    if !days.contains(&date.weekday()) {
+                        continue;
+                    }
+                    let zoned = date.to_datetime(time).to_zoned(tz.clone()).ok()?;
+                    if zoned > self.start {
+                        return Some(zoned);
+                    }
+                }
+                None
+            }
+            Schedule::Interval(span) => {
+                self.n += 1;
+                let span = span.checked_mul(self.n).ok()?;
+                self.start.checked_add(span).ok()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::Zoned;
+
+    use crate::{ParseDateTimeError, Parser};
+
+    fn occurrences(input: &str, start: &str, n: usize) -> Vec<String> {
+        let start = start.parse::<Zoned>().unwrap();
+        Parser::new()
+            .parse_schedule(input)
+            .unwrap()
+            .occurrences(&start)
+            .take(n)
+            .map(|z| z.strftime("%F %T %:z").to_string())
+            .collect()
+    }
+
+    #[test]
+    fn weekly() {
+        // The occurrence on the day of the start is included only if it is
+        // after the start.
+        assert_eq!(
+            occurrences("every sunday at 9am", "2025-06-15T08:00:00+00:00[UTC]", 2),
+            ["2025-06-15 09:00:00 +00:00", "2025-06-22 09:00:00 +00:00"]
+        );
+        assert_eq!(
+            occurrences("every sunday at 9am", "2025-06-15T09:00:00+00:00[UTC]", 1),
+            ["2025-06-22 09:00:00 +00:00"]
+        );
+        assert_eq!(
+            occurrences(
+                "every tuesday and friday",
+                "2025-06-15T10:00:00+00:00[UTC]",
+                3
+            ),
+            [
+                "2025-06-17 00:00:00 +00:00",
+                "2025-06-20 00:00:00 +00:00",
+                "2025-06-24 00:00:00 +00:00"
+            ]
+        );
+        assert_eq!(
+            occurrences("Mon,Wed,Fri 9am", "2025-06-15T10:00:00+00:00[UTC]", 3),
+            [
+                "2025-06-16 09:00:00 +00:00",
+                "2025-06-18 09:00:00 +00:00",
+                "2025-06-20 09:00:00 +00:00"
+            ]
+        );
+        // The time of day is kept across a transition to daylight saving time.
+        assert_eq!(
+            occurrences(
+                "every sunday at 12:00",
+                "2025-03-02T12:00:00-05:00[America/New_York]",
+                2
+            ),
+            ["2025-03-09 12:00:00 -04:00", "2025-03-16 12:00:00 -04:00"]
+        );
+    }
+
+    #[test]
+    fn interval() {
+        assert_eq!(
+            occurrences("every 90 minutes", "2025-06-15T23:00:00+00:00[UTC]", 2),
+            ["2025-06-16 00:30:00 +00:00", "2025-06-16 02:00:00 +00:00"]
+        );
+        // Months are added to the start, so the day of the month does not
+        // drift after a shorter month.
+        assert_eq!(
+            occurrences("every month", "2025-01-31T10:00:00+00:00[UTC]", 3),
+            [
+                "2025-02-28 10:00:00 +00:00",
+                "2025-03-31 10:00:00 +00:00",
+                "2025-04-30 10:00:00 +00:00"
+            ]
+        );
+        // The iterator ends at the end of the supported range.
+        assert_eq!(
+            occurrences("every 1000 years", "8999-06-01T00:00:00+00:00[UTC]", 3),
+            ["9999-06-01 00:00:00 +00:00"]
+        );
+    }
+
+    #[test]
+    fn repeating_interval() {
+        let starts = |input: &str, n: usize| -> Vec<String> {
+            Parser::new()
+                .parse_repeating_interval(input)
+                .unwrap()
+                .occurrences()
+                .take(n)
+                .map(|z| z.strftime("%F %T %:z").to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            starts("R3/2024-01-01T00:00:00Z/P2W", 10),
+            [
+                "2024-01-01 00:00:00 +00:00",
+                "2024-01-15 00:00:00 +00:00",
+                "2024-01-29 00:00:00 +00:00"
+            ]
+        );
+        assert_eq!(
+            starts("R/2024-01-31T10:00+01:00/P1M", 3),
+            [
+                "2024-01-31 10:00:00 +01:00",
+                "2024-02-29 10:00:00 +01:00",
+                "2024-03-31 10:00:00 +01:00"
+            ]
+        );
+        // The duration of an interval given by its start and end.
+        assert_eq!(
+            starts("R2/2024-01-01T10:00Z/12:30", 10),
+            ["2024-01-01 10:00:00 +00:00", "2024-01-01 12:30:00 +00:00"]
+        );
+        // Intervals given by their duration and end go back in time.
+        assert_eq!(
+            starts("R2/P1D/2024-01-10T00:00Z", 10),
+            ["2024-01-09 00:00:00 +00:00", "2024-01-08 00:00:00 +00:00"]
+        );
+        assert!(starts("R0/2024-01-01T00:00Z/P1D", 10).is_empty());
+
+        let interval = Parser::new()
+            .parse_repeating_interval("R5/2024-01-01T00:00Z/PT1H")
+            .unwrap();
+        assert_eq!(interval.repetitions(), Some(5));
+        assert_eq!(interval.span().get_hours(), 1);
+    }
+
+    #[test]
+    fn repeating_interval_errors() {
+        let parser = Parser::new();
+        for input in ["2024-01-01/P1D", "R5/P1D", "every 2 hours"] {
+            assert!(
+                matches!(
+                    parser.parse_repeating_interval(input),
+                    Err(ParseDateTimeError::Parse(_))
+                ),
+                "{input}"
+            );
+        }
+        for input in [
+            "R/2024-01-10T00:00Z/2024-01-01T00:00Z",
+            "R5/2024-01-01/PT0S",
+        ] {
+            assert!(
+                matches!(
+                    parser.parse_repeating_interval(input),
+                    Err(ParseDateTimeError::Resolve(_))
+                ),
+                "{input}"
+            );
+        }
+    }
+}
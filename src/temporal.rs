@@ -0,0 +1,57 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Values classified as the types of TC39 Temporal.
+//!
+//! A [`TemporalValue`] is returned by
+//! [`parse_temporal`](crate::parse_temporal), which tells a date, a time of
+//! day, a date and time and a date and time in a timezone apart, rather than
+//! resolving them all to a [`Zoned`].
+
+use jiff::{
+    civil::{Date, DateTime, Time},
+    Zoned,
+};
+
+/// A date, time of day, date and time or zoned date and time, after the
+/// types of TC39 Temporal (`Temporal.PlainDate`, `Temporal.PlainTime`,
+/// `Temporal.PlainDateTime` and `Temporal.ZonedDateTime`).
+///
+/// # Examples
+///
+/// ```
+/// use jiff::civil::date;
+/// use parse_datetime::{parse_temporal, TemporalValue};
+///
+/// assert!(matches!(
+///     parse_temporal("2024-03-10").unwrap(),
+///     TemporalValue::PlainDate(d) if d == date(2024, 3, 10)
+/// ));
+/// assert!(matches!(
+///     parse_temporal("2024-03-10T10:00").unwrap(),
+///     TemporalValue::PlainDateTime(_)
+/// ));
+///
+/// let TemporalValue::ZonedDateTime(zoned) =
+///     parse_temporal("2024-03-10T10:00[America/New_York]").unwrap()
+/// else {
+///     unreachable!()
+/// };
+/// assert_eq!(zoned.offset().seconds(), -4 * 3600);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum TemporalValue {
+    /// A date without a time of day or a timezone (e.g., "2024-03-10").
+    PlainDate(Date),
+    /// A time of day without a date or an offset (e.g., "10:00" or "T1000").
+    PlainTime(Time),
+    /// A date and time of day without an offset or a timezone (e.g.,
+    /// "2024-03-10T10:00").
+    PlainDateTime(DateTime),
+    /// A date and time of day with an offset (e.g., "2024-03-10T10:00Z"), in
+    /// the timezone of that offset, or in the timezone of a time zone
+    /// annotation (e.g., "2024-03-10T10:00[Europe/Paris]"). A date with a
+    /// time zone annotation is at the start of its day.
+    ZonedDateTime(Zoned),
+}
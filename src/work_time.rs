@@ -0,0 +1,49 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+//! Working time, for work-log durations such as "1w 2d 3h 30m".
+//!
+//! [`Parser::work_time`](crate::Parser::work_time) enables work-log durations
+//! in date strings, where weeks and days are calendar ones, and sets the
+//! [`WorkTime`] with which
+//! [`Parser::parse_work_log`](crate::Parser::parse_work_log) converts them to
+//! a plain number of working hours.
+
+use jiff::SignedDuration;
+
+/// The length of a working day and of a working week, as used by work-log
+/// tools (e.g., Jira) to convert days and weeks to hours.
+///
+/// The default is a day of 8 hours and a week of 5 days.
+///
+/// # Examples
+///
+/// ```
+/// use jiff::SignedDuration;
+/// use parse_datetime::{Parser, WorkTime};
+///
+/// let parser = Parser::new().work_time(WorkTime::new(SignedDuration::from_mins(450), 5));
+/// assert_eq!(
+///     parser.parse_work_log("1d 2h").unwrap(),
+///     SignedDuration::from_mins(570),
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WorkTime {
+    pub(crate) day: SignedDuration,
+    pub(crate) days_per_week: u8,
+}
+
+impl WorkTime {
+    /// Create a working time with days of length `day` and weeks of
+    /// `days_per_week` days.
+    pub fn new(day: SignedDuration, days_per_week: u8) -> Self {
+        Self { day, days_per_week }
+    }
+}
+
+impl Default for WorkTime {
+    fn default() -> Self {
+        Self::new(SignedDuration::from_hours(8), 5)
+    }
+}
@@ -0,0 +1,29 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+#![cfg(feature = "compat-tests")]
+
+use parse_datetime::{compat, parse_datetime_at_date};
+
+#[test]
+fn gnu_compat() {
+    let mut failures = Vec::new();
+    for case in compat::cases() {
+        let result = parse_datetime_at_date(case.base.clone(), case.input);
+        if case.matches(&result) == case.known_difference {
+            let expected = case.expected.unwrap_or("an error");
+            failures.push(if case.known_difference {
+                format!(
+                    "line {}: {:?} is no longer a known difference, remove its \"!\"",
+                    case.line, case.input
+                )
+            } else {
+                format!(
+                    "line {}: {:?} gives {result:?}, expected {expected}",
+                    case.line, case.input
+                )
+            });
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -71,6 +71,11 @@ use common::{check_absolute, check_relative};
 #[case::short_month_in_the_middle_sept("14 sept 2022", "2022-09-14 00:00:00+00:00")]
 #[case::short_month_in_the_middle_oct("14 oct 2022", "2022-10-14 00:00:00+00:00")]
 #[case::short_month_in_the_middle_dec("14 dec 2022", "2022-12-14 00:00:00+00:00")]
+#[case::short_month_with_period("14 nov. 2022", "2022-11-14 00:00:00+00:00")]
+#[case::short_month_at_start_with_period("Sept. 14, 2022", "2022-09-14 00:00:00+00:00")]
+#[case::weekday_and_month_with_period("Mon. Nov. 14 2022", "2022-11-14 00:00:00+00:00")]
+#[case::weekday_tues_with_period("Tues. Nov. 15 2022", "2022-11-15 00:00:00+00:00")]
+#[case::weekday_thurs("Thurs Nov 17 2022", "2022-11-17 00:00:00+00:00")]
 fn test_absolute_date_numeric(#[case] input: &str, #[case] expected: &str) {
     check_absolute(input, expected);
 }
@@ -106,9 +106,6 @@ fn test_time_correction_with_overflow(#[case] input: &str, #[case] expected: &st
 }
 
 #[rstest]
-#[case("24:00:00")]
-#[case("23:60:00")]
-#[case("23:59:60")]
 #[case("13:00:00am")]
 #[case("13:00:00pm")]
 #[case("00:00:00am")]
@@ -122,9 +119,23 @@ fn test_time_correction_with_overflow(#[case] input: &str, #[case] expected: &st
 #[case("23:59:59+00:00:00")]
 fn test_time_invalid(#[case] input: &str) {
     let result = parse_datetime::parse_datetime(input);
-    assert_eq!(
-        result,
-        Err(parse_datetime::ParseDateTimeError::InvalidInput),
+    assert!(
+        matches!(result, Err(parse_datetime::ParseDateTimeError::Parse(_))),
         "Input string '{input}' did not produce an error when parsing"
     );
 }
+
+#[rstest]
+#[case("24:00:00")]
+#[case("23:60:00")]
+#[case("23:59:60")]
+fn test_time_out_of_range(#[case] input: &str) {
+    let result = parse_datetime::parse_datetime(input);
+    assert!(
+        matches!(
+            result,
+            Err(parse_datetime::ParseDateTimeError::InvalidTime(_))
+        ),
+        "Input string '{input}' did not produce an invalid time error"
+    );
+}
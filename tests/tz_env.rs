@@ -0,0 +1,47 @@
+// For the full copyright and license information, please view the LICENSE
+// file that was distributed with this source code.
+
+use std::env;
+
+use parse_datetime::Parser;
+
+// All cases are in a single test, as they set the `TZ` environment variable.
+#[test]
+fn tz_env() {
+    let parser = Parser::new().tz_env(true);
+    for (tz, input, expected) in [
+        ("UTC0", "2025-01-02 03:04:05", "2025-01-02 03:04:05+00:00"),
+        ("UTC+5", "2025-01-02 03:04:05", "2025-01-02 03:04:05-05:00"),
+        ("JST-9", "2025-01-02 03:04:05", "2025-01-02 03:04:05+09:00"),
+        (
+            "EST5EDT,M3.2.0,M11.1.0",
+            "2025-07-01 12:00",
+            "2025-07-01 12:00:00-04:00",
+        ),
+        (
+            ":America/New_York",
+            "2025-01-02 03:04:05",
+            "2025-01-02 03:04:05-05:00",
+        ),
+        // An unknown timezone is UTC, as in GNU date.
+        (
+            "Unknown/Zone",
+            "2025-01-02 03:04:05",
+            "2025-01-02 03:04:05+00:00",
+        ),
+        // A timezone rule takes precedence over the environment.
+        (
+            "UTC+5",
+            r#"TZ="Asia/Tokyo" 2025-01-02 03:04:05"#,
+            "2025-01-02 03:04:05+09:00",
+        ),
+    ] {
+        env::set_var("TZ", tz);
+        let parsed = parser.parse_datetime(input).unwrap();
+        assert_eq!(
+            parsed.strftime("%Y-%m-%d %H:%M:%S%:z").to_string(),
+            expected,
+            "TZ={tz} {input}"
+        );
+    }
+}